@@ -0,0 +1,100 @@
+//! Parser benchmarks, run with `cargo bench --features bench`.
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use sql_helper::ansi::parser::options::{ParserMode, ParserOptions};
+use sql_helper::ansi::parser::{parse_script, parse_script_with, parse_statement};
+
+const DATA_TYPES: &[&str] = &[
+    "INT",
+    "CHARACTER VARYING(255)",
+    "TIMESTAMP(6) WITH TIME ZONE",
+    "DOUBLE PRECISION",
+    "BLOB(2K)",
+    "DECIMAL(10, 2)",
+    "VARCHAR(20 OCTETS)",
+    "BOOLEAN",
+];
+
+const STATEMENTS: &[&str] = &[
+    "CREATE SCHEMA catalog_name.schema_name AUTHORIZATION authorization_name;",
+    "CREATE TABLE sch.tb (id INT GENERATED ALWAYS AS IDENTITY (START WITH 1), name VARCHAR(20));",
+    "ALTER TABLE sch.tb ALTER COLUMN name SET DATA TYPE CHARACTER VARYING(40);",
+    "ALTER TABLE sch.tb ADD COLUMN created_at TIMESTAMP WITHOUT TIME ZONE;",
+    "DROP TABLE sch.tb CASCADE;",
+    "DROP SCHEMA catalog_name.schema_name RESTRICT;",
+];
+
+/// Returns a `CREATE TABLE` statement with the received number of columns.
+fn wide_create_table(columns: usize) -> String {
+    let columns = (0..columns)
+        .map(|i| format!("column_{i} {}", DATA_TYPES[i % DATA_TYPES.len()]))
+        .collect::<Vec<_>>();
+
+    format!("CREATE TABLE sch.wide_table ({})", columns.join(", "))
+}
+
+/// Returns a script with the received number of statements.
+fn long_script(statements: usize) -> String {
+    (0..statements)
+        .map(|i| STATEMENTS[i % STATEMENTS.len()])
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn bench_wide_create_table(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wide_create_table");
+
+    for columns in [10, 100, 500] {
+        let sql = wide_create_table(columns);
+        group.throughput(Throughput::Bytes(sql.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(columns), &sql, |b, sql| {
+            b.iter(|| parse_statement(black_box(sql.as_bytes())).unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_long_script(c: &mut Criterion) {
+    let mut group = c.benchmark_group("long_script");
+
+    for statements in [10, 100, 1000] {
+        let sql = long_script(statements);
+        group.throughput(Throughput::Bytes(sql.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(statements), &sql, |b, sql| {
+            b.iter(|| parse_script(black_box(sql)).unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+/// Measures the checks enabled by the parser options (nesting depth,
+/// statement length, reserved words and terminators) on top of the parsing.
+fn bench_script_options(c: &mut Criterion) {
+    let mut group = c.benchmark_group("script_options");
+    let sql = long_script(100);
+    let options = ParserOptions::new()
+        .with_mode(ParserMode::Strict)
+        .with_max_nesting_depth(2)
+        .with_max_statement_length(1024)
+        .with_require_semicolon(true);
+
+    group.throughput(Throughput::Bytes(sql.len() as u64));
+    group.bench_function("default", |b| {
+        b.iter(|| parse_script_with(black_box(&sql), &ParserOptions::new()).unwrap());
+    });
+    group.bench_function("strict", |b| {
+        b.iter(|| parse_script_with(black_box(&sql), &options).unwrap());
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_wide_create_table,
+    bench_long_script,
+    bench_script_options
+);
+criterion_main!(benches);
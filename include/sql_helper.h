@@ -0,0 +1,61 @@
+#ifndef SQL_HELPER_H
+#define SQL_HELPER_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Result of a validation [(1)].
+//
+// [(1)]: sql_helper_validate
+typedef enum SqlHelperStatus {
+  // All the statements are valid.
+  SQL_HELPER_STATUS_OK = 0,
+  // A statement can't be parsed.
+  SQL_HELPER_STATUS_INVALID_STATEMENT = 1,
+  // The input is a null pointer or is not valid UTF-8.
+  SQL_HELPER_STATUS_INVALID_INPUT = 2,
+} SqlHelperStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Parses the script, returning its statements as a JSON array, where each
+// statement is in the versioned JSON format [(1)].
+//
+// Returns a null pointer if the input is invalid or any statement can't be
+// parsed.
+//
+// # Safety
+// `sql` must be null or point to a NUL-terminated string that is valid for
+// reads during the call.
+//
+// [(1)]: crate::ansi::json
+char *sql_helper_parse_json(const char *sql);
+
+// Validates the script.
+//
+// If a statement can't be parsed, its 1-based line and column are written to
+// `line` and `column`, unless they are null.
+//
+// # Safety
+// `sql` must be null or point to a NUL-terminated string that is valid for
+// reads during the call. `line` and `column` must be null or valid for
+// writes.
+enum SqlHelperStatus sql_helper_validate(const char *sql, size_t *line, size_t *column);
+
+// Releases a string returned by this library. Null pointers are ignored.
+//
+// # Safety
+// `string` must be null or a pointer returned by this library that was not
+// released yet.
+void sql_helper_free_string(char *string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* SQL_HELPER_H */
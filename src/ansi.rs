@@ -1,17 +1,64 @@
 use std::fmt;
 
+use crate::ansi::ast::alter_table::{AlterColumnAction, AlterTable, AlterTableAction};
+use crate::ansi::ast::call::Call;
+use crate::ansi::ast::close::Close;
 use crate::ansi::ast::create_schema::CreateSchema;
 use crate::ansi::ast::create_table::CreateTable;
+use crate::ansi::ast::delete::DeletePositioned;
 use crate::ansi::ast::drop_schema::DropSchema;
 use crate::ansi::ast::drop_table::DropTable;
+use crate::ansi::ast::explain::Explain;
+use crate::ansi::ast::fetch::Fetch;
+use crate::ansi::ast::grant::GrantPrivilege;
+use crate::ansi::ast::open::Open;
+use crate::ansi::ast::return_statement::Return;
+use crate::ansi::ast::revoke::RevokePrivilege;
+use crate::ansi::ast::update::UpdatePositioned;
+use crate::ansi::normalize::{NormalizeOptions, Normalizer};
+use crate::ansi::parameterize::ParameterizedStatement;
+use crate::ansi::redact::Redactor;
+use crate::ansi::visitor::{DialectIdents, VisitorMut};
+use crate::common::dialect::Dialect;
 
 pub mod ast;
+pub mod codegen;
+pub mod completion;
+pub mod cst;
+pub mod dependencies;
+pub mod dialect;
+pub mod dictionary;
+pub mod diff;
+pub mod format;
+#[cfg(feature = "sqlparser")]
+pub mod interop;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod keywords;
+pub mod lexer;
+pub mod lint;
+pub mod locate;
+pub mod metrics;
+pub mod migration;
+pub mod nodes;
+pub mod normalize;
+pub mod objects;
+pub mod parameterize;
 pub mod parser;
+pub mod policy;
+pub mod redact;
+pub mod rename;
+pub mod script;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+pub mod validate;
+pub mod visitor;
 
 /// `ANSI` ast [(1)].
 ///
 /// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#SQL-executable-statement
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Statement {
     /// `CREATE SCHEMA` statement.
     CreateSchema(CreateSchema),
@@ -21,6 +68,206 @@ pub enum Statement {
     DropTable(DropTable),
     /// CREATE TABLE statement
     CreateTable(CreateTable),
+    /// `ALTER TABLE` statement.
+    AlterTable(AlterTable),
+    /// `OPEN` statement.
+    Open(Open),
+    /// `FETCH` statement.
+    Fetch(Fetch),
+    /// `CLOSE` statement.
+    Close(Close),
+    /// `CALL` statement.
+    Call(Call),
+    /// `RETURN` statement.
+    Return(Return),
+    /// Positioned `UPDATE` statement.
+    UpdatePositioned(UpdatePositioned),
+    /// Positioned `DELETE` statement.
+    DeletePositioned(DeletePositioned),
+    /// `EXPLAIN` statement.
+    Explain(Explain),
+    /// `GRANT` privilege statement.
+    GrantPrivilege(GrantPrivilege),
+    /// `REVOKE` privilege statement.
+    RevokePrivilege(RevokePrivilege),
+}
+
+impl Statement {
+    /// Serializes the statement using the identifier rules of the received
+    /// dialect, instead of the quote styles stored in the AST.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(all(feature = "mssql", feature = "mysql"))] {
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// # use sql_helper::mssql::dialect::MsSqlDialect;
+    /// # use sql_helper::mysql::dialect::MySqlDialect;
+    /// let (_, statement) = parse_statement(b"DROP TABLE \"my table\" CASCADE").unwrap();
+    ///
+    /// assert_eq!(statement.to_sql(&MySqlDialect), "DROP TABLE `my table` CASCADE");
+    /// assert_eq!(statement.to_sql(&MsSqlDialect), "DROP TABLE [my table] CASCADE");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let mut statement = self.clone();
+        DialectIdents::new(dialect).visit_statement(&mut statement);
+        statement.to_string()
+    }
+
+    /// Returns a canonical form of the statement, so equivalent statements can
+    /// be compared.
+    ///
+    /// Unquoted identifiers are case folded, and quoted identifiers can be
+    /// unquoted when equivalent to an unquoted one. Keywords and whitespaces
+    /// are not stored in the AST, so they are always rendered in their
+    /// canonical form (upper case, single spaces).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::normalize::NormalizeOptions;
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// let (_, statement) = parse_statement(b"drop   table sch.users cascade").unwrap();
+    ///
+    /// assert_eq!(
+    ///     statement.normalize(NormalizeOptions::new()).to_string(),
+    ///     "DROP TABLE SCH.USERS CASCADE"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn normalize(&self, options: NormalizeOptions) -> Self {
+        let mut statement = self.clone();
+        Normalizer::new(options).visit_statement(&mut statement);
+        statement
+    }
+
+    /// Returns whether both statements are equivalent, once normalized with
+    /// the received options [(1)].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::normalize::{IdentifierCase, NormalizeOptions};
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// let (_, upper) = parse_statement(b"CREATE TABLE Foo (Id INT)").unwrap();
+    /// let (_, lower) = parse_statement(b"create  table foo (id int)").unwrap();
+    ///
+    /// assert!(upper.semantic_eq(&lower, NormalizeOptions::new()));
+    /// assert!(!upper.semantic_eq(
+    ///     &lower,
+    ///     NormalizeOptions::new().with_identifier_case(IdentifierCase::Preserve)
+    /// ));
+    /// ```
+    ///
+    /// [(1)]: Statement::normalize
+    #[must_use]
+    pub fn semantic_eq(&self, other: &Self, options: NormalizeOptions) -> bool {
+        self.normalize(options) == other.normalize(options)
+    }
+
+    /// Replaces the literal values of the statement by dynamic parameters
+    /// (`?`), returning the rewritten statement and the extracted literals in
+    /// parameter order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// # use sql_helper::common::expr::Literal;
+    /// let (_, statement) = parse_statement(b"CALL log('error', 3, :user)").unwrap();
+    /// let parameterized = statement.parameterize();
+    ///
+    /// assert_eq!(parameterized.to_string(), "CALL log(?, ?, :user)");
+    /// assert_eq!(
+    ///     parameterized.literals(),
+    ///     [Literal::CharacterString("error".to_string()), Literal::Numeric("3".to_string())]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn parameterize(&self) -> ParameterizedStatement {
+        ParameterizedStatement::new(self)
+    }
+
+    /// Returns a copy of the statement with its string, binary and numeric
+    /// literals replaced by `'?'`, so it can be logged without exposing user
+    /// data.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// let (_, statement) = parse_statement(b"CALL login('admin', 'hunter2')").unwrap();
+    ///
+    /// assert_eq!(statement.redact().to_string(), "CALL login('?', '?')");
+    /// ```
+    #[must_use]
+    pub fn redact(&self) -> Self {
+        let mut statement = self.clone();
+        Redactor.visit_statement(&mut statement);
+        statement
+    }
+
+    /// Returns whether the statement is a data definition statement (i.e.,
+    /// it creates, alters or drops schema objects).
+    #[must_use]
+    pub const fn is_ddl(&self) -> bool {
+        match self {
+            Self::CreateSchema(_)
+            | Self::DropSchema(_)
+            | Self::DropTable(_)
+            | Self::CreateTable(_)
+            | Self::AlterTable(_) => true,
+            Self::Open(_)
+            | Self::Fetch(_)
+            | Self::Close(_)
+            | Self::Call(_)
+            | Self::Return(_)
+            | Self::UpdatePositioned(_)
+            | Self::DeletePositioned(_)
+            | Self::Explain(_)
+            | Self::GrantPrivilege(_)
+            | Self::RevokePrivilege(_) => false,
+        }
+    }
+
+    /// Returns whether the statement may destroy data: dropping a schema, a
+    /// table or a column, changing the data type of a column, which may
+    /// truncate its values, or deleting rows.
+    ///
+    /// An `EXPLAIN ANALYZE` executes the explained statement, so it's as
+    /// destructive as that statement.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// let (_, drop_column) = parse_statement(b"ALTER TABLE tb DROP COLUMN id RESTRICT").unwrap();
+    /// let (_, add_column) = parse_statement(b"ALTER TABLE tb ADD COLUMN id INT").unwrap();
+    ///
+    /// assert!(drop_column.is_destructive());
+    /// assert!(!add_column.is_destructive());
+    /// ```
+    #[must_use]
+    pub const fn is_destructive(&self) -> bool {
+        match self {
+            Self::DropSchema(_) | Self::DropTable(_) | Self::DeletePositioned(_) => true,
+            Self::CreateSchema(_)
+            | Self::CreateTable(_)
+            | Self::Open(_)
+            | Self::Fetch(_)
+            | Self::Close(_)
+            | Self::Call(_)
+            | Self::Return(_)
+            | Self::UpdatePositioned(_)
+            | Self::GrantPrivilege(_)
+            | Self::RevokePrivilege(_) => false,
+            Self::AlterTable(alter_table) => match alter_table.alter_table_action() {
+                AlterTableAction::DropColumn(..)
+                | AlterTableAction::AlterColumn(_, AlterColumnAction::SetDataType(_)) => true,
+                AlterTableAction::AddColumn(_)
+                | AlterTableAction::AlterColumn(_, AlterColumnAction::DropIdentity)
+                | AlterTableAction::RenameTable(_)
+                | AlterTableAction::RenameColumn(..) => false,
+            },
+            Self::Explain(explain) => explain.analyze() && explain.statement().is_destructive(),
+        }
+    }
 }
 
 impl fmt::Display for Statement {
@@ -30,6 +277,17 @@ impl fmt::Display for Statement {
             Self::DropSchema(drop_schema) => write!(f, "{drop_schema}")?,
             Self::DropTable(drop_table) => write!(f, "{drop_table}")?,
             Self::CreateTable(create_table) => write!(f, "{create_table}")?,
+            Self::AlterTable(alter_table) => write!(f, "{alter_table}")?,
+            Self::Open(open) => write!(f, "{open}")?,
+            Self::Fetch(fetch) => write!(f, "{fetch}")?,
+            Self::Close(close) => write!(f, "{close}")?,
+            Self::Call(call) => write!(f, "{call}")?,
+            Self::Return(return_statement) => write!(f, "{return_statement}")?,
+            Self::UpdatePositioned(update) => write!(f, "{update}")?,
+            Self::DeletePositioned(delete) => write!(f, "{delete}")?,
+            Self::Explain(explain) => write!(f, "{explain}")?,
+            Self::GrantPrivilege(grant) => write!(f, "{grant}")?,
+            Self::RevokePrivilege(revoke) => write!(f, "{revoke}")?,
         }
         Ok(())
     }
@@ -1,6 +1,17 @@
+pub mod alter_table;
+pub mod call;
+pub mod close;
 pub mod common;
 pub mod create_schema;
 pub mod create_table;
 pub mod data_types;
+pub mod delete;
 pub mod drop_schema;
 pub mod drop_table;
+pub mod explain;
+pub mod fetch;
+pub mod grant;
+pub mod open;
+pub mod return_statement;
+pub mod revoke;
+pub mod update;
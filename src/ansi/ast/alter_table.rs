@@ -0,0 +1,148 @@
+use std::fmt;
+
+use crate::ansi::ast::common::{ColumnDefinition, DropBehavior, TableName};
+use crate::ansi::ast::data_types::DataType;
+use crate::common::Ident;
+
+/// `ALTER TABLE` statement (`<alter table statement>`) [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+/// ALTER TABLE <table name> <alter table action>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#alter-table-statement
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterTable {
+    /// `<table name>`
+    table_name: TableName,
+    /// `<alter table action>`
+    alter_table_action: AlterTableAction,
+}
+
+/// Action applied to the altered table (`<alter table action>`) [(1)].
+///
+/// The rename actions are dialect extensions, also parsed from the
+/// `RENAME TABLE <table name> TO <table name>` statement.
+///
+/// # Supported syntax
+/// ```plaintext
+///   ADD [COLUMN] <column definition>
+/// | ALTER [COLUMN] <column name> <alter column action>
+/// | DROP [COLUMN] <column name> <drop behavior>
+/// | RENAME TO <table name>
+/// | RENAME [COLUMN] <column name> TO <column name>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#alter-table-action
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterTableAction {
+    /// `ADD [COLUMN] <column definition>`
+    AddColumn(ColumnDefinition),
+    /// `ALTER [COLUMN] <column name> <alter column action>`
+    AlterColumn(Ident, AlterColumnAction),
+    /// `DROP [COLUMN] <column name> <drop behavior>`
+    DropColumn(Ident, DropBehavior),
+    /// `RENAME TO <table name>`
+    RenameTable(TableName),
+    /// `RENAME [COLUMN] <column name> TO <column name>`
+    RenameColumn(Ident, Ident),
+}
+
+/// Action applied to the altered column (`<alter column action>`) [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+///   SET DATA TYPE <data type>
+/// | DROP IDENTITY
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#alter-column-action
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterColumnAction {
+    /// `SET DATA TYPE <data type>`
+    SetDataType(DataType),
+    /// `DROP IDENTITY`
+    DropIdentity,
+}
+
+impl AlterTableAction {
+    /// Returns whether the action is a dialect extension, not defined by the
+    /// SQL standard (e.g., renaming a column).
+    #[must_use]
+    pub const fn is_dialect_extension(&self) -> bool {
+        matches!(self, Self::RenameTable(_) | Self::RenameColumn(..))
+    }
+}
+
+impl AlterTable {
+    #[must_use]
+    pub const fn new(table_name: TableName, alter_table_action: AlterTableAction) -> Self {
+        Self {
+            table_name,
+            alter_table_action,
+        }
+    }
+
+    #[must_use]
+    pub const fn table_name(&self) -> &TableName {
+        &self.table_name
+    }
+
+    #[must_use]
+    pub const fn alter_table_action(&self) -> &AlterTableAction {
+        &self.alter_table_action
+    }
+
+    pub fn table_name_mut(&mut self) -> &mut TableName {
+        &mut self.table_name
+    }
+
+    pub fn alter_table_action_mut(&mut self) -> &mut AlterTableAction {
+        &mut self.alter_table_action
+    }
+}
+
+impl fmt::Display for AlterTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ALTER TABLE {} {}",
+            self.table_name(),
+            self.alter_table_action()
+        )?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for AlterTableAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddColumn(column_definition) => write!(f, "ADD COLUMN {column_definition}")?,
+            Self::AlterColumn(column_name, alter_column_action) => {
+                write!(f, "ALTER COLUMN {column_name} {alter_column_action}")?;
+            }
+            Self::DropColumn(column_name, drop_behavior) => {
+                write!(f, "DROP COLUMN {column_name} {drop_behavior}")?;
+            }
+            Self::RenameTable(table_name) => write!(f, "RENAME TO {table_name}")?,
+            Self::RenameColumn(column_name, new_column_name) => {
+                write!(f, "RENAME COLUMN {column_name} TO {new_column_name}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AlterColumnAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SetDataType(data_type) => write!(f, "SET DATA TYPE {data_type}")?,
+            Self::DropIdentity => write!(f, "DROP IDENTITY")?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt;
+
+use crate::ansi::ast::common::{RoutineName, ValueSpecification};
+use crate::common::display_comma_separated;
+
+/// `CALL` statement [(1)].
+///
+/// # Supported syntax
+/// ```doc
+/// CALL <routine name> <left paren> [ <SQL argument> [ { <comma> <SQL argument> }... ] ] <right paren>
+///
+/// <SQL argument> ::= <value specification>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_16_1_call_statement
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Call {
+    /// `<routine name>`
+    routine_name: RoutineName,
+    /// `<SQL argument list>`
+    arguments: Vec<ValueSpecification>,
+}
+
+impl Call {
+    #[must_use]
+    pub const fn new(routine_name: RoutineName, arguments: Vec<ValueSpecification>) -> Self {
+        Self {
+            routine_name,
+            arguments,
+        }
+    }
+
+    #[must_use]
+    pub const fn routine_name(&self) -> &RoutineName {
+        &self.routine_name
+    }
+
+    #[must_use]
+    pub fn arguments(&self) -> &[ValueSpecification] {
+        &self.arguments
+    }
+
+    pub fn routine_name_mut(&mut self) -> &mut RoutineName {
+        &mut self.routine_name
+    }
+
+    pub fn arguments_mut(&mut self) -> &mut [ValueSpecification] {
+        &mut self.arguments
+    }
+}
+
+impl fmt::Display for Call {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CALL {}({})",
+            self.routine_name(),
+            display_comma_separated(self.arguments())
+        )?;
+        Ok(())
+    }
+}
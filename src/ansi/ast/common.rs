@@ -1,10 +1,12 @@
 use std::fmt;
 
 use crate::ansi::ast::data_types::DataType;
+pub use crate::common::expr::Literal;
 use crate::common::{display_comma_separated, Ident};
 
 /// Qualified or unqualified identifier representing a schema.
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SchemaName {
     /// Schema unqualified name.
     name: Ident,
@@ -19,11 +21,49 @@ pub struct SchemaName {
 /// [<local or schema qualifier>.]<identifier>
 /// ```
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableName {
     name: Ident,
     opt_local_or_schema: Option<LocalOrSchemaQualifier>,
 }
 
+/// Object name as a generalized list of qualifiers, for dialects whose names
+/// don't fit the `ANSI` `[<catalog>.][<schema>.]<name>` structure (e.g.,
+/// `T-SQL` linked server names, `server.database.schema.table`).
+///
+/// The number of qualifiers accepted by the dialect parsers is defined by the
+/// dialect [(1)]. Every `ANSI` name converts into an object name, so they can
+/// be handled uniformly regardless of the named object.
+///
+/// # Supported syntax
+/// ```plaintext
+/// [<identifier>.]...<identifier>
+/// ```
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::common::ObjectName;
+/// # use sql_helper::ansi::parser::common::{routine_name, schema_name, table_name};
+/// let (_, table) = table_name(b"cat.sch.tb").unwrap();
+/// let (_, routine) = routine_name(b"sch.proc").unwrap();
+/// let (_, schema) = schema_name(b"sch").unwrap();
+///
+/// assert_eq!(ObjectName::from(table.clone()).qualifiers().len(), 2);
+/// assert_eq!(ObjectName::from(table).to_table_name().unwrap().to_string(), "cat.sch.tb");
+/// assert_eq!(ObjectName::from(routine).to_string(), "sch.proc");
+/// assert_eq!(ObjectName::from(schema).to_string(), "sch");
+/// ```
+///
+/// [(1)]: crate::common::dialect::Dialect::max_name_parts
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObjectName {
+    /// Qualifiers, from the outermost one.
+    qualifiers: Vec<Ident>,
+    /// Object unqualified name.
+    name: Ident,
+}
+
 /// Schema name or local qualifier (`<local or schema qualifier>`).
 ///
 /// # Supported syntax
@@ -32,6 +72,7 @@ pub struct TableName {
 /// | <local qualifier>
 /// ```
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LocalOrSchemaQualifier {
     Schema(SchemaName),
     LocalQualifier(LocalQualifier),
@@ -44,6 +85,7 @@ pub enum LocalOrSchemaQualifier {
 /// MODULE
 /// ```
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LocalQualifier {
     /// `MODULE`
     #[default]
@@ -53,19 +95,134 @@ pub enum LocalQualifier {
 /// Column definition for `ANSI` columns [(1)].
 ///
 /// # Supported syntax
-/// `<column name> [<data type>]`
+/// ```plaintext
+///   <column name> [<data type>] [<identity column specification>]
+///     [<column constraint definition>...]
+/// | <column name> { SMALLSERIAL | SERIAL | BIGSERIAL }
+///     [<column constraint definition>...]
+/// ```
 ///
 /// [1]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#column-definition
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnDefinition {
     /// `<column name>`
     column_name: Ident,
     /// `[<data_type>]`
     opt_data_type: Option<DataType>,
+    /// `[<identity column specification>]`
+    opt_identity_column_specification: Option<IdentityColumnSpecification>,
+    /// `[<column constraint definition>...]`
+    #[cfg_attr(feature = "serde", serde(default))]
+    column_constraint_definitions: Vec<ColumnConstraintDefinition>,
+}
+
+/// Column constraint definition [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+/// [CONSTRAINT <constraint name>] <column constraint>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#column-constraint-definition
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnConstraintDefinition {
+    /// `[CONSTRAINT <constraint name>]`.
+    opt_constraint_name: Option<SchemaQualifiedName>,
+    /// `<column constraint>`.
+    column_constraint: ColumnConstraint,
+}
+
+/// Column constraint [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+///   NOT NULL
+/// | <unique specification>
+/// | <references specification>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#column-constraint
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColumnConstraint {
+    /// `NOT NULL`.
+    NotNull,
+    /// `<unique specification>`.
+    Unique(UniqueSpecification),
+    /// `<references specification>`.
+    References(ReferencesSpecification),
+}
+
+/// Identity column specification [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+/// GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY
+/// [ ( [START WITH <start value>] [INCREMENT BY <increment>] ) ]
+/// ```
+///
+/// The `MySQL` `AUTO_INCREMENT` attribute and the `PostgreSQL` `SERIAL` types
+/// are also represented as `BY DEFAULT` identities, keeping the syntax they
+/// were written with [(2)], so the columns are compared as identity columns
+/// regardless of the dialect.
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#identity-column-specification
+/// [(2)]: IdentitySyntax
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdentityColumnSpecification {
+    /// `{ ALWAYS | BY DEFAULT }`
+    identity_generation: IdentityGeneration,
+    /// `[START WITH <start value>]`
+    opt_start_with: Option<i64>,
+    /// `[INCREMENT BY <increment>]`
+    opt_increment_by: Option<i64>,
+    /// Syntax the identity was written with.
+    #[cfg_attr(feature = "serde", serde(default))]
+    syntax: IdentitySyntax,
+}
+
+/// Syntax an identity column is written with.
+///
+/// # Supported syntax
+/// ```plaintext
+///   GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY
+/// | AUTO_INCREMENT
+/// | { SMALLSERIAL | SERIAL | BIGSERIAL }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IdentitySyntax {
+    /// `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY`.
+    #[default]
+    Standard,
+    /// `MySQL` `AUTO_INCREMENT` column attribute.
+    AutoIncrement,
+    /// `PostgreSQL` `SERIAL` types, which replace the column data type.
+    Serial,
+}
+
+/// When an identity column value is generated.
+///
+/// # Supported syntax
+/// ```plaintext
+///   ALWAYS
+/// | BY DEFAULT
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IdentityGeneration {
+    /// `ALWAYS`.
+    Always,
+    /// `BY DEFAULT`.
+    ByDefault,
 }
 
 /// Possible behaviours when dropping a structure.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DropBehavior {
     /// CASCADE - all dependencies are dropped.
     Cascade,
@@ -84,6 +241,7 @@ pub enum DropBehavior {
 /// | NO ACTION
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReferentialAction {
     /// `CASCADE`.
     Cascade,
@@ -104,6 +262,7 @@ pub enum ReferentialAction {
 /// ON DELETE <referential action>
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeleteRule {
     referential_action: ReferentialAction,
 }
@@ -115,6 +274,7 @@ pub struct DeleteRule {
 /// ON UPDATE <referential action>
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateRule {
     referential_action: ReferentialAction,
 }
@@ -127,6 +287,7 @@ pub struct UpdateRule {
 /// | <delete rule> [<update rule>]
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReferentialTriggeredAction {
     /// `<update rule> [<delete rule>]`.
     UpdateFirst(UpdateRule, Option<DeleteRule>),
@@ -143,6 +304,7 @@ pub enum ReferentialTriggeredAction {
 /// | SIMPLE
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MatchType {
     /// `FULL`.
     Full,
@@ -152,6 +314,30 @@ pub enum MatchType {
     Simple,
 }
 
+/// References specification, as used by column-level and table-level
+/// foreign keys.
+///
+/// # Supported syntax
+/// ```plaintext
+/// REFERENCES <referenced table and columns>
+///   [MATCH <match type>] [<referential triggered action>]
+///
+/// <referenced table and columns> ::=
+///   <table name> [(<column name list> [, <referenced period specification>])]
+///
+/// <referenced period specification> ::=
+///   PERIOD <application time period name>
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferencesSpecification {
+    table_name: TableName,
+    opt_referenced_columns: Option<ColumnNameList>,
+    opt_referenced_period: Option<Ident>,
+    opt_match_type: Option<MatchType>,
+    opt_referential_triggered_action: Option<ReferentialTriggeredAction>,
+}
+
 /// Column name list
 ///
 /// # Supported syntax
@@ -159,10 +345,59 @@ pub enum MatchType {
 /// <column name> [ {<comma> <column name> }...]
 /// ```
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnNameList {
     column_names: Vec<Ident>,
 }
 
+/// Key column of a unique or primary key constraint, with an optional sort
+/// direction as commonly emitted by database dumps.
+///
+/// # Supported syntax
+/// ```plaintext
+/// <column name> [ ASC | DESC ]
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyColumn {
+    column_name: Ident,
+    opt_ordering: Option<Ordering>,
+}
+
+/// Sort direction of a key column [(1)](KeyColumn).
+///
+/// # Supported syntax
+/// ```plaintext
+///   ASC
+/// | DESC
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ordering {
+    /// `ASC`.
+    Asc,
+    /// `DESC`.
+    Desc,
+}
+
+/// Unique specification of a unique constraint [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+///   UNIQUE
+/// | PRIMARY KEY
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#unique-specification
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UniqueSpecification {
+    /// `UNIQUE`.
+    Unique,
+    /// `PRIMARY KEY`.
+    PrimaryKey,
+}
+
 /// System versioning clause
 ///
 /// # Supported syntax
@@ -170,17 +405,72 @@ pub struct ColumnNameList {
 /// SYSTEM VERSIONING CLAUSE
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SystemVersioningClause {}
 
+/// Schema qualified name (`<schema qualified name>`), naming objects such as
+/// routines, domains, sequence generators, user-defined types and character
+/// sets.
+///
+/// # Supported syntax
+/// ```plaintext
+/// [<schema name>.]<identifier>
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaQualifiedName {
+    name: Ident,
+    opt_schema_name: Option<SchemaName>,
+}
+
+/// Routine name with possibly schema qualification (`<schema qualified routine
+/// name>`), which has the same structure as any other schema qualified name.
+pub type RoutineName = SchemaQualifiedName;
+
+/// Value specification (`<value specification>`), or `NULL`.
+///
+/// # Supported syntax
+/// ```plaintext
+///   <literal>
+/// | <host parameter name>
+/// | <SQL parameter reference>
+/// | <dynamic parameter specification>
+/// | NULL
+///
+/// <host parameter name> ::= <colon> <identifier>
+///
+/// <dynamic parameter specification> ::= <question mark>
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValueSpecification {
+    /// `<literal>`.
+    Literal(Literal),
+    /// `<host parameter name>` (e.g., `:name`).
+    HostParameter(Ident),
+    /// `<SQL parameter reference>`, inside of routines.
+    SqlParameter(Ident),
+    /// `<dynamic parameter specification>` (i.e., `?`), bound by position.
+    DynamicParameter,
+    /// `NULL`.
+    Null,
+}
+
 impl SchemaName {
     #[must_use]
-    pub fn new(opt_catalog_name: Option<&Ident>, name: &Ident) -> Self {
+    pub fn new(name: impl Into<Ident>) -> Self {
         Self {
-            name: name.clone(),
-            opt_catalog_name: opt_catalog_name.cloned(),
+            name: name.into(),
+            opt_catalog_name: None,
         }
     }
 
+    #[must_use]
+    pub fn with_catalog_name(mut self, catalog_name: impl Into<Ident>) -> Self {
+        self.opt_catalog_name = Some(catalog_name.into());
+        self
+    }
+
     #[must_use]
     pub const fn name(&self) -> &Ident {
         &self.name
@@ -190,6 +480,31 @@ impl SchemaName {
     pub const fn opt_catalog_name(&self) -> Option<&Ident> {
         self.opt_catalog_name.as_ref()
     }
+
+    pub fn name_mut(&mut self) -> &mut Ident {
+        &mut self.name
+    }
+
+    pub fn opt_catalog_name_mut(&mut self) -> Option<&mut Ident> {
+        self.opt_catalog_name.as_mut()
+    }
+
+    /// Returns whether both schema names refer to the same schema, following
+    /// the identifiers case folding [(1)].
+    ///
+    /// [(1)]: Ident::refers_to
+    #[must_use]
+    pub fn refers_to(&self, other: &Self) -> bool {
+        let same_catalog = match (self.opt_catalog_name(), other.opt_catalog_name()) {
+            (Some(catalog_name), Some(other_catalog_name)) => {
+                catalog_name.refers_to(other_catalog_name)
+            }
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_catalog && self.name().refers_to(other.name())
+    }
 }
 
 impl fmt::Display for SchemaName {
@@ -206,14 +521,15 @@ impl fmt::Display for SchemaName {
 
 impl TableName {
     #[must_use]
-    pub fn new(name: &Ident) -> Self {
+    pub fn new(name: impl Into<Ident>) -> Self {
         Self {
-            name: name.clone(),
+            name: name.into(),
             opt_local_or_schema: None,
         }
     }
 
-    pub fn with_local_or_schema(&mut self, local_or_schema: LocalOrSchemaQualifier) -> &mut Self {
+    #[must_use]
+    pub fn with_local_or_schema(mut self, local_or_schema: LocalOrSchemaQualifier) -> Self {
         self.opt_local_or_schema = Some(local_or_schema);
         self
     }
@@ -227,6 +543,44 @@ impl TableName {
     pub const fn opt_local_or_schema(&self) -> Option<&LocalOrSchemaQualifier> {
         self.opt_local_or_schema.as_ref()
     }
+
+    pub fn name_mut(&mut self) -> &mut Ident {
+        &mut self.name
+    }
+
+    pub fn opt_local_or_schema_mut(&mut self) -> Option<&mut LocalOrSchemaQualifier> {
+        self.opt_local_or_schema.as_mut()
+    }
+
+    /// Returns whether both table names refer to the same table, following
+    /// the identifiers case folding [(1)].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::common::{LocalOrSchemaQualifier, SchemaName, TableName};
+    /// # use sql_helper::common::Ident;
+    /// let table_name = TableName::new("users")
+    ///     .with_local_or_schema(LocalOrSchemaQualifier::Schema(SchemaName::new("sch")));
+    /// let other = TableName::new(Ident::quoted("USERS"))
+    ///     .with_local_or_schema(LocalOrSchemaQualifier::Schema(SchemaName::new("SCH")));
+    ///
+    /// assert!(table_name.refers_to(&other));
+    /// assert!(!table_name.refers_to(&TableName::new("users")));
+    /// ```
+    ///
+    /// [(1)]: Ident::refers_to
+    #[must_use]
+    pub fn refers_to(&self, other: &Self) -> bool {
+        let same_qualifier = match (self.opt_local_or_schema(), other.opt_local_or_schema()) {
+            (
+                Some(LocalOrSchemaQualifier::Schema(schema_name)),
+                Some(LocalOrSchemaQualifier::Schema(other_schema_name)),
+            ) => schema_name.refers_to(other_schema_name),
+            (local_or_schema, other_local_or_schema) => local_or_schema == other_local_or_schema,
+        };
+
+        same_qualifier && self.name().refers_to(other.name())
+    }
 }
 
 impl fmt::Display for TableName {
@@ -262,14 +616,17 @@ impl fmt::Display for LocalQualifier {
 
 impl ColumnDefinition {
     #[must_use]
-    pub fn new(column_name: &Ident) -> Self {
+    pub fn new(column_name: impl Into<Ident>) -> Self {
         Self {
-            column_name: column_name.clone(),
+            column_name: column_name.into(),
             opt_data_type: None,
+            opt_identity_column_specification: None,
+            column_constraint_definitions: vec![],
         }
     }
 
-    pub fn with_data_type(&mut self, data_type: DataType) -> &mut Self {
+    #[must_use]
+    pub fn with_data_type(mut self, data_type: DataType) -> Self {
         self.opt_data_type = Some(data_type);
         self
     }
@@ -279,18 +636,246 @@ impl ColumnDefinition {
         &self.column_name
     }
 
+    #[must_use]
+    pub const fn with_identity_column_specification(
+        mut self,
+        identity_column_specification: IdentityColumnSpecification,
+    ) -> Self {
+        self.opt_identity_column_specification = Some(identity_column_specification);
+        self
+    }
+
     #[must_use]
     pub const fn opt_data_type(&self) -> Option<DataType> {
         self.opt_data_type
     }
+
+    #[must_use]
+    pub const fn opt_identity_column_specification(&self) -> Option<IdentityColumnSpecification> {
+        self.opt_identity_column_specification
+    }
+
+    #[must_use]
+    pub fn with_column_constraint_definitions(
+        mut self,
+        column_constraint_definitions: Vec<ColumnConstraintDefinition>,
+    ) -> Self {
+        self.column_constraint_definitions = column_constraint_definitions;
+        self
+    }
+
+    #[must_use]
+    pub fn column_constraint_definitions(&self) -> &[ColumnConstraintDefinition] {
+        &self.column_constraint_definitions
+    }
+
+    pub fn column_name_mut(&mut self) -> &mut Ident {
+        &mut self.column_name
+    }
+
+    pub fn opt_data_type_mut(&mut self) -> Option<&mut DataType> {
+        self.opt_data_type.as_mut()
+    }
+
+    pub fn column_constraint_definitions_mut(&mut self) -> &mut [ColumnConstraintDefinition] {
+        &mut self.column_constraint_definitions
+    }
+
+    /// Returns the `SERIAL` type that replaces the data type and identity of
+    /// the column, if the identity was written with that syntax.
+    #[must_use]
+    pub(crate) fn opt_serial_type(&self) -> Option<&'static str> {
+        let identity_column_specification = self.opt_identity_column_specification()?;
+        if identity_column_specification.syntax() != IdentitySyntax::Serial {
+            return None;
+        }
+
+        match self.opt_data_type()? {
+            DataType::Smallint => Some("SMALLSERIAL"),
+            DataType::Integer => Some("SERIAL"),
+            DataType::Bigint => Some("BIGSERIAL"),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for ColumnDefinition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.column_name)?;
 
-        if let Some(data_type) = self.opt_data_type() {
-            write!(f, " {data_type}")?;
+        if let Some(serial_type) = self.opt_serial_type() {
+            write!(f, " {serial_type}")?;
+        } else {
+            if let Some(data_type) = self.opt_data_type() {
+                write!(f, " {data_type}")?;
+            }
+
+            if let Some(identity_column_specification) = self.opt_identity_column_specification() {
+                write!(f, " {identity_column_specification}")?;
+            }
+        }
+
+        for column_constraint_definition in self.column_constraint_definitions() {
+            write!(f, " {column_constraint_definition}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ColumnConstraintDefinition {
+    #[must_use]
+    pub const fn new(column_constraint: ColumnConstraint) -> Self {
+        Self {
+            opt_constraint_name: None,
+            column_constraint,
+        }
+    }
+
+    #[must_use]
+    pub fn with_constraint_name(mut self, constraint_name: SchemaQualifiedName) -> Self {
+        self.opt_constraint_name = Some(constraint_name);
+        self
+    }
+
+    #[must_use]
+    pub const fn opt_constraint_name(&self) -> Option<&SchemaQualifiedName> {
+        self.opt_constraint_name.as_ref()
+    }
+
+    #[must_use]
+    pub const fn column_constraint(&self) -> &ColumnConstraint {
+        &self.column_constraint
+    }
+
+    pub fn opt_constraint_name_mut(&mut self) -> Option<&mut SchemaQualifiedName> {
+        self.opt_constraint_name.as_mut()
+    }
+
+    pub fn column_constraint_mut(&mut self) -> &mut ColumnConstraint {
+        &mut self.column_constraint
+    }
+}
+
+impl fmt::Display for ColumnConstraintDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(constraint_name) = self.opt_constraint_name() {
+            write!(f, "CONSTRAINT {constraint_name} ")?;
+        }
+        write!(f, "{}", self.column_constraint())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for ColumnConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotNull => write!(f, "NOT NULL")?,
+            Self::Unique(unique_specification) => write!(f, "{unique_specification}")?,
+            Self::References(references_specification) => {
+                write!(f, "{references_specification}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl IdentityColumnSpecification {
+    #[must_use]
+    pub const fn new(identity_generation: IdentityGeneration) -> Self {
+        Self {
+            identity_generation,
+            opt_start_with: None,
+            opt_increment_by: None,
+            syntax: IdentitySyntax::Standard,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_syntax(mut self, syntax: IdentitySyntax) -> Self {
+        self.syntax = syntax;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_start_with(mut self, start_with: i64) -> Self {
+        self.opt_start_with = Some(start_with);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_increment_by(mut self, increment_by: i64) -> Self {
+        self.opt_increment_by = Some(increment_by);
+        self
+    }
+
+    #[must_use]
+    pub const fn identity_generation(&self) -> IdentityGeneration {
+        self.identity_generation
+    }
+
+    #[must_use]
+    pub const fn opt_start_with(&self) -> Option<i64> {
+        self.opt_start_with
+    }
+
+    #[must_use]
+    pub const fn opt_increment_by(&self) -> Option<i64> {
+        self.opt_increment_by
+    }
+
+    #[must_use]
+    pub const fn syntax(&self) -> IdentitySyntax {
+        self.syntax
+    }
+
+    /// Returns whether both specifications generate the same values, ignoring
+    /// the syntax they were written with.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::common::{IdentityColumnSpecification, IdentityGeneration, IdentitySyntax};
+    /// let standard = IdentityColumnSpecification::new(IdentityGeneration::ByDefault);
+    /// let auto_increment = standard.with_syntax(IdentitySyntax::AutoIncrement);
+    ///
+    /// assert_ne!(standard, auto_increment);
+    /// assert!(standard.is_equivalent_to(&auto_increment));
+    /// ```
+    #[must_use]
+    pub fn is_equivalent_to(&self, other: &Self) -> bool {
+        self.with_syntax(IdentitySyntax::Standard) == other.with_syntax(IdentitySyntax::Standard)
+    }
+}
+
+impl fmt::Display for IdentityColumnSpecification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `SERIAL` types replace the data type, so the column definition
+        // writes them instead.
+        if self.syntax() == IdentitySyntax::AutoIncrement {
+            write!(f, "AUTO_INCREMENT")?;
+            return Ok(());
+        }
+
+        write!(f, "GENERATED {} AS IDENTITY", self.identity_generation())?;
+
+        match (self.opt_start_with(), self.opt_increment_by()) {
+            (Some(start_with), Some(increment_by)) => {
+                write!(f, " (START WITH {start_with} INCREMENT BY {increment_by})")?;
+            }
+            (Some(start_with), None) => write!(f, " (START WITH {start_with})")?,
+            (None, Some(increment_by)) => write!(f, " (INCREMENT BY {increment_by})")?,
+            (None, None) => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for IdentityGeneration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Always => write!(f, "ALWAYS")?,
+            Self::ByDefault => write!(f, "BY DEFAULT")?,
         }
 
         Ok(())
@@ -398,18 +983,241 @@ impl fmt::Display for MatchType {
     }
 }
 
-impl ColumnNameList {
+impl ReferencesSpecification {
+    #[must_use]
+    pub const fn new(table_name: TableName) -> Self {
+        Self {
+            table_name,
+            opt_referenced_columns: None,
+            opt_referenced_period: None,
+            opt_match_type: None,
+            opt_referential_triggered_action: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_referenced_columns(mut self, referenced_columns: ColumnNameList) -> Self {
+        self.opt_referenced_columns = Some(referenced_columns);
+        self
+    }
+
+    /// Sets the referenced application time period. The period is only valid
+    /// after a list of referenced columns [(1)](Self::with_referenced_columns).
+    #[must_use]
+    pub fn with_referenced_period(mut self, period_name: impl Into<Ident>) -> Self {
+        self.opt_referenced_period = Some(period_name.into());
+        self
+    }
+
+    #[must_use]
+    pub const fn with_match_type(mut self, match_type: MatchType) -> Self {
+        self.opt_match_type = Some(match_type);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_referential_triggered_action(
+        mut self,
+        referential_triggered_action: ReferentialTriggeredAction,
+    ) -> Self {
+        self.opt_referential_triggered_action = Some(referential_triggered_action);
+        self
+    }
+
+    #[must_use]
+    pub const fn table_name(&self) -> &TableName {
+        &self.table_name
+    }
+
+    #[must_use]
+    pub const fn opt_referenced_columns(&self) -> Option<&ColumnNameList> {
+        self.opt_referenced_columns.as_ref()
+    }
+
+    #[must_use]
+    pub const fn opt_referenced_period(&self) -> Option<&Ident> {
+        self.opt_referenced_period.as_ref()
+    }
+
+    #[must_use]
+    pub const fn opt_match_type(&self) -> Option<MatchType> {
+        self.opt_match_type
+    }
+
     #[must_use]
-    pub fn new(column_names: &[Ident]) -> Self {
+    pub const fn opt_referential_triggered_action(&self) -> Option<ReferentialTriggeredAction> {
+        self.opt_referential_triggered_action
+    }
+
+    pub fn table_name_mut(&mut self) -> &mut TableName {
+        &mut self.table_name
+    }
+
+    pub fn opt_referenced_columns_mut(&mut self) -> Option<&mut ColumnNameList> {
+        self.opt_referenced_columns.as_mut()
+    }
+
+    pub fn opt_referenced_period_mut(&mut self) -> Option<&mut Ident> {
+        self.opt_referenced_period.as_mut()
+    }
+}
+
+impl fmt::Display for ReferencesSpecification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "REFERENCES {}", self.table_name())?;
+        if let Some(referenced_columns) = self.opt_referenced_columns() {
+            write!(f, " ({referenced_columns}")?;
+            if let Some(period_name) = self.opt_referenced_period() {
+                write!(f, ", PERIOD {period_name}")?;
+            }
+            write!(f, ")")?;
+        }
+        if let Some(match_type) = self.opt_match_type() {
+            write!(f, " MATCH {match_type}")?;
+        }
+        if let Some(referential_triggered_action) = self.opt_referential_triggered_action() {
+            write!(f, " {referential_triggered_action}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ObjectName {
+    #[must_use]
+    pub fn new(name: impl Into<Ident>) -> Self {
         Self {
-            column_names: column_names.to_vec(),
+            qualifiers: vec![],
+            name: name.into(),
         }
     }
 
+    #[must_use]
+    pub fn with_qualifiers(mut self, qualifiers: Vec<Ident>) -> Self {
+        self.qualifiers = qualifiers;
+        self
+    }
+
+    #[must_use]
+    pub fn qualifiers(&self) -> &[Ident] {
+        &self.qualifiers
+    }
+
+    #[must_use]
+    pub const fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    pub fn qualifiers_mut(&mut self) -> &mut [Ident] {
+        &mut self.qualifiers
+    }
+
+    pub fn name_mut(&mut self) -> &mut Ident {
+        &mut self.name
+    }
+
+    /// Returns the `ANSI` table name with the same parts, or `None` if the name
+    /// has more than three parts.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::common::ObjectName;
+    /// # use sql_helper::common::Ident;
+    /// let name = ObjectName::new("tb").with_qualifiers(vec![Ident::new(b"db"), Ident::new(b"dbo")]);
+    /// assert_eq!(name.to_table_name().unwrap().to_string(), "db.dbo.tb");
+    ///
+    /// let name = ObjectName::new("tb").with_qualifiers(vec![
+    ///     Ident::new(b"srv"),
+    ///     Ident::new(b"db"),
+    ///     Ident::new(b"dbo"),
+    /// ]);
+    /// assert_eq!(name.to_table_name(), None);
+    /// ```
+    #[must_use]
+    pub fn to_table_name(&self) -> Option<TableName> {
+        let table_name = TableName::new(self.name.clone());
+
+        match self.qualifiers.as_slice() {
+            [] => Some(table_name),
+            [schema] => Some(
+                table_name.with_local_or_schema(LocalOrSchemaQualifier::Schema(SchemaName::new(
+                    schema.clone(),
+                ))),
+            ),
+            [catalog, schema] => Some(table_name.with_local_or_schema(
+                LocalOrSchemaQualifier::Schema(
+                    SchemaName::new(schema.clone()).with_catalog_name(catalog.clone()),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl From<TableName> for ObjectName {
+    fn from(table_name: TableName) -> Self {
+        let qualifiers = match table_name.opt_local_or_schema {
+            Some(LocalOrSchemaQualifier::Schema(schema_name)) => schema_name
+                .opt_catalog_name
+                .into_iter()
+                .chain([schema_name.name])
+                .collect(),
+            Some(LocalOrSchemaQualifier::LocalQualifier(local_qualifier)) => {
+                vec![Ident::from(local_qualifier.to_string().as_str())]
+            }
+            None => vec![],
+        };
+
+        Self::new(table_name.name).with_qualifiers(qualifiers)
+    }
+}
+
+impl From<SchemaName> for ObjectName {
+    fn from(schema_name: SchemaName) -> Self {
+        Self::new(schema_name.name)
+            .with_qualifiers(schema_name.opt_catalog_name.into_iter().collect())
+    }
+}
+
+impl From<SchemaQualifiedName> for ObjectName {
+    fn from(schema_qualified_name: SchemaQualifiedName) -> Self {
+        let qualifiers = schema_qualified_name
+            .opt_schema_name
+            .map(|schema_name| {
+                let schema_name = Self::from(schema_name);
+                let mut qualifiers = schema_name.qualifiers;
+                qualifiers.push(schema_name.name);
+                qualifiers
+            })
+            .unwrap_or_default();
+
+        Self::new(schema_qualified_name.name).with_qualifiers(qualifiers)
+    }
+}
+
+impl fmt::Display for ObjectName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for qualifier in self.qualifiers() {
+            write!(f, "{qualifier}.")?;
+        }
+        write!(f, "{}", self.name())?;
+        Ok(())
+    }
+}
+
+impl ColumnNameList {
+    #[must_use]
+    pub fn new(column_names: Vec<Ident>) -> Self {
+        Self { column_names }
+    }
+
     #[must_use]
     pub fn column_names(&self) -> &[Ident] {
         &self.column_names
     }
+
+    pub fn column_names_mut(&mut self) -> &mut [Ident] {
+        &mut self.column_names
+    }
 }
 impl fmt::Display for ColumnNameList {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -418,9 +1226,128 @@ impl fmt::Display for ColumnNameList {
     }
 }
 
+impl KeyColumn {
+    #[must_use]
+    pub fn new(column_name: impl Into<Ident>) -> Self {
+        Self {
+            column_name: column_name.into(),
+            opt_ordering: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_ordering(mut self, ordering: Ordering) -> Self {
+        self.opt_ordering = Some(ordering);
+        self
+    }
+
+    #[must_use]
+    pub const fn column_name(&self) -> &Ident {
+        &self.column_name
+    }
+
+    #[must_use]
+    pub const fn opt_ordering(&self) -> Option<Ordering> {
+        self.opt_ordering
+    }
+
+    pub fn column_name_mut(&mut self) -> &mut Ident {
+        &mut self.column_name
+    }
+}
+
+impl fmt::Display for KeyColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.column_name())?;
+        if let Some(ordering) = self.opt_ordering() {
+            write!(f, " {ordering}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Ordering {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Asc => write!(f, "ASC")?,
+            Self::Desc => write!(f, "DESC")?,
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for UniqueSpecification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unique => write!(f, "UNIQUE")?,
+            Self::PrimaryKey => write!(f, "PRIMARY KEY")?,
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Display for SystemVersioningClause {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "SYSTEM VERSIONING")?;
         Ok(())
     }
 }
+
+impl SchemaQualifiedName {
+    #[must_use]
+    pub fn new(name: impl Into<Ident>) -> Self {
+        Self {
+            name: name.into(),
+            opt_schema_name: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_schema_name(mut self, schema_name: SchemaName) -> Self {
+        self.opt_schema_name = Some(schema_name);
+        self
+    }
+
+    #[must_use]
+    pub const fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn opt_schema_name(&self) -> Option<&SchemaName> {
+        self.opt_schema_name.as_ref()
+    }
+
+    pub fn name_mut(&mut self) -> &mut Ident {
+        &mut self.name
+    }
+
+    pub fn opt_schema_name_mut(&mut self) -> Option<&mut SchemaName> {
+        self.opt_schema_name.as_mut()
+    }
+}
+
+impl fmt::Display for SchemaQualifiedName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(schema_name) = self.opt_schema_name() {
+            write!(f, "{schema_name}.")?;
+        }
+
+        write!(f, "{}", self.name())?;
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ValueSpecification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => write!(f, "{literal}")?,
+            Self::HostParameter(name) => write!(f, ":{name}")?,
+            Self::SqlParameter(name) => write!(f, "{name}")?,
+            Self::DynamicParameter => write!(f, "?")?,
+            Self::Null => write!(f, "NULL")?,
+        }
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@ use crate::common::Ident;
 ///
 /// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#schema-definition
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateSchema {
     /// `<schema name clause>`
     schema_name_clause: SchemaNameClause,
@@ -31,6 +32,7 @@ pub struct CreateSchema {
 ///
 /// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#schema-definition
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SchemaNameClause {
     /// <schema name>
     Simple(SchemaName),
@@ -42,21 +44,23 @@ pub enum SchemaNameClause {
 
 impl CreateSchema {
     #[must_use]
-    pub fn new(schema_name_clause: &SchemaNameClause) -> Self {
-        Self {
-            schema_name_clause: schema_name_clause.clone(),
-        }
+    pub const fn new(schema_name_clause: SchemaNameClause) -> Self {
+        Self { schema_name_clause }
     }
 
     #[must_use]
     pub const fn schema_name_clause(&self) -> &SchemaNameClause {
         &self.schema_name_clause
     }
+
+    pub fn schema_name_clause_mut(&mut self) -> &mut SchemaNameClause {
+        &mut self.schema_name_clause
+    }
 }
 
 impl fmt::Display for CreateSchema {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "CREATE SCHEMA {};", self.schema_name_clause())?;
+        write!(f, "CREATE SCHEMA {}", self.schema_name_clause())?;
         Ok(())
     }
 }
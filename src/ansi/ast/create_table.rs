@@ -1,15 +1,30 @@
 use core::fmt;
 
-use crate::ansi::ast::common::{ColumnDefinition, TableName};
-use crate::common::{display_comma_separated, if_some_string_preceded_by};
+use crate::ansi::ast::common::{
+    ColumnConstraint, ColumnDefinition, ColumnNameList, KeyColumn, ReferencesSpecification,
+    SchemaQualifiedName, TableName, UniqueSpecification,
+};
+use crate::common::ast::SqlOption;
+use crate::common::expr::Expr;
+use crate::common::{display_comma_separated, if_some_string_preceded_by, Ident};
 
 /// Create table statement.
 ///
 /// # Supported syntax
 /// ```plaintext
 /// CREATE [<table scope>] TABLE <table name> <table contents source>
+///     [<table option> [{[,] <table option>}...]]
 /// ```
+///
+/// The table options aren't part of the standard, but dialect clauses such as
+/// `ENGINE=InnoDB` or `TABLESPACE ts` are kept as [`SqlOption`]s, so they are
+/// re-emitted as written. One of the table options can be a partition clause
+/// [(1)], which is kept in the position it was written, as `MySQL` requires it
+/// after the other options and `PostgreSQL` before them.
+///
+/// [(1)]: PartitionBy
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateTable {
     /// `[<table scope>]`.
     opt_table_scope: Option<TableScope>,
@@ -17,6 +32,18 @@ pub struct CreateTable {
     table_name: TableName,
     /// `<table contents source>`
     table_contents_source: TableContentsSource,
+    /// `[<table option>...]`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    table_options: Vec<SqlOption>,
+    /// `[<partition clause>]`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    opt_partition_by: Option<PartitionBy>,
+    /// Number of table options written before the partition clause.
+    #[cfg_attr(feature = "serde", serde(default))]
+    partition_by_position: usize,
 }
 
 /// Table scope clause.
@@ -30,6 +57,7 @@ pub struct CreateTable {
 /// | LOCAL
 /// ```
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableScope {
     /// `GLOBAL TEMPORARY`.
     Global,
@@ -37,6 +65,127 @@ pub enum TableScope {
     Local,
 }
 
+/// Partition clause, as in the `MySQL` and `PostgreSQL` declarative
+/// partitioning.
+///
+/// # Supported syntax
+/// ```plaintext
+/// PARTITION BY <partition method> [COLUMNS]
+///     (<partition key> [{, <partition key>}...])
+///     [PARTITIONS <unsigned integer>]
+///     [(<partition definition> [{, <partition definition>}...])]
+///
+/// <partition method> ::=
+///   RANGE
+/// | LIST
+/// | HASH
+///
+/// <partition key> ::=
+///   <column name>
+/// | <value expression>
+/// ```
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::create_table::{PartitionBy, PartitionMethod};
+/// # use sql_helper::common::expr::Expr;
+/// # use sql_helper::common::Ident;
+/// let partition_by = PartitionBy::new(
+///     PartitionMethod::Hash,
+///     vec![Expr::Identifier(Ident::new(b"id"))],
+/// )
+/// .with_partitions(4);
+///
+/// assert_eq!(partition_by.to_string(), "PARTITION BY HASH (id) PARTITIONS 4");
+/// assert_eq!(partition_by.column_names(), vec![&Ident::new(b"id")]);
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartitionBy {
+    /// `<partition method>`.
+    partition_method: PartitionMethod,
+    /// `[COLUMNS]`.
+    columns: bool,
+    /// `(<partition key>...)`.
+    partition_keys: Vec<Expr>,
+    /// `[PARTITIONS <unsigned integer>]`.
+    opt_partitions: Option<u32>,
+    /// `[(<partition definition>...)]`.
+    partition_definitions: Vec<PartitionDefinition>,
+}
+
+/// Partition method.
+///
+/// # Supported syntax
+/// ```plaintext
+///   RANGE
+/// | LIST
+/// | HASH
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartitionMethod {
+    /// `RANGE`.
+    Range,
+    /// `LIST`.
+    List,
+    /// `HASH`.
+    Hash,
+}
+
+/// Partition definition.
+///
+/// # Supported syntax
+/// ```plaintext
+/// PARTITION <partition name> [<partition values>]
+///     [<table option> [{<table option>}...]]
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartitionDefinition {
+    /// `<partition name>`.
+    name: Ident,
+    /// `[<partition values>]`.
+    opt_partition_values: Option<PartitionValues>,
+    /// `[<table option>...]`.
+    options: Vec<SqlOption>,
+}
+
+/// Values of the rows stored in a partition.
+///
+/// # Supported syntax
+/// ```plaintext
+///   VALUES LESS THAN MAXVALUE
+/// | VALUES LESS THAN (<partition bound> [{, <partition bound>}...])
+/// | VALUES IN (<value expression> [{, <value expression>}...])
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartitionValues {
+    /// `VALUES LESS THAN MAXVALUE`.
+    LessThanMaxValue,
+    /// `VALUES LESS THAN (<partition bound>...)`.
+    LessThan(Vec<PartitionBound>),
+    /// `VALUES IN (<value expression>...)`.
+    In(Vec<Expr>),
+}
+
+/// Upper bound of a range partition.
+///
+/// # Supported syntax
+/// ```plaintext
+///   <value expression>
+/// | MAXVALUE
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartitionBound {
+    /// `<value expression>`.
+    Value(Expr),
+    /// `MAXVALUE`.
+    MaxValue,
+}
+
 /// Table contents source.
 ///
 /// # Supported syntax
@@ -44,6 +193,7 @@ pub enum TableScope {
 /// <table element list>
 /// ```
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableContentsSource {
     /// `<table element list>`.
     TableElementList(TableElementList),
@@ -56,6 +206,7 @@ pub enum TableContentsSource {
 /// (<table element> [{, <table element>}...])
 /// ```
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableElementList {
     /// Element list.
     element_list: Vec<TableElement>,
@@ -65,29 +216,137 @@ pub struct TableElementList {
 ///
 /// # Supported syntax
 /// ```plaintext
-/// <column definition>
+///   <column definition>
+/// | <table constraint definition>
 /// ```
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableElement {
     /// `<column definition>`.
     ColumnDefinition(ColumnDefinition),
+    /// `<table constraint definition>`.
+    TableConstraintDefinition(TableConstraintDefinition),
+}
+
+/// Table constraint definition [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+/// [CONSTRAINT <constraint name>] <table constraint>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#table-constraint-definition
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableConstraintDefinition {
+    /// `[CONSTRAINT <constraint name>]`.
+    opt_constraint_name: Option<SchemaQualifiedName>,
+    /// `<table constraint>`.
+    table_constraint: TableConstraint,
+}
+
+/// Table constraint [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+///   <unique constraint definition>
+/// | <referential constraint definition>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#table-constraint
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TableConstraint {
+    /// `<unique constraint definition>`.
+    Unique(UniqueConstraintDefinition),
+    /// `<referential constraint definition>`.
+    Referential(ReferentialConstraintDefinition),
+}
+
+/// Unique constraint definition [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+/// <unique specification> (<key column> [{, <key column>}...])
+/// ```
+///
+/// The sort direction of the key columns [(2)] is not `ANSI`, but it's
+/// accepted as commonly emitted by database dumps.
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#unique-constraint-definition
+/// [(2)]: KeyColumn
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UniqueConstraintDefinition {
+    /// `<unique specification>`.
+    unique_specification: UniqueSpecification,
+    /// `<key column> [{, <key column>}...]`.
+    key_columns: Vec<KeyColumn>,
+}
+
+/// Referential constraint definition [(1)], i.e., a table-level foreign key.
+///
+/// # Supported syntax
+/// ```plaintext
+/// FOREIGN KEY (<column name list> [, <referencing period specification>])
+///   <references specification>
+///
+/// <referencing period specification> ::=
+///   PERIOD <application time period name>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#referential-constraint-definition
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferentialConstraintDefinition {
+    /// `<column name list>`.
+    referencing_columns: ColumnNameList,
+    /// `[, <referencing period specification>]`.
+    opt_referencing_period: Option<Ident>,
+    /// `<references specification>`.
+    references_specification: ReferencesSpecification,
 }
 
 impl CreateTable {
     #[must_use]
-    pub fn new(table_name: &TableName, table_contents_source: &TableContentsSource) -> Self {
+    pub const fn new(table_name: TableName, table_contents_source: TableContentsSource) -> Self {
         Self {
             opt_table_scope: None,
-            table_name: table_name.clone(),
-            table_contents_source: table_contents_source.clone(),
+            table_name,
+            table_contents_source,
+            table_options: vec![],
+            opt_partition_by: None,
+            partition_by_position: 0,
         }
     }
 
-    pub fn with_table_scope(&mut self, table_scope: TableScope) -> &mut Self {
+    #[must_use]
+    pub const fn with_table_scope(mut self, table_scope: TableScope) -> Self {
         self.opt_table_scope = Some(table_scope);
         self
     }
 
+    #[must_use]
+    pub fn with_table_options(mut self, table_options: Vec<SqlOption>) -> Self {
+        self.table_options = table_options;
+        self
+    }
+
+    /// Sets the partition clause, written before the table options unless
+    /// [a position](Self::with_partition_by_position) is set.
+    #[must_use]
+    pub fn with_partition_by(mut self, partition_by: PartitionBy) -> Self {
+        self.opt_partition_by = Some(partition_by);
+        self
+    }
+
+    /// Sets the number of table options written before the partition clause.
+    #[must_use]
+    pub const fn with_partition_by_position(mut self, partition_by_position: usize) -> Self {
+        self.partition_by_position = partition_by_position;
+        self
+    }
+
     #[must_use]
     pub const fn opt_table_scope(&self) -> Option<TableScope> {
         self.opt_table_scope
@@ -102,6 +361,249 @@ impl CreateTable {
     pub const fn table_contents_source(&self) -> &TableContentsSource {
         &self.table_contents_source
     }
+
+    #[must_use]
+    pub fn table_options(&self) -> &[SqlOption] {
+        &self.table_options
+    }
+
+    #[must_use]
+    pub const fn opt_partition_by(&self) -> Option<&PartitionBy> {
+        self.opt_partition_by.as_ref()
+    }
+
+    /// Returns the number of table options written before the partition
+    /// clause.
+    #[must_use]
+    pub fn partition_by_position(&self) -> usize {
+        self.partition_by_position.min(self.table_options.len())
+    }
+
+    /// Returns the definition of the column, or `None` if the table has no
+    /// such column.
+    ///
+    /// The name is read as an unquoted identifier, so it's folded as the
+    /// column names are when compared by their canonical names [(1)] (e.g.,
+    /// `id` finds the column `ID`, but not the column `"id"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::create_table::create_table;
+    /// let (_, create_table) = create_table(b"CREATE TABLE tb (Id INT, \"Name\" CLOB)").unwrap();
+    ///
+    /// assert_eq!(create_table.column("ID").unwrap().to_string(), "Id INT");
+    /// assert!(create_table.has_column("id"));
+    /// assert!(!create_table.has_column("name"));
+    /// ```
+    ///
+    /// [(1)]: Ident::canonical
+    #[must_use]
+    pub fn column(&self, column_name: &str) -> Option<&ColumnDefinition> {
+        let TableContentsSource::TableElementList(table_element_list) =
+            self.table_contents_source();
+        let column_name = Ident::from(column_name);
+
+        table_element_list
+            .columns()
+            .find(|column_definition| column_definition.column_name().refers_to(&column_name))
+    }
+
+    /// Returns whether the table has the column, folding its name as
+    /// [`CreateTable::column`] does.
+    #[must_use]
+    pub fn has_column(&self, column_name: &str) -> bool {
+        self.column(column_name).is_some()
+    }
+
+    /// Returns the names of the columns of the primary key, in the order they
+    /// are declared in the key, or `None` if the table has no primary key.
+    ///
+    /// The primary key is either a table constraint (e.g., `PRIMARY KEY (a,
+    /// b)`) or a column constraint (e.g., `a INT PRIMARY KEY`). If more than
+    /// one is declared, which is invalid, the first one is returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::create_table::create_table;
+    /// let (_, table_key) =
+    ///     create_table(b"CREATE TABLE tb (a INT, b INT, PRIMARY KEY (b, a))").unwrap();
+    /// let primary_key = table_key.primary_key().unwrap();
+    /// assert_eq!(primary_key.iter().map(|name| name.value()).collect::<Vec<_>>(), vec!["b", "a"]);
+    ///
+    /// let (_, column_key) = create_table(b"CREATE TABLE tb (a INT PRIMARY KEY)").unwrap();
+    /// assert_eq!(column_key.primary_key().unwrap()[0].value(), "a");
+    ///
+    /// let (_, no_key) = create_table(b"CREATE TABLE tb (a INT UNIQUE)").unwrap();
+    /// assert!(no_key.primary_key().is_none());
+    /// ```
+    #[must_use]
+    pub fn primary_key(&self) -> Option<Vec<&Ident>> {
+        let TableContentsSource::TableElementList(table_element_list) =
+            self.table_contents_source();
+
+        table_element_list
+            .element_list()
+            .iter()
+            .find_map(|table_element| match table_element {
+                TableElement::ColumnDefinition(column_definition) => column_definition
+                    .column_constraint_definitions()
+                    .iter()
+                    .any(|column_constraint_definition| {
+                        column_constraint_definition.column_constraint()
+                            == &ColumnConstraint::Unique(UniqueSpecification::PrimaryKey)
+                    })
+                    .then(|| vec![column_definition.column_name()]),
+                TableElement::TableConstraintDefinition(table_constraint_definition) => {
+                    match table_constraint_definition.table_constraint() {
+                        TableConstraint::Unique(unique_constraint_definition)
+                            if unique_constraint_definition.unique_specification()
+                                == UniqueSpecification::PrimaryKey =>
+                        {
+                            Some(
+                                unique_constraint_definition
+                                    .key_columns()
+                                    .iter()
+                                    .map(KeyColumn::column_name)
+                                    .collect(),
+                            )
+                        }
+                        TableConstraint::Unique(_) | TableConstraint::Referential(_) => None,
+                    }
+                }
+            })
+    }
+
+    /// Returns whether the column of the table accepts nulls, i.e., it's not
+    /// an identity column, it's not declared `NOT NULL`, and it's not part of
+    /// the primary key [(1)].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::create_table::create_table;
+    /// let (_, create_table) =
+    ///     create_table(b"CREATE TABLE tb (a INT, b INT NOT NULL, c INT, PRIMARY KEY (c))").unwrap();
+    ///
+    /// let nullable = ["a", "b", "c"]
+    ///     .map(|column_name| create_table.is_nullable(create_table.column(column_name).unwrap()));
+    /// assert_eq!(nullable, [true, false, false]);
+    /// ```
+    ///
+    /// [(1)]: Self::primary_key
+    #[must_use]
+    pub fn is_nullable(&self, column_definition: &ColumnDefinition) -> bool {
+        let declared_not_null = column_definition
+            .column_constraint_definitions()
+            .iter()
+            .any(|column_constraint_definition| {
+                matches!(
+                    column_constraint_definition.column_constraint(),
+                    ColumnConstraint::NotNull
+                        | ColumnConstraint::Unique(UniqueSpecification::PrimaryKey)
+                )
+            });
+        let in_primary_key = self.primary_key().is_some_and(|primary_key| {
+            primary_key
+                .iter()
+                .any(|column_name| column_name.refers_to(column_definition.column_name()))
+        });
+
+        column_definition
+            .opt_identity_column_specification()
+            .is_none()
+            && !declared_not_null
+            && !in_primary_key
+    }
+
+    /// Returns the foreign keys of the table, in the order they were declared,
+    /// as the names of their referencing columns and their references
+    /// specification.
+    ///
+    /// Both the table constraints (e.g., `FOREIGN KEY (a) REFERENCES tb`) and
+    /// the column constraints (e.g., `a INT REFERENCES tb`) are returned. The
+    /// referencing period of a table constraint, if any, is not part of the
+    /// referencing columns.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::create_table::create_table;
+    /// let (_, create_table) = create_table(
+    ///     b"CREATE TABLE tb (a INT REFERENCES x, b INT, FOREIGN KEY (a, b) REFERENCES y (c, d))",
+    /// )
+    /// .unwrap();
+    ///
+    /// let foreign_keys = create_table
+    ///     .foreign_keys()
+    ///     .into_iter()
+    ///     .map(|(referencing_columns, references_specification)| {
+    ///         let referencing_columns = referencing_columns
+    ///             .iter()
+    ///             .map(|column_name| column_name.value())
+    ///             .collect::<Vec<_>>();
+    ///         (referencing_columns, references_specification.to_string())
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     foreign_keys,
+    ///     vec![
+    ///         (vec!["a"], "REFERENCES x".to_string()),
+    ///         (vec!["a", "b"], "REFERENCES y (c, d)".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn foreign_keys(&self) -> Vec<(Vec<&Ident>, &ReferencesSpecification)> {
+        let TableContentsSource::TableElementList(table_element_list) =
+            self.table_contents_source();
+
+        table_element_list
+            .element_list()
+            .iter()
+            .flat_map(|table_element| match table_element {
+                TableElement::ColumnDefinition(column_definition) => column_definition
+                    .column_constraint_definitions()
+                    .iter()
+                    .filter_map(|column_constraint_definition| {
+                        match column_constraint_definition.column_constraint() {
+                            ColumnConstraint::References(references_specification) => Some((
+                                vec![column_definition.column_name()],
+                                references_specification,
+                            )),
+                            ColumnConstraint::NotNull | ColumnConstraint::Unique(_) => None,
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+                TableElement::TableConstraintDefinition(table_constraint_definition) => {
+                    match table_constraint_definition.table_constraint() {
+                        TableConstraint::Referential(referential_constraint_definition) => vec![(
+                            referential_constraint_definition
+                                .referencing_columns()
+                                .column_names()
+                                .iter()
+                                .collect(),
+                            referential_constraint_definition.references_specification(),
+                        )],
+                        TableConstraint::Unique(_) => vec![],
+                    }
+                }
+            })
+            .collect()
+    }
+
+    pub fn table_name_mut(&mut self) -> &mut TableName {
+        &mut self.table_name
+    }
+
+    pub fn table_contents_source_mut(&mut self) -> &mut TableContentsSource {
+        &mut self.table_contents_source
+    }
+
+    pub fn table_options_mut(&mut self) -> &mut Vec<SqlOption> {
+        &mut self.table_options
+    }
+
+    pub fn opt_partition_by_mut(&mut self) -> Option<&mut PartitionBy> {
+        self.opt_partition_by.as_mut()
+    }
 }
 
 impl fmt::Display for CreateTable {
@@ -113,6 +615,17 @@ impl fmt::Display for CreateTable {
             table_name = self.table_name(),
             table_contents_source = self.table_contents_source()
         )?;
+        let (options_before, options_after) =
+            self.table_options().split_at(self.partition_by_position());
+        for option in options_before {
+            write!(f, " {option}")?;
+        }
+        if let Some(partition_by) = self.opt_partition_by() {
+            write!(f, " {partition_by}")?;
+        }
+        for option in options_after {
+            write!(f, " {option}")?;
+        }
         Ok(())
     }
 }
@@ -127,6 +640,198 @@ impl fmt::Display for TableScope {
     }
 }
 
+impl PartitionBy {
+    #[must_use]
+    pub const fn new(partition_method: PartitionMethod, partition_keys: Vec<Expr>) -> Self {
+        Self {
+            partition_method,
+            columns: false,
+            partition_keys,
+            opt_partitions: None,
+            partition_definitions: vec![],
+        }
+    }
+
+    #[must_use]
+    pub const fn with_columns(mut self, columns: bool) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_partitions(mut self, partitions: u32) -> Self {
+        self.opt_partitions = Some(partitions);
+        self
+    }
+
+    #[must_use]
+    pub fn with_partition_definitions(
+        mut self,
+        partition_definitions: Vec<PartitionDefinition>,
+    ) -> Self {
+        self.partition_definitions = partition_definitions;
+        self
+    }
+
+    #[must_use]
+    pub const fn partition_method(&self) -> PartitionMethod {
+        self.partition_method
+    }
+
+    #[must_use]
+    pub const fn columns(&self) -> bool {
+        self.columns
+    }
+
+    #[must_use]
+    pub fn partition_keys(&self) -> &[Expr] {
+        &self.partition_keys
+    }
+
+    #[must_use]
+    pub const fn opt_partitions(&self) -> Option<u32> {
+        self.opt_partitions
+    }
+
+    #[must_use]
+    pub fn partition_definitions(&self) -> &[PartitionDefinition] {
+        &self.partition_definitions
+    }
+
+    /// Returns the names of the columns used as partition keys, ignoring the
+    /// keys that are expressions.
+    #[must_use]
+    pub fn column_names(&self) -> Vec<&Ident> {
+        self.partition_keys()
+            .iter()
+            .filter_map(|partition_key| match partition_key {
+                Expr::Identifier(column_name) => Some(column_name),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn partition_keys_mut(&mut self) -> &mut Vec<Expr> {
+        &mut self.partition_keys
+    }
+
+    pub fn partition_definitions_mut(&mut self) -> &mut Vec<PartitionDefinition> {
+        &mut self.partition_definitions
+    }
+}
+
+impl PartitionDefinition {
+    #[must_use]
+    pub const fn new(name: Ident) -> Self {
+        Self {
+            name,
+            opt_partition_values: None,
+            options: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn with_partition_values(mut self, partition_values: PartitionValues) -> Self {
+        self.opt_partition_values = Some(partition_values);
+        self
+    }
+
+    #[must_use]
+    pub fn with_options(mut self, options: Vec<SqlOption>) -> Self {
+        self.options = options;
+        self
+    }
+
+    #[must_use]
+    pub const fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn opt_partition_values(&self) -> Option<&PartitionValues> {
+        self.opt_partition_values.as_ref()
+    }
+
+    #[must_use]
+    pub fn options(&self) -> &[SqlOption] {
+        &self.options
+    }
+
+    pub fn name_mut(&mut self) -> &mut Ident {
+        &mut self.name
+    }
+}
+
+impl fmt::Display for PartitionBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PARTITION BY {}", self.partition_method())?;
+        if self.columns() {
+            write!(f, " COLUMNS")?;
+        }
+        write!(f, " ({})", display_comma_separated(self.partition_keys()))?;
+        if let Some(partitions) = self.opt_partitions() {
+            write!(f, " PARTITIONS {partitions}")?;
+        }
+        if !self.partition_definitions().is_empty() {
+            write!(
+                f,
+                " ({})",
+                display_comma_separated(self.partition_definitions())
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PartitionMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Range => write!(f, "RANGE")?,
+            Self::List => write!(f, "LIST")?,
+            Self::Hash => write!(f, "HASH")?,
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PartitionDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PARTITION {name}{partition_values}",
+            name = self.name(),
+            partition_values = if_some_string_preceded_by(self.opt_partition_values(), " ")
+        )?;
+        for option in self.options() {
+            write!(f, " {option}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PartitionValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LessThanMaxValue => write!(f, "VALUES LESS THAN MAXVALUE")?,
+            Self::LessThan(bounds) => {
+                write!(f, "VALUES LESS THAN ({})", display_comma_separated(bounds))?;
+            }
+            Self::In(values) => write!(f, "VALUES IN ({})", display_comma_separated(values))?,
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PartitionBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value(value) => write!(f, "{value}")?,
+            Self::MaxValue => write!(f, "MAXVALUE")?,
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Display for TableContentsSource {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -137,16 +842,120 @@ impl fmt::Display for TableContentsSource {
 
 impl TableElementList {
     #[must_use]
-    pub fn new(element_list: &[TableElement]) -> Self {
-        Self {
-            element_list: element_list.to_vec(),
-        }
+    pub const fn new(element_list: Vec<TableElement>) -> Self {
+        Self { element_list }
     }
 
     #[must_use]
     pub fn element_list(&self) -> &[TableElement] {
         &self.element_list
     }
+
+    pub fn element_list_mut(&mut self) -> &mut [TableElement] {
+        &mut self.element_list
+    }
+
+    /// Returns the column definitions, in the order they were declared,
+    /// skipping the table constraints [(1)].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::create_table::TableContentsSource;
+    /// # use sql_helper::ansi::parser::create_table::create_table;
+    /// let (_, create_table) =
+    ///     create_table(b"CREATE TABLE tb (id INT, PRIMARY KEY (id), name CLOB)").unwrap();
+    /// let TableContentsSource::TableElementList(table_element_list) =
+    ///     create_table.table_contents_source();
+    ///
+    /// let column_names = table_element_list
+    ///     .columns()
+    ///     .map(|column_definition| column_definition.column_name().value())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(column_names, vec!["id", "name"]);
+    /// ```
+    ///
+    /// [(1)]: Self::constraints
+    pub fn columns(&self) -> impl Iterator<Item = &ColumnDefinition> {
+        self.element_list()
+            .iter()
+            .filter_map(|table_element| match table_element {
+                TableElement::ColumnDefinition(column_definition) => Some(column_definition),
+                TableElement::TableConstraintDefinition(_) => None,
+            })
+    }
+
+    /// Returns the table constraint definitions, in the order they were
+    /// declared.
+    ///
+    /// The constraints declared by the column definitions [(1)] aren't table
+    /// constraints, so they are not returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::create_table::TableContentsSource;
+    /// # use sql_helper::ansi::parser::create_table::create_table;
+    /// let (_, create_table) = create_table(
+    ///     b"CREATE TABLE tb (id INT UNIQUE, PRIMARY KEY (id), FOREIGN KEY (id) REFERENCES a)",
+    /// )
+    /// .unwrap();
+    /// let TableContentsSource::TableElementList(table_element_list) =
+    ///     create_table.table_contents_source();
+    ///
+    /// let constraints = table_element_list
+    ///     .constraints()
+    ///     .map(ToString::to_string)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(constraints, vec!["PRIMARY KEY (id)", "FOREIGN KEY (id) REFERENCES a"]);
+    /// ```
+    ///
+    /// [(1)]: ColumnDefinition::column_constraint_definitions
+    pub fn constraints(&self) -> impl Iterator<Item = &TableConstraintDefinition> {
+        self.element_list()
+            .iter()
+            .filter_map(|table_element| match table_element {
+                TableElement::ColumnDefinition(_) => None,
+                TableElement::TableConstraintDefinition(table_constraint_definition) => {
+                    Some(table_constraint_definition)
+                }
+            })
+    }
+
+    /// Returns the ordinal position of the column among the columns of the
+    /// table, starting at 1 as in the `INFORMATION_SCHEMA.COLUMNS` view, or
+    /// `None` if there's no such column. The table constraints don't take
+    /// positions, so they don't shift the columns declared after them.
+    ///
+    /// Column names are compared by their canonical names [(1)].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::common::ColumnDefinition;
+    /// # use sql_helper::ansi::ast::create_table::{TableElement, TableElementList};
+    /// # use sql_helper::common::Ident;
+    /// # use sql_helper::ansi::ast::create_table::{
+    /// #     TableConstraint, TableConstraintDefinition, UniqueConstraintDefinition,
+    /// # };
+    /// # use sql_helper::ansi::ast::common::{KeyColumn, UniqueSpecification};
+    /// let primary_key = TableConstraintDefinition::new(TableConstraint::Unique(
+    ///     UniqueConstraintDefinition::new(UniqueSpecification::PrimaryKey, vec![KeyColumn::new("id")]),
+    /// ));
+    /// let table_element_list = TableElementList::new(vec![
+    ///     TableElement::ColumnDefinition(ColumnDefinition::new("id")),
+    ///     TableElement::TableConstraintDefinition(primary_key),
+    ///     TableElement::ColumnDefinition(ColumnDefinition::new("name")),
+    /// ]);
+    ///
+    /// assert_eq!(table_element_list.ordinal_position(&Ident::from("NAME")), Some(2));
+    /// assert_eq!(table_element_list.ordinal_position(&Ident::quoted("name")), None);
+    /// ```
+    ///
+    /// [(1)]: Ident::canonical
+    #[must_use]
+    pub fn ordinal_position(&self, column_name: &Ident) -> Option<usize> {
+        self.columns()
+            .position(|column_definition| column_definition.column_name().refers_to(column_name))
+            .map(|index| index + 1)
+    }
 }
 impl fmt::Display for TableElementList {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -159,7 +968,165 @@ impl fmt::Display for TableElement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::ColumnDefinition(column_definition) => write!(f, "{column_definition}")?,
+            Self::TableConstraintDefinition(table_constraint_definition) => {
+                write!(f, "{table_constraint_definition}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TableConstraintDefinition {
+    #[must_use]
+    pub const fn new(table_constraint: TableConstraint) -> Self {
+        Self {
+            opt_constraint_name: None,
+            table_constraint,
+        }
+    }
+
+    #[must_use]
+    pub fn with_constraint_name(mut self, constraint_name: SchemaQualifiedName) -> Self {
+        self.opt_constraint_name = Some(constraint_name);
+        self
+    }
+
+    #[must_use]
+    pub const fn opt_constraint_name(&self) -> Option<&SchemaQualifiedName> {
+        self.opt_constraint_name.as_ref()
+    }
+
+    #[must_use]
+    pub const fn table_constraint(&self) -> &TableConstraint {
+        &self.table_constraint
+    }
+
+    pub fn opt_constraint_name_mut(&mut self) -> Option<&mut SchemaQualifiedName> {
+        self.opt_constraint_name.as_mut()
+    }
+
+    pub fn table_constraint_mut(&mut self) -> &mut TableConstraint {
+        &mut self.table_constraint
+    }
+}
+
+impl fmt::Display for TableConstraintDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(constraint_name) = self.opt_constraint_name() {
+            write!(f, "CONSTRAINT {constraint_name} ")?;
+        }
+        write!(f, "{}", self.table_constraint())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for TableConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unique(unique_constraint_definition) => {
+                write!(f, "{unique_constraint_definition}")?;
+            }
+            Self::Referential(referential_constraint_definition) => {
+                write!(f, "{referential_constraint_definition}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl UniqueConstraintDefinition {
+    #[must_use]
+    pub const fn new(
+        unique_specification: UniqueSpecification,
+        key_columns: Vec<KeyColumn>,
+    ) -> Self {
+        Self {
+            unique_specification,
+            key_columns,
+        }
+    }
+
+    #[must_use]
+    pub const fn unique_specification(&self) -> UniqueSpecification {
+        self.unique_specification
+    }
+
+    #[must_use]
+    pub fn key_columns(&self) -> &[KeyColumn] {
+        &self.key_columns
+    }
+
+    pub fn key_columns_mut(&mut self) -> &mut [KeyColumn] {
+        &mut self.key_columns
+    }
+}
+
+impl fmt::Display for UniqueConstraintDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({})",
+            self.unique_specification(),
+            display_comma_separated(&self.key_columns)
+        )?;
+        Ok(())
+    }
+}
+
+impl ReferentialConstraintDefinition {
+    #[must_use]
+    pub const fn new(
+        referencing_columns: ColumnNameList,
+        references_specification: ReferencesSpecification,
+    ) -> Self {
+        Self {
+            referencing_columns,
+            opt_referencing_period: None,
+            references_specification,
+        }
+    }
+
+    #[must_use]
+    pub fn with_referencing_period(mut self, period_name: impl Into<Ident>) -> Self {
+        self.opt_referencing_period = Some(period_name.into());
+        self
+    }
+
+    #[must_use]
+    pub const fn referencing_columns(&self) -> &ColumnNameList {
+        &self.referencing_columns
+    }
+
+    #[must_use]
+    pub const fn opt_referencing_period(&self) -> Option<&Ident> {
+        self.opt_referencing_period.as_ref()
+    }
+
+    #[must_use]
+    pub const fn references_specification(&self) -> &ReferencesSpecification {
+        &self.references_specification
+    }
+
+    pub fn referencing_columns_mut(&mut self) -> &mut ColumnNameList {
+        &mut self.referencing_columns
+    }
+
+    pub fn opt_referencing_period_mut(&mut self) -> Option<&mut Ident> {
+        self.opt_referencing_period.as_mut()
+    }
+
+    pub fn references_specification_mut(&mut self) -> &mut ReferencesSpecification {
+        &mut self.references_specification
+    }
+}
+
+impl fmt::Display for ReferentialConstraintDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FOREIGN KEY ({}", self.referencing_columns())?;
+        if let Some(period_name) = self.opt_referencing_period() {
+            write!(f, ", PERIOD {period_name}")?;
         }
+        write!(f, ") {}", self.references_specification())?;
         Ok(())
     }
 }
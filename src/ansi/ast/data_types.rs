@@ -4,26 +4,27 @@ use std::fmt;
 ///
 /// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_6_1_data_type
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
-    /// CHARACTER\[([<character_length>])].
+    /// CHARACTER\[([`<character_length>`])].
     ///
-    /// [<character_length>]: CharacterLength
+    /// [`<character_length>`]: CharacterLength
     Character(Option<CharacterLength>),
-    /// CHAR\[([<character_length>])].
+    /// CHAR\[([`<character_length>`])].
     ///
-    /// [<character_length>]: CharacterLength
+    /// [`<character_length>`]: CharacterLength
     Char(Option<CharacterLength>),
-    /// CHARACTER VARYING\[([<character_length>])].
+    /// CHARACTER VARYING\[([`<character_length>`])].
     ///
-    /// [<character_length>]: CharacterLength
+    /// [`<character_length>`]: CharacterLength
     CharacterVarying(Option<CharacterLength>),
-    /// CHAR VARYING\[([<character_length>])].
+    /// CHAR VARYING\[([`<character_length>`])].
     ///
-    /// [<character_length>]: CharacterLength
+    /// [`<character_length>`]: CharacterLength
     CharVarying(Option<CharacterLength>),
-    /// VARCHAR\[([<character_length>])].
+    /// VARCHAR\[([`<character_length>`])].
     ///
-    /// [<character_length>]: CharacterLength
+    /// [`<character_length>`]: CharacterLength
     Varchar(Option<CharacterLength>),
     /// `CHARACTER LARGE OBJECT[(<character large object length>)]`.
     CharacterLargeObject(Option<CharacterLargeObjectLength>),
@@ -72,6 +73,43 @@ pub enum DataType {
     Time(Option<u32>, WithOrWithoutTimeZone),
     /// `TIMESTAMP [(<temporal precision>)] [<with or without time zone>]`
     Timestamp(Option<u32>, WithOrWithoutTimeZone),
+    /// Dialect spelling of a numeric type (e.g., `INT8`), which is a dialect
+    /// extension.
+    NumericAlias(NumericTypeAlias),
+}
+
+/// Dialect spelling of an `ANSI` numeric type, kept as written so the type is
+/// displayed as parsed.
+///
+/// The aliases are only valid in the dialects that support them [(1)], and
+/// are rejected by the strict parser mode.
+///
+/// # Supported syntax
+/// ```plaintext
+///   DOUBLE
+/// | FLOAT4
+/// | FLOAT8
+/// | INT2
+/// | INT4
+/// | INT8
+/// ```
+///
+/// [(1)]: crate::common::dialect::Dialect::supports_numeric_type_aliases
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumericTypeAlias {
+    /// `DOUBLE`, for `DOUBLE PRECISION`
+    Double,
+    /// `FLOAT4`, for `REAL`
+    Float4,
+    /// `FLOAT8`, for `DOUBLE PRECISION`
+    Float8,
+    /// `INT2`, for `SMALLINT`
+    Int2,
+    /// `INT4`, for `INTEGER`
+    Int4,
+    /// `INT8`, for `BIGINT`
+    Int8,
 }
 
 /// Character length of a string literal [(1)].
@@ -83,6 +121,7 @@ pub enum DataType {
 ///
 /// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#character-length
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharacterLength {
     /// `<length>`
     length: u32,
@@ -100,6 +139,7 @@ pub struct CharacterLength {
 ///
 /// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#char-length-units
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CharLengthUnits {
     /// `CHARACTERS`
     Characters,
@@ -115,6 +155,7 @@ pub enum CharLengthUnits {
 /// <large object length> [<char length units>]
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharacterLargeObjectLength {
     /// `<large object length>`
     length: LargeObjectLength,
@@ -129,6 +170,7 @@ pub struct CharacterLargeObjectLength {
 /// <unsigned integer>[<multiplier>]
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LargeObjectLength {
     /// `<unsigned integer>`.
     length: u32,
@@ -147,6 +189,7 @@ pub struct LargeObjectLength {
 /// | P
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Multiplier {
     /// `K` (kilo)
     K,
@@ -168,6 +211,7 @@ pub enum Multiplier {
 /// [(<precision>[, scale])]
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ExactNumberInfo {
     /// No info was provided.
     #[default]
@@ -186,6 +230,7 @@ pub enum ExactNumberInfo {
 /// | WITHOUT TIME ZONE
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WithOrWithoutTimeZone {
     /// No time zone info was provided.
     #[default]
@@ -196,6 +241,304 @@ pub enum WithOrWithoutTimeZone {
     WithoutTimeZone,
 }
 
+impl DataType {
+    /// Returns the fractional seconds precision of the temporal types, which
+    /// `ANSI` defaults to 0 for `TIME` and to 6 for `TIMESTAMP`, or `None` for
+    /// other types.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::data_types::{DataType, WithOrWithoutTimeZone};
+    /// assert_eq!(DataType::Time(None, WithOrWithoutTimeZone::None).effective_precision(), Some(0));
+    /// assert_eq!(DataType::Timestamp(None, WithOrWithoutTimeZone::None).effective_precision(), Some(6));
+    /// assert_eq!(DataType::Timestamp(Some(3), WithOrWithoutTimeZone::None).effective_precision(), Some(3));
+    /// assert_eq!(DataType::Date.effective_precision(), None);
+    /// ```
+    #[must_use]
+    pub const fn effective_precision(&self) -> Option<u32> {
+        match self {
+            Self::Time(Some(precision), _) | Self::Timestamp(Some(precision), _) => {
+                Some(*precision)
+            }
+            Self::Time(None, _) => Some(0),
+            Self::Timestamp(None, _) => Some(6),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the type is a temporal type with time zone, which is
+    /// never the case when the time zone is omitted.
+    #[must_use]
+    pub const fn is_with_time_zone(&self) -> bool {
+        matches!(
+            self,
+            Self::Time(_, WithOrWithoutTimeZone::WithTimeZone)
+                | Self::Timestamp(_, WithOrWithoutTimeZone::WithTimeZone)
+        )
+    }
+
+    /// Returns the type with the `ANSI` defaults of the omitted parameters
+    /// made explicit: a length of 1 for `CHARACTER` and `BINARY`, a scale of
+    /// 0 for the exact numeric types, and the temporal precision and
+    /// `WITHOUT TIME ZONE` for the temporal types.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::data_types::{DataType, WithOrWithoutTimeZone};
+    /// assert_eq!(
+    ///     DataType::Timestamp(None, WithOrWithoutTimeZone::None).with_explicit_defaults(),
+    ///     DataType::Timestamp(Some(6), WithOrWithoutTimeZone::WithoutTimeZone)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_explicit_defaults(self) -> Self {
+        match self {
+            Self::Character(None) => Self::Character(Some(CharacterLength::new(1))),
+            Self::Char(None) => Self::Char(Some(CharacterLength::new(1))),
+            Self::Binary(None) => Self::Binary(Some(1)),
+            Self::Numeric(ExactNumberInfo::Precision(precision)) => {
+                Self::Numeric(ExactNumberInfo::PrecisionAndScale(precision, 0))
+            }
+            Self::Decimal(ExactNumberInfo::Precision(precision)) => {
+                Self::Decimal(ExactNumberInfo::PrecisionAndScale(precision, 0))
+            }
+            Self::Dec(ExactNumberInfo::Precision(precision)) => {
+                Self::Dec(ExactNumberInfo::PrecisionAndScale(precision, 0))
+            }
+            Self::Time(_, time_zone) => Self::Time(
+                self.effective_precision(),
+                time_zone.with_explicit_default(),
+            ),
+            Self::Timestamp(_, time_zone) => Self::Timestamp(
+                self.effective_precision(),
+                time_zone.with_explicit_default(),
+            ),
+            data_type => data_type,
+        }
+    }
+
+    /// Returns the type spelled as the representative of its aliases, so
+    /// aliases of the same type compare equal. The original type is kept as
+    /// written, to be displayed as such.
+    ///
+    /// The representatives are `CHARACTER`, `VARCHAR`, `CLOB`, `VARBINARY`,
+    /// `BLOB`, `DECIMAL` and `INTEGER`, and the `ANSI` type of the numeric
+    /// aliases [(1)].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::data_types::{DataType, NumericTypeAlias};
+    /// assert_eq!(DataType::Int.canonical(), DataType::Integer);
+    /// assert_eq!(DataType::CharVarying(None).canonical(), DataType::Varchar(None));
+    /// assert_eq!(
+    ///     DataType::NumericAlias(NumericTypeAlias::Int8).canonical(),
+    ///     DataType::Bigint
+    /// );
+    /// ```
+    ///
+    /// [(1)]: NumericTypeAlias
+    #[must_use]
+    pub const fn canonical(self) -> Self {
+        match self {
+            Self::Char(opt_len) => Self::Character(opt_len),
+            Self::CharacterVarying(opt_len) | Self::CharVarying(opt_len) => Self::Varchar(opt_len),
+            Self::CharacterLargeObject(opt_len) | Self::CharLargeObject(opt_len) => {
+                Self::Clob(opt_len)
+            }
+            Self::BinaryVarying(opt_len) => Self::Varbinary(opt_len),
+            Self::BinaryLargeObject(opt_len) => Self::Blob(opt_len),
+            Self::Dec(number_info) => Self::Decimal(number_info),
+            Self::Int => Self::Integer,
+            Self::NumericAlias(alias) => alias.data_type(),
+            data_type => data_type,
+        }
+    }
+
+    /// Returns whether both types are the same once spelled canonically
+    /// [(1)] and with their omitted parameters replaced by the `ANSI` defaults
+    /// [(2)], so `TIMESTAMP` is equivalent to `TIMESTAMP(6) WITHOUT TIME ZONE`
+    /// and `INT` to `INTEGER`.
+    ///
+    /// [(1)]: DataType::canonical
+    /// [(2)]: DataType::with_explicit_defaults
+    #[must_use]
+    pub fn is_equivalent_to(&self, other: &Self) -> bool {
+        self.canonical().with_explicit_defaults() == other.canonical().with_explicit_defaults()
+    }
+
+    /// Returns whether changing a column from this type to the received one
+    /// may truncate or reject its values: the length, precision or scale is
+    /// reduced, or the values are converted to another kind of type (e.g.,
+    /// from `TIMESTAMP` to `DATE` or from `DECIMAL` to `REAL`).
+    ///
+    /// Lengths are compared regardless of their units, and unspecified
+    /// lengths and precisions are treated as unbounded.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::data_types::{CharacterLength, DataType};
+    /// let varchar = |length| DataType::Varchar(Some(CharacterLength::new(length)));
+    ///
+    /// assert!(varchar(20).is_narrowing_to(&varchar(10)));
+    /// assert!(!varchar(10).is_narrowing_to(&varchar(20)));
+    /// assert!(DataType::Bigint.is_narrowing_to(&DataType::Int));
+    /// assert!(!DataType::Int.is_narrowing_to(&DataType::Bigint));
+    /// ```
+    #[must_use]
+    pub fn is_narrowing_to(&self, other: &Self) -> bool {
+        let from = self.canonical().with_explicit_defaults();
+        let to = other.canonical().with_explicit_defaults();
+        if from == to {
+            return false;
+        }
+
+        match (from.capacity(), to.capacity()) {
+            (Capacity::Character(from_length), Capacity::Character(to_length))
+            | (Capacity::Binary(from_length), Capacity::Binary(to_length)) => {
+                to_length < from_length
+            }
+            (
+                Capacity::Exact {
+                    integer_digits: from_digits,
+                    scale: from_scale,
+                },
+                Capacity::Exact {
+                    integer_digits: to_digits,
+                    scale: to_scale,
+                },
+            ) => to_digits < from_digits || to_scale < from_scale,
+            // OBS: approximate types hold integers exactly up to their
+            // mantissa precision, in decimal digits.
+            (
+                Capacity::Exact {
+                    integer_digits,
+                    scale,
+                },
+                Capacity::Approximate(digits),
+            ) => scale > 0 || integer_digits > digits,
+            (Capacity::Approximate(from_digits), Capacity::Approximate(to_digits))
+            | (Capacity::Time(from_digits), Capacity::Time(to_digits))
+            | (Capacity::Timestamp(from_digits), Capacity::Timestamp(to_digits)) => {
+                to_digits < from_digits
+            }
+            (Capacity::Date, Capacity::Timestamp(_)) => false,
+            _ => true,
+        }
+    }
+
+    /// Returns the values the type can hold, to compare types of the same
+    /// kind.
+    fn capacity(&self) -> Capacity {
+        const UNBOUNDED: u64 = u64::MAX;
+
+        let character_length = |opt_len: &Option<CharacterLength>| {
+            opt_len.map_or(UNBOUNDED, |len| u64::from(len.length()))
+        };
+        let exact = |number_info: &ExactNumberInfo| match number_info {
+            ExactNumberInfo::None => Capacity::Exact {
+                integer_digits: u32::MAX,
+                scale: 0,
+            },
+            ExactNumberInfo::Precision(precision) => Capacity::Exact {
+                integer_digits: *precision,
+                scale: 0,
+            },
+            ExactNumberInfo::PrecisionAndScale(precision, scale) => Capacity::Exact {
+                integer_digits: precision.saturating_sub(*scale),
+                scale: *scale,
+            },
+        };
+        let integer = |integer_digits| Capacity::Exact {
+            integer_digits,
+            scale: 0,
+        };
+
+        match self {
+            Self::Character(opt_len)
+            | Self::Char(opt_len)
+            | Self::CharacterVarying(opt_len)
+            | Self::CharVarying(opt_len)
+            | Self::Varchar(opt_len) => Capacity::Character(character_length(opt_len)),
+            Self::CharacterLargeObject(opt_len)
+            | Self::CharLargeObject(opt_len)
+            | Self::Clob(opt_len) => {
+                Capacity::Character(opt_len.map_or(UNBOUNDED, |len| len.length().bytes()))
+            }
+            Self::Binary(opt_len) | Self::BinaryVarying(opt_len) | Self::Varbinary(opt_len) => {
+                Capacity::Binary(opt_len.map_or(UNBOUNDED, u64::from))
+            }
+            Self::BinaryLargeObject(opt_len) | Self::Blob(opt_len) => {
+                Capacity::Binary(opt_len.map_or(UNBOUNDED, |len| len.bytes()))
+            }
+            Self::Numeric(number_info) | Self::Decimal(number_info) | Self::Dec(number_info) => {
+                exact(number_info)
+            }
+            Self::Smallint => integer(5),
+            Self::Integer | Self::Int => integer(10),
+            Self::Bigint => integer(19),
+            Self::Real => Capacity::Approximate(6),
+            Self::Float | Self::DoublePrecision => Capacity::Approximate(15),
+            Self::DecFloat(opt_precision) => Capacity::Approximate(opt_precision.unwrap_or(34)),
+            Self::Boolean => Capacity::Boolean,
+            Self::Date => Capacity::Date,
+            Self::Time(..) => Capacity::Time(self.effective_precision().unwrap_or_default()),
+            Self::Timestamp(..) => {
+                Capacity::Timestamp(self.effective_precision().unwrap_or_default())
+            }
+            Self::NumericAlias(alias) => alias.data_type().capacity(),
+        }
+    }
+}
+
+/// Values that a data type can hold, by kind of type [(1)].
+///
+/// [(1)]: DataType::is_narrowing_to
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Capacity {
+    /// Maximum length of a character string.
+    Character(u64),
+    /// Maximum length of a binary string.
+    Binary(u64),
+    /// Maximum number of digits before and after the decimal point.
+    Exact {
+        integer_digits: u32,
+        scale: u32,
+    },
+    /// Decimal digits of precision.
+    Approximate(u32),
+    Boolean,
+    Date,
+    /// Fractional seconds precision.
+    Time(u32),
+    /// Fractional seconds precision.
+    Timestamp(u32),
+}
+
+impl NumericTypeAlias {
+    /// Returns the `ANSI` type spelled by the alias.
+    #[must_use]
+    pub const fn data_type(self) -> DataType {
+        match self {
+            Self::Double | Self::Float8 => DataType::DoublePrecision,
+            Self::Float4 => DataType::Real,
+            Self::Int2 => DataType::Smallint,
+            Self::Int4 => DataType::Integer,
+            Self::Int8 => DataType::Bigint,
+        }
+    }
+}
+
+impl WithOrWithoutTimeZone {
+    /// Returns `WITHOUT TIME ZONE` if the time zone info was omitted.
+    #[must_use]
+    pub const fn with_explicit_default(self) -> Self {
+        match self {
+            Self::None => Self::WithoutTimeZone,
+            time_zone => time_zone,
+        }
+    }
+}
+
 // TODO split data types
 #[allow(clippy::too_many_lines)]
 impl fmt::Display for DataType {
@@ -358,6 +701,9 @@ impl fmt::Display for DataType {
                     write!(f, " {tz_info}")?;
                 }
             }
+            Self::NumericAlias(alias) => {
+                write!(f, "{alias}")?;
+            }
         }
 
         Ok(())
@@ -373,12 +719,14 @@ impl CharacterLength {
         }
     }
 
-    pub fn with_units(&mut self, units: CharLengthUnits) -> &mut Self {
+    #[must_use]
+    pub const fn with_units(mut self, units: CharLengthUnits) -> Self {
         self.opt_units = Some(units);
         self
     }
 
-    pub fn with_opt_units(&mut self, units: Option<CharLengthUnits>) -> &mut Self {
+    #[must_use]
+    pub const fn with_opt_units(mut self, units: Option<CharLengthUnits>) -> Self {
         self.opt_units = units;
         self
     }
@@ -425,7 +773,8 @@ impl CharacterLargeObjectLength {
         }
     }
 
-    pub fn with_units(&mut self, units: CharLengthUnits) -> &mut Self {
+    #[must_use]
+    pub const fn with_units(mut self, units: CharLengthUnits) -> Self {
         self.opt_units = Some(units);
         self
     }
@@ -462,7 +811,8 @@ impl LargeObjectLength {
         }
     }
 
-    pub fn with_multiplier(&mut self, multiplier: Multiplier) -> &mut Self {
+    #[must_use]
+    pub const fn with_multiplier(mut self, multiplier: Multiplier) -> Self {
         self.multiplier = Some(multiplier);
         self
     }
@@ -476,6 +826,22 @@ impl LargeObjectLength {
     pub const fn opt_multiplier(&self) -> Option<Multiplier> {
         self.multiplier
     }
+
+    /// Returns the length in bytes, applying the multiplier (e.g., `2K` is
+    /// 2048 bytes). Lengths that don't fit in 64 bits saturate.
+    #[must_use]
+    pub fn bytes(&self) -> u64 {
+        let multiplier = match self.opt_multiplier() {
+            None => 1,
+            Some(Multiplier::K) => 1 << 10,
+            Some(Multiplier::M) => 1 << 20,
+            Some(Multiplier::G) => 1 << 30,
+            Some(Multiplier::T) => 1 << 40,
+            Some(Multiplier::P) => 1 << 50,
+        };
+
+        u64::from(self.length()).saturating_mul(multiplier)
+    }
 }
 
 impl fmt::Display for LargeObjectLength {
@@ -503,6 +869,20 @@ impl fmt::Display for Multiplier {
     }
 }
 
+impl fmt::Display for NumericTypeAlias {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Double => write!(f, "DOUBLE")?,
+            Self::Float4 => write!(f, "FLOAT4")?,
+            Self::Float8 => write!(f, "FLOAT8")?,
+            Self::Int2 => write!(f, "INT2")?,
+            Self::Int4 => write!(f, "INT4")?,
+            Self::Int8 => write!(f, "INT8")?,
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Display for ExactNumberInfo {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
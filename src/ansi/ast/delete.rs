@@ -0,0 +1,61 @@
+use std::fmt;
+
+use crate::ansi::ast::common::TableName;
+use crate::common::Ident;
+
+/// Positioned `DELETE` statement (`<delete statement: positioned>`) [(1)].
+///
+/// # Supported syntax
+/// ```doc
+/// DELETE FROM <table name> WHERE CURRENT OF <cursor name>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_14_8_delete_statement_positioned
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeletePositioned {
+    /// `<table name>`
+    table_name: TableName,
+    /// `<cursor name>`
+    cursor_name: Ident,
+}
+
+impl DeletePositioned {
+    #[must_use]
+    pub fn new(table_name: TableName, cursor_name: impl Into<Ident>) -> Self {
+        Self {
+            table_name,
+            cursor_name: cursor_name.into(),
+        }
+    }
+
+    #[must_use]
+    pub const fn table_name(&self) -> &TableName {
+        &self.table_name
+    }
+
+    #[must_use]
+    pub const fn cursor_name(&self) -> &Ident {
+        &self.cursor_name
+    }
+
+    pub fn table_name_mut(&mut self) -> &mut TableName {
+        &mut self.table_name
+    }
+
+    pub fn cursor_name_mut(&mut self) -> &mut Ident {
+        &mut self.cursor_name
+    }
+}
+
+impl fmt::Display for DeletePositioned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DELETE FROM {} WHERE CURRENT OF {}",
+            self.table_name(),
+            self.cursor_name()
+        )?;
+        Ok(())
+    }
+}
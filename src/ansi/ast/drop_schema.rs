@@ -11,6 +11,7 @@ use crate::ansi::ast::common::{DropBehavior, SchemaName};
 ///
 /// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_11_2_drop_schema_statement
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DropSchema {
     /// `<schema name>`
     schema_name: SchemaName,
@@ -20,9 +21,9 @@ pub struct DropSchema {
 
 impl DropSchema {
     #[must_use]
-    pub fn new(schema_name: &SchemaName, drop_behavior: DropBehavior) -> Self {
+    pub const fn new(schema_name: SchemaName, drop_behavior: DropBehavior) -> Self {
         Self {
-            schema_name: schema_name.clone(),
+            schema_name,
             drop_behavior,
         }
     }
@@ -36,13 +37,17 @@ impl DropSchema {
     pub const fn drop_behavior(&self) -> DropBehavior {
         self.drop_behavior
     }
+
+    pub fn schema_name_mut(&mut self) -> &mut SchemaName {
+        &mut self.schema_name
+    }
 }
 
 impl fmt::Display for DropSchema {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "DROP SCHEMA {} {};",
+            "DROP SCHEMA {} {}",
             self.schema_name(),
             self.drop_behavior()
         )?;
@@ -8,6 +8,7 @@ use std::fmt;
 /// DROP TABLE <table name> <drop behavior>
 /// ```
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DropTable {
     /// `<table name>`
     table_name: TableName,
@@ -17,9 +18,9 @@ pub struct DropTable {
 
 impl DropTable {
     #[must_use]
-    pub fn new(table_name: &TableName, drop_behavior: DropBehavior) -> Self {
+    pub const fn new(table_name: TableName, drop_behavior: DropBehavior) -> Self {
         Self {
-            table_name: table_name.clone(),
+            table_name,
             drop_behavior,
         }
     }
@@ -33,6 +34,10 @@ impl DropTable {
     pub const fn drop_behavior(&self) -> DropBehavior {
         self.drop_behavior
     }
+
+    pub fn table_name_mut(&mut self) -> &mut TableName {
+        &mut self.table_name
+    }
 }
 
 impl fmt::Display for DropTable {
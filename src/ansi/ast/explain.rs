@@ -0,0 +1,64 @@
+use std::fmt;
+
+use crate::ansi::Statement;
+
+/// `EXPLAIN` statement, a dialect extension that wraps another statement to
+/// show its execution plan.
+///
+/// It's only accepted by the permissive parser mode [(1)].
+///
+/// # Supported syntax
+/// ```doc
+/// EXPLAIN [ ANALYZE ] <statement>
+/// ```
+///
+/// [(1)]: crate::ansi::parser::options::ParserMode::Permissive
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Explain {
+    /// Whether the statement is executed, to show its actual run times.
+    analyze: bool,
+    /// Explained statement.
+    statement: Box<Statement>,
+}
+
+impl Explain {
+    #[must_use]
+    pub fn new(statement: Statement) -> Self {
+        Self {
+            analyze: false,
+            statement: Box::new(statement),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_analyze(mut self, analyze: bool) -> Self {
+        self.analyze = analyze;
+        self
+    }
+
+    #[must_use]
+    pub const fn analyze(&self) -> bool {
+        self.analyze
+    }
+
+    #[must_use]
+    pub const fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    pub fn statement_mut(&mut self) -> &mut Statement {
+        &mut self.statement
+    }
+}
+
+impl fmt::Display for Explain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EXPLAIN ")?;
+        if self.analyze() {
+            write!(f, "ANALYZE ")?;
+        }
+        write!(f, "{}", self.statement())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use std::fmt;
+
+use crate::common::{display_comma_separated, if_some_string_preceded_by, Ident};
+
+/// `FETCH` statement [(1)].
+///
+/// # Supported syntax
+/// ```doc
+/// FETCH [ [ <fetch orientation> ] FROM ] <cursor name> INTO <fetch target list>
+///
+/// <fetch target list> ::=
+///   <target specification> [ { <comma> <target specification> }... ]
+/// ```
+///
+/// The `FROM` keyword is optional when there is no orientation, and is only
+/// rendered with one.
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_14_5_fetch_statement
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fetch {
+    /// `[ <fetch orientation> ]`
+    opt_fetch_orientation: Option<FetchOrientation>,
+    /// `<cursor name>`
+    cursor_name: Ident,
+    /// `<fetch target list>`
+    target_list: Vec<TargetSpecification>,
+}
+
+/// Fetch orientation.
+///
+/// # Supported syntax
+/// ```doc
+///   NEXT
+/// | PRIOR
+/// | FIRST
+/// | LAST
+/// | { ABSOLUTE | RELATIVE } <signed integer>
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FetchOrientation {
+    /// `NEXT`
+    Next,
+    /// `PRIOR`
+    Prior,
+    /// `FIRST`
+    First,
+    /// `LAST`
+    Last,
+    /// `ABSOLUTE <signed integer>`
+    Absolute(i64),
+    /// `RELATIVE <signed integer>`
+    Relative(i64),
+}
+
+/// Target of a fetched value.
+///
+/// # Supported syntax
+/// ```doc
+///   <host parameter name>
+/// | <SQL parameter reference>
+///
+/// <host parameter name> ::= <colon> <identifier>
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TargetSpecification {
+    /// `<host parameter name>` (e.g., `:name`).
+    HostParameter(Ident),
+    /// `<SQL parameter reference>`, inside of routines.
+    SqlParameter(Ident),
+}
+
+impl Fetch {
+    #[must_use]
+    pub fn new(cursor_name: impl Into<Ident>, target_list: Vec<TargetSpecification>) -> Self {
+        Self {
+            opt_fetch_orientation: None,
+            cursor_name: cursor_name.into(),
+            target_list,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_fetch_orientation(mut self, fetch_orientation: FetchOrientation) -> Self {
+        self.opt_fetch_orientation = Some(fetch_orientation);
+        self
+    }
+
+    #[must_use]
+    pub const fn opt_fetch_orientation(&self) -> Option<FetchOrientation> {
+        self.opt_fetch_orientation
+    }
+
+    #[must_use]
+    pub const fn cursor_name(&self) -> &Ident {
+        &self.cursor_name
+    }
+
+    #[must_use]
+    pub fn target_list(&self) -> &[TargetSpecification] {
+        &self.target_list
+    }
+
+    pub fn cursor_name_mut(&mut self) -> &mut Ident {
+        &mut self.cursor_name
+    }
+
+    pub fn target_list_mut(&mut self) -> &mut [TargetSpecification] {
+        &mut self.target_list
+    }
+}
+
+impl fmt::Display for Fetch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "FETCH{orientation} {cursor_name} INTO {targets}",
+            orientation = if_some_string_preceded_by(
+                self.opt_fetch_orientation()
+                    .map(|orientation| format!("{orientation} FROM")),
+                " "
+            ),
+            cursor_name = self.cursor_name(),
+            targets = display_comma_separated(self.target_list())
+        )?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for FetchOrientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Next => write!(f, "NEXT")?,
+            Self::Prior => write!(f, "PRIOR")?,
+            Self::First => write!(f, "FIRST")?,
+            Self::Last => write!(f, "LAST")?,
+            Self::Absolute(position) => write!(f, "ABSOLUTE {position}")?,
+            Self::Relative(offset) => write!(f, "RELATIVE {offset}")?,
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TargetSpecification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HostParameter(name) => write!(f, ":{name}")?,
+            Self::SqlParameter(name) => write!(f, "{name}")?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,455 @@
+use std::fmt;
+
+use crate::ansi::ast::common::{ColumnNameList, RoutineName, SchemaQualifiedName, TableName};
+use crate::ansi::ast::data_types::DataType;
+use crate::common::{display_comma_separated, Ident};
+
+/// `GRANT` privilege statement (`<grant privilege statement>`) [(1)].
+///
+/// # Supported syntax
+/// ```doc
+/// GRANT <privileges> TO <grantee> [ { <comma> <grantee> }... ]
+///     [ WITH HIERARCHY OPTION ]
+///     [ WITH GRANT OPTION ]
+///     [ GRANTED BY <grantor> ]
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_12_2_grant_privilege_statement
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrantPrivilege {
+    /// `<privileges>`
+    privileges: Privileges,
+    /// `<grantee>` list
+    grantees: Vec<Grantee>,
+    /// `WITH HIERARCHY OPTION`
+    hierarchy_option: bool,
+    /// `WITH GRANT OPTION`
+    grant_option: bool,
+    /// `GRANTED BY <grantor>`
+    opt_grantor: Option<Grantor>,
+}
+
+/// Receiver of privileges (`<grantee>`).
+///
+/// # Supported syntax
+/// ```doc
+///   PUBLIC
+/// | <authorization identifier>
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Grantee {
+    /// `PUBLIC`, every current and future authorization identifier.
+    Public,
+    /// `<authorization identifier>`.
+    AuthorizationIdentifier(Ident),
+}
+
+/// Authorization identifier that grants or revokes privileges (`<grantor>`).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Grantor {
+    /// `CURRENT_USER`.
+    CurrentUser,
+    /// `CURRENT_ROLE`.
+    CurrentRole,
+}
+
+/// Privileges on an object (`<privileges>`) [(1)].
+///
+/// # Supported syntax
+/// ```doc
+/// <object privileges> ON <object name>
+///
+/// <object privileges> ::=
+///     ALL PRIVILEGES
+///   | <action> [ { <comma> <action> }... ]
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_12_3_privileges
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Privileges {
+    /// `<object privileges>`
+    object_privileges: ObjectPrivileges,
+    /// `<object name>`
+    object: GrantObject,
+}
+
+/// Privileges granted on an object (`<object privileges>`).
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObjectPrivileges {
+    /// `ALL PRIVILEGES`.
+    AllPrivileges,
+    /// `<action>` list.
+    Actions(Vec<Action>),
+}
+
+/// Privileged action (`<action>`).
+///
+/// # Supported syntax
+/// ```doc
+///   SELECT [ <left paren> <privilege column list> <right paren> ]
+/// | DELETE
+/// | INSERT [ <left paren> <privilege column list> <right paren> ]
+/// | UPDATE [ <left paren> <privilege column list> <right paren> ]
+/// | REFERENCES [ <left paren> <privilege column list> <right paren> ]
+/// | USAGE
+/// | TRIGGER
+/// | UNDER
+/// | EXECUTE
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// `SELECT`, on some columns or on the whole table.
+    Select(Option<ColumnNameList>),
+    /// `DELETE`.
+    Delete,
+    /// `INSERT`, on some columns or on the whole table.
+    Insert(Option<ColumnNameList>),
+    /// `UPDATE`, on some columns or on the whole table.
+    Update(Option<ColumnNameList>),
+    /// `REFERENCES`, on some columns or on the whole table.
+    References(Option<ColumnNameList>),
+    /// `USAGE`.
+    Usage,
+    /// `TRIGGER`.
+    Trigger,
+    /// `UNDER`.
+    Under,
+    /// `EXECUTE`.
+    Execute,
+}
+
+/// Object that privileges are granted on (`<object name>`).
+///
+/// # Supported syntax
+/// ```doc
+///   [ TABLE ] <table name>
+/// | DOMAIN <domain name>
+/// | SEQUENCE <sequence generator name>
+/// | TYPE <schema-resolved user-defined type name>
+/// | CHARACTER SET <character set name>
+/// | <specific routine designator>
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GrantObject {
+    /// Table, where the `TABLE` keyword is optional.
+    Table(TableName),
+    /// `DOMAIN`.
+    Domain(SchemaQualifiedName),
+    /// `SEQUENCE`.
+    Sequence(SchemaQualifiedName),
+    /// `TYPE`.
+    Type(SchemaQualifiedName),
+    /// `CHARACTER SET`.
+    CharacterSet(SchemaQualifiedName),
+    /// `FUNCTION` or `PROCEDURE`.
+    Routine(SpecificRoutineDesignator),
+}
+
+/// Routine designator (`<specific routine designator>`).
+///
+/// # Supported syntax
+/// ```doc
+/// <routine type> <routine name> [ <data type list> ]
+///
+/// <routine type> ::= FUNCTION | PROCEDURE
+///
+/// <data type list> ::=
+///     <left paren> [ <data type> [ { <comma> <data type> }... ] ] <right paren>
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpecificRoutineDesignator {
+    /// `<routine type>`
+    routine_type: RoutineType,
+    /// `<routine name>`
+    routine_name: RoutineName,
+    /// `<data type list>`, which selects one of the overloaded routines.
+    opt_data_type_list: Option<Vec<DataType>>,
+}
+
+/// Routine type (`<routine type>`).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoutineType {
+    /// `FUNCTION`.
+    Function,
+    /// `PROCEDURE`.
+    Procedure,
+}
+
+impl GrantPrivilege {
+    #[must_use]
+    pub fn new(privileges: Privileges, grantees: Vec<Grantee>) -> Self {
+        Self {
+            privileges,
+            grantees,
+            hierarchy_option: false,
+            grant_option: false,
+            opt_grantor: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_hierarchy_option(mut self, hierarchy_option: bool) -> Self {
+        self.hierarchy_option = hierarchy_option;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_grant_option(mut self, grant_option: bool) -> Self {
+        self.grant_option = grant_option;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_grantor(mut self, grantor: Grantor) -> Self {
+        self.opt_grantor = Some(grantor);
+        self
+    }
+
+    #[must_use]
+    pub const fn privileges(&self) -> &Privileges {
+        &self.privileges
+    }
+
+    #[must_use]
+    pub fn grantees(&self) -> &[Grantee] {
+        &self.grantees
+    }
+
+    #[must_use]
+    pub const fn hierarchy_option(&self) -> bool {
+        self.hierarchy_option
+    }
+
+    #[must_use]
+    pub const fn grant_option(&self) -> bool {
+        self.grant_option
+    }
+
+    #[must_use]
+    pub const fn opt_grantor(&self) -> Option<Grantor> {
+        self.opt_grantor
+    }
+
+    pub fn privileges_mut(&mut self) -> &mut Privileges {
+        &mut self.privileges
+    }
+
+    pub fn grantees_mut(&mut self) -> &mut [Grantee] {
+        &mut self.grantees
+    }
+}
+
+impl fmt::Display for GrantPrivilege {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GRANT {} TO {}",
+            self.privileges(),
+            display_comma_separated(self.grantees())
+        )?;
+        if self.hierarchy_option() {
+            write!(f, " WITH HIERARCHY OPTION")?;
+        }
+        if self.grant_option() {
+            write!(f, " WITH GRANT OPTION")?;
+        }
+        if let Some(grantor) = self.opt_grantor() {
+            write!(f, " GRANTED BY {grantor}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Grantee {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Public => write!(f, "PUBLIC")?,
+            Self::AuthorizationIdentifier(authorization) => write!(f, "{authorization}")?,
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Grantor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CurrentUser => write!(f, "CURRENT_USER")?,
+            Self::CurrentRole => write!(f, "CURRENT_ROLE")?,
+        }
+        Ok(())
+    }
+}
+
+impl Privileges {
+    #[must_use]
+    pub const fn new(object_privileges: ObjectPrivileges, object: GrantObject) -> Self {
+        Self {
+            object_privileges,
+            object,
+        }
+    }
+
+    #[must_use]
+    pub const fn object_privileges(&self) -> &ObjectPrivileges {
+        &self.object_privileges
+    }
+
+    #[must_use]
+    pub const fn object(&self) -> &GrantObject {
+        &self.object
+    }
+
+    pub fn object_privileges_mut(&mut self) -> &mut ObjectPrivileges {
+        &mut self.object_privileges
+    }
+
+    pub fn object_mut(&mut self) -> &mut GrantObject {
+        &mut self.object
+    }
+}
+
+impl fmt::Display for Privileges {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ON {}", self.object_privileges(), self.object())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for ObjectPrivileges {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AllPrivileges => write!(f, "ALL PRIVILEGES")?,
+            Self::Actions(actions) => write!(f, "{}", display_comma_separated(actions))?,
+        }
+        Ok(())
+    }
+}
+
+impl Action {
+    /// Returns the privilege column list of the action, if any.
+    #[must_use]
+    pub const fn opt_column_name_list(&self) -> Option<&ColumnNameList> {
+        match self {
+            Self::Select(opt_column_name_list)
+            | Self::Insert(opt_column_name_list)
+            | Self::Update(opt_column_name_list)
+            | Self::References(opt_column_name_list) => opt_column_name_list.as_ref(),
+            Self::Delete | Self::Usage | Self::Trigger | Self::Under | Self::Execute => None,
+        }
+    }
+
+    pub fn opt_column_name_list_mut(&mut self) -> Option<&mut ColumnNameList> {
+        match self {
+            Self::Select(opt_column_name_list)
+            | Self::Insert(opt_column_name_list)
+            | Self::Update(opt_column_name_list)
+            | Self::References(opt_column_name_list) => opt_column_name_list.as_mut(),
+            Self::Delete | Self::Usage | Self::Trigger | Self::Under | Self::Execute => None,
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Select(_) => write!(f, "SELECT")?,
+            Self::Delete => write!(f, "DELETE")?,
+            Self::Insert(_) => write!(f, "INSERT")?,
+            Self::Update(_) => write!(f, "UPDATE")?,
+            Self::References(_) => write!(f, "REFERENCES")?,
+            Self::Usage => write!(f, "USAGE")?,
+            Self::Trigger => write!(f, "TRIGGER")?,
+            Self::Under => write!(f, "UNDER")?,
+            Self::Execute => write!(f, "EXECUTE")?,
+        }
+        if let Some(column_name_list) = self.opt_column_name_list() {
+            write!(f, " ({column_name_list})")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for GrantObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Table(table_name) => write!(f, "{table_name}")?,
+            Self::Domain(domain_name) => write!(f, "DOMAIN {domain_name}")?,
+            Self::Sequence(sequence_name) => write!(f, "SEQUENCE {sequence_name}")?,
+            Self::Type(type_name) => write!(f, "TYPE {type_name}")?,
+            Self::CharacterSet(character_set_name) => {
+                write!(f, "CHARACTER SET {character_set_name}")?;
+            }
+            Self::Routine(routine) => write!(f, "{routine}")?,
+        }
+        Ok(())
+    }
+}
+
+impl SpecificRoutineDesignator {
+    #[must_use]
+    pub const fn new(routine_type: RoutineType, routine_name: RoutineName) -> Self {
+        Self {
+            routine_type,
+            routine_name,
+            opt_data_type_list: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_data_type_list(mut self, data_type_list: Vec<DataType>) -> Self {
+        self.opt_data_type_list = Some(data_type_list);
+        self
+    }
+
+    #[must_use]
+    pub const fn routine_type(&self) -> RoutineType {
+        self.routine_type
+    }
+
+    #[must_use]
+    pub const fn routine_name(&self) -> &RoutineName {
+        &self.routine_name
+    }
+
+    #[must_use]
+    pub fn opt_data_type_list(&self) -> Option<&[DataType]> {
+        self.opt_data_type_list.as_deref()
+    }
+
+    pub fn routine_name_mut(&mut self) -> &mut RoutineName {
+        &mut self.routine_name
+    }
+
+    pub fn opt_data_type_list_mut(&mut self) -> Option<&mut [DataType]> {
+        self.opt_data_type_list.as_deref_mut()
+    }
+}
+
+impl fmt::Display for SpecificRoutineDesignator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.routine_type(), self.routine_name())?;
+        if let Some(data_type_list) = self.opt_data_type_list() {
+            write!(f, "({})", display_comma_separated(data_type_list))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RoutineType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Function => write!(f, "FUNCTION")?,
+            Self::Procedure => write!(f, "PROCEDURE")?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+use crate::common::Ident;
+
+/// `OPEN` statement [(1)].
+///
+/// # Supported syntax
+/// ```doc
+/// OPEN <cursor name>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_14_4_open_statement
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Open {
+    /// `<cursor name>`
+    cursor_name: Ident,
+}
+
+impl Open {
+    #[must_use]
+    pub fn new(cursor_name: impl Into<Ident>) -> Self {
+        Self {
+            cursor_name: cursor_name.into(),
+        }
+    }
+
+    #[must_use]
+    pub const fn cursor_name(&self) -> &Ident {
+        &self.cursor_name
+    }
+
+    pub fn cursor_name_mut(&mut self) -> &mut Ident {
+        &mut self.cursor_name
+    }
+}
+
+impl fmt::Display for Open {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "OPEN {}", self.cursor_name())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+use crate::ansi::ast::common::ValueSpecification;
+
+/// `RETURN` statement [(1)].
+///
+/// # Supported syntax
+/// ```doc
+/// RETURN <return value>
+///
+/// <return value> ::= <value specification>
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_16_2_return_statement
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Return {
+    /// `<return value>`
+    return_value: ValueSpecification,
+}
+
+impl Return {
+    #[must_use]
+    pub const fn new(return_value: ValueSpecification) -> Self {
+        Self { return_value }
+    }
+
+    #[must_use]
+    pub const fn return_value(&self) -> &ValueSpecification {
+        &self.return_value
+    }
+
+    pub fn return_value_mut(&mut self) -> &mut ValueSpecification {
+        &mut self.return_value
+    }
+}
+
+impl fmt::Display for Return {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RETURN {}", self.return_value())?;
+        Ok(())
+    }
+}
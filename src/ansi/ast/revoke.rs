@@ -0,0 +1,139 @@
+use std::fmt;
+
+use crate::ansi::ast::common::DropBehavior;
+use crate::ansi::ast::grant::{Grantee, Grantor, Privileges};
+use crate::common::display_comma_separated;
+
+/// `REVOKE` privilege statement (`<revoke privilege statement>`) [(1)].
+///
+/// # Supported syntax
+/// ```doc
+/// REVOKE [ <revoke option extension> ] <privileges>
+///     FROM <grantee> [ { <comma> <grantee> }... ]
+///     [ GRANTED BY <grantor> ]
+///     <drop behavior>
+///
+/// <revoke option extension> ::= GRANT OPTION FOR | HIERARCHY OPTION FOR
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_12_7_revoke_statement
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RevokePrivilege {
+    /// `<revoke option extension>`
+    opt_revoke_option_extension: Option<RevokeOptionExtension>,
+    /// `<privileges>`
+    privileges: Privileges,
+    /// `<grantee>` list
+    grantees: Vec<Grantee>,
+    /// `GRANTED BY <grantor>`
+    opt_grantor: Option<Grantor>,
+    /// `<drop behavior>`
+    drop_behavior: DropBehavior,
+}
+
+/// Option revoked instead of the privileges themselves (`<revoke option
+/// extension>`).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RevokeOptionExtension {
+    /// `GRANT OPTION FOR`.
+    GrantOption,
+    /// `HIERARCHY OPTION FOR`.
+    HierarchyOption,
+}
+
+impl RevokePrivilege {
+    #[must_use]
+    pub fn new(
+        privileges: Privileges,
+        grantees: Vec<Grantee>,
+        drop_behavior: DropBehavior,
+    ) -> Self {
+        Self {
+            opt_revoke_option_extension: None,
+            privileges,
+            grantees,
+            opt_grantor: None,
+            drop_behavior,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_revoke_option_extension(
+        mut self,
+        revoke_option_extension: RevokeOptionExtension,
+    ) -> Self {
+        self.opt_revoke_option_extension = Some(revoke_option_extension);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_grantor(mut self, grantor: Grantor) -> Self {
+        self.opt_grantor = Some(grantor);
+        self
+    }
+
+    #[must_use]
+    pub const fn opt_revoke_option_extension(&self) -> Option<RevokeOptionExtension> {
+        self.opt_revoke_option_extension
+    }
+
+    #[must_use]
+    pub const fn privileges(&self) -> &Privileges {
+        &self.privileges
+    }
+
+    #[must_use]
+    pub fn grantees(&self) -> &[Grantee] {
+        &self.grantees
+    }
+
+    #[must_use]
+    pub const fn opt_grantor(&self) -> Option<Grantor> {
+        self.opt_grantor
+    }
+
+    #[must_use]
+    pub const fn drop_behavior(&self) -> DropBehavior {
+        self.drop_behavior
+    }
+
+    pub fn privileges_mut(&mut self) -> &mut Privileges {
+        &mut self.privileges
+    }
+
+    pub fn grantees_mut(&mut self) -> &mut [Grantee] {
+        &mut self.grantees
+    }
+}
+
+impl fmt::Display for RevokePrivilege {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "REVOKE ")?;
+        if let Some(revoke_option_extension) = self.opt_revoke_option_extension() {
+            write!(f, "{revoke_option_extension} ")?;
+        }
+        write!(
+            f,
+            "{} FROM {}",
+            self.privileges(),
+            display_comma_separated(self.grantees())
+        )?;
+        if let Some(grantor) = self.opt_grantor() {
+            write!(f, " GRANTED BY {grantor}")?;
+        }
+        write!(f, " {}", self.drop_behavior())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for RevokeOptionExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GrantOption => write!(f, "GRANT OPTION FOR")?,
+            Self::HierarchyOption => write!(f, "HIERARCHY OPTION FOR")?,
+        }
+        Ok(())
+    }
+}
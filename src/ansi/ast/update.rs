@@ -0,0 +1,156 @@
+use std::fmt;
+
+use crate::ansi::ast::common::{TableName, ValueSpecification};
+use crate::common::{display_comma_separated, Ident};
+
+/// Positioned `UPDATE` statement (`<update statement: positioned>`) [(1)].
+///
+/// # Supported syntax
+/// ```doc
+/// UPDATE <table name> SET <set clause list> WHERE CURRENT OF <cursor name>
+///
+/// <set clause list> ::= <set clause> [ { <comma> <set clause> }... ]
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_14_13_update_statement_positioned
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdatePositioned {
+    /// `<table name>`
+    table_name: TableName,
+    /// `<set clause list>`
+    set_clause_list: Vec<SetClause>,
+    /// `<cursor name>`
+    cursor_name: Ident,
+}
+
+/// Set clause.
+///
+/// # Supported syntax
+/// ```doc
+/// <column name> <equals operator> <update source>
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetClause {
+    /// `<column name>`
+    column_name: Ident,
+    /// `<update source>`
+    update_source: UpdateSource,
+}
+
+/// Value assigned by a set clause.
+///
+/// # Supported syntax
+/// ```doc
+///   <value specification>
+/// | DEFAULT
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UpdateSource {
+    /// `<value specification>`.
+    Value(ValueSpecification),
+    /// `DEFAULT`.
+    Default,
+}
+
+impl UpdatePositioned {
+    #[must_use]
+    pub fn new(
+        table_name: TableName,
+        set_clause_list: Vec<SetClause>,
+        cursor_name: impl Into<Ident>,
+    ) -> Self {
+        Self {
+            table_name,
+            set_clause_list,
+            cursor_name: cursor_name.into(),
+        }
+    }
+
+    #[must_use]
+    pub const fn table_name(&self) -> &TableName {
+        &self.table_name
+    }
+
+    #[must_use]
+    pub fn set_clause_list(&self) -> &[SetClause] {
+        &self.set_clause_list
+    }
+
+    #[must_use]
+    pub const fn cursor_name(&self) -> &Ident {
+        &self.cursor_name
+    }
+
+    pub fn table_name_mut(&mut self) -> &mut TableName {
+        &mut self.table_name
+    }
+
+    pub fn set_clause_list_mut(&mut self) -> &mut [SetClause] {
+        &mut self.set_clause_list
+    }
+
+    pub fn cursor_name_mut(&mut self) -> &mut Ident {
+        &mut self.cursor_name
+    }
+}
+
+impl fmt::Display for UpdatePositioned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UPDATE {} SET {} WHERE CURRENT OF {}",
+            self.table_name(),
+            display_comma_separated(self.set_clause_list()),
+            self.cursor_name()
+        )?;
+        Ok(())
+    }
+}
+
+impl SetClause {
+    #[must_use]
+    pub fn new(column_name: impl Into<Ident>, update_source: UpdateSource) -> Self {
+        Self {
+            column_name: column_name.into(),
+            update_source,
+        }
+    }
+
+    #[must_use]
+    pub const fn column_name(&self) -> &Ident {
+        &self.column_name
+    }
+
+    #[must_use]
+    pub const fn update_source(&self) -> &UpdateSource {
+        &self.update_source
+    }
+
+    pub fn column_name_mut(&mut self) -> &mut Ident {
+        &mut self.column_name
+    }
+
+    pub fn update_source_mut(&mut self) -> &mut UpdateSource {
+        &mut self.update_source
+    }
+}
+
+impl fmt::Display for SetClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.column_name(), self.update_source())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for UpdateSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value(value_specification) => write!(f, "{value_specification}")?,
+            Self::Default => write!(f, "DEFAULT")?,
+        }
+        Ok(())
+    }
+}
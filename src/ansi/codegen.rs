@@ -0,0 +1,362 @@
+//! Rust code generation from `ANSI` DDL.
+use crate::ansi::ast::common::ColumnDefinition;
+use crate::ansi::ast::create_table::{CreateTable, TableContentsSource};
+use crate::ansi::ast::data_types::{DataType, WithOrWithoutTimeZone};
+
+/// Words that can't be used as raw identifiers [(1)], so they are suffixed
+/// with an underscore instead.
+///
+/// [(1)]: https://doc.rust-lang.org/reference/identifiers.html#raw-identifiers
+const NON_RAW_KEYWORDS: &[&str] = &["Self", "crate", "self", "super"];
+
+/// Rust strict and reserved keywords, that must be escaped to be used as field
+/// names.
+const RUST_KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Options of the generated Rust struct [(1)].
+///
+/// [(1)]: rust_struct
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct StructOptions {
+    /// Traits derived by the struct.
+    derives: Vec<String>,
+}
+
+/// Errors raised when generating code.
+#[derive(thiserror::Error, Clone, Eq, PartialEq, Debug)]
+pub enum CodegenError {
+    /// The column has no data type (e.g., it's typed by a domain), so its
+    /// Rust type can't be inferred.
+    #[error("column `{0}` has no data type")]
+    MissingDataType(String),
+}
+
+impl StructOptions {
+    /// Creates options that derive `Debug`, `Clone` and `PartialEq`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            derives: vec![
+                "Debug".to_string(),
+                "Clone".to_string(),
+                "PartialEq".to_string(),
+            ],
+        }
+    }
+
+    /// Adds a derived trait, e.g. `serde::Serialize`.
+    #[must_use]
+    pub fn with_derive(mut self, derive: &str) -> Self {
+        self.derives.push(derive.to_string());
+        self
+    }
+
+    /// Replaces all the derived traits.
+    #[must_use]
+    pub fn with_derives(mut self, derives: Vec<String>) -> Self {
+        self.derives = derives;
+        self
+    }
+
+    #[must_use]
+    pub fn derives(&self) -> &[String] {
+        &self.derives
+    }
+}
+
+impl Default for StructOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Generates a Rust struct that represents a row of the table.
+///
+/// The struct is named after the table (in `PascalCase`) and has a public
+/// field for each column (in `snake_case`). Nullable columns [(2)] are
+/// `Option`s, while identity columns, columns declared `NOT NULL` and the
+/// columns of the primary key are not.
+///
+/// Data types are mapped to `std` types when possible. Exact numerics use
+/// `rust_decimal::Decimal`, and datetimes use `chrono` types.
+///
+/// # Errors
+/// If a column has no data type [(1)].
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::codegen::{rust_struct, StructOptions};
+/// # use sql_helper::ansi::parser::create_table::create_table;
+/// let (_, create_table) = create_table(
+///     b"CREATE TABLE sch.user_account (id BIGINT GENERATED ALWAYS AS IDENTITY, \
+///       email VARCHAR(50) NOT NULL, name VARCHAR(50))",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     rust_struct(&create_table, &StructOptions::new()).unwrap(),
+///     "\
+/// /// Row of the `sch.user_account` table.
+/// #[derive(Debug, Clone, PartialEq)]
+/// pub struct UserAccount {
+///     pub id: i64,
+///     pub email: String,
+///     pub name: Option<String>,
+/// }
+/// "
+/// );
+/// ```
+///
+/// [(1)]: CodegenError::MissingDataType
+/// [(2)]: CreateTable::is_nullable
+pub fn rust_struct(
+    create_table: &CreateTable,
+    options: &StructOptions,
+) -> Result<String, CodegenError> {
+    let TableContentsSource::TableElementList(table_element_list) =
+        create_table.table_contents_source();
+    let fields = table_element_list
+        .columns()
+        .map(|column_definition| {
+            field(
+                column_definition,
+                create_table.is_nullable(column_definition),
+            )
+        })
+        .collect::<Result<String, _>>()?;
+
+    let derive = if options.derives().is_empty() {
+        String::new()
+    } else {
+        format!("#[derive({})]\n", options.derives().join(", "))
+    };
+
+    Ok(format!(
+        "/// Row of the `{}` table.\n{derive}pub struct {} {{\n{fields}}}\n",
+        create_table.table_name(),
+        pascal_case(create_table.table_name().name().value()),
+    ))
+}
+
+/// Returns the struct field of the column, as an indented `pub <name>: <type>,`
+/// line.
+fn field(column_definition: &ColumnDefinition, nullable: bool) -> Result<String, CodegenError> {
+    let column_name = column_definition.column_name();
+    let Some(data_type) = column_definition.opt_data_type() else {
+        return Err(CodegenError::MissingDataType(column_name.to_string()));
+    };
+
+    let rust_type = rust_type(data_type);
+    let rust_type = if nullable {
+        format!("Option<{rust_type}>")
+    } else {
+        rust_type.to_string()
+    };
+
+    Ok(format!(
+        "    pub {}: {rust_type},
+",
+        field_name(column_name.value())
+    ))
+}
+
+/// Returns the Rust type that represents the data type.
+const fn rust_type(data_type: DataType) -> &'static str {
+    match data_type {
+        DataType::Character(_)
+        | DataType::Char(_)
+        | DataType::CharacterVarying(_)
+        | DataType::CharVarying(_)
+        | DataType::Varchar(_)
+        | DataType::CharacterLargeObject(_)
+        | DataType::CharLargeObject(_)
+        | DataType::Clob(_) => "String",
+        DataType::Binary(_)
+        | DataType::BinaryVarying(_)
+        | DataType::Varbinary(_)
+        | DataType::BinaryLargeObject(_)
+        | DataType::Blob(_) => "Vec<u8>",
+        DataType::Numeric(_) | DataType::Decimal(_) | DataType::Dec(_) | DataType::DecFloat(_) => {
+            "rust_decimal::Decimal"
+        }
+        DataType::Smallint => "i16",
+        DataType::Integer | DataType::Int => "i32",
+        DataType::Bigint => "i64",
+        DataType::Real => "f32",
+        DataType::Float | DataType::DoublePrecision => "f64",
+        DataType::Boolean => "bool",
+        DataType::Date => "chrono::NaiveDate",
+        DataType::Time(_, _) => "chrono::NaiveTime",
+        DataType::Timestamp(_, WithOrWithoutTimeZone::WithTimeZone) => {
+            "chrono::DateTime<chrono::FixedOffset>"
+        }
+        DataType::Timestamp(_, _) => "chrono::NaiveDateTime",
+        DataType::NumericAlias(alias) => rust_type(alias.data_type()),
+    }
+}
+
+/// Converts the identifier into a valid Rust field name, in `snake_case`.
+fn field_name(value: &str) -> String {
+    let mut name = String::new();
+    let mut previous_lowercase = false;
+
+    for chr in value.chars() {
+        if chr.is_ascii_alphanumeric() {
+            if chr.is_ascii_uppercase() && previous_lowercase {
+                name.push('_');
+            }
+            previous_lowercase = chr.is_ascii_lowercase() || chr.is_ascii_digit();
+            name.push(chr.to_ascii_lowercase());
+        } else {
+            previous_lowercase = false;
+            name.push('_');
+        }
+    }
+
+    if name.is_empty() || name.starts_with(|chr: char| chr.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    if NON_RAW_KEYWORDS.contains(&name.as_str()) {
+        name.push('_');
+    } else if RUST_KEYWORDS.contains(&name.as_str()) {
+        name.insert_str(0, "r#");
+    }
+
+    name
+}
+
+/// Converts the identifier into a valid Rust type name, in `PascalCase`.
+fn pascal_case(value: &str) -> String {
+    let mut name = String::new();
+
+    for word in field_name(value).trim_start_matches("r#").split('_') {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.extend(chars);
+        }
+    }
+
+    if name.is_empty() || name.starts_with(|chr: char| chr.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    if name == "Self" {
+        name.push('_');
+    }
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use test_case::test_case;
+
+    use crate::ansi::parser::create_table::create_table;
+
+    use super::*;
+
+    #[test]
+    fn all_data_types() {
+        let (_, create_table) = create_table(
+            b"CREATE TABLE tb (a CHAR(1), b CLOB, c VARBINARY(8), d NUMERIC(10, 2), \
+              e SMALLINT, f INTEGER, g BIGINT, h REAL, i DOUBLE PRECISION, j BOOLEAN, k DATE, \
+              l TIME, m TIMESTAMP, n TIMESTAMP WITH TIME ZONE)",
+        )
+        .unwrap();
+
+        assert_str_eq!(
+            "/// Row of the `tb` table.\n\
+             #[derive(Debug, Clone, PartialEq)]\n\
+             pub struct Tb {\n    \
+                 pub a: Option<String>,\n    \
+                 pub b: Option<String>,\n    \
+                 pub c: Option<Vec<u8>>,\n    \
+                 pub d: Option<rust_decimal::Decimal>,\n    \
+                 pub e: Option<i16>,\n    \
+                 pub f: Option<i32>,\n    \
+                 pub g: Option<i64>,\n    \
+                 pub h: Option<f32>,\n    \
+                 pub i: Option<f64>,\n    \
+                 pub j: Option<bool>,\n    \
+                 pub k: Option<chrono::NaiveDate>,\n    \
+                 pub l: Option<chrono::NaiveTime>,\n    \
+                 pub m: Option<chrono::NaiveDateTime>,\n    \
+                 pub n: Option<chrono::DateTime<chrono::FixedOffset>>,\n\
+             }\n",
+            rust_struct(&create_table, &StructOptions::new()).unwrap()
+        );
+    }
+
+    #[test_case("CREATE TABLE tb (id INT)", "Option<i32>" ; "nullable")]
+    #[test_case("CREATE TABLE tb (id INT NOT NULL)", "i32" ; "not null")]
+    #[test_case("CREATE TABLE tb (id INT UNIQUE)", "Option<i32>" ; "unique")]
+    #[test_case("CREATE TABLE tb (id INT PRIMARY KEY)", "i32" ; "column primary key")]
+    #[test_case("CREATE TABLE tb (id INT, PRIMARY KEY (ID))", "i32" ; "table primary key")]
+    #[test_case("CREATE TABLE tb (id INT GENERATED ALWAYS AS IDENTITY)", "i32" ; "identity")]
+    fn nullability(input: &str, expected: &str) {
+        let (_, create_table) = create_table(input.as_bytes()).unwrap();
+
+        assert_str_eq!(
+            format!(
+                "/// Row of the `tb` table.\n\
+                 #[derive(Debug, Clone, PartialEq)]\n\
+                 pub struct Tb {{\n    pub id: {expected},\n}}\n"
+            ),
+            rust_struct(&create_table, &StructOptions::new()).unwrap()
+        );
+    }
+
+    #[test]
+    fn custom_derives() {
+        let (_, create_table) = create_table(b"CREATE TABLE tb (id INT)").unwrap();
+        let options = StructOptions::new()
+            .with_derives(vec![])
+            .with_derive("serde::Serialize");
+
+        assert_str_eq!(
+            "/// Row of the `tb` table.\n\
+             #[derive(serde::Serialize)]\n\
+             pub struct Tb {\n    pub id: Option<i32>,\n}\n",
+            rust_struct(&create_table, &options).unwrap()
+        );
+    }
+
+    #[test]
+    fn missing_data_type() {
+        let (_, create_table) = create_table(b"CREATE TABLE tb (id)").unwrap();
+
+        assert_eq!(
+            Err(CodegenError::MissingDataType("id".to_string())),
+            rust_struct(&create_table, &StructOptions::new())
+        );
+    }
+
+    #[test_case("id", "id")]
+    #[test_case("UserName", "user_name")]
+    #[test_case("ORDER_ID", "order_id")]
+    #[test_case("my column", "my_column")]
+    #[test_case("1st", "_1st")]
+    #[test_case("type", "r#type")]
+    #[test_case("self", "self_")]
+    fn field_names(value: &str, expected: &str) {
+        assert_str_eq!(expected, field_name(value));
+    }
+
+    #[test_case("users", "Users")]
+    #[test_case("user_account", "UserAccount" ; "snake case")]
+    #[test_case("USER_ACCOUNT", "UserAccount" ; "upper snake case")]
+    #[test_case("order items", "OrderItems")]
+    #[test_case("type", "Type")]
+    #[test_case("self", "Self_")]
+    fn struct_names(value: &str, expected: &str) {
+        assert_str_eq!(expected, pascal_case(value));
+    }
+}
@@ -0,0 +1,307 @@
+//! Completion context of a cursor position, for editor autocomplete.
+//!
+//! The context is inferred from the lexemes [(1)] of the statement before the
+//! cursor, so it works while the statement is still being typed. The word
+//! under the cursor, if any, is the prefix being completed and is ignored.
+//!
+//! [(1)]: crate::ansi::lexer
+use crate::ansi::ast::common::TableName;
+use crate::ansi::lexer::{LexemeKind, Lexer};
+use crate::ansi::parser::common::table_name;
+
+const STATEMENT_KEYWORDS: &[&str] = &[
+    "ALTER", "CALL", "CLOSE", "CREATE", "DELETE", "DROP", "EXPLAIN", "FETCH", "GRANT", "OPEN",
+    "RENAME", "RETURN", "REVOKE", "UPDATE",
+];
+const CREATE_KEYWORDS: &[&str] = &["GLOBAL", "LOCAL", "SCHEMA", "TABLE"];
+const DROP_KEYWORDS: &[&str] = &["SCHEMA", "TABLE"];
+const DROP_BEHAVIOR_KEYWORDS: &[&str] = &["CASCADE", "RESTRICT"];
+const ALTER_TABLE_KEYWORDS: &[&str] = &["ADD", "ALTER", "DROP", "RENAME"];
+const ALTER_COLUMN_KEYWORDS: &[&str] = &["DROP", "SET"];
+const PRIVILEGE_KEYWORDS: &[&str] = &[
+    "ALL",
+    "DELETE",
+    "EXECUTE",
+    "INSERT",
+    "REFERENCES",
+    "SELECT",
+    "TRIGGER",
+    "UNDER",
+    "UPDATE",
+    "USAGE",
+];
+
+/// Grammar element expected at a cursor position [(1)].
+///
+/// [(1)]: completion_context
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum CompletionContext {
+    /// One of the keywords, in alphabetical order.
+    Keywords(&'static [&'static str]),
+    /// Name of an existing schema.
+    SchemaName,
+    /// Name of an existing table, possibly after its qualifier.
+    TableName,
+    /// Name of an existing column of the table, if the table is known.
+    ColumnName(Option<TableName>),
+    /// Data type of a column.
+    DataType,
+}
+
+/// Returns the grammar element expected at the byte offset of the script, or
+/// `None` if nothing can be completed there (e.g., inside a comment or where a
+/// new name is expected).
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::common::TableName;
+/// # use sql_helper::ansi::completion::{completion_context, CompletionContext};
+/// let script = "DROP TABLE a CASCADE;\nALTER TABLE users DROP COLUMN na";
+///
+/// assert_eq!(
+///     completion_context(script, script.len()),
+///     Some(CompletionContext::ColumnName(Some(TableName::new("USERS"))))
+/// );
+/// assert_eq!(
+///     completion_context(script, 5),
+///     Some(CompletionContext::Keywords(&["SCHEMA", "TABLE"]))
+/// );
+/// ```
+#[must_use]
+pub fn completion_context(script: &str, offset: usize) -> Option<CompletionContext> {
+    let mut words = vec![];
+    for lexeme in Lexer::new(script) {
+        let span = lexeme.span();
+        let is_word = matches!(
+            lexeme.kind(),
+            LexemeKind::Keyword | LexemeKind::Identifier | LexemeKind::QuotedIdentifier
+        );
+        if span.start() >= offset || (is_word && span.end() >= offset) {
+            break;
+        }
+        if span.end() > offset || (span.end() == offset && lexeme.kind() == LexemeKind::LineComment)
+        {
+            if lexeme.kind() == LexemeKind::Whitespace {
+                break;
+            }
+            return None;
+        }
+
+        // OBS: unquoted words are uppercased, so keywords can be matched as
+        // written in the grammar.
+        match lexeme.kind() {
+            LexemeKind::Semicolon => words.clear(),
+            LexemeKind::Keyword | LexemeKind::Identifier => {
+                words.push(lexeme.text(script).to_ascii_uppercase());
+            }
+            kind if !kind.is_trivia() => words.push(lexeme.text(script).to_string()),
+            _ => {}
+        }
+    }
+
+    statement_context(&words.iter().map(String::as_str).collect::<Vec<_>>())
+}
+
+/// Returns the context after the words of a statement.
+fn statement_context(words: &[&str]) -> Option<CompletionContext> {
+    let words = match words {
+        ["EXPLAIN", "ANALYZE", rest @ ..] | ["EXPLAIN", rest @ ..] => rest,
+        _ => words,
+    };
+
+    // OBS: only table names are qualified, so a qualifier followed by a period
+    // can only be completed by a table name.
+    if let [rest @ .., _, "."] = words {
+        return match statement_context(rest)? {
+            CompletionContext::TableName => Some(CompletionContext::TableName),
+            _ => None,
+        };
+    }
+
+    match words {
+        [] => Some(CompletionContext::Keywords(STATEMENT_KEYWORDS)),
+        ["CREATE"] => Some(CompletionContext::Keywords(CREATE_KEYWORDS)),
+        ["CREATE", "GLOBAL" | "LOCAL"] => Some(CompletionContext::Keywords(&["TEMPORARY"])),
+        ["CREATE", "GLOBAL" | "LOCAL", "TEMPORARY"] => {
+            Some(CompletionContext::Keywords(&["TABLE"]))
+        }
+        ["CREATE", "GLOBAL" | "LOCAL", "TEMPORARY", "TABLE", rest @ ..]
+        | ["CREATE", "TABLE", rest @ ..] => create_table_context(rest),
+        ["DROP"] => Some(CompletionContext::Keywords(DROP_KEYWORDS)),
+        ["DROP", "SCHEMA"] => Some(CompletionContext::SchemaName),
+        ["DROP", "SCHEMA", _] => Some(CompletionContext::Keywords(DROP_BEHAVIOR_KEYWORDS)),
+        ["ALTER" | "RENAME"] => Some(CompletionContext::Keywords(&["TABLE"])),
+        ["DROP" | "ALTER" | "RENAME", "TABLE"]
+        | ["DELETE", "FROM"]
+        | ["UPDATE"]
+        | ["GRANT" | "REVOKE", .., "ON"]
+        | ["GRANT" | "REVOKE", .., "ON", "TABLE"] => Some(CompletionContext::TableName),
+        ["DROP", "TABLE", rest @ ..] => match split_table_name(rest)? {
+            (_, []) => Some(CompletionContext::Keywords(DROP_BEHAVIOR_KEYWORDS)),
+            _ => None,
+        },
+        ["ALTER", "TABLE", rest @ ..] => {
+            let (table_name, rest) = split_table_name(rest)?;
+            alter_table_context(table_name, rest)
+        }
+        ["UPDATE", rest @ ..] => {
+            let (table_name, rest) = split_table_name(rest)?;
+            update_context(table_name, rest)
+        }
+        ["DELETE"] => Some(CompletionContext::Keywords(&["FROM"])),
+        ["DELETE", "FROM", rest @ ..] => match split_table_name(rest)? {
+            (_, []) => Some(CompletionContext::Keywords(&["WHERE"])),
+            _ => None,
+        },
+        ["GRANT" | "REVOKE"] => Some(CompletionContext::Keywords(PRIVILEGE_KEYWORDS)),
+        _ => None,
+    }
+}
+
+/// Returns the context after the table name of a `CREATE TABLE` statement.
+fn create_table_context(words: &[&str]) -> Option<CompletionContext> {
+    let (_, rest) = split_table_name(words)?;
+
+    // OBS: the words of the current table element are the ones after the
+    // last comma of the element list, which has depth 1.
+    let mut depth = 0_usize;
+    let mut element_len = 0;
+    for word in rest {
+        match *word {
+            "(" => {
+                depth += 1;
+                if depth == 1 {
+                    element_len = 0;
+                    continue;
+                }
+            }
+            ")" => depth = depth.saturating_sub(1),
+            "," if depth == 1 => {
+                element_len = 0;
+                continue;
+            }
+            _ => {}
+        }
+        element_len += 1;
+    }
+
+    match (depth, element_len) {
+        (1, 1) => Some(CompletionContext::DataType),
+        _ => None,
+    }
+}
+
+/// Returns the context after the table name of an `ALTER TABLE` statement.
+fn alter_table_context(table_name: TableName, words: &[&str]) -> Option<CompletionContext> {
+    match words {
+        [] => Some(CompletionContext::Keywords(ALTER_TABLE_KEYWORDS)),
+        ["ADD"] => Some(CompletionContext::Keywords(&["COLUMN"])),
+        ["ALTER" | "DROP" | "RENAME"] | ["ALTER" | "DROP" | "RENAME", "COLUMN"] => {
+            Some(CompletionContext::ColumnName(Some(table_name)))
+        }
+        ["ADD", "COLUMN"] => None,
+        ["ADD", _] | ["ADD", "COLUMN", _] | [.., "SET", "DATA", "TYPE"] => {
+            Some(CompletionContext::DataType)
+        }
+        ["DROP", _] | ["DROP", "COLUMN", _] => {
+            Some(CompletionContext::Keywords(DROP_BEHAVIOR_KEYWORDS))
+        }
+        ["ALTER", _] | ["ALTER", "COLUMN", _] => {
+            Some(CompletionContext::Keywords(ALTER_COLUMN_KEYWORDS))
+        }
+        ["RENAME", _] | ["RENAME", "COLUMN", _] => Some(CompletionContext::Keywords(&["TO"])),
+        _ => None,
+    }
+}
+
+/// Returns the context after the table name of an `UPDATE` statement.
+fn update_context(table_name: TableName, words: &[&str]) -> Option<CompletionContext> {
+    match words {
+        [] => Some(CompletionContext::Keywords(&["SET"])),
+        ["SET", ..] if matches!(words.last(), Some(&("SET" | ","))) => {
+            Some(CompletionContext::ColumnName(Some(table_name)))
+        }
+        _ => None,
+    }
+}
+
+/// Splits the words at the end of the table name they start with.
+fn split_table_name<'a, 'b>(words: &'a [&'b str]) -> Option<(TableName, &'a [&'b str])> {
+    let mut len = 1;
+    while words.get(len) == Some(&".") && words.len() > len + 1 {
+        len += 2;
+    }
+
+    let text = words.get(..len)?.concat();
+    match table_name(text.as_bytes()) {
+        Ok(([], table_name)) => Some((table_name, &words[len..])),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::ast::common::{LocalOrSchemaQualifier, SchemaName};
+
+    use super::*;
+
+    /// Returns the context at the position of the `|` in the input.
+    fn context_at_cursor(input: &str) -> Option<CompletionContext> {
+        let offset = input.find('|').unwrap();
+        completion_context(&input.replace('|', ""), offset)
+    }
+
+    #[test_case("|", Some(&CompletionContext::Keywords(STATEMENT_KEYWORDS)) ; "empty script")]
+    #[test_case(
+        "DROP TABLE a CASCADE; EXPLAIN ANALYZE |",
+        Some(&CompletionContext::Keywords(STATEMENT_KEYWORDS)) ;
+        "after explain"
+    )]
+    #[test_case("cre|", Some(&CompletionContext::Keywords(STATEMENT_KEYWORDS)) ; "word prefix")]
+    #[test_case("CREATE |", Some(&CompletionContext::Keywords(CREATE_KEYWORDS)) ; "create")]
+    #[test_case("drop schema |", Some(&CompletionContext::SchemaName) ; "schema name")]
+    #[test_case("DROP TABLE sch.|", Some(&CompletionContext::TableName) ; "qualified table name")]
+    #[test_case(
+        "DROP TABLE sch.tb |",
+        Some(&CompletionContext::Keywords(DROP_BEHAVIOR_KEYWORDS)) ;
+        "drop behavior"
+    )]
+    #[test_case("GRANT SELECT ON |", Some(&CompletionContext::TableName) ; "granted table")]
+    #[test_case("CREATE TABLE tb (id |", Some(&CompletionContext::DataType) ; "column data type")]
+    #[test_case("CREATE TABLE tb (id DECIMAL(1, |", None ; "data type parameters")]
+    #[test_case("CREATE TABLE tb (id INT, |", None ; "new column name")]
+    #[test_case(
+        "ALTER TABLE tb ADD COLUMN c |",
+        Some(&CompletionContext::DataType) ;
+        "added column data type"
+    )]
+    #[test_case(
+        "ALTER TABLE tb ALTER COLUMN c SET DATA TYPE |",
+        Some(&CompletionContext::DataType) ;
+        "altered column data type"
+    )]
+    #[test_case(
+        "UPDATE tb SET a = 1, |",
+        Some(&CompletionContext::ColumnName(Some(TableName::new("TB")))) ;
+        "updated column"
+    )]
+    #[test_case("CALL f('|')", None ; "inside string")]
+    #[test_case("DROP -- comment|", None ; "inside comment")]
+    fn context(input: &str, expected: Option<&CompletionContext>) {
+        assert_eq!(expected, context_at_cursor(input).as_ref());
+    }
+
+    #[test]
+    fn qualified_column_table() {
+        assert_eq!(
+            Some(CompletionContext::ColumnName(Some(
+                TableName::new("TB")
+                    .with_local_or_schema(LocalOrSchemaQualifier::Schema(SchemaName::new("SCH")))
+            ))),
+            context_at_cursor("ALTER TABLE sch.tb DROP |;")
+        );
+    }
+}
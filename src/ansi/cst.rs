@@ -0,0 +1,430 @@
+//! Lossless concrete syntax tree of scripts.
+//!
+//! Unlike the AST, the tree keeps every byte of the source: each token holds
+//! the whitespaces and comments before it (its leading trivia), so rendering
+//! an unedited tree reproduces the source exactly. Replacing a statement only
+//! re-renders its own tokens, keeping the trivia around it, so automated
+//! refactors produce minimal diffs.
+use std::fmt;
+use std::mem;
+
+use crate::ansi::lexer::{LexemeKind, Lexer};
+use crate::ansi::parser::{parse_statement, ScriptError};
+use crate::ansi::Statement;
+use crate::common::span::Span;
+
+/// Lossless syntax tree of a script [(1)].
+///
+/// [(1)]: crate::ansi::cst
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyntaxTree {
+    statements: Vec<StatementNode>,
+    /// Trivia after the last token of the script.
+    trailing_trivia: Vec<Trivia>,
+}
+
+/// Statement of a syntax tree, with the tokens it was parsed from.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatementNode {
+    statement: Statement,
+    tokens: Vec<Token>,
+    /// Semicolon that terminates the statement, if any.
+    opt_terminator: Option<Token>,
+}
+
+/// Token of the source, with the trivia before it.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Token {
+    kind: TokenKind,
+    text: String,
+    leading_trivia: Vec<Trivia>,
+}
+
+/// Kind of a token.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TokenKind {
+    /// Keyword or unquoted identifier (e.g., `TABLE`).
+    Word,
+    /// Quoted identifier (e.g., `"name"`).
+    QuotedIdentifier,
+    /// String literal, including dollar-quoted bodies (e.g., `'text'`).
+    String,
+    /// Number, with any suffix (e.g., `10` or `2K`).
+    Number,
+    /// Statement terminator (`;`).
+    Semicolon,
+    /// Any other character (e.g., `(`).
+    Punctuation,
+}
+
+/// Source text that doesn't affect the statements.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trivia {
+    kind: TriviaKind,
+    text: String,
+}
+
+/// Kind of a trivia.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriviaKind {
+    /// Sequence of whitespaces.
+    Whitespace,
+    /// `--` comment, without the line break that ends it.
+    LineComment,
+    /// `/* */` comment, which may be nested.
+    BlockComment,
+}
+
+impl SyntaxTree {
+    /// Parses the script into a lossless syntax tree.
+    ///
+    /// Comments can be placed anywhere between tokens, including inside the
+    /// statements.
+    ///
+    /// # Errors
+    /// If any statement of the script can't be parsed, or if the script has an
+    /// empty statement (e.g., `;;`) [(1)].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::cst::SyntaxTree;
+    /// let sql = "-- Cleanup\nDROP TABLE a /* old */ CASCADE ;\n\n  drop table b restrict\n";
+    /// let tree = SyntaxTree::parse(sql).unwrap();
+    ///
+    /// assert_eq!(tree.to_string(), sql);
+    /// assert_eq!(tree.statements()[1].statement().to_string(), "DROP TABLE b RESTRICT");
+    /// ```
+    ///
+    /// [(1)]: ScriptError::InvalidStatement
+    pub fn parse(sql: &str) -> Result<Self, ScriptError> {
+        let (tokens, trailing_trivia) = lex(sql);
+        let mut statements = vec![];
+        let mut statement_tokens = vec![];
+        let mut offset = 0;
+        let mut statement_start = 0;
+
+        for token in tokens {
+            let trivia_len = token
+                .leading_trivia
+                .iter()
+                .map(|trivia| trivia.text.len())
+                .sum::<usize>();
+            if statement_tokens.is_empty() {
+                statement_start = offset + trivia_len;
+            }
+            offset += trivia_len + token.text.len();
+
+            if token.kind == TokenKind::Semicolon {
+                let tokens = mem::take(&mut statement_tokens);
+                let node = StatementNode::parse(tokens, Some(token)).ok_or(
+                    ScriptError::InvalidStatement(Span::new(statement_start, sql.len())),
+                )?;
+                statements.push(node);
+            } else {
+                statement_tokens.push(token);
+            }
+        }
+
+        if !statement_tokens.is_empty() {
+            let node = StatementNode::parse(statement_tokens, None).ok_or(
+                ScriptError::InvalidStatement(Span::new(statement_start, sql.len())),
+            )?;
+            statements.push(node);
+        }
+
+        Ok(Self {
+            statements,
+            trailing_trivia,
+        })
+    }
+
+    #[must_use]
+    pub fn statements(&self) -> &[StatementNode] {
+        &self.statements
+    }
+
+    #[must_use]
+    pub fn statements_mut(&mut self) -> &mut [StatementNode] {
+        &mut self.statements
+    }
+
+    #[must_use]
+    pub fn trailing_trivia(&self) -> &[Trivia] {
+        &self.trailing_trivia
+    }
+}
+
+impl StatementNode {
+    /// Parses the statement from its tokens, returning `None` if the tokens
+    /// aren't a valid statement.
+    fn parse(tokens: Vec<Token>, opt_terminator: Option<Token>) -> Option<Self> {
+        let text = statement_text(&tokens);
+        let (remaining, statement) = parse_statement(text.as_bytes()).ok()?;
+        if !remaining.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            statement,
+            tokens,
+            opt_terminator,
+        })
+    }
+
+    #[must_use]
+    pub const fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    #[must_use]
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    #[must_use]
+    pub const fn opt_terminator(&self) -> Option<&Token> {
+        self.opt_terminator.as_ref()
+    }
+
+    /// Replaces the statement, re-rendering its tokens.
+    ///
+    /// The trivia before the statement and its terminator are kept, so only
+    /// the statement text changes in the rendered script. The trivia inside
+    /// the statement is lost.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::common::DropBehavior;
+    /// # use sql_helper::ansi::ast::drop_table::DropTable;
+    /// # use sql_helper::ansi::cst::SyntaxTree;
+    /// # use sql_helper::ansi::Statement;
+    /// let mut tree = SyntaxTree::parse("-- Keep me\ndrop table a cascade;\n").unwrap();
+    ///
+    /// let node = &mut tree.statements_mut()[0];
+    /// let Statement::DropTable(drop_table) = node.statement().clone() else {
+    ///     unreachable!()
+    /// };
+    /// node.set_statement(Statement::DropTable(DropTable::new(
+    ///     drop_table.table_name().clone(),
+    ///     DropBehavior::Restrict,
+    /// )));
+    ///
+    /// assert_eq!(tree.to_string(), "-- Keep me\nDROP TABLE a RESTRICT;\n");
+    /// ```
+    pub fn set_statement(&mut self, statement: Statement) {
+        let (mut tokens, _) = lex(&statement.to_string());
+        let leading_trivia = self
+            .tokens
+            .first_mut()
+            .map(|token| mem::take(&mut token.leading_trivia))
+            .unwrap_or_default();
+        if let Some(first) = tokens.first_mut() {
+            first.leading_trivia = leading_trivia;
+        }
+
+        self.statement = statement;
+        self.tokens = tokens;
+    }
+}
+
+impl Token {
+    #[must_use]
+    pub const fn kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    pub fn leading_trivia(&self) -> &[Trivia] {
+        &self.leading_trivia
+    }
+}
+
+impl Trivia {
+    #[must_use]
+    pub const fn kind(&self) -> TriviaKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for SyntaxTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for statement in self.statements() {
+            write!(f, "{statement}")?;
+        }
+        for trivia in self.trailing_trivia() {
+            write!(f, "{trivia}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for StatementNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in self.tokens() {
+            write!(f, "{token}")?;
+        }
+        if let Some(terminator) = self.opt_terminator() {
+            write!(f, "{terminator}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for trivia in self.leading_trivia() {
+            write!(f, "{trivia}")?;
+        }
+        write!(f, "{}", self.text())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Trivia {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text())?;
+        Ok(())
+    }
+}
+
+/// Splits the source into tokens [(1)], returning them with the trivia after
+/// the last one.
+///
+/// [(1)]: Lexer
+fn lex(sql: &str) -> (Vec<Token>, Vec<Trivia>) {
+    let mut tokens = vec![];
+    let mut trivia = vec![];
+
+    for lexeme in Lexer::new(sql) {
+        let text = lexeme.text(sql).to_string();
+        let token_kind = match lexeme.kind() {
+            LexemeKind::Whitespace | LexemeKind::LineComment | LexemeKind::BlockComment => {
+                let kind = match lexeme.kind() {
+                    LexemeKind::LineComment => TriviaKind::LineComment,
+                    LexemeKind::BlockComment => TriviaKind::BlockComment,
+                    _ => TriviaKind::Whitespace,
+                };
+                trivia.push(Trivia { kind, text });
+                continue;
+            }
+            LexemeKind::Keyword | LexemeKind::Identifier => TokenKind::Word,
+            LexemeKind::QuotedIdentifier => TokenKind::QuotedIdentifier,
+            LexemeKind::String => TokenKind::String,
+            LexemeKind::Number => TokenKind::Number,
+            LexemeKind::Semicolon => TokenKind::Semicolon,
+            LexemeKind::Punctuation => TokenKind::Punctuation,
+        };
+
+        tokens.push(Token {
+            kind: token_kind,
+            text,
+            leading_trivia: mem::take(&mut trivia),
+        });
+    }
+
+    (tokens, trivia)
+}
+
+/// Returns the text of the statement tokens, where comments are replaced by a
+/// single space, so it can be parsed.
+fn statement_text(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .enumerate()
+        .fold(String::new(), |mut text, (index, token)| {
+            let only_whitespaces = token
+                .leading_trivia()
+                .iter()
+                .all(|trivia| trivia.kind() == TriviaKind::Whitespace);
+
+            if index > 0 && only_whitespaces {
+                token
+                    .leading_trivia()
+                    .iter()
+                    .for_each(|trivia| text.push_str(trivia.text()));
+            } else if index > 0 {
+                text.push(' ');
+            }
+            text.push_str(token.text());
+            text
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(""; "empty")]
+    #[test_case("  \n-- only comments\n/* here */"; "only trivia")]
+    #[test_case("DROP TABLE a CASCADE"; "single statement")]
+    #[test_case("\tdrop   TABLE a\n\tCASCADE ;  -- done\n"; "whitespaces and comments")]
+    #[test_case("CREATE TABLE tb (\n  id INT, -- key\n  name VARCHAR(20) /* /* nested */ */\n);"; "comments inside statement")]
+    #[test_case("CREATE SCHEMA sch;\nDROP TABLE \"a;b\" RESTRICT;"; "multiple statements")]
+    #[test_case("CALL f(E'a\\';b');\nCALL g($$;$$)"; "escape and dollar quoted strings")]
+    fn lossless_round_trip(sql: &str) {
+        let tree = SyntaxTree::parse(sql).unwrap();
+
+        assert_str_eq!(sql, tree.to_string());
+    }
+
+    #[test]
+    fn parses_statements() {
+        let tree =
+            SyntaxTree::parse("CREATE TABLE tb (id /* pk */ INT);\n-- x\nDROP TABLE tb CASCADE")
+                .unwrap();
+
+        let statements = tree
+            .statements()
+            .iter()
+            .map(|node| node.statement().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["CREATE TABLE tb (id INT)", "DROP TABLE tb CASCADE"],
+            statements
+        );
+        assert!(tree.statements()[0].opt_terminator().is_some());
+        assert!(tree.statements()[1].opt_terminator().is_none());
+    }
+
+    #[test]
+    fn set_statement_keeps_surrounding_trivia() {
+        let sql = "DROP TABLE a CASCADE;\n\n/* second */ drop  table b restrict ; -- end\n";
+        let mut tree = SyntaxTree::parse(sql).unwrap();
+
+        let replacement = tree.statements()[0].statement().clone();
+        tree.statements_mut()[1].set_statement(replacement);
+
+        assert_str_eq!(
+            "DROP TABLE a CASCADE;\n\n/* second */ DROP TABLE a CASCADE ; -- end\n",
+            tree.to_string()
+        );
+    }
+
+    #[test_case("DROP TABLE a CASCADE;;", Span::new(21, 22); "empty statement")]
+    #[test_case("DROP TABLE a CASCADE;\n SELECT 1", Span::new(23, 31); "unsupported statement")]
+    fn invalid_script(sql: &str, span: Span) {
+        assert_eq!(
+            Err(ScriptError::InvalidStatement(span)),
+            SyntaxTree::parse(sql)
+        );
+    }
+}
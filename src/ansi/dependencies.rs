@@ -0,0 +1,241 @@
+//! Ordering of statements by the objects they depend on.
+//!
+//! A statement depends on the statement that creates an object it uses [(1)]
+//! (e.g., a table is created after its schema, and a column is altered after
+//! it's added). When an object is created more than once, the statement
+//! depends on the nearest creation that precedes it, or on the first one if
+//! none does.
+//!
+//! [(1)]: Statement::referenced_objects
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::ansi::objects::{ObjectName, ObjectReference, ObjectUsage};
+use crate::ansi::Statement;
+
+/// Errors raised when ordering statements by their dependencies.
+#[derive(thiserror::Error, Clone, Eq, PartialEq, Debug)]
+pub enum DependencyError {
+    /// The statements at the indexes depend on each other, each one on the
+    /// previous one and the first one on the last one.
+    #[error("statements {} depend on each other", display_cycle(.0))]
+    Cycle(Vec<usize>),
+}
+
+/// Returns the indexes of the statements in an order where every statement
+/// comes after the statements it depends on. Independent statements keep
+/// their relative order.
+///
+/// # Errors
+/// If statements depend on each other, the error holds one of the cycles.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::dependencies::dependency_order;
+/// # use sql_helper::ansi::parser::parse_script;
+/// let statements = parse_script(
+///     "ALTER TABLE sch.tb ADD COLUMN name INT; CREATE TABLE sch.tb (id INT); CREATE SCHEMA sch",
+/// )
+/// .unwrap()
+/// .into_iter()
+/// .map(|(statement, _)| statement)
+/// .collect::<Vec<_>>();
+///
+/// assert_eq!(dependency_order(&statements), Ok(vec![2, 1, 0]));
+/// ```
+pub fn dependency_order(statements: &[Statement]) -> Result<Vec<usize>, DependencyError> {
+    let references = statements
+        .iter()
+        .map(Statement::referenced_objects)
+        .collect::<Vec<_>>();
+
+    let mut dependents = vec![vec![]; statements.len()];
+    let mut dependencies = vec![vec![]; statements.len()];
+    for (index, statement_references) in references.iter().enumerate() {
+        for reference in statement_references {
+            if reference.usage() == ObjectUsage::Created {
+                continue;
+            }
+            if let Some(creator) = creator(&references, index, reference.object()) {
+                if !dependencies[index].contains(&creator) {
+                    dependencies[index].push(creator);
+                    dependents[creator].push(index);
+                }
+            }
+        }
+    }
+
+    let mut pending = dependencies.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut ready = pending
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count == 0)
+        .map(|(index, _)| Reverse(index))
+        .collect::<BinaryHeap<_>>();
+
+    let mut order = Vec::with_capacity(statements.len());
+    while let Some(Reverse(index)) = ready.pop() {
+        order.push(index);
+        for &dependent in &dependents[index] {
+            pending[dependent] -= 1;
+            if pending[dependent] == 0 {
+                ready.push(Reverse(dependent));
+            }
+        }
+    }
+
+    if order.len() == statements.len() {
+        Ok(order)
+    } else {
+        Err(DependencyError::Cycle(find_cycle(&dependencies, &pending)))
+    }
+}
+
+/// Returns the statements sorted by their dependencies [(1)].
+///
+/// # Errors
+/// If statements depend on each other, the error holds one of the cycles.
+///
+/// [(1)]: dependency_order
+pub fn sort_by_dependencies(statements: &[Statement]) -> Result<Vec<Statement>, DependencyError> {
+    Ok(dependency_order(statements)?
+        .into_iter()
+        .map(|index| statements[index].clone())
+        .collect())
+}
+
+/// Returns the statement that creates the object for the statement at the
+/// index: the nearest preceding creation, or the first following one.
+fn creator(
+    references: &[Vec<ObjectReference>],
+    index: usize,
+    object: &ObjectName,
+) -> Option<usize> {
+    let creates = |other: &usize| {
+        *other != index
+            && references[*other].iter().any(|reference| {
+                reference.usage() == ObjectUsage::Created && reference.object().refers_to(object)
+            })
+    };
+
+    (0..index)
+        .rev()
+        .find(creates)
+        .or_else(|| (index + 1..references.len()).find(creates))
+}
+
+/// Returns a cycle among the statements that still have pending dependencies,
+/// in dependency order.
+fn find_cycle(dependencies: &[Vec<usize>], pending: &[usize]) -> Vec<usize> {
+    // OBS: every statement with pending dependencies depends on another one
+    // with pending dependencies, so walking the dependencies must eventually
+    // revisit a statement.
+    let mut path: Vec<usize> = vec![];
+    let mut current = pending
+        .iter()
+        .position(|count| *count > 0)
+        .unwrap_or_default();
+
+    while !path.contains(&current) {
+        path.push(current);
+        current = dependencies[current]
+            .iter()
+            .copied()
+            .find(|dependency| pending[*dependency] > 0)
+            .unwrap_or(current);
+    }
+
+    let start = path
+        .iter()
+        .position(|index| *index == current)
+        .unwrap_or_default();
+    let mut cycle = path.split_off(start);
+    cycle.reverse();
+    cycle
+}
+
+fn display_cycle(cycle: &[usize]) -> String {
+    cycle
+        .iter()
+        .chain(cycle.first())
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_script;
+
+    use super::*;
+
+    fn parse(input: &str) -> Vec<Statement> {
+        parse_script(input)
+            .unwrap()
+            .into_iter()
+            .map(|(statement, _)| statement)
+            .collect()
+    }
+
+    #[test_case("CREATE SCHEMA a; CREATE SCHEMA b", &[0, 1] ; "independent statements")]
+    #[test_case(
+        "CREATE TABLE sch.tb (id INT); CREATE SCHEMA SCH",
+        &[1, 0] ;
+        "schema before tables"
+    )]
+    #[test_case(
+        "UPDATE tb SET b = 1 WHERE CURRENT OF c; ALTER TABLE tb ADD COLUMN b INT; CREATE TABLE tb (a INT)",
+        &[2, 1, 0] ;
+        "columns before their uses"
+    )]
+    #[test_case(
+        "GRANT SELECT ON t2 TO PUBLIC; ALTER TABLE t1 RENAME TO t2; CREATE TABLE t1 (a INT)",
+        &[2, 1, 0] ;
+        "renamed tables"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT); DROP TABLE tb CASCADE; CREATE TABLE tb (b INT); DROP TABLE tb CASCADE",
+        &[0, 1, 2, 3] ;
+        "recreated objects"
+    )]
+    #[test_case(
+        "CREATE TABLE b (a INT REFERENCES a (id)); CREATE TABLE a (id INT, p INT REFERENCES a)",
+        &[1, 0] ;
+        "referenced tables before foreign keys"
+    )]
+    fn order(input: &str, expected: &[usize]) {
+        assert_eq!(Ok(expected.to_vec()), dependency_order(&parse(input)));
+    }
+
+    #[test]
+    fn sort_statements() {
+        let sorted =
+            sort_by_dependencies(&parse("CREATE TABLE sch.tb (id INT); CREATE SCHEMA sch"))
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec!["CREATE SCHEMA sch", "CREATE TABLE sch.tb (id INT)"],
+            sorted
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        let statements =
+            parse("CREATE SCHEMA sch; ALTER TABLE a RENAME TO b; ALTER TABLE b RENAME TO a");
+
+        let error = dependency_order(&statements).unwrap_err();
+
+        assert_eq!(DependencyError::Cycle(vec![2, 1]), error);
+        assert_eq!(
+            "statements 2 -> 1 -> 2 depend on each other",
+            error.to_string()
+        );
+    }
+}
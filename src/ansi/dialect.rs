@@ -0,0 +1,12 @@
+use crate::ansi::keywords::is_reserved_word;
+use crate::common::dialect::Dialect;
+
+/// `ANSI` dialect, following the SQL 2016 lexical rules.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct AnsiDialect;
+
+impl Dialect for AnsiDialect {
+    fn is_reserved_keyword(&self, word: &str) -> bool {
+        is_reserved_word(word)
+    }
+}
@@ -0,0 +1,319 @@
+//! Data dictionary generation from `ANSI` DDL.
+//!
+//! The dictionary describes the tables created by a script, and can be
+//! serialized (e.g., to JSON, with the `serde` feature) or rendered as
+//! Markdown for documentation pipelines.
+use std::fmt;
+
+use crate::ansi::ast::common::{ColumnConstraint, ColumnDefinition, UniqueSpecification};
+use crate::ansi::ast::create_table::{
+    CreateTable, TableConstraint, TableConstraintDefinition, TableContentsSource,
+};
+use crate::ansi::parser::{parse_script, ScriptError};
+use crate::ansi::Statement;
+use crate::common::Ident;
+
+/// Description of a table.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableEntry {
+    /// Qualified table name.
+    name: String,
+    columns: Vec<ColumnEntry>,
+}
+
+/// Description of a table column.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnEntry {
+    name: String,
+    /// Data type of the column, if declared.
+    data_type: Option<String>,
+    /// Whether the column accepts `NULL` [(1)].
+    ///
+    /// [(1)]: CreateTable::is_nullable
+    nullable: bool,
+    /// Constraints of the column, as SQL: the identity specification, the
+    /// column constraints, and the table constraints that include the column
+    /// (e.g., `PRIMARY KEY (a, b)` for both `a` and `b`).
+    constraints: Vec<String>,
+}
+
+/// Parses the script and describes each table it creates, in order. Other
+/// statements are ignored.
+///
+/// # Errors
+/// If any statement of the script can't be parsed [(1)].
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::dictionary::data_dictionary;
+/// let tables = data_dictionary(
+///     "CREATE TABLE sch.users (id INT GENERATED ALWAYS AS IDENTITY, name VARCHAR(50) NOT NULL, \
+///      PRIMARY KEY (id));",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     tables[0].to_string().lines().collect::<Vec<_>>(),
+///     vec![
+///         "## sch.users",
+///         "",
+///         "| Column | Type | Nullable | Constraints |",
+///         "| --- | --- | --- | --- |",
+///         "| id | INT | NO | GENERATED ALWAYS AS IDENTITY, PRIMARY KEY (id) |",
+///         "| name | VARCHAR(50) | NO | NOT NULL |",
+///     ]
+/// );
+/// ```
+///
+/// [(1)]: parse_script
+pub fn data_dictionary(sql: &str) -> Result<Vec<TableEntry>, ScriptError> {
+    Ok(parse_script(sql)?
+        .iter()
+        .filter_map(|(statement, _)| match statement {
+            Statement::CreateTable(create_table) => Some(TableEntry::from(create_table)),
+            _ => None,
+        })
+        .collect())
+}
+
+impl TableEntry {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn columns(&self) -> &[ColumnEntry] {
+        &self.columns
+    }
+}
+
+impl From<&CreateTable> for TableEntry {
+    fn from(create_table: &CreateTable) -> Self {
+        let TableContentsSource::TableElementList(table_element_list) =
+            create_table.table_contents_source();
+
+        let columns = table_element_list
+            .columns()
+            .map(|column_definition| {
+                let mut column_entry = ColumnEntry::from(column_definition);
+                column_entry.nullable = create_table.is_nullable(column_definition);
+                column_entry.constraints.extend(
+                    table_element_list
+                        .constraints()
+                        .filter(|table_constraint_definition| {
+                            includes_column(
+                                table_constraint_definition,
+                                column_definition.column_name(),
+                            )
+                        })
+                        .map(ToString::to_string),
+                );
+                column_entry
+            })
+            .collect();
+
+        Self {
+            name: create_table.table_name().to_string(),
+            columns,
+        }
+    }
+}
+
+/// Renders the table as a Markdown section, with a row per column.
+impl fmt::Display for TableEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "## {}", self.name())?;
+        writeln!(f)?;
+        writeln!(f, "| Column | Type | Nullable | Constraints |")?;
+        writeln!(f, "| --- | --- | --- | --- |")?;
+
+        for column in self.columns() {
+            writeln!(
+                f,
+                "| {} | {} | {} | {} |",
+                column.name(),
+                column.opt_data_type().unwrap_or_default(),
+                if column.nullable() { "YES" } else { "NO" },
+                column.constraints().join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ColumnEntry {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn opt_data_type(&self) -> Option<&str> {
+        self.data_type.as_deref()
+    }
+
+    #[must_use]
+    pub const fn nullable(&self) -> bool {
+        self.nullable
+    }
+
+    #[must_use]
+    pub fn constraints(&self) -> &[String] {
+        &self.constraints
+    }
+}
+
+/// Describes the column by its own definition, so the table constraints that
+/// include it are not known.
+impl From<&ColumnDefinition> for ColumnEntry {
+    fn from(column_definition: &ColumnDefinition) -> Self {
+        let identity = column_definition.opt_identity_column_specification();
+        let column_constraints = column_definition.column_constraint_definitions();
+
+        Self {
+            name: column_definition.column_name().to_string(),
+            data_type: column_definition
+                .opt_data_type()
+                .map(|data_type| data_type.to_string()),
+            nullable: identity.is_none()
+                && column_constraints
+                    .iter()
+                    .all(|column_constraint_definition| {
+                        !matches!(
+                            column_constraint_definition.column_constraint(),
+                            ColumnConstraint::NotNull
+                                | ColumnConstraint::Unique(UniqueSpecification::PrimaryKey)
+                        )
+                    }),
+            constraints: identity
+                .iter()
+                .map(ToString::to_string)
+                .chain(column_constraints.iter().map(ToString::to_string))
+                .collect(),
+        }
+    }
+}
+
+/// Returns whether the table constraint includes the column, as a key column
+/// or as a referencing column.
+fn includes_column(
+    table_constraint_definition: &TableConstraintDefinition,
+    column_name: &Ident,
+) -> bool {
+    match table_constraint_definition.table_constraint() {
+        TableConstraint::Unique(unique_constraint_definition) => unique_constraint_definition
+            .key_columns()
+            .iter()
+            .any(|key_column| key_column.column_name().refers_to(column_name)),
+        TableConstraint::Referential(referential_constraint_definition) => {
+            referential_constraint_definition
+                .referencing_columns()
+                .column_names()
+                .iter()
+                .any(|referencing_column| referencing_column.refers_to(column_name))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::common::span::Span;
+
+    use super::*;
+
+    #[test]
+    fn tables_of_script() {
+        let tables = data_dictionary(
+            "CREATE TABLE a (id BIGINT GENERATED BY DEFAULT AS IDENTITY (START WITH 10), x);\n\
+             DROP TABLE b CASCADE;\n\
+             CREATE LOCAL TEMPORARY TABLE \"c\" (\"Name\" CLOB);",
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                TableEntry {
+                    name: "a".to_string(),
+                    columns: vec![
+                        ColumnEntry {
+                            name: "id".to_string(),
+                            data_type: Some("BIGINT".to_string()),
+                            nullable: false,
+                            constraints: vec![
+                                "GENERATED BY DEFAULT AS IDENTITY (START WITH 10)".to_string()
+                            ],
+                        },
+                        ColumnEntry {
+                            name: "x".to_string(),
+                            data_type: None,
+                            nullable: true,
+                            constraints: vec![],
+                        },
+                    ],
+                },
+                TableEntry {
+                    name: "\"c\"".to_string(),
+                    columns: vec![ColumnEntry {
+                        name: "\"Name\"".to_string(),
+                        data_type: Some("CLOB".to_string()),
+                        nullable: true,
+                        constraints: vec![],
+                    }],
+                },
+            ],
+            tables
+        );
+    }
+
+    #[test]
+    fn constraints() {
+        let tables = data_dictionary(
+            "CREATE TABLE a (id INT, b INT CONSTRAINT b_ref REFERENCES o, c INT NOT NULL UNIQUE, \
+             PRIMARY KEY (id, b), FOREIGN KEY (C) REFERENCES p (x))",
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                ("id", false, vec!["PRIMARY KEY (id, b)"]),
+                (
+                    "b",
+                    false,
+                    vec!["CONSTRAINT b_ref REFERENCES o", "PRIMARY KEY (id, b)"]
+                ),
+                (
+                    "c",
+                    false,
+                    vec!["NOT NULL", "UNIQUE", "FOREIGN KEY (C) REFERENCES p (x)"]
+                ),
+            ],
+            tables[0]
+                .columns()
+                .iter()
+                .map(|column| (
+                    column.name(),
+                    column.nullable(),
+                    column
+                        .constraints()
+                        .iter()
+                        .map(String::as_str)
+                        .collect::<Vec<_>>()
+                ))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn invalid_script() {
+        assert_eq!(
+            Err(ScriptError::InvalidStatement(Span::new(0, 8))),
+            data_dictionary("SELECT 1")
+        );
+    }
+}
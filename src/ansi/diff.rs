@@ -0,0 +1,620 @@
+//! Generation of the DDL needed to migrate one table definition into another.
+use crate::ansi::ast::alter_table::{AlterColumnAction, AlterTable, AlterTableAction};
+use crate::ansi::ast::common::{ColumnDefinition, DropBehavior};
+use crate::ansi::ast::create_table::{CreateTable, TableConstraintDefinition, TableContentsSource};
+use crate::ansi::ast::drop_table::DropTable;
+use crate::ansi::rename::rename_table_column;
+use crate::ansi::Statement;
+use crate::common::Ident;
+
+/// Errors raised when two table definitions can't be migrated with
+/// `ALTER TABLE` statements.
+#[derive(thiserror::Error, Clone, Eq, PartialEq, Debug)]
+pub enum DiffError {
+    /// The definitions are of different tables.
+    #[error("`{0}` and `{1}` are different tables")]
+    DifferentTables(String, String),
+    /// The change has no `ALTER TABLE` equivalent (e.g., adding an identity to
+    /// an existing column, or changing the constraints of the table), so the
+    /// table must be recreated.
+    #[error("`{0}` can't be changed with ALTER TABLE")]
+    Unsupported(String),
+    /// The renamed column is not defined in the `from` table, or the new name
+    /// is not defined in the `to` one.
+    #[error("column `{0}` can't be renamed to `{1}`")]
+    InvalidRename(String, String),
+}
+
+/// Returns the `ALTER TABLE` statements that transform the `from` table
+/// definition into the `to` one.
+///
+/// Columns are matched by name. The statements drop the removed columns (using
+/// the received drop behavior), then alter the changed columns, and finally
+/// add the new columns, in the order they are defined in `to`.
+///
+/// New columns are added with their column constraints, but the constraints
+/// of the other columns and the table constraints can't be changed, as there
+/// are no `ALTER TABLE` actions for them yet.
+///
+/// # Errors
+/// If the definitions are of different tables, or if any change can't be
+/// expressed with `ALTER TABLE` [(1)][`DiffError`], including any change of
+/// the constraints of the kept columns or of the table constraints.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::common::DropBehavior;
+/// # use sql_helper::ansi::diff::diff_tables;
+/// # use sql_helper::ansi::parser::create_table::create_table;
+/// let (_, from) = create_table(b"CREATE TABLE tb (id INT, name VARCHAR(20))").unwrap();
+/// let (_, to) = create_table(b"CREATE TABLE tb (id BIGINT, email VARCHAR(50))").unwrap();
+///
+/// let migration = diff_tables(&from, &to, DropBehavior::Restrict)
+///     .unwrap()
+///     .iter()
+///     .map(ToString::to_string)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     migration,
+///     vec![
+///         "ALTER TABLE tb DROP COLUMN name RESTRICT",
+///         "ALTER TABLE tb ALTER COLUMN id SET DATA TYPE BIGINT",
+///         "ALTER TABLE tb ADD COLUMN email VARCHAR(50)",
+///     ]
+/// );
+/// ```
+pub fn diff_tables(
+    from: &CreateTable,
+    to: &CreateTable,
+    drop_behavior: DropBehavior,
+) -> Result<Vec<AlterTable>, DiffError> {
+    diff_tables_with_renames(from, to, &[], drop_behavior)
+}
+
+/// Returns the `ALTER TABLE` statements that transform the `from` table
+/// definition into the `to` one, as [`diff_tables`], renaming the received
+/// columns instead of dropping and adding them.
+///
+/// The renames are `(old name, new name)` pairs, and are applied before any
+/// other change.
+///
+/// # Errors
+/// If any renamed column is not defined in `from`, or its new name is not
+/// defined in `to`, or any other change can't be expressed with `ALTER TABLE`
+/// [(1)][`DiffError`].
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::common::DropBehavior;
+/// # use sql_helper::ansi::diff::diff_tables_with_renames;
+/// # use sql_helper::ansi::parser::create_table::create_table;
+/// # use sql_helper::common::Ident;
+/// let (_, from) = create_table(b"CREATE TABLE tb (id INT, name VARCHAR(20))").unwrap();
+/// let (_, to) = create_table(b"CREATE TABLE tb (id INT, full_name VARCHAR(50))").unwrap();
+///
+/// let migration = diff_tables_with_renames(
+///     &from,
+///     &to,
+///     &[(Ident::new(b"name"), Ident::new(b"full_name"))],
+///     DropBehavior::Restrict,
+/// )
+/// .unwrap()
+/// .iter()
+/// .map(ToString::to_string)
+/// .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     migration,
+///     vec![
+///         "ALTER TABLE tb RENAME COLUMN name TO full_name",
+///         "ALTER TABLE tb ALTER COLUMN full_name SET DATA TYPE VARCHAR(50)",
+///     ]
+/// );
+/// ```
+pub fn diff_tables_with_renames(
+    from: &CreateTable,
+    to: &CreateTable,
+    column_renames: &[(Ident, Ident)],
+    drop_behavior: DropBehavior,
+) -> Result<Vec<AlterTable>, DiffError> {
+    if !from.table_name().refers_to(to.table_name()) {
+        return Err(DiffError::DifferentTables(
+            from.table_name().to_string(),
+            to.table_name().to_string(),
+        ));
+    }
+
+    if from.opt_table_scope() != to.opt_table_scope() {
+        return Err(DiffError::Unsupported(to.to_string()));
+    }
+
+    let to_columns = column_definitions(to);
+    let mut renamed_from = from.clone();
+    let mut alter_table_actions = vec![];
+
+    for (column_name, new_column_name) in column_renames {
+        let invalid_rename =
+            || DiffError::InvalidRename(column_name.to_string(), new_column_name.to_string());
+        let is_defined = |columns: &[&ColumnDefinition], column_name: &Ident| {
+            columns
+                .iter()
+                .any(|column_definition| column_definition.column_name().refers_to(column_name))
+        };
+        if !is_defined(&column_definitions(&renamed_from), column_name)
+            || !is_defined(&to_columns, new_column_name)
+        {
+            return Err(invalid_rename());
+        }
+
+        // OBS: the constraints are renamed along with the column, so they are
+        // compared with the new names.
+        rename_table_column(&mut renamed_from, column_name, new_column_name);
+        alter_table_actions.push(AlterTableAction::RenameColumn(
+            column_name.clone(),
+            new_column_name.clone(),
+        ));
+    }
+
+    let from_columns = column_definitions(&renamed_from);
+    let from_constraints = table_constraints(&renamed_from);
+    let to_constraints = table_constraints(to);
+    let constraints_changed = from_constraints
+        .iter()
+        .any(|constraint| !to_constraints.contains(constraint))
+        || to_constraints
+            .iter()
+            .any(|constraint| !from_constraints.contains(constraint));
+    if constraints_changed {
+        return Err(DiffError::Unsupported(to.to_string()));
+    }
+
+    for from_column in &from_columns {
+        if find_column(&to_columns, from_column).is_none() {
+            alter_table_actions.push(AlterTableAction::DropColumn(
+                from_column.column_name().clone(),
+                drop_behavior,
+            ));
+        }
+    }
+
+    for to_column in &to_columns {
+        if let Some(from_column) = find_column(&from_columns, to_column) {
+            for alter_column_action in diff_columns(from_column, to_column)? {
+                alter_table_actions.push(AlterTableAction::AlterColumn(
+                    to_column.column_name().clone(),
+                    alter_column_action,
+                ));
+            }
+        }
+    }
+
+    for to_column in &to_columns {
+        if find_column(&from_columns, to_column).is_none() {
+            alter_table_actions.push(AlterTableAction::AddColumn((*to_column).clone()));
+        }
+    }
+
+    Ok(alter_table_actions
+        .into_iter()
+        .map(|alter_table_action| AlterTable::new(to.table_name().clone(), alter_table_action))
+        .collect())
+}
+
+/// Returns the statements that transform the `from` set of table definitions
+/// into the `to` one.
+///
+/// Tables are matched by name. The statements drop the removed tables (using
+/// the received drop behavior), then alter the changed tables [(1)], and
+/// finally create the new tables, in the order they are defined in `to`.
+///
+/// # Errors
+/// If any table change can't be expressed with `ALTER TABLE` [(1)].
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::common::DropBehavior;
+/// # use sql_helper::ansi::diff::diff_table_definitions;
+/// # use sql_helper::ansi::parser::create_table::create_table;
+/// let (_, a) = create_table(b"CREATE TABLE a (id INT)").unwrap();
+/// let (_, b) = create_table(b"CREATE TABLE b (id INT)").unwrap();
+///
+/// let migration = diff_table_definitions(&[a], &[b], DropBehavior::Restrict)
+///     .unwrap()
+///     .iter()
+///     .map(ToString::to_string)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(migration, vec!["DROP TABLE a RESTRICT", "CREATE TABLE b (id INT)"]);
+/// ```
+///
+/// [(1)]: diff_tables
+pub fn diff_table_definitions(
+    from: &[CreateTable],
+    to: &[CreateTable],
+    drop_behavior: DropBehavior,
+) -> Result<Vec<Statement>, DiffError> {
+    let mut statements = vec![];
+
+    for from_table in from {
+        if find_table(to, from_table).is_none() {
+            statements.push(Statement::DropTable(DropTable::new(
+                from_table.table_name().clone(),
+                drop_behavior,
+            )));
+        }
+    }
+
+    for to_table in to {
+        if let Some(from_table) = find_table(from, to_table) {
+            for alter_table in diff_tables(from_table, to_table, drop_behavior)? {
+                statements.push(Statement::AlterTable(alter_table));
+            }
+        }
+    }
+
+    for to_table in to {
+        if find_table(from, to_table).is_none() {
+            statements.push(Statement::CreateTable(to_table.clone()));
+        }
+    }
+
+    Ok(statements)
+}
+
+/// Returns the actions that transform the `from` column into the `to` one.
+///
+/// The identity is dropped before the data type is changed, as identity
+/// columns are restricted to exact numeric types.
+fn diff_columns(
+    from: &ColumnDefinition,
+    to: &ColumnDefinition,
+) -> Result<Vec<AlterColumnAction>, DiffError> {
+    let mut alter_column_actions = vec![];
+
+    if from.column_constraint_definitions() != to.column_constraint_definitions() {
+        return Err(DiffError::Unsupported(to.to_string()));
+    }
+
+    match (
+        from.opt_identity_column_specification(),
+        to.opt_identity_column_specification(),
+    ) {
+        (Some(_), None) => alter_column_actions.push(AlterColumnAction::DropIdentity),
+        (Some(from_identity), Some(to_identity))
+            if from_identity.is_equivalent_to(&to_identity) => {}
+        (None, None) => {}
+        _ => return Err(DiffError::Unsupported(to.to_string())),
+    }
+
+    match (from.opt_data_type(), to.opt_data_type()) {
+        (Some(from_data_type), Some(to_data_type))
+            if from_data_type.is_equivalent_to(&to_data_type) => {}
+        (_, Some(data_type)) => {
+            alter_column_actions.push(AlterColumnAction::SetDataType(data_type));
+        }
+        (Some(_), None) => return Err(DiffError::Unsupported(to.to_string())),
+        (None, None) => {}
+    }
+
+    Ok(alter_column_actions)
+}
+
+fn find_table<'a>(
+    tables: &'a [CreateTable],
+    create_table: &CreateTable,
+) -> Option<&'a CreateTable> {
+    tables
+        .iter()
+        .find(|other| other.table_name().refers_to(create_table.table_name()))
+}
+
+fn find_column<'a>(
+    columns: &[&'a ColumnDefinition],
+    column_definition: &ColumnDefinition,
+) -> Option<&'a ColumnDefinition> {
+    columns
+        .iter()
+        .find(|other| {
+            other
+                .column_name()
+                .refers_to(column_definition.column_name())
+        })
+        .copied()
+}
+
+fn column_definitions(create_table: &CreateTable) -> Vec<&ColumnDefinition> {
+    let TableContentsSource::TableElementList(table_element_list) =
+        create_table.table_contents_source();
+
+    table_element_list.columns().collect()
+}
+
+fn table_constraints(create_table: &CreateTable) -> Vec<&TableConstraintDefinition> {
+    let TableContentsSource::TableElementList(table_element_list) =
+        create_table.table_contents_source();
+
+    table_element_list.constraints().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::parser::create_table::create_table;
+
+    use super::*;
+
+    fn diff(from: &str, to: &str) -> Result<Vec<String>, DiffError> {
+        let (_, from) = create_table(from.as_ref()).unwrap();
+        let (_, to) = create_table(to.as_ref()).unwrap();
+
+        diff_tables(&from, &to, DropBehavior::Cascade).map(|alter_tables| {
+            alter_tables
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        })
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (id INT, name VARCHAR(20))",
+        "CREATE TABLE tb (id INT, name VARCHAR(20))",
+        &[] ;
+        "same definition"
+    )]
+    #[test_case(
+        "CREATE TABLE sch.tb (id INT)",
+        "CREATE TABLE sch.tb (id INT, name VARCHAR(20), age SMALLINT)",
+        &[
+            "ALTER TABLE sch.tb ADD COLUMN name VARCHAR(20)",
+            "ALTER TABLE sch.tb ADD COLUMN age SMALLINT",
+        ] ;
+        "added columns"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT, name VARCHAR(20))",
+        "CREATE TABLE tb (name VARCHAR(20))",
+        &["ALTER TABLE tb DROP COLUMN id CASCADE"] ;
+        "removed column"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT GENERATED ALWAYS AS IDENTITY, name VARCHAR(20))",
+        "CREATE TABLE tb (id VARCHAR(36), name CLOB)",
+        &[
+            "ALTER TABLE tb ALTER COLUMN id DROP IDENTITY",
+            "ALTER TABLE tb ALTER COLUMN id SET DATA TYPE VARCHAR(36)",
+            "ALTER TABLE tb ALTER COLUMN name SET DATA TYPE CLOB",
+        ] ;
+        "retyped columns"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (name VARCHAR(20), id INT)",
+        "CREATE TABLE tb (id INT, name VARCHAR(20))",
+        &[] ;
+        "reordered columns"
+    )]
+    #[test_case(
+        "CREATE TABLE sch.tb (id INT, \"NAME\" VARCHAR(20))",
+        "CREATE TABLE SCH.TB (ID INT, name VARCHAR(20))",
+        &[] ;
+        "case folded names"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a TIMESTAMP, b TIME, c DECIMAL(10), d CHAR)",
+        "CREATE TABLE tb (a TIMESTAMP(6) WITHOUT TIME ZONE, b TIME(0), c DECIMAL(10, 0), d CHAR(1))",
+        &[] ;
+        "explicit defaults"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT, b DEC(10, 2), c CHAR VARYING(5), d CHARACTER LARGE OBJECT)",
+        "CREATE TABLE tb (a INTEGER, b DECIMAL(10, 2), c VARCHAR(5), d CLOB)",
+        &[] ;
+        "aliased types"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a CHAR, b INT)",
+        "CREATE TABLE tb (a VARCHAR(1), b BIGINT)",
+        &[
+            "ALTER TABLE tb ALTER COLUMN a SET DATA TYPE VARCHAR(1)",
+            "ALTER TABLE tb ALTER COLUMN b SET DATA TYPE BIGINT",
+        ] ;
+        "changed types"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a TIMESTAMP, b TIME)",
+        "CREATE TABLE tb (a TIMESTAMP WITH TIME ZONE, b TIME(3))",
+        &[
+            "ALTER TABLE tb ALTER COLUMN a SET DATA TYPE TIMESTAMP WITH TIME ZONE",
+            "ALTER TABLE tb ALTER COLUMN b SET DATA TYPE TIME(3)",
+        ] ;
+        "changed defaults"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT AUTO_INCREMENT)",
+        "CREATE TABLE tb (id INT GENERATED BY DEFAULT AS IDENTITY)",
+        &[] ;
+        "auto increment as identity"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id BIGSERIAL)",
+        "CREATE TABLE tb (id BIGINT GENERATED BY DEFAULT AS IDENTITY)",
+        &[] ;
+        "serial as identity"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT NOT NULL, PRIMARY KEY (id), FOREIGN KEY (id) REFERENCES a)",
+        "CREATE TABLE tb (FOREIGN KEY (id) REFERENCES a, id BIGINT NOT NULL, PRIMARY KEY (id))",
+        &["ALTER TABLE tb ALTER COLUMN id SET DATA TYPE BIGINT"] ;
+        "same constraints"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT, PRIMARY KEY (id))",
+        "CREATE TABLE tb (id INT, name INT NOT NULL UNIQUE, PRIMARY KEY (id))",
+        &["ALTER TABLE tb ADD COLUMN name INT NOT NULL UNIQUE"] ;
+        "added column with constraints"
+    )]
+    fn migration(from: &str, to: &str, expected: &[&str]) {
+        let expected = expected.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert_eq!(Ok(expected), diff(from, to));
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (id INT)",
+        "CREATE TABLE tb (id INT GENERATED ALWAYS AS IDENTITY)" ;
+        "added identity"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT GENERATED ALWAYS AS IDENTITY)",
+        "CREATE TABLE tb (id INT GENERATED BY DEFAULT AS IDENTITY)" ;
+        "changed identity"
+    )]
+    #[test_case("CREATE TABLE tb (id INT)", "CREATE TABLE tb (id)" ; "removed data type")]
+    #[test_case(
+        "CREATE TABLE tb (id INT)",
+        "CREATE LOCAL TEMPORARY TABLE tb (id INT)" ;
+        "changed table scope"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT)",
+        "CREATE TABLE tb (id INT NOT NULL, PRIMARY KEY (id), FOREIGN KEY (id) REFERENCES a)" ;
+        "added constraints"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT NOT NULL, PRIMARY KEY (id), FOREIGN KEY (id) REFERENCES a)",
+        "CREATE TABLE tb (id INT)" ;
+        "removed constraints"
+    )]
+    #[test_case("CREATE TABLE tb (id INT)", "CREATE TABLE tb (id INT NOT NULL)" ; "added not null")]
+    #[test_case(
+        "CREATE TABLE tb (id INT UNIQUE)",
+        "CREATE TABLE tb (id INT PRIMARY KEY)" ;
+        "changed column constraint"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT, PRIMARY KEY (id))",
+        "CREATE TABLE tb (id INT, key INT, PRIMARY KEY (key))" ;
+        "constraint on added column"
+    )]
+    fn migration_unsupported(from: &str, to: &str) {
+        assert!(matches!(diff(from, to), Err(DiffError::Unsupported(_))));
+    }
+
+    #[test_case(
+        &[("name", "full_name")],
+        &[
+            "ALTER TABLE tb RENAME COLUMN name TO full_name",
+            "ALTER TABLE tb DROP COLUMN age CASCADE",
+            "ALTER TABLE tb ALTER COLUMN id SET DATA TYPE VARCHAR(50)",
+            "ALTER TABLE tb ALTER COLUMN full_name SET DATA TYPE VARCHAR(50)",
+            "ALTER TABLE tb ADD COLUMN key INT",
+        ] ;
+        "renamed column"
+    )]
+    #[test_case(
+        &[("id", "key"), ("name", "id")],
+        &[
+            "ALTER TABLE tb RENAME COLUMN id TO key",
+            "ALTER TABLE tb RENAME COLUMN name TO id",
+            "ALTER TABLE tb DROP COLUMN age CASCADE",
+            "ALTER TABLE tb ALTER COLUMN id SET DATA TYPE VARCHAR(50)",
+            "ALTER TABLE tb ADD COLUMN full_name VARCHAR(50)",
+        ] ;
+        "swapped names"
+    )]
+    fn migration_with_renames(renames: &[(&str, &str)], expected: &[&str]) {
+        let (_, from) =
+            create_table(b"CREATE TABLE tb (id INT, name VARCHAR(20), age INT)").unwrap();
+        let (_, to) =
+            create_table(b"CREATE TABLE tb (id VARCHAR(50), key INT, full_name VARCHAR(50))")
+                .unwrap();
+        let renames = renames
+            .iter()
+            .map(|(from, to)| (Ident::new(from.as_bytes()), Ident::new(to.as_bytes())))
+            .collect::<Vec<_>>();
+
+        let migration = diff_tables_with_renames(&from, &to, &renames, DropBehavior::Cascade)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected, migration);
+    }
+
+    #[test]
+    fn migration_with_renamed_constraint_columns() {
+        let (_, from) = create_table(
+            b"CREATE TABLE tb (id INT, PRIMARY KEY (id), FOREIGN KEY (id) REFERENCES tb (id))",
+        )
+        .unwrap();
+        let (_, to) = create_table(
+            b"CREATE TABLE tb (key INT, PRIMARY KEY (key), FOREIGN KEY (key) REFERENCES tb (key))",
+        )
+        .unwrap();
+
+        let migration = diff_tables_with_renames(
+            &from,
+            &to,
+            &[(Ident::new(b"id"), Ident::new(b"key"))],
+            DropBehavior::Cascade,
+        )
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+        assert_eq!(vec!["ALTER TABLE tb RENAME COLUMN id TO key"], migration);
+    }
+
+    #[test_case(&[("missing", "id")] ; "unknown column")]
+    #[test_case(&[("name", "missing")] ; "unknown new name")]
+    fn migration_invalid_rename(renames: &[(&str, &str)]) {
+        let (_, from) = create_table(b"CREATE TABLE tb (id INT, name VARCHAR(20))").unwrap();
+        let (_, to) = create_table(b"CREATE TABLE tb (id INT)").unwrap();
+        let renames = renames
+            .iter()
+            .map(|(from, to)| (Ident::new(from.as_bytes()), Ident::new(to.as_bytes())))
+            .collect::<Vec<_>>();
+
+        assert!(matches!(
+            diff_tables_with_renames(&from, &to, &renames, DropBehavior::Cascade),
+            Err(DiffError::InvalidRename(..))
+        ));
+    }
+
+    #[test]
+    fn table_definitions() {
+        let tables = |inputs: &[&str]| {
+            inputs
+                .iter()
+                .map(|input| create_table(input.as_ref()).unwrap().1)
+                .collect::<Vec<_>>()
+        };
+        let from = tables(&["CREATE TABLE a (id INT)", "CREATE TABLE b (id INT)"]);
+        let to = tables(&["CREATE TABLE c (id INT)", "CREATE TABLE b (id BIGINT)"]);
+
+        assert_eq!(
+            vec![
+                "DROP TABLE a CASCADE",
+                "ALTER TABLE b ALTER COLUMN id SET DATA TYPE BIGINT",
+                "CREATE TABLE c (id INT)",
+            ],
+            diff_table_definitions(&from, &to, DropBehavior::Cascade)
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn migration_different_tables() {
+        assert_eq!(
+            Err(DiffError::DifferentTables("a".to_string(), "b".to_string())),
+            diff("CREATE TABLE a (id INT)", "CREATE TABLE b (id INT)")
+        );
+    }
+}
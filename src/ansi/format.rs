@@ -0,0 +1,952 @@
+use crate::ansi::ast::alter_table::{AlterColumnAction, AlterTable, AlterTableAction};
+use crate::ansi::ast::call::Call;
+use crate::ansi::ast::close::Close;
+use crate::ansi::ast::common::{
+    ColumnConstraint, ColumnDefinition, Literal, LocalOrSchemaQualifier, ReferencesSpecification,
+    SchemaQualifiedName, TableName, ValueSpecification,
+};
+use crate::ansi::ast::create_schema::{CreateSchema, SchemaNameClause};
+use crate::ansi::ast::create_table::{
+    CreateTable, TableConstraint, TableConstraintDefinition, TableContentsSource, TableElement,
+};
+use crate::ansi::ast::delete::DeletePositioned;
+use crate::ansi::ast::drop_schema::DropSchema;
+use crate::ansi::ast::drop_table::DropTable;
+use crate::ansi::ast::explain::Explain;
+use crate::ansi::ast::fetch::Fetch;
+use crate::ansi::ast::grant::{
+    Action, GrantObject, GrantPrivilege, Grantee, ObjectPrivileges, Privileges,
+};
+use crate::ansi::ast::open::Open;
+use crate::ansi::ast::return_statement::Return;
+use crate::ansi::ast::revoke::RevokePrivilege;
+use crate::ansi::ast::update::{UpdatePositioned, UpdateSource};
+use crate::ansi::lexer::{LexemeKind, Lexer};
+use crate::ansi::locate::ParsedScript;
+use crate::ansi::parser::ScriptError;
+use crate::ansi::script::{join_statements, TerminatorPolicy};
+use crate::ansi::Statement;
+use crate::common::display_comma_separated;
+use crate::common::span::Span;
+
+/// Style used by [`format_statement`] to render statements.
+///
+/// The default options render statements exactly as their `Display`
+/// implementation.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FormatOptions {
+    /// Number of spaces used for each indentation level.
+    indent_width: usize,
+    /// Whether each `CREATE TABLE` element is rendered in its own line.
+    element_per_line: bool,
+    /// Case used for keywords.
+    keyword_case: KeywordCase,
+    /// Width after which `CREATE TABLE` elements are split into lines.
+    opt_max_line_width: Option<usize>,
+    /// Policy used by [`format_script`] to terminate the statements.
+    terminator_policy: TerminatorPolicy,
+}
+
+/// Case used to render keywords.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum KeywordCase {
+    /// `CREATE TABLE`.
+    #[default]
+    Upper,
+    /// `create table`.
+    Lower,
+    /// Keywords are rendered as the AST renders them.
+    ///
+    /// As the original spelling of keywords is not kept by the parser, this is
+    /// the same as [`KeywordCase::Upper`] for now.
+    Preserve,
+}
+
+impl FormatOptions {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            indent_width: 4,
+            element_per_line: false,
+            keyword_case: KeywordCase::Upper,
+            opt_max_line_width: None,
+            terminator_policy: TerminatorPolicy::Always,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_indent_width(mut self, indent_width: usize) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_element_per_line(mut self, element_per_line: bool) -> Self {
+        self.element_per_line = element_per_line;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_keyword_case(mut self, keyword_case: KeywordCase) -> Self {
+        self.keyword_case = keyword_case;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_line_width(mut self, max_line_width: usize) -> Self {
+        self.opt_max_line_width = Some(max_line_width);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_terminator_policy(mut self, terminator_policy: TerminatorPolicy) -> Self {
+        self.terminator_policy = terminator_policy;
+        self
+    }
+
+    #[must_use]
+    pub const fn indent_width(&self) -> usize {
+        self.indent_width
+    }
+
+    #[must_use]
+    pub const fn element_per_line(&self) -> bool {
+        self.element_per_line
+    }
+
+    #[must_use]
+    pub const fn keyword_case(&self) -> KeywordCase {
+        self.keyword_case
+    }
+
+    #[must_use]
+    pub const fn opt_max_line_width(&self) -> Option<usize> {
+        self.opt_max_line_width
+    }
+
+    #[must_use]
+    pub const fn terminator_policy(&self) -> TerminatorPolicy {
+        self.terminator_policy
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Renders the statement using the received style.
+///
+/// Identifiers are always rendered as they are in the AST; only keywords,
+/// whitespaces and line breaks are affected by the options.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::format::{format_statement, FormatOptions, KeywordCase};
+/// # use sql_helper::ansi::parser::parse_statement;
+/// let (_, statement) = parse_statement(b"CREATE TABLE tb (id INT, name VARCHAR(20))").unwrap();
+/// let options = FormatOptions::new()
+///     .with_indent_width(2)
+///     .with_element_per_line(true)
+///     .with_keyword_case(KeywordCase::Lower);
+///
+/// assert_eq!(
+///     format_statement(&statement, &options),
+///     "create table tb (\n  id int,\n  name varchar(20)\n)"
+/// );
+/// ```
+#[must_use]
+pub fn format_statement(statement: &Statement, options: &FormatOptions) -> String {
+    let formatter = Formatter { options };
+
+    match statement {
+        Statement::CreateSchema(create_schema) => formatter.create_schema(create_schema),
+        Statement::DropSchema(drop_schema) => formatter.drop_schema(drop_schema),
+        Statement::DropTable(drop_table) => formatter.drop_table(drop_table),
+        Statement::CreateTable(create_table) => formatter.create_table(create_table),
+        Statement::AlterTable(alter_table) => formatter.alter_table(alter_table),
+        Statement::Open(open) => formatter.open(open),
+        Statement::Fetch(fetch) => formatter.fetch(fetch),
+        Statement::Close(close) => formatter.close(close),
+        Statement::Call(call) => formatter.call(call),
+        Statement::Return(return_statement) => formatter.return_statement(return_statement),
+        Statement::UpdatePositioned(update) => formatter.update_positioned(update),
+        Statement::DeletePositioned(delete) => formatter.delete_positioned(delete),
+        Statement::Explain(explain) => formatter.explain(explain),
+        Statement::GrantPrivilege(grant) => formatter.grant_privilege(grant),
+        Statement::RevokePrivilege(revoke) => formatter.revoke_privilege(revoke),
+    }
+}
+
+/// Parses the script and renders each of its statements using the received
+/// style, in its own line, terminated following the terminator policy [(1)].
+///
+/// The comments before the first statement and after the last one (e.g., a
+/// file header) are kept as written [(2)]. The comments between or inside the
+/// statements can't be placed in the rendered statements, so the script is
+/// refused instead of losing them.
+///
+/// # Errors
+/// If any statement of the script can't be parsed [(3)], or if there's a
+/// comment between or inside the statements [(4)].
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::format::{format_script, FormatOptions};
+/// # use sql_helper::ansi::parser::ScriptError;
+/// # use sql_helper::common::span::Span;
+/// let formatted = format_script(
+///     "-- header\ndrop table a cascade; drop table b restrict",
+///     &FormatOptions::new(),
+/// );
+/// assert_eq!(
+///     formatted.unwrap(),
+///     "-- header\nDROP TABLE a CASCADE;\nDROP TABLE b RESTRICT;\n"
+/// );
+///
+/// let formatted = format_script("drop table a -- old\ncascade", &FormatOptions::new());
+/// assert_eq!(formatted, Err(ScriptError::MisplacedComment(Span::new(13, 19))));
+/// ```
+///
+/// [(1)]: TerminatorPolicy
+/// [(2)]: ParsedScript
+/// [(3)]: crate::ansi::parser::parse_script
+/// [(4)]: ScriptError::MisplacedComment
+pub fn format_script(sql: &str, options: &FormatOptions) -> Result<String, ScriptError> {
+    let script = ParsedScript::parse(sql)?;
+    let body_start = script.prologue().len();
+    let body_end = sql.len() - script.epilogue().len();
+
+    if let Some(comment) = Lexer::new(&sql[body_start..body_end]).find(|lexeme| {
+        matches!(
+            lexeme.kind(),
+            LexemeKind::LineComment | LexemeKind::BlockComment
+        )
+    }) {
+        let span = comment.span();
+        return Err(ScriptError::MisplacedComment(Span::new(
+            body_start + span.start(),
+            body_start + span.end(),
+        )));
+    }
+
+    let statements = script
+        .statements()
+        .iter()
+        .map(|statement| format_statement(statement, options))
+        .collect::<Vec<_>>();
+    let mut formatted = join_statements(statements, options.terminator_policy());
+
+    // OBS: the whitespaces around the statements are not kept, as the
+    // formatter places the statements in their own lines.
+    let prologue = if script.prologue().trim().is_empty() {
+        ""
+    } else {
+        script.prologue()
+    };
+    let epilogue = script.epilogue().trim_start_matches([' ', '\t']);
+    if epilogue.trim().is_empty() {
+        return Ok(format!("{prologue}{formatted}"));
+    }
+    if !formatted.is_empty() && !formatted.ends_with('\n') {
+        formatted.push('\n');
+    }
+
+    Ok(format!("{prologue}{formatted}{epilogue}"))
+}
+
+struct Formatter<'a> {
+    options: &'a FormatOptions,
+}
+
+impl Formatter<'_> {
+    fn keyword(&self, keyword: &dyn ToString) -> String {
+        let keyword = keyword.to_string();
+
+        match self.options.keyword_case() {
+            KeywordCase::Upper | KeywordCase::Preserve => keyword,
+            KeywordCase::Lower => keyword.to_lowercase(),
+        }
+    }
+
+    fn create_schema(&self, create_schema: &CreateSchema) -> String {
+        let schema_name_clause = match create_schema.schema_name_clause() {
+            SchemaNameClause::Simple(schema_name) => schema_name.to_string(),
+            SchemaNameClause::Authorization(authorization) => {
+                format!("{} {authorization}", self.keyword(&"AUTHORIZATION"))
+            }
+            SchemaNameClause::NamedAuthorization(schema_name, authorization) => {
+                format!(
+                    "{schema_name} {} {authorization}",
+                    self.keyword(&"AUTHORIZATION")
+                )
+            }
+        };
+
+        format!("{} {schema_name_clause}", self.keyword(&"CREATE SCHEMA"))
+    }
+
+    fn drop_schema(&self, drop_schema: &DropSchema) -> String {
+        format!(
+            "{} {} {}",
+            self.keyword(&"DROP SCHEMA"),
+            drop_schema.schema_name(),
+            self.keyword(&drop_schema.drop_behavior())
+        )
+    }
+
+    fn drop_table(&self, drop_table: &DropTable) -> String {
+        format!(
+            "{} {} {}",
+            self.keyword(&"DROP TABLE"),
+            self.table_name(drop_table.table_name()),
+            self.keyword(&drop_table.drop_behavior())
+        )
+    }
+
+    fn create_table(&self, create_table: &CreateTable) -> String {
+        let mut header = self.keyword(&"CREATE");
+        if let Some(table_scope) = create_table.opt_table_scope() {
+            header.push(' ');
+            header.push_str(&self.keyword(&table_scope));
+        }
+        header = format!(
+            "{header} {} {}",
+            self.keyword(&"TABLE"),
+            self.table_name(create_table.table_name())
+        );
+
+        let TableContentsSource::TableElementList(table_element_list) =
+            create_table.table_contents_source();
+        let elements = table_element_list
+            .element_list()
+            .iter()
+            .map(|table_element| match table_element {
+                TableElement::ColumnDefinition(column_definition) => {
+                    self.column_definition(column_definition)
+                }
+                TableElement::TableConstraintDefinition(table_constraint_definition) => {
+                    self.table_constraint_definition(table_constraint_definition)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut table_options = create_table
+            .table_options()
+            .iter()
+            .map(|option| {
+                let separator = if option.equals() { "=" } else { " " };
+                format!(
+                    " {}{separator}{}",
+                    self.keyword(&option.name()),
+                    option.value()
+                )
+            })
+            .collect::<Vec<_>>();
+        if let Some(partition_by) = create_table.opt_partition_by() {
+            table_options.insert(
+                create_table.partition_by_position(),
+                format!(" {partition_by}"),
+            );
+        }
+        let table_options = table_options.concat();
+
+        let single_line = format!("{header} ({}){table_options}", elements.join(", "));
+        let exceeds_width = self
+            .options
+            .opt_max_line_width()
+            .is_some_and(|max_line_width| single_line.chars().count() > max_line_width);
+
+        if !elements.is_empty() && (self.options.element_per_line() || exceeds_width) {
+            let indent = " ".repeat(self.options.indent_width());
+            let elements = elements
+                .iter()
+                .map(|element| format!("{indent}{element}"))
+                .collect::<Vec<_>>()
+                .join(",\n");
+
+            format!("{header} (\n{elements}\n){table_options}")
+        } else {
+            single_line
+        }
+    }
+
+    fn alter_table(&self, alter_table: &AlterTable) -> String {
+        let alter_table_action = match alter_table.alter_table_action() {
+            AlterTableAction::AddColumn(column_definition) => format!(
+                "{} {}",
+                self.keyword(&"ADD COLUMN"),
+                self.column_definition(column_definition)
+            ),
+            AlterTableAction::AlterColumn(column_name, alter_column_action) => {
+                let alter_column_action = match alter_column_action {
+                    AlterColumnAction::SetDataType(data_type) => {
+                        format!(
+                            "{} {}",
+                            self.keyword(&"SET DATA TYPE"),
+                            self.keyword(data_type)
+                        )
+                    }
+                    AlterColumnAction::DropIdentity => self.keyword(&"DROP IDENTITY"),
+                };
+                format!(
+                    "{} {column_name} {alter_column_action}",
+                    self.keyword(&"ALTER COLUMN")
+                )
+            }
+            AlterTableAction::DropColumn(column_name, drop_behavior) => format!(
+                "{} {column_name} {}",
+                self.keyword(&"DROP COLUMN"),
+                self.keyword(drop_behavior)
+            ),
+            AlterTableAction::RenameTable(table_name) => format!(
+                "{} {}",
+                self.keyword(&"RENAME TO"),
+                self.table_name(table_name)
+            ),
+            AlterTableAction::RenameColumn(column_name, new_column_name) => format!(
+                "{} {column_name} {} {new_column_name}",
+                self.keyword(&"RENAME COLUMN"),
+                self.keyword(&"TO")
+            ),
+        };
+
+        format!(
+            "{} {} {alter_table_action}",
+            self.keyword(&"ALTER TABLE"),
+            self.table_name(alter_table.table_name())
+        )
+    }
+
+    fn open(&self, open: &Open) -> String {
+        format!("{} {}", self.keyword(&"OPEN"), open.cursor_name())
+    }
+
+    fn fetch(&self, fetch: &Fetch) -> String {
+        let mut header = self.keyword(&"FETCH");
+        if let Some(fetch_orientation) = fetch.opt_fetch_orientation() {
+            header = format!(
+                "{header} {} {}",
+                self.keyword(&fetch_orientation),
+                self.keyword(&"FROM")
+            );
+        }
+
+        format!(
+            "{header} {} {} {}",
+            fetch.cursor_name(),
+            self.keyword(&"INTO"),
+            display_comma_separated(fetch.target_list())
+        )
+    }
+
+    fn close(&self, close: &Close) -> String {
+        format!("{} {}", self.keyword(&"CLOSE"), close.cursor_name())
+    }
+
+    fn call(&self, call: &Call) -> String {
+        let arguments = call
+            .arguments()
+            .iter()
+            .map(|argument| self.value_specification(argument))
+            .collect::<Vec<_>>();
+
+        format!(
+            "{} {}({})",
+            self.keyword(&"CALL"),
+            call.routine_name(),
+            arguments.join(", ")
+        )
+    }
+
+    fn return_statement(&self, return_statement: &Return) -> String {
+        format!(
+            "{} {}",
+            self.keyword(&"RETURN"),
+            self.value_specification(return_statement.return_value())
+        )
+    }
+
+    fn update_positioned(&self, update: &UpdatePositioned) -> String {
+        let set_clause_list = update
+            .set_clause_list()
+            .iter()
+            .map(|set_clause| {
+                let update_source = match set_clause.update_source() {
+                    UpdateSource::Value(value_specification) => {
+                        self.value_specification(value_specification)
+                    }
+                    UpdateSource::Default => self.keyword(&"DEFAULT"),
+                };
+                format!("{} = {update_source}", set_clause.column_name())
+            })
+            .collect::<Vec<_>>();
+
+        format!(
+            "{} {} {} {} {} {}",
+            self.keyword(&"UPDATE"),
+            self.table_name(update.table_name()),
+            self.keyword(&"SET"),
+            set_clause_list.join(", "),
+            self.keyword(&"WHERE CURRENT OF"),
+            update.cursor_name()
+        )
+    }
+
+    fn delete_positioned(&self, delete: &DeletePositioned) -> String {
+        format!(
+            "{} {} {} {}",
+            self.keyword(&"DELETE FROM"),
+            self.table_name(delete.table_name()),
+            self.keyword(&"WHERE CURRENT OF"),
+            delete.cursor_name()
+        )
+    }
+
+    fn explain(&self, explain: &Explain) -> String {
+        let mut header = self.keyword(&"EXPLAIN");
+        if explain.analyze() {
+            header.push(' ');
+            header.push_str(&self.keyword(&"ANALYZE"));
+        }
+
+        format!(
+            "{header} {}",
+            format_statement(explain.statement(), self.options)
+        )
+    }
+
+    fn grant_privilege(&self, grant: &GrantPrivilege) -> String {
+        let mut formatted = format!(
+            "{} {} {} {}",
+            self.keyword(&"GRANT"),
+            self.privileges(grant.privileges()),
+            self.keyword(&"TO"),
+            self.grantees(grant.grantees())
+        );
+        if grant.hierarchy_option() {
+            formatted.push(' ');
+            formatted.push_str(&self.keyword(&"WITH HIERARCHY OPTION"));
+        }
+        if grant.grant_option() {
+            formatted.push(' ');
+            formatted.push_str(&self.keyword(&"WITH GRANT OPTION"));
+        }
+        if let Some(grantor) = grant.opt_grantor() {
+            formatted = format!(
+                "{formatted} {} {}",
+                self.keyword(&"GRANTED BY"),
+                self.keyword(&grantor)
+            );
+        }
+
+        formatted
+    }
+
+    fn revoke_privilege(&self, revoke: &RevokePrivilege) -> String {
+        let mut formatted = self.keyword(&"REVOKE");
+        if let Some(revoke_option_extension) = revoke.opt_revoke_option_extension() {
+            formatted.push(' ');
+            formatted.push_str(&self.keyword(&revoke_option_extension));
+        }
+        formatted = format!(
+            "{formatted} {} {} {}",
+            self.privileges(revoke.privileges()),
+            self.keyword(&"FROM"),
+            self.grantees(revoke.grantees())
+        );
+        if let Some(grantor) = revoke.opt_grantor() {
+            formatted = format!(
+                "{formatted} {} {}",
+                self.keyword(&"GRANTED BY"),
+                self.keyword(&grantor)
+            );
+        }
+
+        format!("{formatted} {}", self.keyword(&revoke.drop_behavior()))
+    }
+
+    fn privileges(&self, privileges: &Privileges) -> String {
+        let object_privileges = match privileges.object_privileges() {
+            ObjectPrivileges::AllPrivileges => self.keyword(&"ALL PRIVILEGES"),
+            ObjectPrivileges::Actions(actions) => actions
+                .iter()
+                .map(|action| self.action(action))
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+
+        let object = match privileges.object() {
+            GrantObject::Table(table_name) => self.table_name(table_name),
+            GrantObject::Domain(name) => format!("{} {name}", self.keyword(&"DOMAIN")),
+            GrantObject::Sequence(name) => format!("{} {name}", self.keyword(&"SEQUENCE")),
+            GrantObject::Type(name) => format!("{} {name}", self.keyword(&"TYPE")),
+            GrantObject::CharacterSet(name) => {
+                format!("{} {name}", self.keyword(&"CHARACTER SET"))
+            }
+            GrantObject::Routine(routine) => {
+                let mut formatted = format!(
+                    "{} {}",
+                    self.keyword(&routine.routine_type()),
+                    routine.routine_name()
+                );
+                if let Some(data_type_list) = routine.opt_data_type_list() {
+                    let data_types = data_type_list
+                        .iter()
+                        .map(|data_type| self.keyword(data_type))
+                        .collect::<Vec<_>>();
+                    formatted = format!("{formatted}({})", data_types.join(", "));
+                }
+                formatted
+            }
+        };
+
+        format!("{object_privileges} {} {object}", self.keyword(&"ON"))
+    }
+
+    /// Renders the grantees, where `PUBLIC` is a keyword.
+    fn grantees(&self, grantees: &[Grantee]) -> String {
+        grantees
+            .iter()
+            .map(|grantee| match grantee {
+                Grantee::Public => self.keyword(grantee),
+                Grantee::AuthorizationIdentifier(authorization) => authorization.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn action(&self, action: &Action) -> String {
+        let keyword = match action {
+            Action::Select(_) => "SELECT",
+            Action::Delete => "DELETE",
+            Action::Insert(_) => "INSERT",
+            Action::Update(_) => "UPDATE",
+            Action::References(_) => "REFERENCES",
+            Action::Usage => "USAGE",
+            Action::Trigger => "TRIGGER",
+            Action::Under => "UNDER",
+            Action::Execute => "EXECUTE",
+        };
+
+        match action.opt_column_name_list() {
+            Some(column_name_list) => format!("{} ({column_name_list})", self.keyword(&keyword)),
+            None => self.keyword(&keyword),
+        }
+    }
+
+    /// Renders the value, where `NULL` and the boolean literals are keywords.
+    fn value_specification(&self, value_specification: &ValueSpecification) -> String {
+        match value_specification {
+            ValueSpecification::Null
+            | ValueSpecification::Literal(Literal::True | Literal::False | Literal::Unknown) => {
+                self.keyword(value_specification)
+            }
+            _ => value_specification.to_string(),
+        }
+    }
+
+    fn column_definition(&self, column_definition: &ColumnDefinition) -> String {
+        let mut formatted = column_definition.column_name().to_string();
+
+        if let Some(serial_type) = column_definition.opt_serial_type() {
+            formatted.push(' ');
+            formatted.push_str(&self.keyword(&serial_type));
+        } else {
+            if let Some(data_type) = column_definition.opt_data_type() {
+                formatted.push(' ');
+                formatted.push_str(&self.keyword(&data_type));
+            }
+
+            if let Some(identity_column_specification) =
+                column_definition.opt_identity_column_specification()
+            {
+                formatted.push(' ');
+                formatted.push_str(&self.keyword(&identity_column_specification));
+            }
+        }
+
+        for column_constraint_definition in column_definition.column_constraint_definitions() {
+            formatted.push(' ');
+            if let Some(constraint_name) = column_constraint_definition.opt_constraint_name() {
+                formatted.push_str(&self.constraint_name_definition(constraint_name));
+            }
+            match column_constraint_definition.column_constraint() {
+                ColumnConstraint::NotNull => formatted.push_str(&self.keyword(&"NOT NULL")),
+                ColumnConstraint::Unique(unique_specification) => {
+                    formatted.push_str(&self.keyword(unique_specification));
+                }
+                ColumnConstraint::References(references_specification) => {
+                    formatted.push_str(&self.references_specification(references_specification));
+                }
+            }
+        }
+
+        formatted
+    }
+
+    fn table_constraint_definition(
+        &self,
+        table_constraint_definition: &TableConstraintDefinition,
+    ) -> String {
+        let mut formatted = String::new();
+
+        if let Some(constraint_name) = table_constraint_definition.opt_constraint_name() {
+            formatted.push_str(&self.constraint_name_definition(constraint_name));
+        }
+
+        match table_constraint_definition.table_constraint() {
+            TableConstraint::Unique(unique_constraint_definition) => {
+                let key_columns = unique_constraint_definition
+                    .key_columns()
+                    .iter()
+                    .map(|key_column| match key_column.opt_ordering() {
+                        Some(ordering) => {
+                            format!("{} {}", key_column.column_name(), self.keyword(&ordering))
+                        }
+                        None => key_column.column_name().to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                formatted
+                    .push_str(&self.keyword(&unique_constraint_definition.unique_specification()));
+                formatted.push_str(" (");
+                formatted.push_str(&key_columns.join(", "));
+                formatted.push(')');
+            }
+            TableConstraint::Referential(referential_constraint_definition) => {
+                formatted.push_str(&self.keyword(&"FOREIGN KEY"));
+                formatted.push_str(" (");
+                formatted.push_str(
+                    &referential_constraint_definition
+                        .referencing_columns()
+                        .to_string(),
+                );
+                if let Some(period_name) =
+                    referential_constraint_definition.opt_referencing_period()
+                {
+                    formatted.push_str(", ");
+                    formatted.push_str(&self.keyword(&"PERIOD"));
+                    formatted.push(' ');
+                    formatted.push_str(&period_name.to_string());
+                }
+                formatted.push_str(") ");
+                formatted.push_str(&self.references_specification(
+                    referential_constraint_definition.references_specification(),
+                ));
+            }
+        }
+
+        formatted
+    }
+
+    /// Renders `CONSTRAINT <constraint name>`, followed by a space.
+    fn constraint_name_definition(&self, constraint_name: &SchemaQualifiedName) -> String {
+        format!("{} {constraint_name} ", self.keyword(&"CONSTRAINT"))
+    }
+
+    fn references_specification(
+        &self,
+        references_specification: &ReferencesSpecification,
+    ) -> String {
+        let mut formatted = format!(
+            "{} {}",
+            self.keyword(&"REFERENCES"),
+            self.table_name(references_specification.table_name())
+        );
+
+        if let Some(referenced_columns) = references_specification.opt_referenced_columns() {
+            formatted.push_str(" (");
+            formatted.push_str(&referenced_columns.to_string());
+            if let Some(period_name) = references_specification.opt_referenced_period() {
+                formatted.push_str(", ");
+                formatted.push_str(&self.keyword(&"PERIOD"));
+                formatted.push(' ');
+                formatted.push_str(&period_name.to_string());
+            }
+            formatted.push(')');
+        }
+
+        if let Some(match_type) = references_specification.opt_match_type() {
+            formatted.push(' ');
+            formatted.push_str(&self.keyword(&"MATCH"));
+            formatted.push(' ');
+            formatted.push_str(&self.keyword(&match_type));
+        }
+
+        if let Some(referential_triggered_action) =
+            references_specification.opt_referential_triggered_action()
+        {
+            formatted.push(' ');
+            formatted.push_str(&self.keyword(&referential_triggered_action));
+        }
+
+        formatted
+    }
+
+    fn table_name(&self, table_name: &TableName) -> String {
+        match table_name.opt_local_or_schema() {
+            Some(LocalOrSchemaQualifier::LocalQualifier(local_qualifier)) => {
+                format!("{}.{}", self.keyword(&local_qualifier), table_name.name())
+            }
+            _ => table_name.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_statement;
+
+    use super::*;
+
+    #[test_case("CREATE SCHEMA cat.sch AUTHORIZATION \"user\"")]
+    #[test_case("DROP SCHEMA sch CASCADE")]
+    #[test_case("DROP TABLE MODULE.tb RESTRICT")]
+    #[test_case("CREATE LOCAL TEMPORARY TABLE tb (id INT, name VARCHAR(20))")]
+    #[test_case("CREATE TABLE tb (id INT, CONSTRAINT sch.pk PRIMARY KEY (id DESC), UNIQUE (id))")]
+    #[test_case(
+        "CREATE TABLE tb (id SERIAL PRIMARY KEY, a INT NOT NULL CONSTRAINT fk REFERENCES u (b, PERIOD p), \
+         FOREIGN KEY (a, PERIOD q) REFERENCES MODULE.u MATCH FULL ON DELETE CASCADE)"
+    )]
+    #[test_case("ALTER TABLE tb ALTER COLUMN id SET DATA TYPE BIGINT")]
+    #[test_case("FETCH ABSOLUTE -1 FROM c INTO :a, b")]
+    #[test_case("CALL sch.proc(1.5, 'a''b', NULL, :c)")]
+    #[test_case("UPDATE MODULE.tb SET a = DEFAULT, b = NULL WHERE CURRENT OF c")]
+    #[test_case("GRANT ALL PRIVILEGES ON CHARACTER SET sch.cs TO a, b")]
+    fn default_options_match_display(input: &str) {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+
+        assert_str_eq!(
+            statement.to_string(),
+            format_statement(&statement, &FormatOptions::default())
+        );
+    }
+
+    #[test_case(
+        "CREATE SCHEMA sch AUTHORIZATION \"USER\"",
+        "create schema sch authorization \"USER\"" ;
+        "create schema"
+    )]
+    #[test_case("DROP SCHEMA SCH CASCADE", "drop schema SCH cascade" ; "drop schema")]
+    #[test_case("DROP TABLE MODULE.TB RESTRICT", "drop table module.TB restrict" ; "drop table")]
+    #[test_case(
+        "CREATE GLOBAL TEMPORARY TABLE TB (ID INT GENERATED ALWAYS AS IDENTITY, T TIME WITH TIME ZONE)",
+        "create global temporary table TB (ID int generated always as identity, T time with time zone)" ;
+        "create table"
+    )]
+    #[test_case(
+        "CREATE TABLE TB (ID INT, CONSTRAINT PK PRIMARY KEY (ID ASC), UNIQUE (ID))",
+        "create table TB (ID int, constraint PK primary key (ID asc), unique (ID))" ;
+        "create table constraints"
+    )]
+    #[test_case(
+        "CREATE TABLE TB (ID SERIAL PRIMARY KEY, A INT NOT NULL REFERENCES U (B, PERIOD P), \
+         FOREIGN KEY (A, PERIOD Q) REFERENCES MODULE.U MATCH FULL ON UPDATE SET NULL)",
+        "create table TB (ID serial primary key, A int not null references U (B, period P), \
+         foreign key (A, period Q) references module.U match full on update set null)" ;
+        "create table foreign keys"
+    )]
+    #[test_case(
+        "ALTER TABLE TB ADD COLUMN ID INT",
+        "alter table TB add column ID int" ;
+        "alter table"
+    )]
+    #[test_case(
+        "FETCH PRIOR FROM C INTO :A, B",
+        "fetch prior from C into :A, B" ;
+        "fetch"
+    )]
+    #[test_case("CALL P('A', TRUE)", "call P('A', true)" ; "call")]
+    #[test_case("RETURN NULL", "return null" ; "return statement")]
+    #[test_case(
+        "DELETE FROM MODULE.TB WHERE CURRENT OF C",
+        "delete from module.TB where current of C" ;
+        "delete positioned"
+    )]
+    #[test_case("EXPLAIN ANALYZE CLOSE C", "explain analyze close C" ; "explain")]
+    #[test_case(
+        "GRANT SELECT (A), USAGE ON MODULE.T TO U WITH GRANT OPTION",
+        "grant select (A), usage on module.T to U with grant option" ;
+        "grant privilege"
+    )]
+    #[test_case(
+        "GRANT UNDER ON TYPE T TO PUBLIC, U WITH HIERARCHY OPTION GRANTED BY CURRENT_USER",
+        "grant under on type T to public, U with hierarchy option granted by current_user" ;
+        "grant privilege clauses"
+    )]
+    #[test_case(
+        "REVOKE HIERARCHY OPTION FOR UNDER ON TYPE T FROM PUBLIC GRANTED BY CURRENT_ROLE RESTRICT",
+        "revoke hierarchy option for under on type T from public granted by current_role restrict" ;
+        "revoke privilege clauses"
+    )]
+    #[test_case(
+        "REVOKE GRANT OPTION FOR EXECUTE ON FUNCTION F(INT) FROM U CASCADE",
+        "revoke grant option for execute on function F(int) from U cascade" ;
+        "revoke privilege"
+    )]
+    fn lower_case_keywords(input: &str, expected: &str) {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+        let options = FormatOptions::new().with_keyword_case(KeywordCase::Lower);
+
+        assert_str_eq!(expected, format_statement(&statement, &options));
+    }
+
+    #[test]
+    fn element_per_line() {
+        let (_, statement) =
+            parse_statement(b"CREATE TABLE tb (id INT, name VARCHAR(20))").unwrap();
+        let options = FormatOptions::new().with_element_per_line(true);
+
+        assert_str_eq!(
+            "CREATE TABLE tb (\n    id INT,\n    name VARCHAR(20)\n)",
+            format_statement(&statement, &options)
+        );
+    }
+
+    #[test_case(42, "CREATE TABLE tb (id INT, name VARCHAR(20))" ; "fits")]
+    #[test_case(
+        41,
+        "CREATE TABLE tb (\n    id INT,\n    name VARCHAR(20)\n)" ;
+        "exceeds"
+    )]
+    fn max_line_width(max_line_width: usize, expected: &str) {
+        let (_, statement) =
+            parse_statement(b"CREATE TABLE tb (id INT, name VARCHAR(20))").unwrap();
+        let options = FormatOptions::new().with_max_line_width(max_line_width);
+
+        assert_str_eq!(expected, format_statement(&statement, &options));
+    }
+
+    #[test_case(
+        "-- header\n/* license */\ndrop table a cascade;  drop table b restrict",
+        "-- header\n/* license */\nDROP TABLE a CASCADE;\nDROP TABLE b RESTRICT;\n" ;
+        "header"
+    )]
+    #[test_case(
+        "  drop table a cascade -- end\n-- footer",
+        "DROP TABLE a CASCADE;\n-- end\n-- footer" ;
+        "footer"
+    )]
+    #[test_case("\n drop table a cascade ; \n", "DROP TABLE a CASCADE;\n" ; "whitespaces")]
+    #[test_case("-- only\n", "-- only\n" ; "no statements")]
+    fn script_comments(input: &str, expected: &str) {
+        assert_str_eq!(
+            expected,
+            format_script(input, &FormatOptions::new()).unwrap()
+        );
+    }
+
+    #[test_case("drop table a cascade; -- a\ndrop table b restrict", 22, 26 ; "between statements")]
+    #[test_case("create table tb (\n  id int -- the id\n)", 27, 36 ; "inside statement")]
+    #[test_case("drop /* a */ table a cascade", 5, 12 ; "block comment")]
+    fn misplaced_comments(input: &str, start: usize, end: usize) {
+        assert_eq!(
+            Err(ScriptError::MisplacedComment(Span::new(start, end))),
+            format_script(input, &FormatOptions::new())
+        );
+    }
+}
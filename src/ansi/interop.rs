@@ -0,0 +1,1319 @@
+//! Conversions between the `ANSI` AST and the [sqlparser] AST.
+//!
+//! Conversions are fallible in both directions, as each AST has constructs
+//! that can't be represented in the other. No conversion panics.
+//!
+//! [sqlparser]: https://github.com/sqlparser-rs/sqlparser-rs
+use sqlparser::ast;
+
+use crate::ansi::ast::alter_table::{AlterColumnAction, AlterTable, AlterTableAction};
+use crate::ansi::ast::close::Close;
+use crate::ansi::ast::common::{
+    ColumnDefinition, DropBehavior, LocalOrSchemaQualifier, SchemaName, TableName,
+};
+use crate::ansi::ast::create_schema::{CreateSchema, SchemaNameClause};
+use crate::ansi::ast::create_table::{
+    CreateTable, TableContentsSource, TableElement, TableElementList, TableScope,
+};
+use crate::ansi::ast::data_types::{
+    CharLengthUnits, CharacterLargeObjectLength, CharacterLength, DataType, ExactNumberInfo,
+    LargeObjectLength, Multiplier, NumericTypeAlias, WithOrWithoutTimeZone,
+};
+use crate::ansi::ast::drop_schema::DropSchema;
+use crate::ansi::ast::drop_table::DropTable;
+use crate::ansi::ast::explain::Explain;
+use crate::ansi::Statement;
+use crate::common::expr::{
+    BinaryLiteral, BinaryOperator, BinaryRadix, DollarQuotedString, EscapeString, Expr, Literal,
+    UnaryOperator,
+};
+use crate::common::{Ident, QuoteStyle};
+
+/// Errors raised when converting between the `ANSI` and the sqlparser ASTs.
+#[derive(thiserror::Error, Clone, Eq, PartialEq, Debug)]
+pub enum ConversionError {
+    /// The sqlparser construct has no `ANSI` AST equivalent.
+    #[error("`{0}` is not supported by the ANSI AST")]
+    UnsupportedByAnsi(String),
+    /// The `ANSI` construct has no sqlparser AST equivalent.
+    #[error("`{0}` is not supported by the sqlparser AST")]
+    UnsupportedBySqlparser(String),
+    /// A length or precision doesn't fit in the target AST.
+    #[error("{0} is out of range")]
+    OutOfRange(u64),
+}
+
+impl From<Ident> for ast::Ident {
+    fn from(ident: Ident) -> Self {
+        let quote_style = match ident.quote_style() {
+            QuoteStyle::None => None,
+            QuoteStyle::DoubleQuote => Some('"'),
+            QuoteStyle::Bracket => Some('['),
+            QuoteStyle::Backtick => Some('`'),
+        };
+
+        Self {
+            value: ident.value().to_string(),
+            quote_style,
+        }
+    }
+}
+
+impl TryFrom<ast::Ident> for Ident {
+    type Error = ConversionError;
+
+    fn try_from(ident: ast::Ident) -> Result<Self, Self::Error> {
+        let quote_style = match ident.quote_style {
+            None => QuoteStyle::None,
+            Some('"') => QuoteStyle::DoubleQuote,
+            Some('[') => QuoteStyle::Bracket,
+            Some('`') => QuoteStyle::Backtick,
+            Some(_) => return Err(ConversionError::UnsupportedByAnsi(ident.to_string())),
+        };
+
+        Ok(Self::new_quoted(ident.value.as_bytes(), quote_style))
+    }
+}
+
+impl TryFrom<Expr> for ast::Expr {
+    type Error = ConversionError;
+
+    fn try_from(expr: Expr) -> Result<Self, Self::Error> {
+        let converted = match expr {
+            Expr::Literal(literal) => Self::Value(literal.try_into()?),
+            Expr::Null => Self::Value(ast::Value::Null),
+            Expr::Identifier(ident) => Self::Identifier(ident.into()),
+            Expr::CompoundIdentifier(idents) => {
+                Self::CompoundIdentifier(idents.into_iter().map(Into::into).collect())
+            }
+            Expr::HostParameter(name) => Self::Value(ast::Value::Placeholder(format!(":{name}"))),
+            Expr::UnaryOp(op, expr) => Self::UnaryOp {
+                op: op.into(),
+                expr: Box::new((*expr).try_into()?),
+            },
+            Expr::BinaryOp(left, op, right) => Self::BinaryOp {
+                left: Box::new((*left).try_into()?),
+                op: op.into(),
+                right: Box::new((*right).try_into()?),
+            },
+            Expr::IsNull(expr) => Self::IsNull(Box::new((*expr).try_into()?)),
+            Expr::IsNotNull(expr) => Self::IsNotNull(Box::new((*expr).try_into()?)),
+            Expr::Nested(expr) => Self::Nested(Box::new((*expr).try_into()?)),
+            Expr::Raw(raw) => return Err(ConversionError::UnsupportedBySqlparser(raw.to_string())),
+        };
+
+        Ok(converted)
+    }
+}
+
+impl TryFrom<ast::Expr> for Expr {
+    type Error = ConversionError;
+
+    fn try_from(expr: ast::Expr) -> Result<Self, Self::Error> {
+        let converted = match expr {
+            ast::Expr::Value(ast::Value::Null) => Self::Null,
+            ast::Expr::Value(ast::Value::Placeholder(placeholder)) => {
+                match placeholder.strip_prefix(':') {
+                    Some(name) if !name.is_empty() => {
+                        Self::HostParameter(Ident::new(name.as_bytes()))
+                    }
+                    _ => return Err(ConversionError::UnsupportedByAnsi(placeholder)),
+                }
+            }
+            ast::Expr::Value(value) => Self::Literal(value.try_into()?),
+            ast::Expr::Identifier(ident) => Self::Identifier(ident.try_into()?),
+            ast::Expr::CompoundIdentifier(idents) => Self::CompoundIdentifier(
+                idents
+                    .into_iter()
+                    .map(Ident::try_from)
+                    .collect::<Result<_, _>>()?,
+            ),
+            ast::Expr::UnaryOp { op, expr } => {
+                Self::UnaryOp(unary_operator(op)?, Box::new((*expr).try_into()?))
+            }
+            ast::Expr::BinaryOp { left, op, right } => Self::BinaryOp(
+                Box::new((*left).try_into()?),
+                binary_operator(op)?,
+                Box::new((*right).try_into()?),
+            ),
+            ast::Expr::IsNull(expr) => Self::IsNull(Box::new((*expr).try_into()?)),
+            ast::Expr::IsNotNull(expr) => Self::IsNotNull(Box::new((*expr).try_into()?)),
+            ast::Expr::Nested(expr) => Self::Nested(Box::new((*expr).try_into()?)),
+            expr => return Err(ConversionError::UnsupportedByAnsi(expr.to_string())),
+        };
+
+        Ok(converted)
+    }
+}
+
+impl TryFrom<Literal> for ast::Value {
+    type Error = ConversionError;
+
+    fn try_from(literal: Literal) -> Result<Self, Self::Error> {
+        let converted = match literal {
+            Literal::Numeric(value) => Self::Number(value, false),
+            Literal::CharacterString(value) => Self::SingleQuotedString(value),
+            Literal::EscapeString(value) if value.escape_prefix() => {
+                Self::EscapedStringLiteral(value.value().to_string())
+            }
+            Literal::EscapeString(value) => Self::SingleQuotedString(value.value().to_string()),
+            Literal::Binary(value) if value.radix() == BinaryRadix::Hexadecimal => {
+                Self::HexStringLiteral(value.digits().to_string())
+            }
+            Literal::DollarQuotedString(value) => {
+                Self::DollarQuotedString(ast::DollarQuotedString {
+                    value: value.value().to_string(),
+                    tag: value.opt_tag().map(ToString::to_string),
+                })
+            }
+            Literal::True => Self::Boolean(true),
+            Literal::False => Self::Boolean(false),
+            Literal::Binary(_) | Literal::Unknown => {
+                return Err(ConversionError::UnsupportedBySqlparser(literal.to_string()))
+            }
+        };
+
+        Ok(converted)
+    }
+}
+
+impl TryFrom<ast::Value> for Literal {
+    type Error = ConversionError;
+
+    fn try_from(value: ast::Value) -> Result<Self, Self::Error> {
+        let converted = match value {
+            ast::Value::Number(value, false) => Self::Numeric(value),
+            ast::Value::SingleQuotedString(value) => Self::CharacterString(value),
+            ast::Value::HexStringLiteral(digits) => {
+                Self::Binary(BinaryLiteral::hexadecimal(digits))
+            }
+            ast::Value::EscapedStringLiteral(value) => {
+                let text = value.replace('\\', r"\\").replace('\'', r"\'");
+                Self::EscapeString(EscapeString::new(text, value).with_escape_prefix(true))
+            }
+            ast::Value::DollarQuotedString(ast::DollarQuotedString { value, tag }) => {
+                let dollar_quoted_string = DollarQuotedString::new(value);
+                Self::DollarQuotedString(match tag {
+                    Some(tag) => dollar_quoted_string.with_tag(tag),
+                    None => dollar_quoted_string,
+                })
+            }
+            ast::Value::Boolean(true) => Self::True,
+            ast::Value::Boolean(false) => Self::False,
+            value => return Err(ConversionError::UnsupportedByAnsi(value.to_string())),
+        };
+
+        Ok(converted)
+    }
+}
+
+impl From<UnaryOperator> for ast::UnaryOperator {
+    fn from(op: UnaryOperator) -> Self {
+        match op {
+            UnaryOperator::Plus => Self::Plus,
+            UnaryOperator::Minus => Self::Minus,
+            UnaryOperator::Not => Self::Not,
+        }
+    }
+}
+
+impl From<BinaryOperator> for ast::BinaryOperator {
+    fn from(op: BinaryOperator) -> Self {
+        match op {
+            BinaryOperator::Plus => Self::Plus,
+            BinaryOperator::Minus => Self::Minus,
+            BinaryOperator::Multiply => Self::Multiply,
+            BinaryOperator::Divide => Self::Divide,
+            BinaryOperator::Concat => Self::StringConcat,
+            BinaryOperator::Eq => Self::Eq,
+            BinaryOperator::NotEq => Self::NotEq,
+            BinaryOperator::Lt => Self::Lt,
+            BinaryOperator::LtEq => Self::LtEq,
+            BinaryOperator::Gt => Self::Gt,
+            BinaryOperator::GtEq => Self::GtEq,
+            BinaryOperator::And => Self::And,
+            BinaryOperator::Or => Self::Or,
+        }
+    }
+}
+
+impl TryFrom<DataType> for ast::DataType {
+    type Error = ConversionError;
+
+    fn try_from(data_type: DataType) -> Result<Self, Self::Error> {
+        let converted = match data_type {
+            DataType::Character(opt_len) => Self::Character(opt_len.map(Into::into)),
+            DataType::Char(opt_len) => Self::Char(opt_len.map(Into::into)),
+            DataType::CharacterVarying(opt_len) => Self::CharacterVarying(opt_len.map(Into::into)),
+            DataType::CharVarying(opt_len) => Self::CharVarying(opt_len.map(Into::into)),
+            DataType::Varchar(opt_len) => Self::Varchar(opt_len.map(Into::into)),
+            DataType::CharacterLargeObject(opt_len) => {
+                Self::CharacterLargeObject(clob_length_to_sqlparser(data_type, opt_len)?)
+            }
+            DataType::CharLargeObject(opt_len) => {
+                Self::CharLargeObject(clob_length_to_sqlparser(data_type, opt_len)?)
+            }
+            DataType::Clob(opt_len) => Self::Clob(clob_length_to_sqlparser(data_type, opt_len)?),
+            DataType::Binary(opt_len) => Self::Binary(opt_len.map(u64::from)),
+            DataType::Varbinary(opt_len) => Self::Varbinary(opt_len.map(u64::from)),
+            DataType::Blob(opt_len) => Self::Blob(opt_len.map(lob_length_to_sqlparser)),
+            DataType::Numeric(info) => Self::Numeric(info.into()),
+            DataType::Decimal(info) => Self::Decimal(info.into()),
+            DataType::Dec(info) => Self::Dec(info.into()),
+            DataType::Smallint => Self::SmallInt(None),
+            DataType::Integer => Self::Integer(None),
+            DataType::Int => Self::Int(None),
+            DataType::Bigint => Self::BigInt(None),
+            DataType::Float => Self::Float(None),
+            DataType::Real => Self::Real,
+            DataType::DoublePrecision => Self::DoublePrecision,
+            DataType::NumericAlias(NumericTypeAlias::Double) => Self::Double,
+            // OBS: sqlparser has no other alias, so they're converted to the
+            // type they spell.
+            DataType::NumericAlias(alias) => Self::try_from(alias.data_type())?,
+            DataType::Boolean => Self::Boolean,
+            DataType::Date => Self::Date,
+            DataType::Time(opt_precision, time_zone) => {
+                Self::Time(opt_precision.map(u64::from), time_zone.into())
+            }
+            DataType::Timestamp(opt_precision, time_zone) => {
+                Self::Timestamp(opt_precision.map(u64::from), time_zone.into())
+            }
+            DataType::BinaryVarying(_) | DataType::BinaryLargeObject(_) | DataType::DecFloat(_) => {
+                return Err(ConversionError::UnsupportedBySqlparser(
+                    data_type.to_string(),
+                ))
+            }
+        };
+
+        Ok(converted)
+    }
+}
+
+impl TryFrom<ast::DataType> for DataType {
+    type Error = ConversionError;
+
+    fn try_from(data_type: ast::DataType) -> Result<Self, Self::Error> {
+        let converted = match &data_type {
+            ast::DataType::Character(opt_len) => Self::Character(character_length(*opt_len)?),
+            ast::DataType::Char(opt_len) => Self::Char(character_length(*opt_len)?),
+            ast::DataType::CharacterVarying(opt_len) => {
+                Self::CharacterVarying(character_length(*opt_len)?)
+            }
+            ast::DataType::CharVarying(opt_len) => Self::CharVarying(character_length(*opt_len)?),
+            ast::DataType::Varchar(opt_len) => Self::Varchar(character_length(*opt_len)?),
+            ast::DataType::CharacterLargeObject(opt_len) => {
+                Self::CharacterLargeObject(clob_length(*opt_len)?)
+            }
+            ast::DataType::CharLargeObject(opt_len) => {
+                Self::CharLargeObject(clob_length(*opt_len)?)
+            }
+            ast::DataType::Clob(opt_len) => Self::Clob(clob_length(*opt_len)?),
+            ast::DataType::Binary(opt_len) => Self::Binary(opt_u32(*opt_len)?),
+            ast::DataType::Varbinary(opt_len) => Self::Varbinary(opt_u32(*opt_len)?),
+            ast::DataType::Blob(opt_len) => {
+                Self::Blob(opt_u32(*opt_len)?.map(LargeObjectLength::new))
+            }
+            ast::DataType::Numeric(info) => Self::Numeric(exact_number_info(*info)?),
+            ast::DataType::Decimal(info) => Self::Decimal(exact_number_info(*info)?),
+            ast::DataType::Dec(info) => Self::Dec(exact_number_info(*info)?),
+            ast::DataType::SmallInt(None) => Self::Smallint,
+            ast::DataType::Integer(None) => Self::Integer,
+            ast::DataType::Int(None) => Self::Int,
+            ast::DataType::BigInt(None) => Self::Bigint,
+            ast::DataType::Float(None) => Self::Float,
+            ast::DataType::Real => Self::Real,
+            ast::DataType::DoublePrecision => Self::DoublePrecision,
+            ast::DataType::Double => Self::NumericAlias(NumericTypeAlias::Double),
+            ast::DataType::Boolean => Self::Boolean,
+            ast::DataType::Date => Self::Date,
+            ast::DataType::Time(opt_precision, time_zone) => Self::Time(
+                opt_u32(*opt_precision)?,
+                time_zone_info(&data_type, *time_zone)?,
+            ),
+            ast::DataType::Timestamp(opt_precision, time_zone) => Self::Timestamp(
+                opt_u32(*opt_precision)?,
+                time_zone_info(&data_type, *time_zone)?,
+            ),
+            _ => return Err(ConversionError::UnsupportedByAnsi(data_type.to_string())),
+        };
+
+        Ok(converted)
+    }
+}
+
+impl From<CharacterLength> for ast::CharacterLength {
+    fn from(character_length: CharacterLength) -> Self {
+        Self {
+            length: u64::from(character_length.length()),
+            unit: character_length.opt_units().map(|units| match units {
+                CharLengthUnits::Characters => ast::CharLengthUnits::Characters,
+                CharLengthUnits::Octets => ast::CharLengthUnits::Octets,
+            }),
+        }
+    }
+}
+
+impl From<ExactNumberInfo> for ast::ExactNumberInfo {
+    fn from(info: ExactNumberInfo) -> Self {
+        match info {
+            ExactNumberInfo::None => Self::None,
+            ExactNumberInfo::Precision(precision) => Self::Precision(u64::from(precision)),
+            ExactNumberInfo::PrecisionAndScale(precision, scale) => {
+                Self::PrecisionAndScale(u64::from(precision), u64::from(scale))
+            }
+        }
+    }
+}
+
+impl From<WithOrWithoutTimeZone> for ast::TimezoneInfo {
+    fn from(time_zone: WithOrWithoutTimeZone) -> Self {
+        match time_zone {
+            WithOrWithoutTimeZone::None => Self::None,
+            WithOrWithoutTimeZone::WithTimeZone => Self::WithTimeZone,
+            WithOrWithoutTimeZone::WithoutTimeZone => Self::WithoutTimeZone,
+        }
+    }
+}
+
+impl TryFrom<Statement> for ast::Statement {
+    type Error = ConversionError;
+
+    fn try_from(statement: Statement) -> Result<Self, Self::Error> {
+        Self::try_from(&statement)
+    }
+}
+
+impl TryFrom<&Statement> for ast::Statement {
+    type Error = ConversionError;
+
+    fn try_from(statement: &Statement) -> Result<Self, Self::Error> {
+        match statement {
+            Statement::CreateSchema(create_schema) => create_schema.try_into(),
+            Statement::DropSchema(drop_schema) => Ok(drop_schema.into()),
+            Statement::DropTable(drop_table) => drop_table.try_into(),
+            Statement::CreateTable(create_table) => create_table.try_into(),
+            Statement::AlterTable(alter_table) => alter_table.try_into(),
+            Statement::Close(close) => Ok(Self::Close {
+                cursor: ast::CloseCursor::Specific {
+                    name: close.cursor_name().clone().into(),
+                },
+            }),
+            Statement::Explain(explain) => Ok(Self::Explain {
+                describe_alias: false,
+                analyze: explain.analyze(),
+                verbose: false,
+                statement: Box::new(explain.statement().try_into()?),
+                format: None,
+            }),
+            // OBS: sqlparser has no `OPEN`, `CALL`, `RETURN` nor positioned
+            // statements, its `FETCH` targets a table, and its privileges
+            // don't cover the `ANSI` objects and actions.
+            Statement::Open(_)
+            | Statement::Fetch(_)
+            | Statement::Call(_)
+            | Statement::Return(_)
+            | Statement::UpdatePositioned(_)
+            | Statement::DeletePositioned(_)
+            | Statement::GrantPrivilege(_)
+            | Statement::RevokePrivilege(_) => Err(ConversionError::UnsupportedBySqlparser(
+                statement.to_string(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<ast::Statement> for Statement {
+    type Error = ConversionError;
+
+    fn try_from(statement: ast::Statement) -> Result<Self, Self::Error> {
+        match statement {
+            ast::Statement::CreateSchema {
+                schema_name,
+                if_not_exists: false,
+            } => Ok(Self::CreateSchema(CreateSchema::new(schema_name_clause(
+                schema_name,
+            )?))),
+            ast::Statement::Drop {
+                object_type: object_type @ (ast::ObjectType::Table | ast::ObjectType::Schema),
+                if_exists: false,
+                mut names,
+                cascade,
+                restrict,
+                purge: false,
+            } if names.len() == 1 && cascade != restrict => {
+                let name = names.remove(0);
+                let drop_behavior = if cascade {
+                    DropBehavior::Cascade
+                } else {
+                    DropBehavior::Restrict
+                };
+
+                if object_type == ast::ObjectType::Table {
+                    Ok(Self::DropTable(DropTable::new(
+                        table_name(name)?,
+                        drop_behavior,
+                    )))
+                } else {
+                    Ok(Self::DropSchema(DropSchema::new(
+                        schema_name(name)?,
+                        drop_behavior,
+                    )))
+                }
+            }
+            ast::Statement::CreateTable {
+                or_replace: false,
+                temporary,
+                external: false,
+                global,
+                if_not_exists: false,
+                name,
+                columns,
+                constraints,
+                hive_distribution: ast::HiveDistributionStyle::NONE,
+                hive_formats,
+                table_properties,
+                with_options,
+                file_format: None,
+                location: None,
+                query: None,
+                without_rowid: false,
+                like: None,
+                clone: None,
+                engine: None,
+                default_charset: None,
+                collation: None,
+                on_commit: None,
+                on_cluster: None,
+            } if constraints.is_empty()
+                && table_properties.is_empty()
+                && with_options.is_empty()
+                && hive_formats.as_ref().is_none_or(is_default_hive_format) =>
+            {
+                let opt_table_scope = match (temporary, global) {
+                    (false, None) => None,
+                    (true, Some(true)) => Some(TableScope::Global),
+                    (true, Some(false)) => Some(TableScope::Local),
+                    _ => {
+                        return Err(ConversionError::UnsupportedByAnsi(
+                            "TEMPORARY without GLOBAL or LOCAL".to_string(),
+                        ))
+                    }
+                };
+
+                let element_list = columns
+                    .into_iter()
+                    .map(|column| column_definition(column).map(TableElement::ColumnDefinition))
+                    .collect::<Result<_, _>>()?;
+
+                let mut create_table = CreateTable::new(
+                    table_name(name)?,
+                    TableContentsSource::TableElementList(TableElementList::new(element_list)),
+                );
+                if let Some(table_scope) = opt_table_scope {
+                    create_table = create_table.with_table_scope(table_scope);
+                }
+
+                Ok(Self::CreateTable(create_table))
+            }
+            ast::Statement::AlterTable { name, operation } => Ok(Self::AlterTable(
+                AlterTable::new(table_name(name)?, alter_table_action(operation)?),
+            )),
+            ast::Statement::Close {
+                cursor: ast::CloseCursor::Specific { name },
+            } => Ok(Self::Close(Close::new(Ident::try_from(name)?))),
+            ast::Statement::Explain {
+                describe_alias: false,
+                analyze,
+                verbose: false,
+                statement,
+                format: None,
+            } => explain(*statement, analyze),
+            statement => Err(ConversionError::UnsupportedByAnsi(statement.to_string())),
+        }
+    }
+}
+
+impl TryFrom<CreateSchema> for ast::Statement {
+    type Error = ConversionError;
+
+    fn try_from(create_schema: CreateSchema) -> Result<Self, Self::Error> {
+        Self::try_from(&create_schema)
+    }
+}
+
+impl TryFrom<&CreateSchema> for ast::Statement {
+    type Error = ConversionError;
+
+    fn try_from(create_schema: &CreateSchema) -> Result<Self, Self::Error> {
+        let schema_name = match create_schema.schema_name_clause().clone() {
+            SchemaNameClause::Simple(schema_name) => ast::SchemaName::Simple(schema_name.into()),
+            SchemaNameClause::Authorization(authorization) => {
+                ast::SchemaName::UnnamedAuthorization(authorization.into())
+            }
+            SchemaNameClause::NamedAuthorization(schema_name, authorization) => {
+                ast::SchemaName::NamedAuthorization(schema_name.into(), authorization.into())
+            }
+        };
+
+        Ok(Self::CreateSchema {
+            schema_name,
+            if_not_exists: false,
+        })
+    }
+}
+
+impl From<DropSchema> for ast::Statement {
+    fn from(drop_schema: DropSchema) -> Self {
+        Self::from(&drop_schema)
+    }
+}
+
+impl From<&DropSchema> for ast::Statement {
+    fn from(drop_schema: &DropSchema) -> Self {
+        Self::Drop {
+            object_type: ast::ObjectType::Schema,
+            if_exists: false,
+            names: vec![drop_schema.schema_name().clone().into()],
+            cascade: drop_schema.drop_behavior() == DropBehavior::Cascade,
+            restrict: drop_schema.drop_behavior() == DropBehavior::Restrict,
+            purge: false,
+        }
+    }
+}
+
+impl TryFrom<DropTable> for ast::Statement {
+    type Error = ConversionError;
+
+    fn try_from(drop_table: DropTable) -> Result<Self, Self::Error> {
+        Self::try_from(&drop_table)
+    }
+}
+
+impl TryFrom<&DropTable> for ast::Statement {
+    type Error = ConversionError;
+
+    fn try_from(drop_table: &DropTable) -> Result<Self, Self::Error> {
+        Ok(Self::Drop {
+            object_type: ast::ObjectType::Table,
+            if_exists: false,
+            names: vec![drop_table.table_name().clone().try_into()?],
+            cascade: drop_table.drop_behavior() == DropBehavior::Cascade,
+            restrict: drop_table.drop_behavior() == DropBehavior::Restrict,
+            purge: false,
+        })
+    }
+}
+
+impl TryFrom<CreateTable> for ast::Statement {
+    type Error = ConversionError;
+
+    fn try_from(create_table: CreateTable) -> Result<Self, Self::Error> {
+        Self::try_from(&create_table)
+    }
+}
+
+impl TryFrom<&CreateTable> for ast::Statement {
+    type Error = ConversionError;
+
+    fn try_from(create_table: &CreateTable) -> Result<Self, Self::Error> {
+        if let Some(option) = create_table.table_options().first() {
+            return Err(ConversionError::UnsupportedBySqlparser(option.to_string()));
+        }
+        if let Some(partition_by) = create_table.opt_partition_by() {
+            return Err(ConversionError::UnsupportedBySqlparser(
+                partition_by.to_string(),
+            ));
+        }
+
+        let TableContentsSource::TableElementList(table_element_list) =
+            create_table.table_contents_source();
+        let columns = table_element_list
+            .element_list()
+            .iter()
+            .map(|table_element| match table_element {
+                TableElement::ColumnDefinition(column_definition) => column_definition.try_into(),
+                // OBS: sqlparser keeps the constraints apart from the columns,
+                // so their position in the element list would be lost.
+                TableElement::TableConstraintDefinition(table_constraint_definition) => {
+                    Err(ConversionError::UnsupportedBySqlparser(
+                        table_constraint_definition.to_string(),
+                    ))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::CreateTable {
+            or_replace: false,
+            temporary: create_table.opt_table_scope().is_some(),
+            external: false,
+            global: create_table
+                .opt_table_scope()
+                .map(|table_scope| table_scope == TableScope::Global),
+            if_not_exists: false,
+            name: create_table.table_name().clone().try_into()?,
+            columns,
+            constraints: vec![],
+            hive_distribution: ast::HiveDistributionStyle::NONE,
+            hive_formats: None,
+            table_properties: vec![],
+            with_options: vec![],
+            file_format: None,
+            location: None,
+            query: None,
+            without_rowid: false,
+            like: None,
+            clone: None,
+            engine: None,
+            default_charset: None,
+            collation: None,
+            on_commit: None,
+            on_cluster: None,
+        })
+    }
+}
+
+impl TryFrom<AlterTable> for ast::Statement {
+    type Error = ConversionError;
+
+    fn try_from(alter_table: AlterTable) -> Result<Self, Self::Error> {
+        Self::try_from(&alter_table)
+    }
+}
+
+impl TryFrom<&AlterTable> for ast::Statement {
+    type Error = ConversionError;
+
+    fn try_from(alter_table: &AlterTable) -> Result<Self, Self::Error> {
+        let operation = match alter_table.alter_table_action() {
+            AlterTableAction::AddColumn(column_definition) => ast::AlterTableOperation::AddColumn {
+                column_keyword: true,
+                if_not_exists: false,
+                column_def: column_definition.try_into()?,
+            },
+            AlterTableAction::AlterColumn(
+                column_name,
+                AlterColumnAction::SetDataType(data_type),
+            ) => ast::AlterTableOperation::AlterColumn {
+                column_name: column_name.clone().into(),
+                op: ast::AlterColumnOperation::SetDataType {
+                    data_type: (*data_type).try_into()?,
+                    using: None,
+                },
+            },
+            AlterTableAction::DropColumn(column_name, DropBehavior::Cascade) => {
+                ast::AlterTableOperation::DropColumn {
+                    column_name: column_name.clone().into(),
+                    if_exists: false,
+                    cascade: true,
+                }
+            }
+            AlterTableAction::RenameTable(table_name) => ast::AlterTableOperation::RenameTable {
+                table_name: table_name.clone().try_into()?,
+            },
+            AlterTableAction::RenameColumn(column_name, new_column_name) => {
+                ast::AlterTableOperation::RenameColumn {
+                    old_column_name: column_name.clone().into(),
+                    new_column_name: new_column_name.clone().into(),
+                }
+            }
+            // OBS: sqlparser has no `DROP IDENTITY`, and it can't tell
+            // `RESTRICT` apart from no drop behavior at all.
+            alter_table_action @ (AlterTableAction::AlterColumn(
+                _,
+                AlterColumnAction::DropIdentity,
+            )
+            | AlterTableAction::DropColumn(_, DropBehavior::Restrict)) => {
+                return Err(ConversionError::UnsupportedBySqlparser(
+                    alter_table_action.to_string(),
+                ))
+            }
+        };
+
+        Ok(Self::AlterTable {
+            name: alter_table.table_name().clone().try_into()?,
+            operation,
+        })
+    }
+}
+
+impl TryFrom<ColumnDefinition> for ast::ColumnDef {
+    type Error = ConversionError;
+
+    fn try_from(column_definition: ColumnDefinition) -> Result<Self, Self::Error> {
+        Self::try_from(&column_definition)
+    }
+}
+
+impl TryFrom<&ColumnDefinition> for ast::ColumnDef {
+    type Error = ConversionError;
+
+    fn try_from(column_definition: &ColumnDefinition) -> Result<Self, Self::Error> {
+        // OBS: constraints are rejected as column options are when converting
+        // from sqlparser, so conversions are consistent in both directions.
+        let (Some(data_type), None, []) = (
+            column_definition.opt_data_type(),
+            column_definition.opt_identity_column_specification(),
+            column_definition.column_constraint_definitions(),
+        ) else {
+            return Err(ConversionError::UnsupportedBySqlparser(
+                column_definition.to_string(),
+            ));
+        };
+
+        Ok(Self {
+            name: column_definition.column_name().clone().into(),
+            data_type: data_type.try_into()?,
+            collation: None,
+            options: vec![],
+        })
+    }
+}
+
+impl From<SchemaName> for ast::ObjectName {
+    fn from(schema_name: SchemaName) -> Self {
+        let mut idents = vec![];
+        if let Some(catalog_name) = schema_name.opt_catalog_name() {
+            idents.push(catalog_name.clone().into());
+        }
+        idents.push(schema_name.name().clone().into());
+
+        Self(idents)
+    }
+}
+
+impl TryFrom<TableName> for ast::ObjectName {
+    type Error = ConversionError;
+
+    fn try_from(table_name: TableName) -> Result<Self, Self::Error> {
+        let mut idents = match table_name.opt_local_or_schema() {
+            None => vec![],
+            Some(LocalOrSchemaQualifier::Schema(schema_name)) => Self::from(schema_name.clone()).0,
+            Some(LocalOrSchemaQualifier::LocalQualifier(_)) => {
+                return Err(ConversionError::UnsupportedBySqlparser(
+                    table_name.to_string(),
+                ))
+            }
+        };
+        idents.push(table_name.name().clone().into());
+
+        Ok(Self(idents))
+    }
+}
+
+fn schema_name_clause(schema_name: ast::SchemaName) -> Result<SchemaNameClause, ConversionError> {
+    match schema_name {
+        ast::SchemaName::Simple(name) => Ok(SchemaNameClause::Simple(self::schema_name(name)?)),
+        ast::SchemaName::UnnamedAuthorization(authorization) => {
+            Ok(SchemaNameClause::Authorization(authorization.try_into()?))
+        }
+        ast::SchemaName::NamedAuthorization(name, authorization) => {
+            Ok(SchemaNameClause::NamedAuthorization(
+                self::schema_name(name)?,
+                authorization.try_into()?,
+            ))
+        }
+    }
+}
+
+fn schema_name(object_name: ast::ObjectName) -> Result<SchemaName, ConversionError> {
+    let display = object_name.to_string();
+    let mut idents = object_name.0.into_iter();
+
+    match (idents.next(), idents.next(), idents.next()) {
+        (Some(name), None, None) => Ok(SchemaName::new(Ident::try_from(name)?)),
+        (Some(catalog_name), Some(name), None) => Ok(SchemaName::new(Ident::try_from(name)?)
+            .with_catalog_name(Ident::try_from(catalog_name)?)),
+        _ => Err(ConversionError::UnsupportedByAnsi(display)),
+    }
+}
+
+fn table_name(object_name: ast::ObjectName) -> Result<TableName, ConversionError> {
+    let display = object_name.to_string();
+    let mut idents = object_name.0;
+
+    let Some(name) = idents.pop() else {
+        return Err(ConversionError::UnsupportedByAnsi(display));
+    };
+    let table_name = TableName::new(Ident::try_from(name)?);
+
+    if idents.is_empty() {
+        Ok(table_name)
+    } else if idents.len() <= 2 {
+        Ok(
+            table_name.with_local_or_schema(LocalOrSchemaQualifier::Schema(schema_name(
+                ast::ObjectName(idents),
+            )?)),
+        )
+    } else {
+        Err(ConversionError::UnsupportedByAnsi(display))
+    }
+}
+
+fn column_definition(column: ast::ColumnDef) -> Result<ColumnDefinition, ConversionError> {
+    if column.collation.is_some() || !column.options.is_empty() {
+        return Err(ConversionError::UnsupportedByAnsi(column.to_string()));
+    }
+
+    Ok(ColumnDefinition::new(Ident::try_from(column.name)?)
+        .with_data_type(column.data_type.try_into()?))
+}
+
+fn alter_table_action(
+    operation: ast::AlterTableOperation,
+) -> Result<AlterTableAction, ConversionError> {
+    match operation {
+        ast::AlterTableOperation::AddColumn {
+            column_keyword: _,
+            if_not_exists: false,
+            column_def,
+        } => Ok(AlterTableAction::AddColumn(column_definition(column_def)?)),
+        ast::AlterTableOperation::AlterColumn {
+            column_name,
+            op:
+                ast::AlterColumnOperation::SetDataType {
+                    data_type,
+                    using: None,
+                },
+        } => Ok(AlterTableAction::AlterColumn(
+            column_name.try_into()?,
+            AlterColumnAction::SetDataType(data_type.try_into()?),
+        )),
+        ast::AlterTableOperation::DropColumn {
+            column_name,
+            if_exists: false,
+            cascade: true,
+        } => Ok(AlterTableAction::DropColumn(
+            column_name.try_into()?,
+            DropBehavior::Cascade,
+        )),
+        ast::AlterTableOperation::RenameTable { table_name: name } => {
+            Ok(AlterTableAction::RenameTable(table_name(name)?))
+        }
+        ast::AlterTableOperation::RenameColumn {
+            old_column_name,
+            new_column_name,
+        } => Ok(AlterTableAction::RenameColumn(
+            old_column_name.try_into()?,
+            new_column_name.try_into()?,
+        )),
+        operation => Err(ConversionError::UnsupportedByAnsi(operation.to_string())),
+    }
+}
+
+/// Converts the explained statement, which can't be another `EXPLAIN`.
+fn explain(statement: ast::Statement, analyze: bool) -> Result<Statement, ConversionError> {
+    if matches!(statement, ast::Statement::Explain { .. }) {
+        return Err(ConversionError::UnsupportedByAnsi(statement.to_string()));
+    }
+
+    Ok(Statement::Explain(
+        Explain::new(statement.try_into()?).with_analyze(analyze),
+    ))
+}
+
+fn unary_operator(op: ast::UnaryOperator) -> Result<UnaryOperator, ConversionError> {
+    let converted = match op {
+        ast::UnaryOperator::Plus => UnaryOperator::Plus,
+        ast::UnaryOperator::Minus => UnaryOperator::Minus,
+        ast::UnaryOperator::Not => UnaryOperator::Not,
+        op => return Err(ConversionError::UnsupportedByAnsi(op.to_string())),
+    };
+
+    Ok(converted)
+}
+
+fn binary_operator(op: ast::BinaryOperator) -> Result<BinaryOperator, ConversionError> {
+    let converted = match op {
+        ast::BinaryOperator::Plus => BinaryOperator::Plus,
+        ast::BinaryOperator::Minus => BinaryOperator::Minus,
+        ast::BinaryOperator::Multiply => BinaryOperator::Multiply,
+        ast::BinaryOperator::Divide => BinaryOperator::Divide,
+        ast::BinaryOperator::StringConcat => BinaryOperator::Concat,
+        ast::BinaryOperator::Eq => BinaryOperator::Eq,
+        ast::BinaryOperator::NotEq => BinaryOperator::NotEq,
+        ast::BinaryOperator::Lt => BinaryOperator::Lt,
+        ast::BinaryOperator::LtEq => BinaryOperator::LtEq,
+        ast::BinaryOperator::Gt => BinaryOperator::Gt,
+        ast::BinaryOperator::GtEq => BinaryOperator::GtEq,
+        ast::BinaryOperator::And => BinaryOperator::And,
+        ast::BinaryOperator::Or => BinaryOperator::Or,
+        op => return Err(ConversionError::UnsupportedByAnsi(op.to_string())),
+    };
+
+    Ok(converted)
+}
+
+fn is_default_hive_format(hive_format: &ast::HiveFormat) -> bool {
+    hive_format.row_format.is_none()
+        && hive_format.storage.is_none()
+        && hive_format.location.is_none()
+}
+
+fn clob_length_to_sqlparser(
+    data_type: DataType,
+    opt_len: Option<CharacterLargeObjectLength>,
+) -> Result<Option<u64>, ConversionError> {
+    match opt_len {
+        Some(len) if len.opt_units().is_some() => Err(ConversionError::UnsupportedBySqlparser(
+            data_type.to_string(),
+        )),
+        opt_len => Ok(opt_len.map(|len| lob_length_to_sqlparser(len.length()))),
+    }
+}
+
+fn lob_length_to_sqlparser(len: LargeObjectLength) -> u64 {
+    let multiplier = len
+        .opt_multiplier()
+        .map_or(1, |multiplier| match multiplier {
+            Multiplier::K => 1 << 10,
+            Multiplier::M => 1 << 20,
+            Multiplier::G => 1 << 30,
+            Multiplier::T => 1 << 40,
+            Multiplier::P => 1 << 50,
+        });
+
+    u64::from(len.length()).saturating_mul(multiplier)
+}
+
+fn opt_u32(opt_value: Option<u64>) -> Result<Option<u32>, ConversionError> {
+    opt_value
+        .map(|value| u32::try_from(value).map_err(|_| ConversionError::OutOfRange(value)))
+        .transpose()
+}
+
+fn character_length(
+    opt_len: Option<ast::CharacterLength>,
+) -> Result<Option<CharacterLength>, ConversionError> {
+    opt_len
+        .map(|len| {
+            let units = len.unit.map(|unit| match unit {
+                ast::CharLengthUnits::Characters => CharLengthUnits::Characters,
+                ast::CharLengthUnits::Octets => CharLengthUnits::Octets,
+            });
+            let length = opt_u32(Some(len.length))?.unwrap_or_default();
+
+            Ok(CharacterLength::new(length).with_opt_units(units))
+        })
+        .transpose()
+}
+
+fn clob_length(
+    opt_len: Option<u64>,
+) -> Result<Option<CharacterLargeObjectLength>, ConversionError> {
+    Ok(opt_u32(opt_len)?.map(|len| CharacterLargeObjectLength::new(LargeObjectLength::new(len))))
+}
+
+fn exact_number_info(info: ast::ExactNumberInfo) -> Result<ExactNumberInfo, ConversionError> {
+    let to_u32 = |value: u64| u32::try_from(value).map_err(|_| ConversionError::OutOfRange(value));
+
+    match info {
+        ast::ExactNumberInfo::None => Ok(ExactNumberInfo::None),
+        ast::ExactNumberInfo::Precision(precision) => {
+            Ok(ExactNumberInfo::Precision(to_u32(precision)?))
+        }
+        ast::ExactNumberInfo::PrecisionAndScale(precision, scale) => Ok(
+            ExactNumberInfo::PrecisionAndScale(to_u32(precision)?, to_u32(scale)?),
+        ),
+    }
+}
+
+fn time_zone_info(
+    data_type: &ast::DataType,
+    time_zone: ast::TimezoneInfo,
+) -> Result<WithOrWithoutTimeZone, ConversionError> {
+    match time_zone {
+        ast::TimezoneInfo::None => Ok(WithOrWithoutTimeZone::None),
+        ast::TimezoneInfo::WithTimeZone => Ok(WithOrWithoutTimeZone::WithTimeZone),
+        ast::TimezoneInfo::WithoutTimeZone => Ok(WithOrWithoutTimeZone::WithoutTimeZone),
+        ast::TimezoneInfo::Tz => Err(ConversionError::UnsupportedByAnsi(data_type.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use sqlparser::dialect::GenericDialect;
+    use sqlparser::parser::Parser;
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_statement;
+    use crate::common::expr::RawExpr;
+    use crate::common::span::Span;
+
+    use super::*;
+
+    #[test_case("CHARACTER(10)")]
+    #[test_case("CHAR(10 OCTETS)")]
+    #[test_case("CHARACTER VARYING(10 CHARACTERS)")]
+    #[test_case("CHAR VARYING")]
+    #[test_case("VARCHAR(255)")]
+    #[test_case("CLOB(100)")]
+    #[test_case("BINARY(16)")]
+    #[test_case("VARBINARY(16)")]
+    #[test_case("BLOB")]
+    #[test_case("NUMERIC(10, 2)")]
+    #[test_case("DECIMAL(10)")]
+    #[test_case("SMALLINT")]
+    #[test_case("INTEGER")]
+    #[test_case("BIGINT")]
+    #[test_case("REAL")]
+    #[test_case("DOUBLE PRECISION")]
+    #[test_case("BOOLEAN")]
+    #[test_case("DATE")]
+    #[test_case("TIME(3) WITH TIME ZONE")]
+    #[test_case("TIMESTAMP WITHOUT TIME ZONE")]
+    fn data_type_round_trip(input: &str) {
+        let (_, data_type) = crate::ansi::parser::data_types::data_type(input.as_ref()).unwrap();
+
+        let converted = ast::DataType::try_from(data_type).unwrap();
+        assert_eq!(Ok(data_type), DataType::try_from(converted));
+    }
+
+    #[test]
+    fn data_type_lob_multiplier() {
+        let (_, data_type) = crate::ansi::parser::data_types::data_type(b"BLOB(2K)").unwrap();
+
+        assert_eq!(
+            ast::DataType::try_from(data_type),
+            Ok(ast::DataType::Blob(Some(2048)))
+        );
+    }
+
+    #[test_case(DataType::DecFloat(None))]
+    #[test_case(DataType::BinaryVarying(None))]
+    #[test_case(DataType::BinaryLargeObject(None))]
+    fn data_type_unsupported_by_sqlparser(data_type: DataType) {
+        assert_eq!(
+            ast::DataType::try_from(data_type),
+            Err(ConversionError::UnsupportedBySqlparser(
+                data_type.to_string()
+            ))
+        );
+    }
+
+    #[test_case("price * (1 - discount)")]
+    #[test_case("tb.name || 'it''s'")]
+    #[test_case("id <> -1 AND :id IS NOT NULL")]
+    #[test_case("NOT a IS NULL OR TRUE")]
+    #[test_case("x >= +2.5 AND NULL = FALSE")]
+    #[test_case(r"a = E'it\'s\\'" ; "escape string")]
+    #[test_case("a = $$it's$$ || $fn$b$fn$" ; "dollar quoted strings")]
+    #[test_case("a = X'1F'" ; "hexadecimal string")]
+    fn expr_round_trip(input: &str) {
+        let parsed = Parser::new(&GenericDialect {})
+            .try_with_sql(input)
+            .unwrap()
+            .parse_expr()
+            .unwrap();
+
+        let expr = Expr::try_from(parsed.clone()).unwrap();
+        assert_str_eq!(input, expr.to_string());
+        assert_eq!(Ok(parsed), ast::Expr::try_from(expr));
+    }
+
+    #[test]
+    fn expr_unsupported_by_sqlparser() {
+        let expr = Expr::IsNull(Box::new(Expr::Literal(Literal::Unknown)));
+
+        assert_eq!(
+            ast::Expr::try_from(expr),
+            Err(ConversionError::UnsupportedBySqlparser(
+                "UNKNOWN".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn raw_expr_unsupported_by_sqlparser() {
+        let expr = Expr::Raw(RawExpr::new("a LIKE 'b'", Span::new(0, 10)));
+
+        assert_eq!(
+            ast::Expr::try_from(expr),
+            Err(ConversionError::UnsupportedBySqlparser(
+                "a LIKE 'b'".to_string()
+            ))
+        );
+    }
+
+    #[test_case("a LIKE 'b'")]
+    #[test_case("$1 = a")]
+    #[test_case("a % 2")]
+    fn expr_unsupported_by_ansi(input: &str) {
+        let parsed = Parser::new(&GenericDialect {})
+            .try_with_sql(input)
+            .unwrap()
+            .parse_expr()
+            .unwrap();
+
+        assert!(matches!(
+            Expr::try_from(parsed),
+            Err(ConversionError::UnsupportedByAnsi(_))
+        ));
+    }
+
+    #[test_case(ast::DataType::Uuid)]
+    #[test_case(ast::DataType::Text)]
+    #[test_case(ast::DataType::TinyInt(None))]
+    #[test_case(ast::DataType::Int(Some(11)))]
+    #[test_case(ast::DataType::Timestamp(None, ast::TimezoneInfo::Tz))]
+    fn data_type_unsupported_by_ansi(data_type: ast::DataType) {
+        let expected = Err(ConversionError::UnsupportedByAnsi(data_type.to_string()));
+
+        assert_eq!(DataType::try_from(data_type), expected);
+    }
+
+    #[test]
+    fn data_type_out_of_range() {
+        assert_eq!(
+            DataType::try_from(ast::DataType::Binary(Some(u64::MAX))),
+            Err(ConversionError::OutOfRange(u64::MAX))
+        );
+    }
+
+    #[test_case("CREATE SCHEMA cat.sch")]
+    #[test_case("CREATE SCHEMA AUTHORIZATION \"user\"")]
+    #[test_case("DROP SCHEMA sch CASCADE")]
+    #[test_case("DROP TABLE cat.sch.tb RESTRICT")]
+    #[test_case("CREATE TABLE tb (id INT, name VARCHAR(20))")]
+    #[test_case("CREATE GLOBAL TEMPORARY TABLE tb (id INT)")]
+    #[test_case("ALTER TABLE sch.tb ADD COLUMN id INT")]
+    #[test_case("ALTER TABLE tb ALTER COLUMN name SET DATA TYPE VARCHAR(20)")]
+    #[test_case("ALTER TABLE tb DROP COLUMN id CASCADE")]
+    #[test_case("ALTER TABLE tb RENAME TO sch.other")]
+    #[test_case("ALTER TABLE tb RENAME COLUMN a TO b")]
+    #[test_case("CLOSE c")]
+    #[test_case("EXPLAIN ANALYZE DROP TABLE tb CASCADE")]
+    fn statement_round_trip(input: &str) {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+
+        let converted = ast::Statement::try_from(statement.clone()).unwrap();
+        assert_eq!(Ok(statement), Statement::try_from(converted));
+    }
+
+    #[test_case("CREATE TABLE tb (id INT, name VARCHAR(20))")]
+    #[test_case("DROP TABLE sch.tb CASCADE")]
+    #[test_case("ALTER TABLE tb ALTER COLUMN id SET DATA TYPE BIGINT")]
+    #[test_case("ALTER TABLE tb RENAME COLUMN a TO b")]
+    #[test_case("EXPLAIN CREATE TABLE tb (id INT)")]
+    fn statement_from_sqlparser(input: &str) {
+        let converted = Parser::parse_sql(&GenericDialect {}, input)
+            .unwrap()
+            .remove(0)
+            .try_into()
+            .map(|statement: Statement| statement.to_string());
+
+        assert_eq!(Ok(input.to_string()), converted);
+    }
+
+    #[test_case("DROP TABLE tb" ; "no drop behavior")]
+    #[test_case("DROP TABLE IF EXISTS tb CASCADE" ; "if exists")]
+    #[test_case("DROP TABLE a, b CASCADE" ; "multiple tables")]
+    #[test_case("CREATE TABLE tb (id INT NOT NULL)" ; "column options")]
+    #[test_case("CREATE TABLE tb (id INT, PRIMARY KEY (id))" ; "constraints")]
+    #[test_case("CREATE TABLE IF NOT EXISTS tb (id INT)" ; "if not exists")]
+    #[test_case("ALTER TABLE tb DROP COLUMN id" ; "drop column without drop behavior")]
+    #[test_case("ALTER TABLE tb DROP CONSTRAINT c" ; "unsupported alter table operation")]
+    #[test_case("CREATE VIEW v AS SELECT 1" ; "unsupported statement")]
+    #[test_case("CLOSE ALL" ; "close all cursors")]
+    fn statement_unsupported_by_ansi(input: &str) {
+        let statement = Parser::parse_sql(&GenericDialect {}, input)
+            .unwrap()
+            .remove(0);
+
+        assert!(matches!(
+            Statement::try_from(statement),
+            Err(ConversionError::UnsupportedByAnsi(_))
+        ));
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (id INT GENERATED ALWAYS AS IDENTITY)",
+        "id INT GENERATED ALWAYS AS IDENTITY" ;
+        "identity column"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT, CONSTRAINT pk PRIMARY KEY (id))",
+        "CONSTRAINT pk PRIMARY KEY (id)" ;
+        "table constraint"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT REFERENCES u (id))",
+        "id INT REFERENCES u (id)" ;
+        "column constraint"
+    )]
+    fn statement_unsupported_by_sqlparser(input: &str, construct: &str) {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+
+        assert_eq!(
+            ast::Statement::try_from(statement),
+            Err(ConversionError::UnsupportedBySqlparser(
+                construct.to_string()
+            ))
+        );
+    }
+
+    #[test_case("OPEN c")]
+    #[test_case("FETCH NEXT FROM c INTO :a")]
+    #[test_case("CALL proc(1)")]
+    #[test_case("RETURN :a")]
+    #[test_case("DELETE FROM tb WHERE CURRENT OF c")]
+    #[test_case("GRANT SELECT ON tb TO usr")]
+    fn statement_without_sqlparser_equivalent(input: &str) {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+
+        assert_eq!(
+            ast::Statement::try_from(statement),
+            Err(ConversionError::UnsupportedBySqlparser(input.to_string()))
+        );
+    }
+
+    #[test_case("ALTER TABLE tb DROP COLUMN id RESTRICT", "DROP COLUMN id RESTRICT" ; "restrict")]
+    #[test_case(
+        "ALTER TABLE tb ALTER COLUMN id DROP IDENTITY",
+        "ALTER COLUMN id DROP IDENTITY" ;
+        "drop identity"
+    )]
+    fn alter_table_unsupported_by_sqlparser(input: &str, construct: &str) {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+
+        assert_eq!(
+            ast::Statement::try_from(statement),
+            Err(ConversionError::UnsupportedBySqlparser(
+                construct.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn statement_by_reference() {
+        let statement = Statement::CreateTable(CreateTable::new(
+            TableName::new("tb")
+                .with_local_or_schema(LocalOrSchemaQualifier::Schema(SchemaName::new("sch"))),
+            TableContentsSource::TableElementList(TableElementList::new(vec![
+                TableElement::ColumnDefinition(ColumnDefinition::new("name").with_data_type(
+                    DataType::CharacterVarying(Some(
+                        CharacterLength::new(20).with_units(CharLengthUnits::Octets),
+                    )),
+                )),
+            ])),
+        ));
+
+        let converted = ast::Statement::try_from(&statement).unwrap();
+        assert_str_eq!(
+            "CREATE TABLE sch.tb (name CHARACTER VARYING(20 OCTETS))",
+            converted.to_string()
+        );
+        assert_eq!(Ok(statement), Statement::try_from(converted));
+    }
+
+    #[test]
+    fn sqlparser_display() {
+        let (_, statement) = parse_statement(b"CREATE TABLE \"tb\" (id INT)").unwrap();
+
+        assert_str_eq!(
+            "CREATE TABLE \"tb\" (id INT)",
+            ast::Statement::try_from(statement).unwrap().to_string()
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Versioned JSON representation of statements.
+//!
+//! Unlike the raw serde representation of the AST, this format is meant to be
+//! consumed by external tools, so it is versioned and any change to it bumps
+//! [`WIRE_FORMAT_VERSION`].
+//!
+//! # Format (version 1)
+//! A statement is wrapped in an envelope with the format version:
+//! ```json
+//! {"version": 1, "statement": {"DropTable": {...}}}
+//! ```
+//!
+//! - Structs are objects with a member per field, named as in the AST
+//!   getters. Optional fields use the `opt_` prefix and are always present,
+//!   holding `null` when absent. The only exception are the table options of
+//!   `CREATE TABLE`, which are omitted when empty;
+//! - Enums are tagged by the variant name: unit variants are strings (e.g.
+//!   `"Cascade"`), and other variants are objects with a single member, named
+//!   after the variant (e.g. `{"Varchar": {...}}`). Tuple variants hold an
+//!   array of their values;
+//! - Identifiers are objects with their `value` and `quote_style`.
+//!
+//! The format is pinned by the `json_wire_format` integration tests.
+use crate::ansi::Statement;
+
+/// Version of the JSON representation produced by [`Statement::to_json`].
+pub const WIRE_FORMAT_VERSION: u64 = 1;
+
+/// Errors raised when reading a JSON statement.
+#[derive(thiserror::Error, Clone, Eq, PartialEq, Debug)]
+pub enum JsonError {
+    /// The input is not a valid JSON statement envelope.
+    #[error("invalid JSON statement: {0}")]
+    Invalid(String),
+    /// The statement was written with another format version.
+    #[error("unsupported wire format version {0}, expected {WIRE_FORMAT_VERSION}")]
+    UnsupportedVersion(u64),
+}
+
+#[derive(serde::Serialize)]
+struct Envelope<'a> {
+    version: u64,
+    statement: &'a Statement,
+}
+
+#[derive(serde::Deserialize)]
+struct RawEnvelope {
+    version: u64,
+    statement: serde_json::Value,
+}
+
+impl Statement {
+    /// Serializes the statement into the versioned JSON format [(1)].
+    ///
+    /// # Panics
+    /// Never, as all the AST structures are serializable to JSON.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// let (_, statement) = parse_statement(b"DROP SCHEMA sch CASCADE").unwrap();
+    ///
+    /// assert_eq!(
+    ///     statement.to_json(),
+    ///     r#"{"version":1,"statement":{"DropSchema":{"schema_name":{"name":{"value":"sch","quote_style":"None"},"opt_catalog_name":null},"drop_behavior":"Cascade"}}}"#
+    /// );
+    /// ```
+    ///
+    /// [(1)]: crate::ansi::json
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let envelope = Envelope {
+            version: WIRE_FORMAT_VERSION,
+            statement: self,
+        };
+
+        serde_json::to_string(&envelope).expect("statements are always serializable")
+    }
+
+    /// Deserializes a statement from the versioned JSON format [(1)].
+    ///
+    /// # Errors
+    /// If the input is not a statement envelope, or if it was written with
+    /// another format version [(2)].
+    ///
+    /// [(1)]: crate::ansi::json
+    /// [(2)]: JsonError
+    pub fn from_json(json: &str) -> Result<Self, JsonError> {
+        let envelope: RawEnvelope =
+            serde_json::from_str(json).map_err(|err| JsonError::Invalid(err.to_string()))?;
+
+        if envelope.version != WIRE_FORMAT_VERSION {
+            return Err(JsonError::UnsupportedVersion(envelope.version));
+        }
+
+        serde_json::from_value(envelope.statement)
+            .map_err(|err| JsonError::Invalid(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn unsupported_version() {
+        assert_eq!(
+            Err(JsonError::UnsupportedVersion(2)),
+            Statement::from_json(r#"{"version":2,"statement":null}"#)
+        );
+    }
+
+    #[test]
+    fn invalid_statement() {
+        assert!(matches!(
+            Statement::from_json(r#"{"version":1,"statement":{"Select":{}}}"#),
+            Err(JsonError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn missing_version() {
+        assert!(matches!(
+            Statement::from_json(r#"{"statement":{}}"#),
+            Err(JsonError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,399 @@
+/// `ANSI` reserved words [(1)], sorted so they can be binary searched.
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#reserved-word
+pub const RESERVED_WORDS: &[&str] = &[
+    "ABS",
+    "ACOS",
+    "ALL",
+    "ALLOCATE",
+    "ALTER",
+    "AND",
+    "ANY",
+    "ARE",
+    "ARRAY",
+    "ARRAY_AGG",
+    "ARRAY_MAX_CARDINALITY",
+    "AS",
+    "ASENSITIVE",
+    "ASIN",
+    "ASYMMETRIC",
+    "AT",
+    "ATAN",
+    "ATOMIC",
+    "AUTHORIZATION",
+    "AVG",
+    "BEGIN",
+    "BEGIN_FRAME",
+    "BEGIN_PARTITION",
+    "BETWEEN",
+    "BIGINT",
+    "BINARY",
+    "BLOB",
+    "BOOLEAN",
+    "BOTH",
+    "BY",
+    "CALL",
+    "CALLED",
+    "CARDINALITY",
+    "CASCADED",
+    "CASE",
+    "CAST",
+    "CEIL",
+    "CEILING",
+    "CHAR",
+    "CHARACTER",
+    "CHARACTER_LENGTH",
+    "CHAR_LENGTH",
+    "CHECK",
+    "CLASSIFIER",
+    "CLOB",
+    "CLOSE",
+    "COALESCE",
+    "COLLATE",
+    "COLLECT",
+    "COLUMN",
+    "COMMIT",
+    "CONDITION",
+    "CONNECT",
+    "CONSTRAINT",
+    "CONTAINS",
+    "CONVERT",
+    "COPY",
+    "CORR",
+    "CORRESPONDING",
+    "COS",
+    "COSH",
+    "COUNT",
+    "COVAR_POP",
+    "COVAR_SAMP",
+    "CREATE",
+    "CROSS",
+    "CUBE",
+    "CUME_DIST",
+    "CURRENT",
+    "CURRENT_CATALOG",
+    "CURRENT_DATE",
+    "CURRENT_DEFAULT_TRANSFORM_GROUP",
+    "CURRENT_PATH",
+    "CURRENT_ROLE",
+    "CURRENT_ROW",
+    "CURRENT_SCHEMA",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_TRANSFORM_GROUP_FOR_TYPE",
+    "CURRENT_USER",
+    "CURSOR",
+    "CYCLE",
+    "DATE",
+    "DAY",
+    "DEALLOCATE",
+    "DEC",
+    "DECFLOAT",
+    "DECIMAL",
+    "DECLARE",
+    "DEFAULT",
+    "DEFINE",
+    "DELETE",
+    "DENSE_RANK",
+    "DEREF",
+    "DESCRIBE",
+    "DETERMINISTIC",
+    "DISCONNECT",
+    "DISTINCT",
+    "DOUBLE",
+    "DROP",
+    "DYNAMIC",
+    "EACH",
+    "ELEMENT",
+    "ELSE",
+    "EMPTY",
+    "END",
+    "END-EXEC",
+    "END_FRAME",
+    "END_PARTITION",
+    "EQUALS",
+    "ESCAPE",
+    "EVERY",
+    "EXCEPT",
+    "EXEC",
+    "EXECUTE",
+    "EXISTS",
+    "EXP",
+    "EXTERNAL",
+    "EXTRACT",
+    "FALSE",
+    "FETCH",
+    "FILTER",
+    "FIRST_VALUE",
+    "FLOAT",
+    "FLOOR",
+    "FOR",
+    "FOREIGN",
+    "FRAME_ROW",
+    "FREE",
+    "FROM",
+    "FULL",
+    "FUNCTION",
+    "FUSION",
+    "GET",
+    "GLOBAL",
+    "GRANT",
+    "GROUP",
+    "GROUPING",
+    "GROUPS",
+    "HAVING",
+    "HOLD",
+    "HOUR",
+    "IDENTITY",
+    "IN",
+    "INDICATOR",
+    "INITIAL",
+    "INNER",
+    "INOUT",
+    "INSENSITIVE",
+    "INSERT",
+    "INT",
+    "INTEGER",
+    "INTERSECT",
+    "INTERSECTION",
+    "INTERVAL",
+    "INTO",
+    "IS",
+    "JOIN",
+    "JSON_ARRAY",
+    "JSON_ARRAYAGG",
+    "JSON_EXISTS",
+    "JSON_OBJECT",
+    "JSON_OBJECTAGG",
+    "JSON_QUERY",
+    "JSON_TABLE",
+    "JSON_TABLE_PRIMITIVE",
+    "JSON_VALUE",
+    "LAG",
+    "LANGUAGE",
+    "LARGE",
+    "LAST_VALUE",
+    "LATERAL",
+    "LEAD",
+    "LEADING",
+    "LEFT",
+    "LIKE",
+    "LIKE_REGEX",
+    "LISTAGG",
+    "LN",
+    "LOCAL",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "LOG",
+    "LOG10",
+    "LOWER",
+    "MATCH",
+    "MATCHES",
+    "MATCH_NUMBER",
+    "MATCH_RECOGNIZE",
+    "MAX",
+    "MEASURES",
+    "MEMBER",
+    "MERGE",
+    "METHOD",
+    "MIN",
+    "MINUTE",
+    "MOD",
+    "MODIFIES",
+    "MODULE",
+    "MONTH",
+    "MULTISET",
+    "NATIONAL",
+    "NATURAL",
+    "NCHAR",
+    "NCLOB",
+    "NEW",
+    "NO",
+    "NONE",
+    "NORMALIZE",
+    "NOT",
+    "NTH_VALUE",
+    "NTILE",
+    "NULL",
+    "NULLIF",
+    "NUMERIC",
+    "OCCURRENCES_REGEX",
+    "OCTET_LENGTH",
+    "OF",
+    "OFFSET",
+    "OLD",
+    "OMIT",
+    "ON",
+    "ONE",
+    "ONLY",
+    "OPEN",
+    "OR",
+    "ORDER",
+    "OUT",
+    "OUTER",
+    "OVER",
+    "OVERLAPS",
+    "OVERLAY",
+    "PARAMETER",
+    "PARTITION",
+    "PATTERN",
+    "PER",
+    "PERCENT",
+    "PERCENTILE_CONT",
+    "PERCENTILE_DISC",
+    "PERCENT_RANK",
+    "PERIOD",
+    "PORTION",
+    "POSITION",
+    "POSITION_REGEX",
+    "POWER",
+    "PRECEDES",
+    "PRECISION",
+    "PREPARE",
+    "PRIMARY",
+    "PROCEDURE",
+    "PTF",
+    "RANGE",
+    "RANK",
+    "READS",
+    "REAL",
+    "RECURSIVE",
+    "REF",
+    "REFERENCES",
+    "REFERENCING",
+    "REGR_AVGX",
+    "REGR_AVGY",
+    "REGR_COUNT",
+    "REGR_INTERCEPT",
+    "REGR_R2",
+    "REGR_SLOPE",
+    "REGR_SXX",
+    "REGR_SXY",
+    "REGR_SYY",
+    "RELEASE",
+    "RESULT",
+    "RETURN",
+    "RETURNS",
+    "REVOKE",
+    "RIGHT",
+    "ROLLBACK",
+    "ROLLUP",
+    "ROW",
+    "ROWS",
+    "ROW_NUMBER",
+    "RUNNING",
+    "SAVEPOINT",
+    "SCOPE",
+    "SCROLL",
+    "SEARCH",
+    "SECOND",
+    "SEEK",
+    "SELECT",
+    "SENSITIVE",
+    "SESSION_USER",
+    "SET",
+    "SHOW",
+    "SIMILAR",
+    "SIN",
+    "SINH",
+    "SKIP",
+    "SMALLINT",
+    "SOME",
+    "SPECIFIC",
+    "SPECIFICTYPE",
+    "SQL",
+    "SQLEXCEPTION",
+    "SQLSTATE",
+    "SQLWARNING",
+    "SQRT",
+    "START",
+    "STATIC",
+    "STDDEV_POP",
+    "STDDEV_SAMP",
+    "SUBMULTISET",
+    "SUBSET",
+    "SUBSTRING",
+    "SUBSTRING_REGEX",
+    "SUCCEEDS",
+    "SUM",
+    "SYMMETRIC",
+    "SYSTEM",
+    "SYSTEM_TIME",
+    "SYSTEM_USER",
+    "TABLE",
+    "TABLESAMPLE",
+    "TAN",
+    "TANH",
+    "THEN",
+    "TIME",
+    "TIMESTAMP",
+    "TIMEZONE_HOUR",
+    "TIMEZONE_MINUTE",
+    "TO",
+    "TRAILING",
+    "TRANSLATE",
+    "TRANSLATE_REGEX",
+    "TRANSLATION",
+    "TREAT",
+    "TRIGGER",
+    "TRIM",
+    "TRIM_ARRAY",
+    "TRUE",
+    "TRUNCATE",
+    "UESCAPE",
+    "UNION",
+    "UNIQUE",
+    "UNKNOWN",
+    "UNNEST",
+    "UPDATE",
+    "UPPER",
+    "USER",
+    "USING",
+    "VALUE",
+    "VALUES",
+    "VALUE_OF",
+    "VARBINARY",
+    "VARCHAR",
+    "VARYING",
+    "VAR_POP",
+    "VAR_SAMP",
+    "VERSIONING",
+    "WHEN",
+    "WHENEVER",
+    "WHERE",
+    "WIDTH_BUCKET",
+    "WINDOW",
+    "WITH",
+    "WITHIN",
+    "WITHOUT",
+    "YEAR",
+];
+
+/// Returns whether the received word is an `ANSI` reserved word [(1)],
+/// ignoring case.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::keywords::is_reserved_word;
+/// assert!(is_reserved_word("TABLE"));
+/// assert!(is_reserved_word("table"));
+/// assert!(!is_reserved_word("table_name"));
+/// ```
+///
+/// [(1)]: RESERVED_WORDS
+#[must_use]
+pub fn is_reserved_word(word: &str) -> bool {
+    RESERVED_WORDS
+        .binary_search(&word.to_ascii_uppercase().as_str())
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserved_words_are_sorted() {
+        assert!(RESERVED_WORDS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,254 @@
+//! Token stream of scripts.
+//!
+//! The lexer splits the source into lexemes without parsing it, so tools that
+//! only need the tokens (e.g., syntax highlighters and editors) work on any
+//! script, including the ones with statements that can't be parsed. Every byte
+//! of the source belongs to exactly one lexeme, so whitespaces and comments
+//! are also yielded.
+use crate::ansi::keywords::is_reserved_word;
+use crate::common::is_sql_identifier;
+use crate::common::span::Span;
+use crate::common::split::{
+    block_comment_end, dollar_quoted_end, escape_string_end, line_comment_end, quoted_end,
+    starts_escape_string,
+};
+
+/// Lexeme of the source, with its kind and the span of its text.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lexeme {
+    kind: LexemeKind,
+    span: Span,
+}
+
+/// Kind of a lexeme.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LexemeKind {
+    /// Reserved word [(1)] (e.g., `TABLE`).
+    ///
+    /// [(1)]: crate::ansi::keywords::RESERVED_WORDS
+    Keyword,
+    /// Unquoted word that is not reserved, including the non-reserved
+    /// keywords (e.g., `users` or `CASCADE`).
+    Identifier,
+    /// Quoted identifier (e.g., `"name"`).
+    QuotedIdentifier,
+    /// String literal, including dollar-quoted bodies (e.g., `'text'`).
+    String,
+    /// Number, with any suffix (e.g., `10` or `2K`).
+    Number,
+    /// Statement terminator (`;`).
+    Semicolon,
+    /// Any other character (e.g., `(`).
+    Punctuation,
+    /// Sequence of whitespaces.
+    Whitespace,
+    /// `--` comment, without the line break that ends it.
+    LineComment,
+    /// `/* */` comment, which may be nested.
+    BlockComment,
+}
+
+/// Iterator over the lexemes of a script [(1)].
+///
+/// [(1)]: crate::ansi::lexer
+#[derive(Clone, Debug)]
+pub struct Lexer<'a> {
+    sql: &'a str,
+    offset: usize,
+}
+
+impl Lexeme {
+    #[must_use]
+    pub const fn new(kind: LexemeKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> LexemeKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the text of the lexeme in the source it was read from.
+    #[must_use]
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.span.start()..self.span.end()]
+    }
+}
+
+impl LexemeKind {
+    /// Returns whether the lexeme doesn't affect the statements (i.e., it's a
+    /// whitespace or a comment).
+    #[must_use]
+    pub const fn is_trivia(&self) -> bool {
+        matches!(
+            self,
+            Self::Whitespace | Self::LineComment | Self::BlockComment
+        )
+    }
+}
+
+impl<'a> Lexer<'a> {
+    /// Creates a lexer over the script.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::lexer::{Lexer, LexemeKind};
+    /// let sql = "DROP TABLE users -- old\n";
+    /// let lexemes = Lexer::new(sql)
+    ///     .map(|lexeme| (lexeme.kind(), lexeme.text(sql)))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     lexemes,
+    ///     vec![
+    ///         (LexemeKind::Keyword, "DROP"),
+    ///         (LexemeKind::Whitespace, " "),
+    ///         (LexemeKind::Keyword, "TABLE"),
+    ///         (LexemeKind::Whitespace, " "),
+    ///         (LexemeKind::Identifier, "users"),
+    ///         (LexemeKind::Whitespace, " "),
+    ///         (LexemeKind::LineComment, "-- old"),
+    ///         (LexemeKind::Whitespace, "\n"),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn new(sql: &'a str) -> Self {
+        Self { sql, offset: 0 }
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Lexeme;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.sql.as_bytes();
+        let offset = self.offset;
+        let chr = *bytes.get(offset)?;
+        let next = bytes.get(offset + 1).copied();
+
+        let (end, kind) = match (chr, next) {
+            (b'-', Some(b'-')) => (line_comment_end(bytes, offset), LexemeKind::LineComment),
+            (b'/', Some(b'*')) => (block_comment_end(bytes, offset), LexemeKind::BlockComment),
+            _ if chr.is_ascii_whitespace() => {
+                let len = bytes[offset..]
+                    .iter()
+                    .take_while(|chr| chr.is_ascii_whitespace())
+                    .count();
+                (offset + len, LexemeKind::Whitespace)
+            }
+            (b';', _) => (offset + 1, LexemeKind::Semicolon),
+            (b'\'', _) => (quoted_end(bytes, offset, b'\''), LexemeKind::String),
+            (b'E' | b'e', _) if starts_escape_string(bytes, offset) => {
+                (escape_string_end(bytes, offset + 1), LexemeKind::String)
+            }
+            (b'"' | b'`', _) => (quoted_end(bytes, offset, chr), LexemeKind::QuotedIdentifier),
+            (b'[', _) => (
+                quoted_end(bytes, offset, b']'),
+                LexemeKind::QuotedIdentifier,
+            ),
+            (b'$', _) if dollar_quoted_end(bytes, offset) > offset + 1 => {
+                (dollar_quoted_end(bytes, offset), LexemeKind::String)
+            }
+            _ if is_sql_identifier(chr) => {
+                let len = bytes[offset..]
+                    .iter()
+                    .take_while(|chr| is_sql_identifier(**chr))
+                    .count();
+                let kind = if chr.is_ascii_digit() {
+                    LexemeKind::Number
+                } else if is_reserved_word(&self.sql[offset..offset + len]) {
+                    LexemeKind::Keyword
+                } else {
+                    LexemeKind::Identifier
+                };
+                (offset + len, kind)
+            }
+            _ => {
+                let len = self.sql[offset..].chars().next().map_or(1, char::len_utf8);
+                (offset + len, LexemeKind::Punctuation)
+            }
+        };
+
+        self.offset = end;
+        Some(Lexeme::new(kind, Span::new(offset, end)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("", &[] ; "empty")]
+    #[test_case(
+        "CALL f(1, 'a;b')",
+        &[
+            (LexemeKind::Keyword, "CALL"),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::Identifier, "f"),
+            (LexemeKind::Punctuation, "("),
+            (LexemeKind::Number, "1"),
+            (LexemeKind::Punctuation, ","),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::String, "'a;b'"),
+            (LexemeKind::Punctuation, ")"),
+        ] ;
+        "literals"
+    )]
+    #[test_case(
+        "drop table \"a b\" /* /* x */ */;",
+        &[
+            (LexemeKind::Keyword, "drop"),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::Keyword, "table"),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::QuotedIdentifier, "\"a b\""),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::BlockComment, "/* /* x */ */"),
+            (LexemeKind::Semicolon, ";"),
+        ] ;
+        "quoted identifiers and nested comments"
+    )]
+    #[test_case(
+        "E'\\'' $$x$$ é",
+        &[
+            (LexemeKind::String, "E'\\''"),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::String, "$$x$$"),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::Punctuation, "é"),
+        ] ;
+        "escape and dollar quoted strings"
+    )]
+    fn lex(sql: &str, expected: &[(LexemeKind, &str)]) {
+        let lexemes = Lexer::new(sql)
+            .map(|lexeme| (lexeme.kind(), lexeme.text(sql)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected.to_vec(), lexemes);
+    }
+
+    #[test]
+    fn lexemes_cover_the_source() {
+        let sql = "SELECT 'unterminated";
+
+        let spans = Lexer::new(sql)
+            .map(|lexeme| lexeme.span())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![Span::new(0, 6), Span::new(6, 7), Span::new(7, 20)],
+            spans
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Lint rules over parsed `ANSI` statements.
+//!
+//! A [`Linter`] runs a set of [`Rule`]s over every statement of a script, and
+//! reports each violation as a [`Diagnostic`] that points to the statement in
+//! the source text.
+use std::fmt;
+
+use crate::ansi::parser::{parse_script, ScriptError};
+use crate::ansi::Statement;
+use crate::common::report::Report;
+use crate::common::span::Span;
+
+pub mod rules;
+
+/// Check applied to each statement.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::lint::{Linter, Rule};
+/// # use sql_helper::ansi::Statement;
+/// struct NoDropSchema;
+///
+/// impl Rule for NoDropSchema {
+///     fn name(&self) -> &'static str {
+///         "no-drop-schema"
+///     }
+///
+///     fn check(&self, statement: &Statement) -> Vec<String> {
+///         match statement {
+///             Statement::DropSchema(_) => vec!["schemas must not be dropped".to_string()],
+///             _ => vec![],
+///         }
+///     }
+/// }
+///
+/// let linter = Linter::new().with_rule(Box::new(NoDropSchema));
+/// let diagnostics = linter.lint("DROP SCHEMA sch RESTRICT;").unwrap();
+///
+/// assert_eq!(diagnostics[0].to_string(), "0..24: warning[no-drop-schema]: schemas must not be dropped");
+/// ```
+pub trait Rule {
+    /// Unique name of the rule, in kebab case.
+    fn name(&self) -> &'static str;
+
+    /// Severity of the diagnostics emitted by the rule.
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Returns a message for each violation of the rule in the statement.
+    fn check(&self, statement: &Statement) -> Vec<String>;
+}
+
+/// Severity of a diagnostic.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// The statement is valid, but likely a mistake.
+    Warning,
+    /// The statement must be fixed.
+    Error,
+}
+
+/// Rule violation found in a statement.
+///
+/// With the `serde` feature, diagnostics can be serialized for machine
+/// consumption (e.g., as JSON in CI reports).
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Diagnostic {
+    /// Name of the violated rule.
+    rule: &'static str,
+    severity: Severity,
+    message: String,
+    /// Span of the statement that violates the rule.
+    span: Span,
+}
+
+/// Set of rules applied to scripts.
+///
+/// The default linter has all the built-in rules [(1)].
+///
+/// [(1)]: rules
+pub struct Linter {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl Severity {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())?;
+        Ok(())
+    }
+}
+
+impl Diagnostic {
+    #[must_use]
+    pub const fn new(rule: &'static str, severity: Severity, message: String, span: Span) -> Self {
+        Self {
+            rule,
+            severity,
+            message,
+            span,
+        }
+    }
+
+    #[must_use]
+    pub const fn rule(&self) -> &'static str {
+        self.rule
+    }
+
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}[{}]: {}",
+            self.span(),
+            self.severity(),
+            self.rule(),
+            self.message()
+        )?;
+        Ok(())
+    }
+}
+
+impl From<&Diagnostic> for Report {
+    /// Creates a report of the diagnostic, pointing to the statement that
+    /// violates the rule.
+    fn from(diagnostic: &Diagnostic) -> Self {
+        Self::new(
+            format!(
+                "{}[{}]: {}",
+                diagnostic.severity(),
+                diagnostic.rule(),
+                diagnostic.message()
+            ),
+            diagnostic.span(),
+        )
+    }
+}
+
+impl Linter {
+    /// Creates a linter without any rule.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { rules: vec![] }
+    }
+
+    #[must_use]
+    pub fn with_rule(mut self, rule: Box<dyn Rule>) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    #[must_use]
+    pub fn rules(&self) -> &[Box<dyn Rule>] {
+        &self.rules
+    }
+
+    /// Parses the script and lints each of its statements.
+    ///
+    /// # Errors
+    /// If any statement of the script can't be parsed [(1)].
+    ///
+    /// [(1)]: parse_script
+    pub fn lint(&self, sql: &str) -> Result<Vec<Diagnostic>, ScriptError> {
+        let statements = parse_script(sql)?;
+
+        Ok(statements
+            .iter()
+            .flat_map(|(statement, span)| self.lint_statement(statement, *span))
+            .collect())
+    }
+
+    /// Lints a single statement, using the received span for all the
+    /// diagnostics.
+    #[must_use]
+    pub fn lint_statement(&self, statement: &Statement, span: Span) -> Vec<Diagnostic> {
+        self.rules
+            .iter()
+            .flat_map(|rule| {
+                rule.check(statement)
+                    .into_iter()
+                    .map(|message| Diagnostic::new(rule.name(), rule.severity(), message, span))
+            })
+            .collect()
+    }
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self::new()
+            .with_rule(Box::new(rules::MissingPrimaryKey))
+            .with_rule(Box::new(rules::UnboundedVarchar))
+            .with_rule(Box::new(rules::ReservedWordIdentifier))
+            .with_rule(Box::new(rules::FloatForMoney))
+            .with_rule(Box::new(rules::DropWithoutRestrict))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn lint_script() {
+        let sql = "  DROP TABLE a CASCADE;\nDROP TABLE b RESTRICT;\n\nDROP SCHEMA c CASCADE ;  ";
+        let diagnostics = Linter::default().lint(sql).unwrap();
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.span().source_text(sql).unwrap())
+                .collect::<Vec<_>>(),
+            vec!["DROP TABLE a CASCADE", "DROP SCHEMA c CASCADE"]
+        );
+    }
+
+    #[test]
+    fn lint_invalid_statement() {
+        let sql = "DROP TABLE a RESTRICT;\nSELECT 1";
+
+        assert_eq!(
+            Linter::default().lint(sql),
+            Err(ScriptError::InvalidStatement(Span::new(23, 31)))
+        );
+    }
+
+    #[test]
+    fn lint_without_rules() {
+        assert_eq!(Linter::new().lint("DROP TABLE a CASCADE"), Ok(vec![]));
+    }
+
+    #[test]
+    fn report_diagnostic() {
+        let sql = "DROP TABLE a RESTRICT;\nDROP TABLE b CASCADE;";
+        let diagnostics = Linter::default().lint(sql).unwrap();
+
+        assert_eq!(
+            Report::from(&diagnostics[0]).render(sql),
+            "warning[drop-without-restrict]: table `b` is dropped with CASCADE, use RESTRICT\n \
+             --> 2:1\n  \
+              |\n\
+             2 | DROP TABLE b CASCADE;\n  \
+              | ^^^^^^^^^^^^^^^^^^^^\n"
+        );
+    }
+}
@@ -0,0 +1,293 @@
+//! Built-in lint rules.
+use crate::ansi::ast::alter_table::{AlterColumnAction, AlterTableAction};
+use crate::ansi::ast::common::{ColumnDefinition, DropBehavior};
+use crate::ansi::ast::create_table::TableContentsSource;
+use crate::ansi::ast::data_types::DataType;
+use crate::ansi::keywords::is_reserved_word;
+use crate::ansi::lint::{Rule, Severity};
+use crate::ansi::visitor::VisitorMut;
+use crate::ansi::Statement;
+use crate::common::Ident;
+
+/// Words that, as part of a column name, suggest that it stores monetary
+/// values.
+const MONEY_WORDS: &[&str] = &[
+    "amount", "balance", "cost", "fee", "money", "price", "salary", "total",
+];
+
+/// Tables must have a primary key, declared either as a table constraint
+/// (`PRIMARY KEY (id)`) or as a column constraint (`id INT PRIMARY KEY`).
+pub struct MissingPrimaryKey;
+
+/// Character varying columns must have a maximum length.
+pub struct UnboundedVarchar;
+
+/// Identifiers must not be `ANSI` reserved words, even if quoted.
+pub struct ReservedWordIdentifier;
+
+/// Approximate numeric types must not be used for monetary values.
+pub struct FloatForMoney;
+
+/// Drops must use `RESTRICT`, so dependent objects are not dropped silently.
+pub struct DropWithoutRestrict;
+
+impl Rule for MissingPrimaryKey {
+    fn name(&self) -> &'static str {
+        "missing-primary-key"
+    }
+
+    fn check(&self, statement: &Statement) -> Vec<String> {
+        match statement {
+            Statement::CreateTable(create_table) if create_table.primary_key().is_none() => {
+                vec![format!(
+                    "table `{}` has no primary key",
+                    create_table.table_name()
+                )]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl Rule for UnboundedVarchar {
+    fn name(&self) -> &'static str {
+        "unbounded-varchar"
+    }
+
+    fn check(&self, statement: &Statement) -> Vec<String> {
+        column_data_types(statement)
+            .into_iter()
+            .filter(|(_, data_type)| {
+                matches!(
+                    data_type,
+                    DataType::CharacterVarying(None)
+                        | DataType::CharVarying(None)
+                        | DataType::Varchar(None)
+                )
+            })
+            .map(|(column_name, _)| format!("column `{column_name}` has no maximum length"))
+            .collect()
+    }
+}
+
+impl Rule for ReservedWordIdentifier {
+    fn name(&self) -> &'static str {
+        "reserved-word-identifier"
+    }
+
+    fn check(&self, statement: &Statement) -> Vec<String> {
+        let mut collector = IdentCollector::default();
+        collector.visit_statement(&mut statement.clone());
+
+        collector
+            .idents
+            .into_iter()
+            .filter(|ident| is_reserved_word(ident.value()))
+            .map(|ident| format!("identifier `{ident}` is a reserved word"))
+            .collect()
+    }
+}
+
+impl Rule for FloatForMoney {
+    fn name(&self) -> &'static str {
+        "float-for-money"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, statement: &Statement) -> Vec<String> {
+        column_data_types(statement)
+            .into_iter()
+            .filter(|(column_name, data_type)| {
+                matches!(
+                    data_type.canonical(),
+                    DataType::Float | DataType::Real | DataType::DoublePrecision
+                ) && is_money_column(column_name)
+            })
+            .map(|(column_name, data_type)| {
+                format!("column `{column_name}` stores money as `{data_type}`, use `DECIMAL`")
+            })
+            .collect()
+    }
+}
+
+impl Rule for DropWithoutRestrict {
+    fn name(&self) -> &'static str {
+        "drop-without-restrict"
+    }
+
+    fn check(&self, statement: &Statement) -> Vec<String> {
+        let dropped = match statement {
+            Statement::DropSchema(drop_schema)
+                if drop_schema.drop_behavior() == DropBehavior::Cascade =>
+            {
+                format!("schema `{}`", drop_schema.schema_name())
+            }
+            Statement::DropTable(drop_table)
+                if drop_table.drop_behavior() == DropBehavior::Cascade =>
+            {
+                format!("table `{}`", drop_table.table_name())
+            }
+            Statement::AlterTable(alter_table) => match alter_table.alter_table_action() {
+                AlterTableAction::DropColumn(column_name, DropBehavior::Cascade) => {
+                    format!("column `{column_name}`")
+                }
+                _ => return vec![],
+            },
+            _ => return vec![],
+        };
+
+        vec![format!("{dropped} is dropped with CASCADE, use RESTRICT")]
+    }
+}
+
+/// Visitor that collects every identifier of a statement.
+#[derive(Default)]
+struct IdentCollector {
+    idents: Vec<Ident>,
+}
+
+impl VisitorMut for IdentCollector {
+    fn visit_ident(&mut self, ident: &mut Ident) {
+        self.idents.push(ident.clone());
+    }
+}
+
+/// Returns the columns typed by the statement, with their data type. Columns
+/// are typed when the table is created, when they are added to the table, and
+/// when their data type is changed.
+fn column_data_types(statement: &Statement) -> Vec<(&Ident, DataType)> {
+    match statement {
+        Statement::CreateTable(create_table) => {
+            let TableContentsSource::TableElementList(table_element_list) =
+                create_table.table_contents_source();
+            table_element_list
+                .columns()
+                .filter_map(column_definition_data_type)
+                .collect()
+        }
+        Statement::AlterTable(alter_table) => match alter_table.alter_table_action() {
+            AlterTableAction::AddColumn(column_definition) => {
+                column_definition_data_type(column_definition)
+                    .into_iter()
+                    .collect()
+            }
+            AlterTableAction::AlterColumn(
+                column_name,
+                AlterColumnAction::SetDataType(data_type),
+            ) => vec![(column_name, *data_type)],
+            _ => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+fn column_definition_data_type(column_definition: &ColumnDefinition) -> Option<(&Ident, DataType)> {
+    column_definition
+        .opt_data_type()
+        .map(|data_type| (column_definition.column_name(), data_type))
+}
+
+fn is_money_column(column_name: &Ident) -> bool {
+    let column_name = column_name.value().to_lowercase();
+
+    column_name
+        .split('_')
+        .any(|word| MONEY_WORDS.contains(&word))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_statement;
+
+    use super::*;
+
+    fn check(rule: &dyn Rule, input: &str) -> Vec<String> {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+        rule.check(&statement)
+    }
+
+    #[test_case("CREATE TABLE tb (id INT)", &["table `tb` has no primary key"] ; "create table")]
+    #[test_case(
+        "CREATE TABLE tb (id INT UNIQUE, UNIQUE (id))",
+        &["table `tb` has no primary key"] ;
+        "unique constraints"
+    )]
+    #[test_case("CREATE TABLE tb (id INT, PRIMARY KEY (id))", &[] ; "table primary key")]
+    #[test_case("CREATE TABLE tb (id INT PRIMARY KEY)", &[] ; "column primary key")]
+    #[test_case("DROP TABLE tb RESTRICT", &[] ; "drop table")]
+    fn missing_primary_key(input: &str, expected: &[&str]) {
+        assert_eq!(expected, check(&MissingPrimaryKey, input));
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (a VARCHAR, b VARCHAR(10), c CHAR VARYING, d CHAR)",
+        &["column `a` has no maximum length", "column `c` has no maximum length"] ;
+        "create table"
+    )]
+    #[test_case(
+        "ALTER TABLE tb ALTER COLUMN a SET DATA TYPE CHARACTER VARYING",
+        &["column `a` has no maximum length"] ;
+        "alter column"
+    )]
+    #[test_case("ALTER TABLE tb ADD COLUMN a VARCHAR(5)", &[] ; "add bounded column")]
+    fn unbounded_varchar(input: &str, expected: &[&str]) {
+        assert_eq!(expected, check(&UnboundedVarchar, input));
+    }
+
+    #[test_case(
+        "CREATE TABLE \"user\" (\"select\" INT, name VARCHAR(10))",
+        &[
+            "identifier `\"user\"` is a reserved word",
+            "identifier `\"select\"` is a reserved word",
+        ] ;
+        "quoted reserved words"
+    )]
+    #[test_case("DROP SCHEMA sch RESTRICT", &[] ; "no reserved words")]
+    fn reserved_word_identifier(input: &str, expected: &[&str]) {
+        assert_eq!(expected, check(&ReservedWordIdentifier, input));
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (unit_price FLOAT, total DOUBLE PRECISION, ratio REAL, fee DECIMAL(10, 2))",
+        &[
+            "column `unit_price` stores money as `FLOAT`, use `DECIMAL`",
+            "column `total` stores money as `DOUBLE PRECISION`, use `DECIMAL`",
+        ] ;
+        "create table"
+    )]
+    #[test_case(
+        "ALTER TABLE tb ALTER COLUMN Amount SET DATA TYPE REAL",
+        &["column `Amount` stores money as `REAL`, use `DECIMAL`"] ;
+        "alter column"
+    )]
+    #[test_case("CREATE TABLE tb (costume FLOAT)", &[] ; "partial word")]
+    fn float_for_money(input: &str, expected: &[&str]) {
+        assert_eq!(expected, check(&FloatForMoney, input));
+    }
+
+    #[test_case(
+        "DROP SCHEMA sch CASCADE",
+        &["schema `sch` is dropped with CASCADE, use RESTRICT"] ;
+        "drop schema"
+    )]
+    #[test_case(
+        "DROP TABLE sch.tb CASCADE",
+        &["table `sch.tb` is dropped with CASCADE, use RESTRICT"] ;
+        "drop table"
+    )]
+    #[test_case(
+        "ALTER TABLE tb DROP COLUMN id CASCADE",
+        &["column `id` is dropped with CASCADE, use RESTRICT"] ;
+        "drop column"
+    )]
+    #[test_case("DROP TABLE tb RESTRICT", &[] ; "restrict")]
+    fn drop_without_restrict(input: &str, expected: &[&str]) {
+        assert_eq!(expected, check(&DropWithoutRestrict, input));
+    }
+}
@@ -0,0 +1,394 @@
+//! Location of AST nodes in the source, for editor hover and go-to-definition.
+//!
+//! The parser only tracks the span of each statement, so the spans of the
+//! nodes inside it are recovered by matching each identifier and data type
+//! [(1)], in pre-order, with the next lexemes [(2)] of the statement that
+//! render the same text. Names and column definitions span their children,
+//! and a nested statement (e.g., explained) spans up to the end of its parent.
+//!
+//! Nodes that can't be matched with the source have no span.
+//!
+//! The comments and whitespaces around the statements (e.g., a file header or
+//! vendor hints) are kept as the prologue and epilogue of the script, so it
+//! can be re-emitted with them [(3)].
+//!
+//! [(1)]: crate::ansi::nodes::Node
+//! [(2)]: crate::ansi::lexer
+//! [(3)]: ParsedScript::to_script
+use crate::ansi::lexer::{Lexeme, LexemeKind, Lexer};
+use crate::ansi::nodes::{Node, NodeId, NodeMap};
+use crate::ansi::parser::{parse_script, ScriptError};
+use crate::ansi::script::{self, TerminatorPolicy};
+use crate::ansi::Statement;
+use crate::common::span::Span;
+
+/// Script parsed with the spans of its nodes.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ParsedScript {
+    source: String,
+    statements: Vec<Statement>,
+    /// Span of each node, indexed by its id [(1)].
+    ///
+    /// [(1)]: NodeId::index
+    node_spans: Vec<Option<Span>>,
+    /// Comments and whitespaces before the first statement.
+    prologue: Span,
+    /// Comments and whitespaces after the last statement, without the line
+    /// break that ends it.
+    epilogue: Span,
+}
+
+impl ParsedScript {
+    /// Parses the script [(1)], locating its nodes in the source.
+    ///
+    /// Comments are accepted anywhere between the statements, but only the
+    /// ones before the first statement and after the last one are kept, as
+    /// the prologue and the epilogue of the script.
+    ///
+    /// # Errors
+    /// If any statement of the script can't be parsed.
+    ///
+    /// [(1)]: parse_script
+    pub fn parse(sql: &str) -> Result<Self, ScriptError> {
+        let lexemes = Lexer::new(sql)
+            .filter(|lexeme| !lexeme.kind().is_trivia())
+            .collect::<Vec<_>>();
+        let prologue_end = lexemes
+            .first()
+            .map_or(sql.len(), |lexeme| lexeme.span().start());
+        let last_end = lexemes
+            .last()
+            .map_or(sql.len(), |lexeme| lexeme.span().end());
+        let (statements, spans): (Vec<_>, Vec<_>) = parse_script(sql)?.into_iter().unzip();
+
+        let nodes = NodeMap::new(&statements);
+        let mut node_spans: Vec<Option<Span>> = vec![None; nodes.ids().count()];
+        let mut statement_lexemes: &[Lexeme] = &[];
+        for id in nodes.ids() {
+            let opt_span = match (nodes.node(id), nodes.parent(id)) {
+                (Some(Node::Statement(_)), None) => {
+                    let span = spans[nodes.statement_index(id).unwrap_or_default()];
+                    statement_lexemes = lexemes_within(&lexemes, span);
+                    Some(span)
+                }
+                (Some(node @ Node::Statement(_)), Some(parent)) => {
+                    let opt_parent_span = node_spans[parent.index()];
+                    locate(sql, &mut statement_lexemes, &node_text(node), 1)
+                        .zip(opt_parent_span)
+                        .map(|(span, parent_span)| Span::new(span.start(), parent_span.end()))
+                }
+                (Some(node @ (Node::Ident(_) | Node::DataType(_))), _) => {
+                    locate(sql, &mut statement_lexemes, &node_text(node), usize::MAX)
+                }
+                _ => None,
+            };
+            node_spans[id.index()] = opt_span;
+        }
+
+        // OBS: in pre-order, children come after their parents, so walking the
+        // ids backwards extends each name and column definition by all of its
+        // descendants.
+        for id in nodes.ids().collect::<Vec<_>>().into_iter().rev() {
+            let (Some(span), Some(parent)) = (node_spans[id.index()], nodes.parent(id)) else {
+                continue;
+            };
+            if !matches!(nodes.node(parent), Some(Node::Statement(_))) {
+                node_spans[parent.index()] =
+                    Some(node_spans[parent.index()].map_or(span, |parent_span| {
+                        Span::new(
+                            parent_span.start().min(span.start()),
+                            parent_span.end().max(span.end()),
+                        )
+                    }));
+            }
+        }
+
+        let rest = &sql[last_end..];
+        let line_break = rest.trim_start_matches([' ', '\t', '\r']);
+        let epilogue_start = match line_break.strip_prefix('\n') {
+            Some(epilogue) => sql.len() - epilogue.len(),
+            None => last_end,
+        };
+
+        Ok(Self {
+            source: sql.to_string(),
+            statements,
+            node_spans,
+            prologue: Span::new(0, prologue_end),
+            epilogue: Span::new(epilogue_start, sql.len()),
+        })
+    }
+
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    #[must_use]
+    pub fn statements(&self) -> &[Statement] {
+        &self.statements
+    }
+
+    /// Returns the comments and whitespaces before the first statement (e.g.,
+    /// a file header), or the whole source if it has no statements.
+    #[must_use]
+    pub fn prologue(&self) -> &str {
+        &self.source[self.prologue.start()..self.prologue.end()]
+    }
+
+    /// Returns the comments and whitespaces after the last statement, without
+    /// the line break that ends it.
+    #[must_use]
+    pub fn epilogue(&self) -> &str {
+        &self.source[self.epilogue.start()..self.epilogue.end()]
+    }
+
+    /// Serializes the statements into a script [(1)], surrounded by the
+    /// prologue and the epilogue of the source.
+    ///
+    /// The epilogue always starts in its own line, so a script whose
+    /// statements are already serialized as the policy renders them is
+    /// re-emitted as is.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::locate::ParsedScript;
+    /// # use sql_helper::ansi::script::TerminatorPolicy;
+    /// let sql = "-- migration: 42\ndrop table a cascade;\n/* end */\n";
+    /// let script = ParsedScript::parse(sql).unwrap();
+    ///
+    /// assert_eq!(script.prologue(), "-- migration: 42\n");
+    /// assert_eq!(script.epilogue(), "/* end */\n");
+    /// assert_eq!(
+    ///     script.to_script(TerminatorPolicy::Always),
+    ///     "-- migration: 42\nDROP TABLE a CASCADE;\n/* end */\n"
+    /// );
+    /// ```
+    ///
+    /// [(1)]: script::to_script
+    #[must_use]
+    pub fn to_script(&self, policy: TerminatorPolicy) -> String {
+        let mut statements = script::to_script(&self.statements, policy);
+        if !statements.is_empty() && !statements.ends_with('\n') && !self.epilogue().is_empty() {
+            statements.push('\n');
+        }
+
+        [self.prologue(), &statements, self.epilogue()].concat()
+    }
+
+    /// Returns the nodes of the statements [(1)], whose ids can be used to get
+    /// their spans.
+    ///
+    /// [(1)]: NodeMap
+    #[must_use]
+    pub fn nodes(&self) -> NodeMap<'_> {
+        NodeMap::new(&self.statements)
+    }
+
+    /// Returns the span of the node, or `None` if it wasn't found in the
+    /// source.
+    #[must_use]
+    pub fn node_span(&self, id: NodeId) -> Option<Span> {
+        self.node_spans.get(id.index()).copied().flatten()
+    }
+}
+
+/// Returns the innermost node at the byte offset of the script, followed by
+/// its ancestors up to the statement, all with their spans. The result is
+/// empty if the offset is not inside a statement.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::locate::{node_at_offset, ParsedScript};
+/// # use sql_helper::ansi::nodes::Node;
+/// let script = ParsedScript::parse("DROP TABLE a CASCADE;\nCREATE TABLE sch.users (id INT)").unwrap();
+///
+/// let nodes = node_at_offset(&script, 40);
+/// let texts = nodes
+///     .iter()
+///     .map(|(_, span)| span.source_text(script.source()).unwrap())
+///     .collect::<Vec<_>>();
+///
+/// assert!(matches!(nodes[0].0, Node::Ident(ident) if ident.value() == "users"));
+/// assert_eq!(
+///     texts,
+///     vec!["users", "sch.users", "CREATE TABLE sch.users (id INT)"]
+/// );
+/// ```
+#[must_use]
+pub fn node_at_offset(script: &ParsedScript, offset: usize) -> Vec<(Node<'_>, Span)> {
+    let nodes = script.nodes();
+    let contains = |id: &NodeId| {
+        script
+            .node_span(*id)
+            .is_some_and(|span| span.start() <= offset && offset < span.end())
+    };
+
+    // OBS: in pre-order, the last node that contains the offset is the
+    // innermost one.
+    let Some(innermost) = nodes.ids().filter(contains).last() else {
+        return vec![];
+    };
+
+    std::iter::once(innermost)
+        .chain(nodes.ancestors(innermost))
+        .filter_map(|id| nodes.node(id).zip(script.node_span(id)))
+        .collect()
+}
+
+/// Returns the lexemes inside the span.
+fn lexemes_within(lexemes: &[Lexeme], span: Span) -> &[Lexeme] {
+    let start = lexemes.partition_point(|lexeme| lexeme.span().start() < span.start());
+    let end = lexemes.partition_point(|lexeme| lexeme.span().end() <= span.end());
+    &lexemes[start..end.max(start)]
+}
+
+/// Returns the rendered text of a node.
+fn node_text(node: Node<'_>) -> String {
+    match node {
+        Node::Statement(statement) => statement.to_string(),
+        Node::SchemaName(schema_name) => schema_name.to_string(),
+        Node::TableName(table_name) => table_name.to_string(),
+        Node::ColumnDefinition(column_definition) => column_definition.to_string(),
+        Node::DataType(data_type) => data_type.to_string(),
+        Node::Ident(ident) => ident.to_string(),
+    }
+}
+
+/// Finds the first lexemes that match the first `max_len` lexemes of the text,
+/// returning their span and skipping the lexemes up to them.
+fn locate(source: &str, lexemes: &mut &[Lexeme], text: &str, max_len: usize) -> Option<Span> {
+    let expected = Lexer::new(text)
+        .filter(|lexeme| !lexeme.kind().is_trivia())
+        .take(max_len)
+        .collect::<Vec<_>>();
+    if expected.is_empty() {
+        return None;
+    }
+
+    let start = lexemes.windows(expected.len()).position(|window| {
+        window.iter().zip(&expected).all(|(lexeme, expected)| {
+            let (found, wanted) = (lexeme.text(source), expected.text(text));
+            match expected.kind() {
+                LexemeKind::QuotedIdentifier | LexemeKind::String => found == wanted,
+                _ => found.eq_ignore_ascii_case(wanted),
+            }
+        })
+    })?;
+    let end = start + expected.len();
+
+    let span = Span::new(lexemes[start].span().start(), lexemes[end - 1].span().end());
+    *lexemes = &lexemes[end..];
+    Some(span)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    fn texts_at(sql: &str, offset: usize) -> Vec<&str> {
+        let script = ParsedScript::parse(sql).unwrap();
+
+        node_at_offset(&script, offset)
+            .iter()
+            .map(|(_, span)| span.source_text(sql).unwrap())
+            .collect()
+    }
+
+    #[test_case(
+        "ALTER TABLE tb ADD COLUMN c VARCHAR (20)",
+        38,
+        &["VARCHAR (20)", "c VARCHAR (20)", "ALTER TABLE tb ADD COLUMN c VARCHAR (20)"] ;
+        "data type"
+    )]
+    #[test_case(
+        "create table \"Tb\" (tb int, \"tb\" int)",
+        28,
+        &["\"tb\"", "\"tb\" int", "create table \"Tb\" (tb int, \"tb\" int)"] ;
+        "quoted identifiers"
+    )]
+    #[test_case(
+        "EXPLAIN DROP TABLE tb CASCADE",
+        20,
+        &["tb", "tb", "DROP TABLE tb CASCADE", "EXPLAIN DROP TABLE tb CASCADE"] ;
+        "nested statement"
+    )]
+    #[test_case(
+        "UPDATE tb SET a = :a WHERE CURRENT OF a",
+        38,
+        &["a", "UPDATE tb SET a = :a WHERE CURRENT OF a"] ;
+        "repeated identifiers"
+    )]
+    #[test_case("DROP TABLE tb CASCADE ;  ", 23, &[] ; "outside statements")]
+    fn nodes_at_offset(sql: &str, offset: usize, expected: &[&str]) {
+        assert_eq!(expected.to_vec(), texts_at(sql, offset));
+    }
+
+    #[test_case(
+        "-- !psql\n/*+ hint */ DROP TABLE a CASCADE;   \r\n-- end",
+        "-- !psql\n/*+ hint */ ",
+        "-- end" ;
+        "header and footer"
+    )]
+    #[test_case("DROP TABLE a CASCADE -- end\n", "", " -- end\n" ; "trailing comment")]
+    #[test_case("DROP TABLE a CASCADE", "", "" ; "no trivia")]
+    #[test_case("-- only\n", "-- only\n", "" ; "no statements")]
+    fn prologue_and_epilogue(sql: &str, prologue: &str, epilogue: &str) {
+        let script = ParsedScript::parse(sql).unwrap();
+
+        assert_eq!((prologue, epilogue), (script.prologue(), script.epilogue()));
+    }
+
+    #[test_case(
+        "-- header\nDROP TABLE a CASCADE;\nDROP TABLE b RESTRICT;\n-- footer\n",
+        TerminatorPolicy::Always ;
+        "always terminated"
+    )]
+    #[test_case(
+        "/* header */\nDROP TABLE a CASCADE;\nDROP TABLE b RESTRICT\n-- footer",
+        TerminatorPolicy::Separator ;
+        "separated"
+    )]
+    #[test_case("-- only\n", TerminatorPolicy::Always ; "no statements")]
+    fn script_round_trip(sql: &str, policy: TerminatorPolicy) {
+        let script = ParsedScript::parse(sql).unwrap();
+
+        assert_eq!(sql, script.to_script(policy));
+    }
+
+    #[test]
+    fn comments_between_statements() {
+        let sql = "-- header\nDROP TABLE a CASCADE; -- a\n/* b */\nDROP TABLE b RESTRICT;\n";
+        let script = ParsedScript::parse(sql).unwrap();
+
+        assert_eq!(
+            vec!["DROP TABLE a CASCADE", "DROP TABLE b RESTRICT"],
+            script
+                .nodes()
+                .ids()
+                .filter(|id| script.nodes().parent(*id).is_none())
+                .map(|id| script.node_span(id).unwrap().source_text(sql).unwrap())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(vec!["b", "b", "DROP TABLE b RESTRICT"], texts_at(sql, 56));
+        assert_eq!(
+            "-- header\nDROP TABLE a CASCADE;\nDROP TABLE b RESTRICT;\n",
+            script.to_script(TerminatorPolicy::Always)
+        );
+    }
+
+    #[test]
+    fn locates_all_nodes() {
+        let script = ParsedScript::parse(
+            "CREATE TABLE \"sch\".tb (id INT, name CHAR VARYING(20));\n\
+             GRANT SELECT (id) ON TABLE sch.tb TO PUBLIC",
+        )
+        .unwrap();
+        let nodes = script.nodes();
+
+        assert_eq!(None, nodes.ids().find(|id| script.node_span(*id).is_none()));
+    }
+}
@@ -0,0 +1,162 @@
+//! Size and complexity metrics of parsed statements.
+use crate::ansi::nodes::{Node, NodeMap};
+use crate::ansi::visitor::VisitorMut;
+use crate::ansi::Statement;
+use crate::common::expr::Literal;
+
+/// Size and complexity of a statement [(1)].
+///
+/// [(1)]: Statement::metrics
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatementMetrics {
+    /// Number of AST nodes [(1)], including the statement itself.
+    ///
+    /// [(1)]: crate::ansi::nodes::Node
+    node_count: usize,
+    /// Number of nodes in the longest path from the statement to a leaf.
+    depth: usize,
+    /// Number of statements, including the nested ones (e.g., explained).
+    statement_count: usize,
+    /// Number of identifiers.
+    identifier_count: usize,
+    /// Number of column definitions.
+    column_count: usize,
+    /// Number of literal values.
+    literal_count: usize,
+    /// Number of schemas, tables and columns referenced [(1)].
+    ///
+    /// [(1)]: Statement::referenced_objects
+    object_reference_count: usize,
+}
+
+impl StatementMetrics {
+    #[must_use]
+    pub const fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    #[must_use]
+    pub const fn statement_count(&self) -> usize {
+        self.statement_count
+    }
+
+    #[must_use]
+    pub const fn identifier_count(&self) -> usize {
+        self.identifier_count
+    }
+
+    #[must_use]
+    pub const fn column_count(&self) -> usize {
+        self.column_count
+    }
+
+    #[must_use]
+    pub const fn literal_count(&self) -> usize {
+        self.literal_count
+    }
+
+    #[must_use]
+    pub const fn object_reference_count(&self) -> usize {
+        self.object_reference_count
+    }
+}
+
+impl Statement {
+    /// Returns the size and complexity metrics of the statement, so tooling
+    /// can reject overly complex statements or report schema complexity.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// let (_, statement) = parse_statement(b"CREATE TABLE sch.users (id INT, name VARCHAR(20))").unwrap();
+    /// let metrics = statement.metrics();
+    ///
+    /// assert_eq!(metrics.column_count(), 2);
+    /// assert_eq!(metrics.identifier_count(), 4);
+    /// assert_eq!(metrics.depth(), 4);
+    /// ```
+    #[must_use]
+    pub fn metrics(&self) -> StatementMetrics {
+        let statements = std::slice::from_ref(self);
+        let nodes = NodeMap::new(statements);
+
+        let mut metrics = StatementMetrics {
+            node_count: nodes.ids().count(),
+            depth: nodes
+                .ids()
+                .map(|id| nodes.ancestors(id).count() + 1)
+                .max()
+                .unwrap_or_default(),
+            object_reference_count: self.referenced_objects().len(),
+            ..StatementMetrics::default()
+        };
+        for id in nodes.ids() {
+            match nodes.node(id) {
+                Some(Node::Statement(_)) => metrics.statement_count += 1,
+                Some(Node::Ident(_)) => metrics.identifier_count += 1,
+                Some(Node::ColumnDefinition(_)) => metrics.column_count += 1,
+                Some(Node::SchemaName(_) | Node::TableName(_) | Node::DataType(_)) | None => {}
+            }
+        }
+
+        let mut literal_counter = LiteralCounter::default();
+        literal_counter.visit_statement(&mut self.clone());
+        metrics.literal_count = literal_counter.count;
+
+        metrics
+    }
+}
+
+/// Visitor that counts the literals of a statement.
+#[derive(Default)]
+struct LiteralCounter {
+    count: usize,
+}
+
+impl VisitorMut for LiteralCounter {
+    fn visit_literal(&mut self, _literal: &mut Literal) {
+        self.count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_statement;
+
+    // OBS: the expected metrics are, in order, the node, depth, statement,
+    // identifier, column, literal and object reference counts.
+    #[test_case("DROP TABLE tb CASCADE", [3, 3, 1, 1, 0, 0, 1] ; "drop table")]
+    #[test_case("DROP TABLE sch.tb CASCADE", [5, 4, 1, 2, 0, 0, 2] ; "qualified drop table")]
+    #[test_case("CALL f(1, 'a', :p, NULL)", [3, 2, 1, 2, 0, 2, 0] ; "call with literals")]
+    #[test_case(
+        "EXPLAIN ANALYZE CREATE TABLE tb (a INT, b INT)",
+        [10, 4, 2, 3, 2, 0, 3] ;
+        "nested statement"
+    )]
+    fn metrics(input: &str, expected: [usize; 7]) {
+        let (_, statement) = parse_statement(input.as_bytes()).unwrap();
+        let metrics = statement.metrics();
+
+        assert_eq!(
+            expected,
+            [
+                metrics.node_count(),
+                metrics.depth(),
+                metrics.statement_count(),
+                metrics.identifier_count(),
+                metrics.column_count(),
+                metrics.literal_count(),
+                metrics.object_reference_count(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,531 @@
+//! Rendering of schema changes as migration files.
+//!
+//! A [`Migration`] holds the statements that apply a change (`up`) and the ones
+//! that revert it (`down`), and is rendered as the files expected by a
+//! migration tool [(1)][`MigrationLayout`].
+//!
+//! The `down` statements can be computed from the table definitions before and
+//! after the change, or by inverting the `up` statements [(2)].
+//!
+//! [(2)]: invert_statements
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::ansi::ast::alter_table::{AlterTable, AlterTableAction};
+use crate::ansi::ast::common::{DropBehavior, TableName};
+use crate::ansi::ast::create_schema::SchemaNameClause;
+use crate::ansi::ast::create_table::CreateTable;
+use crate::ansi::ast::drop_schema::DropSchema;
+use crate::ansi::ast::drop_table::DropTable;
+use crate::ansi::ast::revoke::RevokePrivilege;
+use crate::ansi::diff::{diff_table_definitions, DiffError};
+use crate::ansi::script::{to_script, TerminatorPolicy};
+use crate::ansi::Statement;
+
+/// Errors raised when inverting statements [(1)].
+///
+/// [(1)]: invert_statements
+#[derive(thiserror::Error, Clone, Eq, PartialEq, Debug)]
+pub enum InversionError {
+    /// The statements at the indexes can't be inverted, as the state they
+    /// change is not known from the statements themselves (e.g., the
+    /// definition of a dropped table, or the data type before a change).
+    #[error("statements {} can't be inverted", display_indexes(.0))]
+    NonInvertible(Vec<usize>),
+}
+
+/// File naming conventions of the supported migration tools.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum MigrationLayout {
+    /// Reversible `sqlx` migrations, as `<version>_<name>.up.sql` and
+    /// `<version>_<name>.down.sql` [(1)].
+    ///
+    /// [(1)]: https://docs.rs/sqlx/latest/sqlx/macro.migrate.html
+    Sqlx,
+    /// Diesel migrations, as `<version>_<name>/up.sql` and
+    /// `<version>_<name>/down.sql` [(1)].
+    ///
+    /// [(1)]: https://diesel.rs/guides/getting-started
+    Diesel,
+}
+
+/// Reversible schema change.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Migration {
+    /// Version that orders the migration, e.g. `20240101000000` for `sqlx`,
+    /// or `2024-01-01-000000` for Diesel.
+    version: String,
+    /// Name of the migration, e.g. `create_users`.
+    name: String,
+    /// Statements that apply the change.
+    up: Vec<Statement>,
+    /// Statements that revert the change.
+    down: Vec<Statement>,
+}
+
+/// Migration file, with its path relative to the migrations directory.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct MigrationFile {
+    path: PathBuf,
+    contents: String,
+}
+
+impl Migration {
+    #[must_use]
+    pub fn new(version: &str, name: &str, up: Vec<Statement>, down: Vec<Statement>) -> Self {
+        Self {
+            version: version.to_string(),
+            name: name.to_string(),
+            up,
+            down,
+        }
+    }
+
+    /// Creates the migration between two sets of table definitions.
+    ///
+    /// The `up` statements transform `from` into `to`, and the `down`
+    /// statements transform `to` back into `from` [(1)].
+    ///
+    /// # Errors
+    /// If any change can't be expressed with `ALTER TABLE`, in either
+    /// direction [(1)].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::common::DropBehavior;
+    /// # use sql_helper::ansi::migration::{Migration, MigrationLayout};
+    /// # use sql_helper::ansi::parser::create_table::create_table;
+    /// let (_, users) = create_table(b"CREATE TABLE users (id INT)").unwrap();
+    ///
+    /// let migration = Migration::from_table_definitions(
+    ///     "20240101000000",
+    ///     "create_users",
+    ///     &[],
+    ///     &[users],
+    ///     DropBehavior::Restrict,
+    /// )
+    /// .unwrap();
+    /// let files = migration.files(MigrationLayout::Sqlx);
+    ///
+    /// assert_eq!(files[0].path().to_str(), Some("20240101000000_create_users.up.sql"));
+    /// assert_eq!(files[0].contents(), "CREATE TABLE users (id INT);\n");
+    /// assert_eq!(files[1].path().to_str(), Some("20240101000000_create_users.down.sql"));
+    /// assert_eq!(files[1].contents(), "DROP TABLE users RESTRICT;\n");
+    /// ```
+    ///
+    /// [(1)]: diff_table_definitions
+    pub fn from_table_definitions(
+        version: &str,
+        name: &str,
+        from: &[CreateTable],
+        to: &[CreateTable],
+        drop_behavior: DropBehavior,
+    ) -> Result<Self, DiffError> {
+        Ok(Self::new(
+            version,
+            name,
+            diff_table_definitions(from, to, drop_behavior)?,
+            diff_table_definitions(to, from, drop_behavior)?,
+        ))
+    }
+
+    /// Creates the migration whose `down` statements invert the `up` ones
+    /// [(1)].
+    ///
+    /// # Errors
+    /// If any `up` statement can't be inverted [(1)].
+    ///
+    /// [(1)]: invert_statements
+    pub fn from_statements(
+        version: &str,
+        name: &str,
+        up: Vec<Statement>,
+        drop_behavior: DropBehavior,
+    ) -> Result<Self, InversionError> {
+        let down = invert_statements(&up, drop_behavior)?;
+        Ok(Self::new(version, name, up, down))
+    }
+
+    #[must_use]
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn up(&self) -> &[Statement] {
+        &self.up
+    }
+
+    #[must_use]
+    pub fn down(&self) -> &[Statement] {
+        &self.down
+    }
+
+    /// Returns the `up` and `down` files of the migration, in that order, with
+    /// one statement per line.
+    #[must_use]
+    pub fn files(&self, layout: MigrationLayout) -> Vec<MigrationFile> {
+        let prefix = format!("{}_{}", self.version(), self.name());
+        let (up_path, down_path) = match layout {
+            MigrationLayout::Sqlx => (
+                PathBuf::from(format!("{prefix}.up.sql")),
+                PathBuf::from(format!("{prefix}.down.sql")),
+            ),
+            MigrationLayout::Diesel => (
+                Path::new(&prefix).join("up.sql"),
+                Path::new(&prefix).join("down.sql"),
+            ),
+        };
+
+        vec![
+            MigrationFile::new(up_path, to_script(self.up(), TerminatorPolicy::Always)),
+            MigrationFile::new(down_path, to_script(self.down(), TerminatorPolicy::Always)),
+        ]
+    }
+
+    /// Writes the migration files into the migrations directory, creating
+    /// any missing directory.
+    ///
+    /// # Errors
+    /// If any file can't be written.
+    pub fn write(&self, directory: &Path, layout: MigrationLayout) -> io::Result<()> {
+        for file in self.files(layout) {
+            let path = directory.join(file.path());
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, file.contents())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl MigrationFile {
+    #[must_use]
+    pub const fn new(path: PathBuf, contents: String) -> Self {
+        Self { path, contents }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[must_use]
+    pub fn contents(&self) -> &str {
+        &self.contents
+    }
+}
+
+/// Returns the statements that revert the received ones, in reverse order,
+/// using the drop behavior for the generated `DROP` statements.
+///
+/// Creations are inverted by drops, renames by the opposite rename, and
+/// granted privileges by their revocation. Statements that change nothing
+/// stored (e.g., cursor statements or `EXPLAIN`) need no inverse.
+///
+/// # Errors
+/// If any statement can't be inverted, the error holds the indexes of all of
+/// them.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::common::DropBehavior;
+/// # use sql_helper::ansi::migration::{invert_statements, InversionError};
+/// # use sql_helper::ansi::parser::parse_script;
+/// # use sql_helper::ansi::script::{to_script, TerminatorPolicy};
+/// let statements = parse_script("CREATE TABLE tb (id INT); ALTER TABLE tb ADD COLUMN name INT")
+///     .unwrap()
+///     .into_iter()
+///     .map(|(statement, _)| statement)
+///     .collect::<Vec<_>>();
+///
+/// let down = invert_statements(&statements, DropBehavior::Restrict).unwrap();
+/// assert_eq!(
+///     to_script(&down, TerminatorPolicy::Separator),
+///     "ALTER TABLE tb DROP COLUMN name RESTRICT;\nDROP TABLE tb RESTRICT"
+/// );
+///
+/// let (statement, _) = parse_script("DROP TABLE tb CASCADE").unwrap().remove(0);
+/// assert_eq!(
+///     invert_statements(&[statement], DropBehavior::Restrict),
+///     Err(InversionError::NonInvertible(vec![0]))
+/// );
+/// ```
+pub fn invert_statements(
+    statements: &[Statement],
+    drop_behavior: DropBehavior,
+) -> Result<Vec<Statement>, InversionError> {
+    let mut inverses = vec![];
+    let mut non_invertible = vec![];
+    for (index, statement) in statements.iter().enumerate() {
+        match inverse(statement, drop_behavior) {
+            Some(inverse) => inverses.extend(inverse),
+            None => non_invertible.push(index),
+        }
+    }
+
+    if !non_invertible.is_empty() {
+        return Err(InversionError::NonInvertible(non_invertible));
+    }
+
+    inverses.reverse();
+    Ok(inverses)
+}
+
+/// Returns the statements that revert the received one (none if it changes
+/// nothing stored), or `None` if it can't be inverted.
+fn inverse(statement: &Statement, drop_behavior: DropBehavior) -> Option<Vec<Statement>> {
+    let inverse = match statement {
+        Statement::CreateSchema(create_schema) => match create_schema.schema_name_clause() {
+            SchemaNameClause::Simple(schema_name)
+            | SchemaNameClause::NamedAuthorization(schema_name, _) => {
+                Statement::DropSchema(DropSchema::new(schema_name.clone(), drop_behavior))
+            }
+            // OBS: the schema name is implementation defined.
+            SchemaNameClause::Authorization(_) => return None,
+        },
+        Statement::CreateTable(create_table) => Statement::DropTable(DropTable::new(
+            create_table.table_name().clone(),
+            drop_behavior,
+        )),
+        Statement::AlterTable(alter_table) => {
+            Statement::AlterTable(inverse_alter_table(alter_table, drop_behavior)?)
+        }
+        Statement::GrantPrivilege(grant) => {
+            let mut revoke = RevokePrivilege::new(
+                grant.privileges().clone(),
+                grant.grantees().to_vec(),
+                drop_behavior,
+            );
+            if let Some(grantor) = grant.opt_grantor() {
+                revoke = revoke.with_grantor(grantor);
+            }
+            Statement::RevokePrivilege(revoke)
+        }
+        Statement::Explain(explain) if explain.analyze() => {
+            return inverse(explain.statement(), drop_behavior);
+        }
+        Statement::Open(_) | Statement::Fetch(_) | Statement::Close(_) | Statement::Explain(_) => {
+            return Some(vec![]);
+        }
+        Statement::DropSchema(_)
+        | Statement::DropTable(_)
+        | Statement::Call(_)
+        | Statement::Return(_)
+        | Statement::UpdatePositioned(_)
+        | Statement::DeletePositioned(_)
+        | Statement::RevokePrivilege(_) => return None,
+    };
+
+    Some(vec![inverse])
+}
+
+fn inverse_alter_table(
+    alter_table: &AlterTable,
+    drop_behavior: DropBehavior,
+) -> Option<AlterTable> {
+    let table_name = alter_table.table_name();
+    let inverse = match alter_table.alter_table_action() {
+        AlterTableAction::AddColumn(column_definition) => AlterTable::new(
+            table_name.clone(),
+            AlterTableAction::DropColumn(column_definition.column_name().clone(), drop_behavior),
+        ),
+        AlterTableAction::RenameColumn(column_name, new_column_name) => AlterTable::new(
+            table_name.clone(),
+            AlterTableAction::RenameColumn(new_column_name.clone(), column_name.clone()),
+        ),
+        AlterTableAction::RenameTable(new_table_name) => AlterTable::new(
+            renamed_table(table_name, new_table_name),
+            AlterTableAction::RenameTable(table_name.clone()),
+        ),
+        AlterTableAction::AlterColumn(..) | AlterTableAction::DropColumn(..) => return None,
+    };
+
+    Some(inverse)
+}
+
+/// Returns the name of the renamed table, which stays in the schema of the
+/// original one unless the new name is qualified.
+fn renamed_table(table_name: &TableName, new_table_name: &TableName) -> TableName {
+    match (
+        new_table_name.opt_local_or_schema(),
+        table_name.opt_local_or_schema(),
+    ) {
+        (None, Some(local_or_schema)) => new_table_name
+            .clone()
+            .with_local_or_schema(local_or_schema.clone()),
+        _ => new_table_name.clone(),
+    }
+}
+
+fn display_indexes(indexes: &[usize]) -> String {
+    indexes
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use test_case::test_case;
+
+    use crate::ansi::parser::create_table::create_table;
+    use crate::ansi::parser::parse_script;
+
+    use super::*;
+
+    fn migration() -> Migration {
+        let (_, from) = create_table(b"CREATE TABLE tb (id INT, name VARCHAR(20))").unwrap();
+        let (_, to) = create_table(b"CREATE TABLE tb (id BIGINT)").unwrap();
+
+        Migration::from_table_definitions(
+            "2024-01-01-000000",
+            "change_tb",
+            &[from],
+            &[to],
+            DropBehavior::Cascade,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn diesel_files() {
+        let files = migration().files(MigrationLayout::Diesel);
+
+        assert_eq!(
+            vec![
+                Path::new("2024-01-01-000000_change_tb").join("up.sql"),
+                Path::new("2024-01-01-000000_change_tb").join("down.sql"),
+            ],
+            files
+                .iter()
+                .map(|file| file.path().to_path_buf())
+                .collect::<Vec<_>>()
+        );
+        assert_str_eq!(
+            "ALTER TABLE tb DROP COLUMN name CASCADE;\n\
+             ALTER TABLE tb ALTER COLUMN id SET DATA TYPE BIGINT;\n",
+            files[0].contents()
+        );
+        assert_str_eq!(
+            "ALTER TABLE tb ALTER COLUMN id SET DATA TYPE INT;\n\
+             ALTER TABLE tb ADD COLUMN name VARCHAR(20);\n",
+            files[1].contents()
+        );
+    }
+
+    #[test]
+    fn irreversible_migration() {
+        let (_, from) =
+            create_table(b"CREATE TABLE tb (id INT GENERATED ALWAYS AS IDENTITY)").unwrap();
+        let (_, to) = create_table(b"CREATE TABLE tb (id INT)").unwrap();
+
+        assert!(matches!(
+            Migration::from_table_definitions(
+                "1",
+                "drop_identity",
+                &[from],
+                &[to],
+                DropBehavior::Cascade
+            ),
+            Err(DiffError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn write_files() {
+        let directory = std::env::temp_dir().join(format!("sql-helper-{}", std::process::id()));
+        migration()
+            .write(&directory, MigrationLayout::Sqlx)
+            .unwrap();
+
+        let up = fs::read_to_string(directory.join("2024-01-01-000000_change_tb.up.sql")).unwrap();
+        let down =
+            fs::read_to_string(directory.join("2024-01-01-000000_change_tb.down.sql")).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(to_script(migration().up(), TerminatorPolicy::Always), up);
+        assert_eq!(
+            to_script(migration().down(), TerminatorPolicy::Always),
+            down
+        );
+    }
+
+    fn parse(input: &str) -> Vec<Statement> {
+        parse_script(input)
+            .unwrap()
+            .into_iter()
+            .map(|(statement, _)| statement)
+            .collect()
+    }
+
+    #[test_case(
+        "CREATE SCHEMA sch; CREATE TABLE sch.tb (id INT)",
+        "DROP TABLE sch.tb CASCADE;\nDROP SCHEMA sch CASCADE" ;
+        "creations"
+    )]
+    #[test_case(
+        "ALTER TABLE tb ADD COLUMN a INT; ALTER TABLE tb RENAME COLUMN a TO b",
+        "ALTER TABLE tb RENAME COLUMN b TO a;\nALTER TABLE tb DROP COLUMN a CASCADE" ;
+        "column changes"
+    )]
+    #[test_case(
+        "ALTER TABLE sch.a RENAME TO b",
+        "ALTER TABLE sch.b RENAME TO sch.a" ;
+        "table rename keeps the schema"
+    )]
+    #[test_case(
+        "GRANT SELECT ON tb TO usr GRANTED BY CURRENT_USER",
+        "REVOKE SELECT ON tb FROM usr GRANTED BY CURRENT_USER CASCADE" ;
+        "granted privileges"
+    )]
+    #[test_case(
+        "EXPLAIN ANALYZE CREATE TABLE tb (id INT); EXPLAIN DROP TABLE tb CASCADE; CLOSE cur",
+        "DROP TABLE tb CASCADE" ;
+        "statements without stored changes"
+    )]
+    fn invert(input: &str, expected: &str) {
+        let down = invert_statements(&parse(input), DropBehavior::Cascade).unwrap();
+
+        assert_str_eq!(expected, to_script(&down, TerminatorPolicy::Separator));
+    }
+
+    #[test]
+    fn non_invertible_statements() {
+        let statements = parse(
+            "CREATE TABLE tb (id INT); DROP TABLE other CASCADE; \
+             ALTER TABLE tb ALTER COLUMN id SET DATA TYPE BIGINT; CREATE SCHEMA AUTHORIZATION usr",
+        );
+
+        let error = invert_statements(&statements, DropBehavior::Cascade).unwrap_err();
+
+        assert_eq!(InversionError::NonInvertible(vec![1, 2, 3]), error);
+        assert_eq!("statements 1, 2, 3 can't be inverted", error.to_string());
+    }
+
+    #[test]
+    fn migration_from_statements() {
+        let migration = Migration::from_statements(
+            "1",
+            "create_tb",
+            parse("CREATE TABLE tb (id INT)"),
+            DropBehavior::Restrict,
+        )
+        .unwrap();
+
+        assert_str_eq!(
+            "DROP TABLE tb RESTRICT;\n",
+            to_script(migration.down(), TerminatorPolicy::Always)
+        );
+    }
+}
@@ -0,0 +1,502 @@
+//! Node identities and parent tracking over parsed statements.
+//!
+//! A [`NodeMap`] numbers every node of a list of statements in pre-order, so
+//! the same statements always get the same ids, and records the parent and the
+//! statement of each node. Analyzers can then navigate upward from any node
+//! (e.g., to report "column `id` of table `users`, at statement 3").
+use crate::ansi::ast::alter_table::{AlterColumnAction, AlterTable, AlterTableAction};
+use crate::ansi::ast::call::Call;
+use crate::ansi::ast::common::{
+    ColumnConstraint, ColumnDefinition, LocalOrSchemaQualifier, ReferencesSpecification,
+    SchemaName, SchemaQualifiedName, TableName, ValueSpecification,
+};
+use crate::ansi::ast::create_schema::{CreateSchema, SchemaNameClause};
+use crate::ansi::ast::create_table::{
+    CreateTable, TableConstraint, TableConstraintDefinition, TableContentsSource, TableElement,
+};
+use crate::ansi::ast::data_types::DataType;
+use crate::ansi::ast::delete::DeletePositioned;
+use crate::ansi::ast::fetch::{Fetch, TargetSpecification};
+use crate::ansi::ast::grant::{Action, GrantObject, Grantee, ObjectPrivileges, Privileges};
+use crate::ansi::ast::update::{UpdatePositioned, UpdateSource};
+use crate::ansi::Statement;
+use crate::common::Ident;
+
+/// Identity of a node in a [`NodeMap`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct NodeId(usize);
+
+/// Node of the `ANSI` AST.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Node<'a> {
+    Statement(&'a Statement),
+    SchemaName(&'a SchemaName),
+    TableName(&'a TableName),
+    ColumnDefinition(&'a ColumnDefinition),
+    DataType(DataType),
+    Ident(&'a Ident),
+}
+
+/// Nodes of a list of statements, with their parents [(1)].
+///
+/// [(1)]: crate::ansi::nodes
+#[derive(Clone, Debug)]
+pub struct NodeMap<'a> {
+    entries: Vec<Entry<'a>>,
+}
+
+#[derive(Clone, Debug)]
+struct Entry<'a> {
+    node: Node<'a>,
+    opt_parent: Option<NodeId>,
+    statement_index: usize,
+}
+
+impl NodeId {
+    /// Returns the position of the node in the pre-order traversal of the
+    /// statements.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.0
+    }
+}
+
+impl<'a> NodeMap<'a> {
+    /// Numbers all the nodes of the statements.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::nodes::{Node, NodeMap};
+    /// # use sql_helper::ansi::parser::parse_script;
+    /// # use sql_helper::ansi::Statement;
+    /// let statements = parse_script("DROP TABLE a CASCADE; CREATE TABLE users (id INT)")
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|(statement, _)| statement)
+    ///     .collect::<Vec<_>>();
+    /// let nodes = NodeMap::new(&statements);
+    ///
+    /// let column = nodes
+    ///     .ids()
+    ///     .find(|id| matches!(nodes.node(*id), Some(Node::ColumnDefinition(_))))
+    ///     .unwrap();
+    /// let table = nodes.ancestors(column).find_map(|id| match nodes.node(id) {
+    ///     Some(Node::Statement(Statement::CreateTable(create_table))) => {
+    ///         Some(create_table.table_name())
+    ///     }
+    ///     _ => None,
+    /// });
+    ///
+    /// assert_eq!(nodes.statement_index(column), Some(1));
+    /// assert_eq!(table.unwrap().to_string(), "users");
+    /// ```
+    #[must_use]
+    pub fn new(statements: &'a [Statement]) -> Self {
+        let mut builder = Builder::default();
+
+        for (statement_index, statement) in statements.iter().enumerate() {
+            builder.statement_index = statement_index;
+            builder.statement(statement);
+        }
+
+        Self {
+            entries: builder.entries,
+        }
+    }
+
+    /// Returns the ids of all the nodes, in pre-order.
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.entries.len()).map(NodeId)
+    }
+
+    /// Returns the node with the received id, if any.
+    #[must_use]
+    pub fn node(&self, id: NodeId) -> Option<Node<'a>> {
+        self.entries.get(id.0).map(|entry| entry.node)
+    }
+
+    /// Returns the parent of the node, or `None` for statements and unknown
+    /// ids.
+    #[must_use]
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.entries.get(id.0).and_then(|entry| entry.opt_parent)
+    }
+
+    /// Returns the ancestors of the node, from its parent up to its statement.
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        std::iter::successors(self.parent(id), |id| self.parent(*id))
+    }
+
+    /// Returns the children of the node, in order.
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.ids()
+            .skip(id.0 + 1)
+            .filter(move |child| self.parent(*child) == Some(id))
+    }
+
+    /// Returns the index of the statement that holds the node.
+    #[must_use]
+    pub fn statement_index(&self, id: NodeId) -> Option<usize> {
+        self.entries.get(id.0).map(|entry| entry.statement_index)
+    }
+}
+
+/// Pre-order traversal that records each node with its parent.
+#[derive(Default)]
+struct Builder<'a> {
+    entries: Vec<Entry<'a>>,
+    opt_parent: Option<NodeId>,
+    statement_index: usize,
+}
+
+impl<'a> Builder<'a> {
+    /// Records the node, and records its children (added by the received
+    /// function) under it.
+    fn node(&mut self, node: Node<'a>, children: impl FnOnce(&mut Self)) {
+        let id = NodeId(self.entries.len());
+        self.entries.push(Entry {
+            node,
+            opt_parent: self.opt_parent,
+            statement_index: self.statement_index,
+        });
+
+        let opt_parent = self.opt_parent.replace(id);
+        children(self);
+        self.opt_parent = opt_parent;
+    }
+
+    fn statement(&mut self, statement: &'a Statement) {
+        self.node(Node::Statement(statement), |builder| match statement {
+            Statement::CreateSchema(create_schema) => builder.create_schema(create_schema),
+            Statement::DropSchema(drop_schema) => builder.schema_name(drop_schema.schema_name()),
+            Statement::DropTable(drop_table) => builder.table_name(drop_table.table_name()),
+            Statement::CreateTable(create_table) => builder.create_table(create_table),
+            Statement::AlterTable(alter_table) => builder.alter_table(alter_table),
+            Statement::Open(open) => builder.ident(open.cursor_name()),
+            Statement::Fetch(fetch) => builder.fetch(fetch),
+            Statement::Close(close) => builder.ident(close.cursor_name()),
+            Statement::Call(call) => builder.call(call),
+            Statement::Return(return_statement) => {
+                builder.value_specification(return_statement.return_value());
+            }
+            Statement::UpdatePositioned(update) => builder.update_positioned(update),
+            Statement::DeletePositioned(delete) => builder.delete_positioned(delete),
+            Statement::Explain(explain) => builder.statement(explain.statement()),
+            Statement::GrantPrivilege(grant) => {
+                builder.privileges(grant.privileges());
+                builder.grantees(grant.grantees());
+            }
+            Statement::RevokePrivilege(revoke) => {
+                builder.privileges(revoke.privileges());
+                builder.grantees(revoke.grantees());
+            }
+        });
+    }
+
+    fn create_schema(&mut self, create_schema: &'a CreateSchema) {
+        match create_schema.schema_name_clause() {
+            SchemaNameClause::Simple(schema_name) => self.schema_name(schema_name),
+            SchemaNameClause::Authorization(authorization) => self.ident(authorization),
+            SchemaNameClause::NamedAuthorization(schema_name, authorization) => {
+                self.schema_name(schema_name);
+                self.ident(authorization);
+            }
+        }
+    }
+
+    fn create_table(&mut self, create_table: &'a CreateTable) {
+        self.table_name(create_table.table_name());
+
+        let TableContentsSource::TableElementList(table_element_list) =
+            create_table.table_contents_source();
+        for table_element in table_element_list.element_list() {
+            match table_element {
+                TableElement::ColumnDefinition(column_definition) => {
+                    self.column_definition(column_definition);
+                }
+                TableElement::TableConstraintDefinition(table_constraint_definition) => {
+                    self.table_constraint_definition(table_constraint_definition);
+                }
+            }
+        }
+    }
+
+    fn alter_table(&mut self, alter_table: &'a AlterTable) {
+        self.table_name(alter_table.table_name());
+
+        match alter_table.alter_table_action() {
+            AlterTableAction::AddColumn(column_definition) => {
+                self.column_definition(column_definition);
+            }
+            AlterTableAction::AlterColumn(column_name, alter_column_action) => {
+                self.ident(column_name);
+                if let AlterColumnAction::SetDataType(data_type) = alter_column_action {
+                    self.node(Node::DataType(*data_type), |_| {});
+                }
+            }
+            AlterTableAction::DropColumn(column_name, _) => self.ident(column_name),
+            AlterTableAction::RenameTable(table_name) => self.table_name(table_name),
+            AlterTableAction::RenameColumn(column_name, new_column_name) => {
+                self.ident(column_name);
+                self.ident(new_column_name);
+            }
+        }
+    }
+
+    fn fetch(&mut self, fetch: &'a Fetch) {
+        self.ident(fetch.cursor_name());
+
+        for target_specification in fetch.target_list() {
+            match target_specification {
+                TargetSpecification::HostParameter(name)
+                | TargetSpecification::SqlParameter(name) => self.ident(name),
+            }
+        }
+    }
+
+    fn call(&mut self, call: &'a Call) {
+        if let Some(schema_name) = call.routine_name().opt_schema_name() {
+            self.schema_name(schema_name);
+        }
+        self.ident(call.routine_name().name());
+
+        for argument in call.arguments() {
+            self.value_specification(argument);
+        }
+    }
+
+    fn update_positioned(&mut self, update: &'a UpdatePositioned) {
+        self.table_name(update.table_name());
+
+        for set_clause in update.set_clause_list() {
+            self.ident(set_clause.column_name());
+            if let UpdateSource::Value(value_specification) = set_clause.update_source() {
+                self.value_specification(value_specification);
+            }
+        }
+
+        self.ident(update.cursor_name());
+    }
+
+    fn delete_positioned(&mut self, delete: &'a DeletePositioned) {
+        self.table_name(delete.table_name());
+        self.ident(delete.cursor_name());
+    }
+
+    fn privileges(&mut self, privileges: &'a Privileges) {
+        if let ObjectPrivileges::Actions(actions) = privileges.object_privileges() {
+            for column_name_list in actions.iter().filter_map(Action::opt_column_name_list) {
+                for column_name in column_name_list.column_names() {
+                    self.ident(column_name);
+                }
+            }
+        }
+
+        match privileges.object() {
+            GrantObject::Table(table_name) => self.table_name(table_name),
+            GrantObject::Domain(name)
+            | GrantObject::Sequence(name)
+            | GrantObject::Type(name)
+            | GrantObject::CharacterSet(name) => {
+                if let Some(schema_name) = name.opt_schema_name() {
+                    self.schema_name(schema_name);
+                }
+                self.ident(name.name());
+            }
+            GrantObject::Routine(routine) => {
+                if let Some(schema_name) = routine.routine_name().opt_schema_name() {
+                    self.schema_name(schema_name);
+                }
+                self.ident(routine.routine_name().name());
+                for data_type in routine.opt_data_type_list().unwrap_or_default() {
+                    self.node(Node::DataType(*data_type), |_| {});
+                }
+            }
+        }
+    }
+
+    /// Records the authorization identifiers of the grantees, skipping
+    /// `PUBLIC`.
+    fn grantees(&mut self, grantees: &'a [Grantee]) {
+        for grantee in grantees {
+            if let Grantee::AuthorizationIdentifier(authorization) = grantee {
+                self.ident(authorization);
+            }
+        }
+    }
+
+    /// Records the parameter of the value, if it's one.
+    fn value_specification(&mut self, value_specification: &'a ValueSpecification) {
+        match value_specification {
+            ValueSpecification::HostParameter(name) | ValueSpecification::SqlParameter(name) => {
+                self.ident(name);
+            }
+            ValueSpecification::Literal(_)
+            | ValueSpecification::DynamicParameter
+            | ValueSpecification::Null => {}
+        }
+    }
+
+    fn schema_name(&mut self, schema_name: &'a SchemaName) {
+        self.node(Node::SchemaName(schema_name), |builder| {
+            if let Some(catalog_name) = schema_name.opt_catalog_name() {
+                builder.ident(catalog_name);
+            }
+            builder.ident(schema_name.name());
+        });
+    }
+
+    fn table_name(&mut self, table_name: &'a TableName) {
+        self.node(Node::TableName(table_name), |builder| {
+            if let Some(LocalOrSchemaQualifier::Schema(schema_name)) =
+                table_name.opt_local_or_schema()
+            {
+                builder.schema_name(schema_name);
+            }
+            builder.ident(table_name.name());
+        });
+    }
+
+    fn column_definition(&mut self, column_definition: &'a ColumnDefinition) {
+        self.node(Node::ColumnDefinition(column_definition), |builder| {
+            builder.ident(column_definition.column_name());
+            if let Some(data_type) = column_definition.opt_data_type() {
+                builder.node(Node::DataType(data_type), |_| {});
+            }
+            for column_constraint_definition in column_definition.column_constraint_definitions() {
+                if let Some(constraint_name) = column_constraint_definition.opt_constraint_name() {
+                    builder.schema_qualified_name(constraint_name);
+                }
+                if let ColumnConstraint::References(references_specification) =
+                    column_constraint_definition.column_constraint()
+                {
+                    builder.references_specification(references_specification);
+                }
+            }
+        });
+    }
+
+    fn table_constraint_definition(
+        &mut self,
+        table_constraint_definition: &'a TableConstraintDefinition,
+    ) {
+        if let Some(constraint_name) = table_constraint_definition.opt_constraint_name() {
+            self.schema_qualified_name(constraint_name);
+        }
+
+        match table_constraint_definition.table_constraint() {
+            TableConstraint::Unique(unique_constraint_definition) => {
+                for key_column in unique_constraint_definition.key_columns() {
+                    self.ident(key_column.column_name());
+                }
+            }
+            TableConstraint::Referential(referential_constraint_definition) => {
+                for column_name in referential_constraint_definition
+                    .referencing_columns()
+                    .column_names()
+                {
+                    self.ident(column_name);
+                }
+                if let Some(period_name) =
+                    referential_constraint_definition.opt_referencing_period()
+                {
+                    self.ident(period_name);
+                }
+                self.references_specification(
+                    referential_constraint_definition.references_specification(),
+                );
+            }
+        }
+    }
+
+    fn references_specification(&mut self, references_specification: &'a ReferencesSpecification) {
+        self.table_name(references_specification.table_name());
+        if let Some(referenced_columns) = references_specification.opt_referenced_columns() {
+            for column_name in referenced_columns.column_names() {
+                self.ident(column_name);
+            }
+        }
+        if let Some(period_name) = references_specification.opt_referenced_period() {
+            self.ident(period_name);
+        }
+    }
+
+    fn schema_qualified_name(&mut self, schema_qualified_name: &'a SchemaQualifiedName) {
+        if let Some(schema_name) = schema_qualified_name.opt_schema_name() {
+            self.schema_name(schema_name);
+        }
+        self.ident(schema_qualified_name.name());
+    }
+
+    fn ident(&mut self, ident: &'a Ident) {
+        self.node(Node::Ident(ident), |_| {});
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::ansi::parser::parse_statement;
+
+    use super::*;
+
+    fn statements(sql: &[&str]) -> Vec<Statement> {
+        sql.iter()
+            .map(|sql| parse_statement(sql.as_bytes()).unwrap().1)
+            .collect()
+    }
+
+    #[test]
+    fn pre_order_ids() {
+        let statements = statements(&["DROP TABLE sch.tb CASCADE"]);
+        let nodes = NodeMap::new(&statements);
+
+        let kinds = nodes
+            .ids()
+            .map(|id| match nodes.node(id).unwrap() {
+                Node::Statement(_) => "statement".to_string(),
+                Node::TableName(_) => "table name".to_string(),
+                Node::SchemaName(_) => "schema name".to_string(),
+                Node::Ident(ident) => ident.to_string(),
+                node => format!("{node:?}"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec!["statement", "table name", "schema name", "sch", "tb"],
+            kinds
+        );
+    }
+
+    #[test]
+    fn parents_and_children() {
+        let statements = statements(&["CREATE SCHEMA sch", "CREATE TABLE tb (id INT, name)"]);
+        let nodes = NodeMap::new(&statements);
+
+        let second_statement = nodes
+            .ids()
+            .filter(|id| nodes.parent(*id).is_none())
+            .nth(1)
+            .unwrap();
+        let children = nodes.children(second_statement).collect::<Vec<_>>();
+        assert_eq!(3, children.len());
+
+        let name_column = children[2];
+        let name_ident = nodes.children(name_column).next().unwrap();
+        assert_eq!(
+            vec![name_column, second_statement],
+            nodes.ancestors(name_ident).collect::<Vec<_>>()
+        );
+        assert_eq!(Some(1), nodes.statement_index(name_ident));
+    }
+
+    #[test]
+    fn unknown_id() {
+        let nodes = NodeMap::new(&[]);
+
+        assert_eq!(None, nodes.node(NodeId(0)));
+        assert_eq!(None, nodes.parent(NodeId(0)));
+        assert_eq!(None, nodes.statement_index(NodeId(0)));
+    }
+}
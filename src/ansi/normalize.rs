@@ -0,0 +1,224 @@
+use crate::ansi::dialect::AnsiDialect;
+use crate::ansi::visitor::VisitorMut;
+use crate::ansi::Statement;
+use crate::common::{Ident, QuoteStyle};
+
+/// Options of the statement normalization [(1)].
+///
+/// [(1)]: crate::ansi::Statement::normalize
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct NormalizeOptions {
+    /// Case that unquoted identifiers are folded to.
+    identifier_case: IdentifierCase,
+    /// Whether quoted identifiers that are equivalent to an unquoted one are
+    /// unquoted.
+    unquote_identifiers: bool,
+}
+
+/// Case folding applied to unquoted identifiers.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum IdentifierCase {
+    /// Folds to upper case, following the `ANSI` rules.
+    #[default]
+    Upper,
+    /// Folds to lower case, as some dialects do (e.g. `PostgreSQL`).
+    Lower,
+    /// Keeps identifiers as they are.
+    Preserve,
+}
+
+impl NormalizeOptions {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            identifier_case: IdentifierCase::Upper,
+            unquote_identifiers: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_identifier_case(mut self, identifier_case: IdentifierCase) -> Self {
+        self.identifier_case = identifier_case;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_unquote_identifiers(mut self, unquote_identifiers: bool) -> Self {
+        self.unquote_identifiers = unquote_identifiers;
+        self
+    }
+
+    #[must_use]
+    pub const fn identifier_case(&self) -> IdentifierCase {
+        self.identifier_case
+    }
+
+    #[must_use]
+    pub const fn unquote_identifiers(&self) -> bool {
+        self.unquote_identifiers
+    }
+}
+
+/// Statement compared, ordered and hashed by its normalized form [(1)], so it
+/// can be used as a key of sets and maps where equivalent statements collapse.
+///
+/// # Examples
+/// ```rust
+/// # use std::collections::HashSet;
+/// # use sql_helper::ansi::normalize::{NormalizedStatement, NormalizeOptions};
+/// # use sql_helper::ansi::parser::parse_statement;
+/// let statements = ["DROP TABLE Foo CASCADE", "drop table FOO cascade", "DROP TABLE \"Foo\" CASCADE"]
+///     .map(|sql| parse_statement(sql.as_bytes()).unwrap().1);
+///
+/// let unique = statements
+///     .iter()
+///     .map(|statement| NormalizedStatement::new(statement, NormalizeOptions::new()))
+///     .collect::<HashSet<_>>();
+/// assert_eq!(unique.len(), 2);
+/// ```
+///
+/// [(1)]: Statement::normalize
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct NormalizedStatement {
+    statement: Statement,
+}
+
+impl NormalizedStatement {
+    #[must_use]
+    pub fn new(statement: &Statement, options: NormalizeOptions) -> Self {
+        Self {
+            statement: statement.normalize(options),
+        }
+    }
+
+    /// Returns the normalized statement.
+    #[must_use]
+    pub const fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    #[must_use]
+    pub fn into_statement(self) -> Statement {
+        self.statement
+    }
+}
+
+impl IdentifierCase {
+    fn fold(self, value: &str) -> String {
+        match self {
+            Self::Upper => value.to_uppercase(),
+            Self::Lower => value.to_lowercase(),
+            Self::Preserve => value.to_string(),
+        }
+    }
+}
+
+/// Visitor that normalizes every identifier of a statement.
+pub(crate) struct Normalizer {
+    options: NormalizeOptions,
+}
+
+impl Normalizer {
+    pub(crate) const fn new(options: NormalizeOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl VisitorMut for Normalizer {
+    fn visit_ident(&mut self, ident: &mut Ident) {
+        if *ident.quote_style() == QuoteStyle::None {
+            let folded = self.options.identifier_case().fold(ident.value());
+            *ident = Ident::from(folded);
+            return;
+        }
+
+        // OBS: a quoted identifier is only equivalent to an unquoted one if
+        // its value is already in the folded case, as the quoted value is
+        // case-sensitive.
+        let unquoted = Ident::from_str_unchecked(ident.value());
+        if self.options.unquote_identifiers()
+            && !unquoted.needs_quoting(&AnsiDialect)
+            && self.options.identifier_case().fold(ident.value()) == ident.value()
+        {
+            *ident = unquoted;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_statement;
+
+    use super::*;
+
+    #[test_case(
+        "CREATE TABLE sch.Users (Id INT, \"Name\" VARCHAR(20))",
+        NormalizeOptions::new(),
+        "CREATE TABLE SCH.USERS (ID INT, \"Name\" VARCHAR(20))" ;
+        "ansi folding"
+    )]
+    #[test_case(
+        "CREATE TABLE sch.Users (Id INT, \"Name\" VARCHAR(20))",
+        NormalizeOptions::new().with_identifier_case(IdentifierCase::Lower),
+        "CREATE TABLE sch.users (id INT, \"Name\" VARCHAR(20))" ;
+        "lower folding"
+    )]
+    #[test_case(
+        "DROP TABLE \"SCH\".\"Users\" CASCADE",
+        NormalizeOptions::new().with_unquote_identifiers(true),
+        "DROP TABLE SCH.\"Users\" CASCADE" ;
+        "unquote folded identifiers"
+    )]
+    #[test_case(
+        "DROP TABLE \"TABLE\".\"MY TABLE\" CASCADE",
+        NormalizeOptions::new().with_unquote_identifiers(true),
+        "DROP TABLE \"TABLE\".\"MY TABLE\" CASCADE" ;
+        "keep required quotes"
+    )]
+    #[test_case(
+        "DROP TABLE \"users\" CASCADE",
+        NormalizeOptions::new()
+            .with_identifier_case(IdentifierCase::Preserve)
+            .with_unquote_identifiers(true),
+        "DROP TABLE users CASCADE" ;
+        "preserve case"
+    )]
+    fn normalize(input: &str, options: NormalizeOptions, expected: &str) {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+
+        assert_str_eq!(expected, statement.normalize(options).to_string());
+    }
+
+    #[test]
+    fn normalized_statements_are_comparable() {
+        let (_, lower) = parse_statement(b"DROP TABLE users CASCADE").unwrap();
+        let (_, quoted) = parse_statement(b"DROP TABLE \"USERS\" CASCADE").unwrap();
+        let options = NormalizeOptions::new().with_unquote_identifiers(true);
+
+        assert_eq!(lower.normalize(options), quoted.normalize(options));
+    }
+
+    #[test_case("DROP TABLE foo CASCADE", "DROP TABLE FOO CASCADE", NormalizeOptions::new(), true ; "case folding")]
+    #[test_case("DROP TABLE foo CASCADE", "DROP TABLE \"foo\" CASCADE", NormalizeOptions::new(), false ; "quoted lower case")]
+    #[test_case(
+        "DROP TABLE foo CASCADE",
+        "DROP TABLE \"FOO\" CASCADE",
+        NormalizeOptions::new().with_unquote_identifiers(true),
+        true ;
+        "quoted folded case"
+    )]
+    #[test_case("DROP TABLE foo CASCADE", "DROP TABLE foo RESTRICT", NormalizeOptions::new(), false ; "different statements")]
+    fn semantic_eq(left: &str, right: &str, options: NormalizeOptions, expected: bool) {
+        let (_, left) = parse_statement(left.as_bytes()).unwrap();
+        let (_, right) = parse_statement(right.as_bytes()).unwrap();
+
+        assert_eq!(expected, left.semantic_eq(&right, options));
+        assert_eq!(
+            expected,
+            NormalizedStatement::new(&left, options) == NormalizedStatement::new(&right, options)
+        );
+    }
+}
@@ -0,0 +1,517 @@
+use crate::ansi::ast::alter_table::{AlterTable, AlterTableAction};
+use crate::ansi::ast::common::{
+    ColumnConstraint, ColumnDefinition, LocalOrSchemaQualifier, ReferencesSpecification,
+    SchemaName, TableName,
+};
+use crate::ansi::ast::create_schema::SchemaNameClause;
+use crate::ansi::ast::create_table::{TableConstraint, TableContentsSource, TableElement};
+use crate::ansi::ast::grant::{Action, GrantObject, ObjectPrivileges, Privileges};
+use crate::ansi::Statement;
+use crate::common::Ident;
+
+/// Database object used by a statement, and how it's used [(1)].
+///
+/// [(1)]: Statement::referenced_objects
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObjectReference {
+    /// Referenced object.
+    object: ObjectName,
+    /// How the object is used by the statement.
+    usage: ObjectUsage,
+}
+
+/// Name of a database object.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObjectName {
+    /// Schema.
+    Schema(SchemaName),
+    /// Table.
+    Table(TableName),
+    /// Column, with the table it belongs to.
+    Column(TableName, Ident),
+}
+
+/// How a statement uses an object.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObjectUsage {
+    /// The statement creates the object.
+    Created,
+    /// The statement drops the object.
+    Dropped,
+    /// The statement changes the object definition.
+    Altered,
+    /// The statement depends on the object, without changing it.
+    Referenced,
+}
+
+impl ObjectReference {
+    #[must_use]
+    pub const fn new(object: ObjectName, usage: ObjectUsage) -> Self {
+        Self { object, usage }
+    }
+
+    #[must_use]
+    pub const fn object(&self) -> &ObjectName {
+        &self.object
+    }
+
+    #[must_use]
+    pub const fn usage(&self) -> ObjectUsage {
+        self.usage
+    }
+}
+
+impl ObjectName {
+    /// Returns whether both names refer to the same object, following the
+    /// identifiers case folding [(1)].
+    ///
+    /// [(1)]: Ident::refers_to
+    #[must_use]
+    pub fn refers_to(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Schema(schema_name), Self::Schema(other_schema_name)) => {
+                schema_name.refers_to(other_schema_name)
+            }
+            (Self::Table(table_name), Self::Table(other_table_name)) => {
+                table_name.refers_to(other_table_name)
+            }
+            (
+                Self::Column(table_name, column_name),
+                Self::Column(other_table_name, other_column_name),
+            ) => table_name.refers_to(other_table_name) && column_name.refers_to(other_column_name),
+            _ => false,
+        }
+    }
+}
+
+impl Statement {
+    /// Returns the schemas, tables and columns that the statement creates,
+    /// drops or references, in the order they appear in the statement.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::ast::common::{SchemaName, TableName, LocalOrSchemaQualifier};
+    /// # use sql_helper::ansi::objects::{ObjectName, ObjectReference, ObjectUsage};
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// let (_, statement) = parse_statement(b"DROP TABLE sch.tb CASCADE").unwrap();
+    /// let table_name = TableName::new("tb")
+    ///     .with_local_or_schema(LocalOrSchemaQualifier::Schema(SchemaName::new("sch")));
+    ///
+    /// assert_eq!(
+    ///     statement.referenced_objects(),
+    ///     vec![
+    ///         ObjectReference::new(
+    ///             ObjectName::Schema(SchemaName::new("sch")),
+    ///             ObjectUsage::Referenced
+    ///         ),
+    ///         ObjectReference::new(ObjectName::Table(table_name), ObjectUsage::Dropped),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn referenced_objects(&self) -> Vec<ObjectReference> {
+        let mut references = vec![];
+
+        match self {
+            Self::CreateSchema(create_schema) => match create_schema.schema_name_clause() {
+                SchemaNameClause::Simple(schema_name)
+                | SchemaNameClause::NamedAuthorization(schema_name, _) => {
+                    references.push(ObjectReference::new(
+                        ObjectName::Schema(schema_name.clone()),
+                        ObjectUsage::Created,
+                    ));
+                }
+                // OBS: the schema name is implementation defined when only
+                // the authorization is given.
+                SchemaNameClause::Authorization(_) => {}
+            },
+            Self::DropSchema(drop_schema) => references.push(ObjectReference::new(
+                ObjectName::Schema(drop_schema.schema_name().clone()),
+                ObjectUsage::Dropped,
+            )),
+            Self::DropTable(drop_table) => {
+                push_table(
+                    &mut references,
+                    drop_table.table_name(),
+                    ObjectUsage::Dropped,
+                );
+            }
+            Self::CreateTable(create_table) => {
+                let table_name = create_table.table_name();
+                push_table(&mut references, table_name, ObjectUsage::Created);
+
+                let TableContentsSource::TableElementList(table_element_list) =
+                    create_table.table_contents_source();
+                for table_element in table_element_list.element_list() {
+                    match table_element {
+                        TableElement::ColumnDefinition(column_definition) => {
+                            references.push(ObjectReference::new(
+                                ObjectName::Column(
+                                    table_name.clone(),
+                                    column_definition.column_name().clone(),
+                                ),
+                                ObjectUsage::Created,
+                            ));
+                            push_column_constraints(&mut references, column_definition);
+                        }
+                        TableElement::TableConstraintDefinition(table_constraint_definition) => {
+                            if let TableConstraint::Referential(referential_constraint_definition) =
+                                table_constraint_definition.table_constraint()
+                            {
+                                push_references_specification(
+                                    &mut references,
+                                    referential_constraint_definition.references_specification(),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            Self::AlterTable(alter_table) => push_alter_table(&mut references, alter_table),
+            // OBS: cursors are not schema objects (the tables they read are
+            // only named by their declaration), and returned values name no
+            // object.
+            Self::Open(_) | Self::Fetch(_) | Self::Close(_) | Self::Return(_) => {}
+            Self::UpdatePositioned(update) => {
+                let table_name = update.table_name();
+                push_table(&mut references, table_name, ObjectUsage::Referenced);
+
+                for set_clause in update.set_clause_list() {
+                    references.push(ObjectReference::new(
+                        ObjectName::Column(table_name.clone(), set_clause.column_name().clone()),
+                        ObjectUsage::Referenced,
+                    ));
+                }
+            }
+            Self::DeletePositioned(delete) => {
+                push_table(
+                    &mut references,
+                    delete.table_name(),
+                    ObjectUsage::Referenced,
+                );
+            }
+            Self::Call(call) => {
+                if let Some(schema_name) = call.routine_name().opt_schema_name() {
+                    references.push(ObjectReference::new(
+                        ObjectName::Schema(schema_name.clone()),
+                        ObjectUsage::Referenced,
+                    ));
+                }
+            }
+            Self::GrantPrivilege(grant) => push_privileges(&mut references, grant.privileges()),
+            Self::RevokePrivilege(revoke) => {
+                push_privileges(&mut references, revoke.privileges());
+            }
+            // OBS: only `EXPLAIN ANALYZE` executes the explained statement.
+            Self::Explain(explain) => {
+                if explain.analyze() {
+                    references = explain.statement().referenced_objects();
+                }
+            }
+        }
+
+        references
+    }
+
+    /// Returns the object the statement creates, drops or alters, or `None` if
+    /// it's not named in the statement (e.g., `CREATE SCHEMA AUTHORIZATION
+    /// usr`) or the statement doesn't change any object (e.g., `FETCH`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::objects::ObjectName;
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// let (_, statement) = parse_statement(b"ALTER TABLE tb DROP COLUMN id CASCADE").unwrap();
+    ///
+    /// assert!(matches!(
+    ///     statement.object_name(),
+    ///     Some(ObjectName::Table(table_name)) if table_name.to_string() == "tb"
+    /// ));
+    /// ```
+    #[must_use]
+    pub fn object_name(&self) -> Option<ObjectName> {
+        match self {
+            Self::CreateSchema(create_schema) => match create_schema.schema_name_clause() {
+                SchemaNameClause::Simple(schema_name)
+                | SchemaNameClause::NamedAuthorization(schema_name, _) => {
+                    Some(ObjectName::Schema(schema_name.clone()))
+                }
+                SchemaNameClause::Authorization(_) => None,
+            },
+            Self::DropSchema(drop_schema) => {
+                Some(ObjectName::Schema(drop_schema.schema_name().clone()))
+            }
+            Self::DropTable(drop_table) => Some(ObjectName::Table(drop_table.table_name().clone())),
+            Self::CreateTable(create_table) => {
+                Some(ObjectName::Table(create_table.table_name().clone()))
+            }
+            Self::AlterTable(alter_table) => {
+                Some(ObjectName::Table(alter_table.table_name().clone()))
+            }
+            Self::Open(_)
+            | Self::Fetch(_)
+            | Self::Close(_)
+            | Self::Call(_)
+            | Self::Return(_)
+            | Self::UpdatePositioned(_)
+            | Self::DeletePositioned(_)
+            | Self::GrantPrivilege(_)
+            | Self::RevokePrivilege(_) => None,
+            Self::Explain(explain) => explain
+                .analyze()
+                .then(|| explain.statement().object_name())
+                .flatten(),
+        }
+    }
+}
+
+/// Pushes the object of the privileges, which is only referenced, and its
+/// privileged columns. Objects other than tables are only known by their
+/// schemas.
+fn push_privileges(references: &mut Vec<ObjectReference>, privileges: &Privileges) {
+    let opt_schema_name = match privileges.object() {
+        GrantObject::Table(table_name) => {
+            push_table(references, table_name, ObjectUsage::Referenced);
+
+            if let ObjectPrivileges::Actions(actions) = privileges.object_privileges() {
+                for column_name_list in actions.iter().filter_map(Action::opt_column_name_list) {
+                    for column_name in column_name_list.column_names() {
+                        references.push(ObjectReference::new(
+                            ObjectName::Column(table_name.clone(), column_name.clone()),
+                            ObjectUsage::Referenced,
+                        ));
+                    }
+                }
+            }
+            return;
+        }
+        GrantObject::Domain(name)
+        | GrantObject::Sequence(name)
+        | GrantObject::Type(name)
+        | GrantObject::CharacterSet(name) => name.opt_schema_name(),
+        GrantObject::Routine(routine) => routine.routine_name().opt_schema_name(),
+    };
+
+    if let Some(schema_name) = opt_schema_name {
+        references.push(ObjectReference::new(
+            ObjectName::Schema(schema_name.clone()),
+            ObjectUsage::Referenced,
+        ));
+    }
+}
+
+/// Pushes the table, preceded by its schema (if any), which is always only
+/// referenced.
+/// Pushes the altered table, and the column or table named by the action.
+fn push_alter_table(references: &mut Vec<ObjectReference>, alter_table: &AlterTable) {
+    let table_name = alter_table.table_name();
+    push_table(references, table_name, ObjectUsage::Altered);
+
+    // OBS: a rename alters the object under its old name, and creates the
+    // new name.
+    let columns = match alter_table.alter_table_action() {
+        AlterTableAction::AddColumn(column_definition) => {
+            vec![(column_definition.column_name(), ObjectUsage::Created)]
+        }
+        AlterTableAction::AlterColumn(column_name, _) => vec![(column_name, ObjectUsage::Altered)],
+        AlterTableAction::DropColumn(column_name, _) => vec![(column_name, ObjectUsage::Dropped)],
+        AlterTableAction::RenameTable(new_table_name) => {
+            push_table(references, new_table_name, ObjectUsage::Created);
+            vec![]
+        }
+        AlterTableAction::RenameColumn(column_name, new_column_name) => vec![
+            (column_name, ObjectUsage::Altered),
+            (new_column_name, ObjectUsage::Created),
+        ],
+    };
+    for (column_name, usage) in columns {
+        references.push(ObjectReference::new(
+            ObjectName::Column(table_name.clone(), column_name.clone()),
+            usage,
+        ));
+    }
+    if let AlterTableAction::AddColumn(column_definition) = alter_table.alter_table_action() {
+        push_column_constraints(references, column_definition);
+    }
+}
+
+/// Pushes the objects referenced by the foreign keys of the column.
+fn push_column_constraints(
+    references: &mut Vec<ObjectReference>,
+    column_definition: &ColumnDefinition,
+) {
+    for column_constraint_definition in column_definition.column_constraint_definitions() {
+        if let ColumnConstraint::References(references_specification) =
+            column_constraint_definition.column_constraint()
+        {
+            push_references_specification(references, references_specification);
+        }
+    }
+}
+
+/// Pushes the table and the columns referenced by a foreign key.
+fn push_references_specification(
+    references: &mut Vec<ObjectReference>,
+    references_specification: &ReferencesSpecification,
+) {
+    let table_name = references_specification.table_name();
+    push_table(references, table_name, ObjectUsage::Referenced);
+
+    if let Some(referenced_columns) = references_specification.opt_referenced_columns() {
+        for column_name in referenced_columns.column_names() {
+            references.push(ObjectReference::new(
+                ObjectName::Column(table_name.clone(), column_name.clone()),
+                ObjectUsage::Referenced,
+            ));
+        }
+    }
+}
+
+fn push_table(references: &mut Vec<ObjectReference>, table_name: &TableName, usage: ObjectUsage) {
+    if let Some(LocalOrSchemaQualifier::Schema(schema_name)) = table_name.opt_local_or_schema() {
+        references.push(ObjectReference::new(
+            ObjectName::Schema(schema_name.clone()),
+            ObjectUsage::Referenced,
+        ));
+    }
+
+    references.push(ObjectReference::new(
+        ObjectName::Table(table_name.clone()),
+        usage,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_statement;
+
+    use super::*;
+
+    fn describe(reference: &ObjectReference) -> String {
+        let object = match reference.object() {
+            ObjectName::Schema(schema_name) => format!("schema {schema_name}"),
+            ObjectName::Table(table_name) => format!("table {table_name}"),
+            ObjectName::Column(table_name, column_name) => {
+                format!("column {table_name}.{column_name}")
+            }
+        };
+
+        format!("{:?} {object}", reference.usage())
+    }
+
+    #[test_case("CREATE SCHEMA cat.sch", &["Created schema cat.sch"] ; "create schema")]
+    #[test_case(
+        "CREATE SCHEMA sch AUTHORIZATION usr",
+        &["Created schema sch"] ;
+        "create schema with authorization"
+    )]
+    #[test_case("CREATE SCHEMA AUTHORIZATION usr", &[] ; "create schema authorization only")]
+    #[test_case("DROP SCHEMA sch CASCADE", &["Dropped schema sch"] ; "drop schema")]
+    #[test_case("DROP TABLE tb RESTRICT", &["Dropped table tb"] ; "drop table")]
+    #[test_case("DROP TABLE MODULE.tb RESTRICT", &["Dropped table MODULE.tb"] ; "drop module table")]
+    #[test_case(
+        "CREATE TABLE cat.sch.tb (id INT, name VARCHAR(20))",
+        &[
+            "Referenced schema cat.sch",
+            "Created table cat.sch.tb",
+            "Created column cat.sch.tb.id",
+            "Created column cat.sch.tb.name",
+        ] ;
+        "create table"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT REFERENCES sch.u (a, PERIOD p), FOREIGN KEY (id) REFERENCES tb)",
+        &[
+            "Created table tb",
+            "Created column tb.id",
+            "Referenced schema sch",
+            "Referenced table sch.u",
+            "Referenced column sch.u.a",
+            "Referenced table tb",
+        ] ;
+        "create table with foreign keys"
+    )]
+    #[test_case(
+        "ALTER TABLE tb ADD COLUMN a INT NOT NULL REFERENCES u",
+        &["Altered table tb", "Created column tb.a", "Referenced table u"] ;
+        "add column with foreign key"
+    )]
+    #[test_case(
+        "ALTER TABLE sch.tb DROP COLUMN id CASCADE",
+        &["Referenced schema sch", "Altered table sch.tb", "Dropped column sch.tb.id"] ;
+        "alter table"
+    )]
+    #[test_case(
+        "RENAME TABLE tb TO sch.tb2",
+        &["Altered table tb", "Referenced schema sch", "Created table sch.tb2"] ;
+        "rename table"
+    )]
+    #[test_case(
+        "ALTER TABLE tb RENAME a TO b",
+        &["Altered table tb", "Altered column tb.a", "Created column tb.b"] ;
+        "rename column"
+    )]
+    #[test_case(
+        "UPDATE sch.tb SET a = 1, b = DEFAULT WHERE CURRENT OF c",
+        &[
+            "Referenced schema sch",
+            "Referenced table sch.tb",
+            "Referenced column sch.tb.a",
+            "Referenced column sch.tb.b",
+        ] ;
+        "update positioned"
+    )]
+    #[test_case("CALL sch.proc()", &["Referenced schema sch"] ; "call")]
+    #[test_case("FETCH c INTO :a", &[] ; "fetch")]
+    #[test_case("EXPLAIN DROP TABLE tb CASCADE", &[] ; "explain")]
+    #[test_case(
+        "GRANT SELECT (a), DELETE ON sch.tb TO usr",
+        &["Referenced schema sch", "Referenced table sch.tb", "Referenced column sch.tb.a"] ;
+        "grant on table"
+    )]
+    #[test_case(
+        "REVOKE USAGE ON SEQUENCE sch.seq FROM usr RESTRICT",
+        &["Referenced schema sch"] ;
+        "revoke on sequence"
+    )]
+    #[test_case("EXPLAIN ANALYZE DROP TABLE tb CASCADE", &["Dropped table tb"] ; "explain analyze")]
+    fn referenced_objects(input: &str, expected: &[&str]) {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+
+        assert_eq!(
+            expected,
+            statement
+                .referenced_objects()
+                .iter()
+                .map(describe)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test_case("CREATE SCHEMA cat.sch", Some("schema cat.sch") ; "create schema")]
+    #[test_case("CREATE SCHEMA AUTHORIZATION usr", None ; "create schema authorization only")]
+    #[test_case("DROP SCHEMA sch CASCADE", Some("schema sch") ; "drop schema")]
+    #[test_case("CREATE TABLE sch.tb (id INT)", Some("table sch.tb") ; "create table")]
+    #[test_case("DROP TABLE tb RESTRICT", Some("table tb") ; "drop table")]
+    #[test_case("ALTER TABLE tb ADD COLUMN id INT", Some("table tb") ; "alter table")]
+    #[test_case("DELETE FROM tb WHERE CURRENT OF c", None ; "delete positioned")]
+    #[test_case("EXPLAIN DROP TABLE tb CASCADE", None ; "explain")]
+    #[test_case("EXPLAIN ANALYZE DROP TABLE tb CASCADE", Some("table tb") ; "explain analyze")]
+    fn object_name(input: &str, expected: Option<&str>) {
+        let (_, statement) = parse_statement(input.as_bytes()).unwrap();
+        let object_name = statement
+            .object_name()
+            .map(|object_name| describe(&ObjectReference::new(object_name, ObjectUsage::Created)));
+
+        assert_eq!(
+            expected.map(|expected| format!("Created {expected}")),
+            object_name
+        );
+    }
+}
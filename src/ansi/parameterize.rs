@@ -0,0 +1,123 @@
+use std::fmt;
+
+use crate::ansi::ast::common::ValueSpecification;
+use crate::ansi::visitor::{walk_value_specification, VisitorMut};
+use crate::ansi::Statement;
+use crate::common::expr::Literal;
+
+/// Statement whose literal values were replaced by dynamic parameters (`?`),
+/// along with the extracted literals [(1)].
+///
+/// The literals are stored in the order their parameters appear in the
+/// statement, so they can be bound by position. `NULL` and the named
+/// parameters are kept as they are.
+///
+/// [(1)]: Statement::parameterize
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ParameterizedStatement {
+    statement: Statement,
+    literals: Vec<Literal>,
+}
+
+impl ParameterizedStatement {
+    #[must_use]
+    pub fn new(statement: &Statement) -> Self {
+        let mut statement = statement.clone();
+        let mut parameterizer = Parameterizer::default();
+        parameterizer.visit_statement(&mut statement);
+
+        Self {
+            statement,
+            literals: parameterizer.literals,
+        }
+    }
+
+    /// Returns the statement with the dynamic parameters.
+    #[must_use]
+    pub const fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    /// Returns the extracted literals, in parameter order.
+    #[must_use]
+    pub fn literals(&self) -> &[Literal] {
+        &self.literals
+    }
+
+    #[must_use]
+    pub fn into_parts(self) -> (Statement, Vec<Literal>) {
+        (self.statement, self.literals)
+    }
+}
+
+impl fmt::Display for ParameterizedStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.statement)?;
+        Ok(())
+    }
+}
+
+/// Visitor that moves every literal value of a statement out of it, leaving a
+/// dynamic parameter in its place.
+#[derive(Default)]
+struct Parameterizer {
+    literals: Vec<Literal>,
+}
+
+impl VisitorMut for Parameterizer {
+    fn visit_value_specification(&mut self, value_specification: &mut ValueSpecification) {
+        match std::mem::replace(value_specification, ValueSpecification::DynamicParameter) {
+            ValueSpecification::Literal(literal) => self.literals.push(literal),
+            other => {
+                *value_specification = other;
+                walk_value_specification(self, value_specification);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_statement;
+
+    use super::*;
+
+    #[test_case(
+        "CALL f(1, 'it''s', :name, NULL, ?)",
+        "CALL f(?, ?, :name, NULL, ?)",
+        &[Literal::Numeric("1".to_string()), Literal::CharacterString("it's".to_string())] ;
+        "call arguments"
+    )]
+    #[test_case(
+        "UPDATE tb SET a = -1.5, b = TRUE, c = NULL WHERE CURRENT OF cur",
+        "UPDATE tb SET a = ?, b = ?, c = NULL WHERE CURRENT OF cur",
+        &[Literal::Numeric("-1.5".to_string()), Literal::True] ;
+        "update set clauses"
+    )]
+    #[test_case(
+        "DROP TABLE tb CASCADE",
+        "DROP TABLE tb CASCADE",
+        &[] ;
+        "no literals"
+    )]
+    fn parameterize(input: &str, expected: &str, literals: &[Literal]) {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+        let parameterized = ParameterizedStatement::new(&statement);
+
+        assert_str_eq!(expected, parameterized.to_string());
+        assert_eq!(literals, parameterized.literals());
+    }
+
+    #[test]
+    fn parameterized_statement_is_reparsable() {
+        let (_, statement) = parse_statement(b"RETURN 'done'").unwrap();
+        let (parameterized, literals) = statement.parameterize().into_parts();
+
+        let (_, reparsed) = parse_statement(parameterized.to_string().as_ref()).unwrap();
+        assert_eq!(parameterized, reparsed);
+        assert_eq!(vec![Literal::CharacterString("done".to_string())], literals);
+    }
+}
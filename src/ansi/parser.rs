@@ -2,18 +2,57 @@ use nom::branch::alt;
 use nom::combinator::map;
 use nom::IResult;
 
+use crate::ansi::ast::common::{ColumnDefinition, IdentitySyntax, Literal, ValueSpecification};
+use crate::ansi::ast::create_table::{TableConstraint, TableConstraintDefinition};
+use crate::ansi::ast::data_types::DataType;
+use crate::ansi::keywords::is_reserved_word;
+use crate::ansi::lexer::{LexemeKind, Lexer};
+use crate::ansi::normalize::{IdentifierCase, NormalizeOptions};
+use crate::ansi::parser::alter_table::{alter_table, rename_table};
+use crate::ansi::parser::call::call;
+use crate::ansi::parser::close::close;
 use crate::ansi::parser::create_schema::create_schema;
-use crate::ansi::parser::create_table::create_table;
+use crate::ansi::parser::create_table::{create_table, create_table_allowing_empty};
+use crate::ansi::parser::delete::delete_positioned;
 use crate::ansi::parser::drop_schema::drop_schema;
 use crate::ansi::parser::drop_table::drop_table;
+use crate::ansi::parser::explain::explain;
+use crate::ansi::parser::fetch::fetch;
+use crate::ansi::parser::grant::grant_privilege;
+use crate::ansi::parser::open::open;
+use crate::ansi::parser::options::{ParserMode, ParserOptions};
+use crate::ansi::parser::return_statement::return_statement;
+use crate::ansi::parser::revoke::revoke_privilege;
+use crate::ansi::parser::update::update_positioned;
+use crate::ansi::visitor::{walk_column_definition, walk_table_constraint_definition, VisitorMut};
 use crate::ansi::Statement;
+use crate::common::dialect::StringEscapes;
+use crate::common::expr::{BinaryNotation, BinaryRadix};
+use crate::common::parsers::whitespace_len;
+use crate::common::report::Report;
+use crate::common::span::Span;
+use crate::common::split::{statement_spans, BatchSeparator};
+use crate::common::{Ident, QuoteStyle};
 
+pub mod alter_table;
+pub mod call;
+pub mod close;
 pub mod common;
 pub mod create_schema;
 pub mod create_table;
 pub mod data_types;
+pub mod delete;
+pub mod detect;
 pub mod drop_schema;
 pub mod drop_table;
+pub mod explain;
+pub mod fetch;
+pub mod grant;
+pub mod open;
+pub mod options;
+pub mod return_statement;
+pub mod revoke;
+pub mod update;
 
 /// Parses a `Statement` [(1)] from the give input.
 ///
@@ -28,5 +67,703 @@ pub fn parse_statement(i: &[u8]) -> IResult<&[u8], Statement> {
         map(drop_schema, Statement::DropSchema),
         map(drop_table, Statement::DropTable),
         map(create_table, Statement::CreateTable),
+        map(alter_table, Statement::AlterTable),
+        map(rename_table, Statement::AlterTable),
+        map(open, Statement::Open),
+        map(fetch, Statement::Fetch),
+        map(close, Statement::Close),
+        map(call, Statement::Call),
+        map(return_statement, Statement::Return),
+        map(update_positioned, Statement::UpdatePositioned),
+        map(delete_positioned, Statement::DeletePositioned),
+        map(explain, Statement::Explain),
+        map(grant_privilege, Statement::GrantPrivilege),
+        map(revoke_privilege, Statement::RevokePrivilege),
     ))(i)
 }
+
+/// Errors raised when parsing a script [(1)].
+///
+/// [(1)]: parse_script_with
+#[derive(thiserror::Error, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ScriptError {
+    /// The statement starting at the span start can't be parsed. The span ends
+    /// at the end of the script.
+    #[error("invalid statement at {0}")]
+    InvalidStatement(Span),
+    /// The statement is longer than the maximum statement length.
+    #[error("statement at {0} is too long")]
+    StatementTooLong(Span),
+    /// The statement has more nested parentheses than the maximum nesting
+    /// depth.
+    #[error("statement at {0} is nested too deeply")]
+    NestingTooDeep(Span),
+    /// The statement is not terminated by a semicolon, which is required.
+    #[error("statement at {0} is missing a semicolon")]
+    MissingSemicolon(Span),
+    /// The statement uses a reserved word as an unquoted identifier, which is
+    /// not allowed in strict mode.
+    #[error("statement at {0} uses a reserved word as identifier")]
+    ReservedWordIdentifier(Span),
+    /// The statement is a dialect extension (e.g., `EXPLAIN` or renaming a
+    /// table), which is not allowed in strict mode.
+    #[error("statement at {0} is a dialect extension")]
+    DialectExtension(Span),
+    /// The comment is between or inside the statements, where the formatter
+    /// [(1)] can't keep it. The span is the one of the comment.
+    ///
+    /// [(1)]: crate::ansi::format::format_script
+    #[error("comment at {0} can't be kept by the formatter")]
+    MisplacedComment(Span),
+}
+
+impl ScriptError {
+    /// Returns the span of the statement that raised the error, or of the
+    /// misplaced comment [(1)].
+    ///
+    /// [(1)]: Self::MisplacedComment
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        match self {
+            Self::InvalidStatement(span)
+            | Self::StatementTooLong(span)
+            | Self::NestingTooDeep(span)
+            | Self::MissingSemicolon(span)
+            | Self::ReservedWordIdentifier(span)
+            | Self::DialectExtension(span)
+            | Self::MisplacedComment(span) => *span,
+        }
+    }
+}
+
+impl From<ScriptError> for Report {
+    /// Creates a report of the error, pointing to the statement that raised
+    /// it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::parse_script;
+    /// # use sql_helper::common::report::Report;
+    /// let source = "DROP TABLE a CASCADE;\nDROP TABLE;";
+    /// let error = parse_script(source).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     Report::from(error).render(source),
+    ///     "error: invalid statement\n \
+    ///      --> 2:1\n  \
+    ///       |\n\
+    ///      2 | DROP TABLE;\n  \
+    ///       | ^^^^^^^^^^^ this statement can't be parsed\n"
+    /// );
+    /// ```
+    fn from(error: ScriptError) -> Self {
+        let (message, label) = match error {
+            ScriptError::InvalidStatement(_) => {
+                ("invalid statement", "this statement can't be parsed")
+            }
+            ScriptError::StatementTooLong(_) => (
+                "statement is too long",
+                "exceeds the maximum statement length",
+            ),
+            ScriptError::NestingTooDeep(_) => (
+                "statement is nested too deeply",
+                "exceeds the maximum nesting depth",
+            ),
+            ScriptError::MissingSemicolon(_) => (
+                "statement is missing a semicolon",
+                "expected a semicolon after this statement",
+            ),
+            ScriptError::ReservedWordIdentifier(_) => (
+                "statement uses a reserved word as identifier",
+                "quote the identifier to use a reserved word",
+            ),
+            ScriptError::DialectExtension(_) => (
+                "statement is a dialect extension",
+                "not allowed in strict mode",
+            ),
+            ScriptError::MisplacedComment(_) => (
+                "comment can't be formatted",
+                "only comments before or after all the statements are kept",
+            ),
+        };
+
+        Self::new(format!("error: {message}"), error.span()).with_label(label)
+    }
+}
+
+/// Parses all the statements of a script, returning each one with its span.
+///
+/// The spans don't include the statement terminator nor the whitespaces
+/// around the statement, so they can be used to echo the exact source text of
+/// a statement (e.g., when reporting errors found by a later analysis).
+///
+/// # Errors
+/// If any statement of the script can't be parsed [(1)].
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::parser::parse_script;
+/// let sql = "DROP TABLE a CASCADE;\n  DROP TABLE b RESTRICT;";
+/// let statements = parse_script(sql).unwrap();
+///
+/// assert_eq!(statements[1].1.source_text(sql), Some("DROP TABLE b RESTRICT"));
+/// ```
+///
+/// [(1)]: ScriptError
+#[doc(alias = "parse_statements")]
+pub fn parse_script(sql: &str) -> Result<Vec<(Statement, Span)>, ScriptError> {
+    parse_script_with(sql, &ParserOptions::new())
+}
+
+/// Parses all the statements of a script, as [`parse_script`], applying the
+/// received options to each statement.
+///
+/// # Errors
+/// If any statement of the script can't be parsed, or doesn't respect the
+/// options [(1)].
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::normalize::IdentifierCase;
+/// # use sql_helper::ansi::parser::options::ParserOptions;
+/// # use sql_helper::ansi::parser::{parse_script_with, ScriptError};
+/// # use sql_helper::common::span::Span;
+/// let options = ParserOptions::new()
+///     .with_identifier_case(IdentifierCase::Lower)
+///     .with_require_semicolon(true);
+///
+/// let statements = parse_script_with("DROP TABLE Users CASCADE;", &options).unwrap();
+/// assert_eq!(statements[0].0.to_string(), "DROP TABLE users CASCADE");
+///
+/// assert_eq!(
+///     parse_script_with("DROP TABLE Users CASCADE", &options),
+///     Err(ScriptError::MissingSemicolon(Span::new(0, 24)))
+/// );
+/// ```
+///
+/// [(1)]: ScriptError
+pub fn parse_script_with(
+    sql: &str,
+    options: &ParserOptions,
+) -> Result<Vec<(Statement, Span)>, ScriptError> {
+    if options.batch_separator() == BatchSeparator::Semicolon {
+        return parse_statements(sql, Span::new(0, sql.len()), options);
+    }
+
+    let mut statements = vec![];
+    // OBS: the statements are split with the escapes of their string literals.
+    for span in statement_spans(
+        sql,
+        options.batch_separator(),
+        StringEscapes::EscapeStringPrefix,
+    ) {
+        statements.extend(parse_statements(sql, span, options)?);
+    }
+
+    Ok(statements)
+}
+
+/// Parses the statements inside the span of the script, applying the options
+/// to each statement.
+fn parse_statements(
+    sql: &str,
+    span: Span,
+    options: &ParserOptions,
+) -> Result<Vec<(Statement, Span)>, ScriptError> {
+    let bytes = sql.as_bytes();
+    let mut statements = vec![];
+    let mut offset = span.start();
+
+    loop {
+        offset += whitespace_len(&bytes[offset..span.end()]);
+        if offset >= span.end() {
+            break;
+        }
+
+        let Ok((remaining, statement)) = parse_statement_with(&bytes[offset..span.end()], options)
+        else {
+            return Err(ScriptError::InvalidStatement(Span::new(offset, span.end())));
+        };
+
+        // OBS: the statement ends at its last token, so neither the terminator
+        // nor the comments around it are part of its span.
+        let end = span.end() - remaining.len();
+        let mut tokens = Lexer::new(&sql[offset..end])
+            .filter(|lexeme| !lexeme.kind().is_trivia())
+            .collect::<Vec<_>>();
+        let terminated = tokens
+            .last()
+            .is_some_and(|lexeme| lexeme.kind() == LexemeKind::Semicolon);
+        if terminated {
+            tokens.pop();
+        }
+        let statement_end = offset + tokens.last().map_or(0, |lexeme| lexeme.span().end());
+        let statement_text = &sql[offset..statement_end];
+        let span = Span::new(offset, statement_end);
+
+        if options.require_semicolon()
+            && options.batch_separator() == BatchSeparator::Semicolon
+            && !terminated
+        {
+            return Err(ScriptError::MissingSemicolon(span));
+        }
+        if options
+            .opt_max_statement_length()
+            .is_some_and(|max_statement_length| statement_text.len() > max_statement_length)
+        {
+            return Err(ScriptError::StatementTooLong(span));
+        }
+        if options
+            .opt_max_nesting_depth()
+            .is_some_and(|max_nesting_depth| nesting_depth(statement_text) > max_nesting_depth)
+        {
+            return Err(ScriptError::NestingTooDeep(span));
+        }
+
+        let mut statement = statement;
+        if options.mode() == ParserMode::Strict {
+            let mut dialect_extension_finder = DialectExtensionFinder::default();
+            dialect_extension_finder.visit_statement(&mut statement);
+            if is_dialect_extension(&statement) || dialect_extension_finder.found {
+                return Err(ScriptError::DialectExtension(span));
+            }
+            let mut finder = ReservedWordFinder::default();
+            finder.visit_statement(&mut statement);
+            if finder.found {
+                return Err(ScriptError::ReservedWordIdentifier(span));
+            }
+        }
+        if options.identifier_case() != IdentifierCase::Preserve {
+            statement = statement
+                .normalize(NormalizeOptions::new().with_identifier_case(options.identifier_case()));
+        }
+
+        statements.push((statement, span));
+
+        offset = end;
+    }
+
+    Ok(statements)
+}
+
+/// Parses a statement, as [`parse_statement`], also accepting the extensions
+/// enabled by the options.
+fn parse_statement_with<'a>(i: &'a [u8], options: &ParserOptions) -> IResult<&'a [u8], Statement> {
+    parse_statement(i).or_else(|err| {
+        if options.mode() == ParserMode::Permissive && options.allow_empty_table_element_list() {
+            map(create_table_allowing_empty, Statement::CreateTable)(i)
+        } else {
+            Err(err)
+        }
+    })
+}
+
+/// Returns whether the statement, or any of its clauses, is a dialect
+/// extension not defined by the SQL standard.
+fn is_dialect_extension(statement: &Statement) -> bool {
+    match statement {
+        Statement::Explain(_) => true,
+        Statement::CreateTable(create_table) => {
+            !create_table.table_options().is_empty() || create_table.opt_partition_by().is_some()
+        }
+        Statement::AlterTable(alter_table) => {
+            alter_table.alter_table_action().is_dialect_extension()
+        }
+        _ => false,
+    }
+}
+
+/// Returns the maximum depth of nested parentheses in the statement, ignoring
+/// the ones in string literals, quoted identifiers and comments.
+fn nesting_depth(statement: &str) -> usize {
+    let mut depth = 0_usize;
+    let mut max_depth = 0;
+
+    for lexeme in Lexer::new(statement) {
+        match (lexeme.kind(), lexeme.text(statement)) {
+            (LexemeKind::Punctuation, "(") => {
+                depth += 1;
+                max_depth = max_depth.max(depth);
+            }
+            (LexemeKind::Punctuation, ")") => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    max_depth
+}
+
+/// Visitor that looks for reserved words used as unquoted identifiers.
+#[derive(Default)]
+struct ReservedWordFinder {
+    found: bool,
+}
+
+impl VisitorMut for ReservedWordFinder {
+    fn visit_ident(&mut self, ident: &mut Ident) {
+        self.found |= *ident.quote_style() == QuoteStyle::None && is_reserved_word(ident.value());
+    }
+}
+
+/// Visitor that looks for data types and identity columns syntaxes that are
+/// dialect extensions.
+#[derive(Default)]
+struct DialectExtensionFinder {
+    found: bool,
+}
+
+impl VisitorMut for DialectExtensionFinder {
+    fn visit_column_definition(&mut self, column_definition: &mut ColumnDefinition) {
+        self.found |= column_definition
+            .opt_identity_column_specification()
+            .is_some_and(|identity| identity.syntax() != IdentitySyntax::Standard);
+        walk_column_definition(self, column_definition);
+    }
+
+    fn visit_table_constraint_definition(
+        &mut self,
+        table_constraint_definition: &mut TableConstraintDefinition,
+    ) {
+        self.found |= match table_constraint_definition.table_constraint() {
+            TableConstraint::Unique(unique_constraint_definition) => unique_constraint_definition
+                .key_columns()
+                .iter()
+                .any(|key_column| key_column.opt_ordering().is_some()),
+            TableConstraint::Referential(_) => false,
+        };
+        walk_table_constraint_definition(self, table_constraint_definition);
+    }
+
+    fn visit_value_specification(&mut self, value_specification: &mut ValueSpecification) {
+        self.found |= match value_specification {
+            ValueSpecification::Literal(
+                Literal::EscapeString(_) | Literal::DollarQuotedString(_),
+            ) => true,
+            ValueSpecification::Literal(Literal::Binary(binary_literal)) => {
+                binary_literal.radix() != BinaryRadix::Hexadecimal
+                    || binary_literal.notation() != BinaryNotation::Quoted
+            }
+            _ => false,
+        };
+    }
+
+    fn visit_data_type(&mut self, data_type: &mut DataType) {
+        self.found |= matches!(data_type, DataType::NumericAlias(_));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::ast::create_table::TableContentsSource;
+
+    use super::*;
+
+    #[test_case("", &[] ; "empty")]
+    #[test_case("  DROP TABLE a CASCADE  ", &["DROP TABLE a CASCADE"] ; "single statement")]
+    #[test_case(
+        "DROP TABLE a CASCADE;\nDROP SCHEMA b RESTRICT ;\n\n",
+        &["DROP TABLE a CASCADE", "DROP SCHEMA b RESTRICT"] ;
+        "multiple statements"
+    )]
+    #[test_case(
+        "DROP TABLE a CASCADE;\n\ncreate table  tb (\n    id INT\n) ;",
+        &["DROP TABLE a CASCADE", "create table  tb (\n    id INT\n)"] ;
+        "multiline statement"
+    )]
+    fn script_spans(sql: &str, expected: &[&str]) {
+        let spans = parse_script(sql)
+            .unwrap()
+            .iter()
+            .map(|(_, span)| span.source_text(sql).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected, spans);
+    }
+
+    #[test_case(
+        "DROP TABLE a CASCADE;\nDROP TABLE b CASCADE",
+        ParserOptions::new().with_require_semicolon(true),
+        ScriptError::MissingSemicolon(Span::new(22, 42)) ;
+        "missing semicolon"
+    )]
+    #[test_case(
+        "DROP TABLE a CASCADE -- x;",
+        ParserOptions::new().with_require_semicolon(true),
+        ScriptError::MissingSemicolon(Span::new(0, 20)) ;
+        "semicolon inside comment"
+    )]
+    #[test_case(
+        "DROP TABLE abc CASCADE;",
+        ParserOptions::new().with_max_statement_length(21),
+        ScriptError::StatementTooLong(Span::new(0, 22)) ;
+        "statement too long"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a DECIMAL(10, 2), \"(\" INT)",
+        ParserOptions::new().with_max_nesting_depth(1),
+        ScriptError::NestingTooDeep(Span::new(0, 43)) ;
+        "nesting too deep"
+    )]
+    #[test_case(
+        "DROP TABLE tb CASCADE; CREATE TABLE tb (\"select\" INT, \"from\" INT, user INT)",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::ReservedWordIdentifier(Span::new(23, 75)) ;
+        "strict reserved word"
+    )]
+    #[test_case(
+        "CLOSE c; EXPLAIN CLOSE c",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(9, 24)) ;
+        "strict dialect extension"
+    )]
+    #[test_case(
+        "ALTER TABLE tb RENAME COLUMN a TO b",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(0, 35)) ;
+        "strict rename column"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT8)",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(0, 24)) ;
+        "strict numeric type alias"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT) ENGINE=InnoDB",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(0, 37)) ;
+        "strict table options"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT) PARTITION BY HASH (a)",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(0, 45)) ;
+        "strict partition clause"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT AUTO_INCREMENT)",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(0, 38)) ;
+        "strict auto increment"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a SERIAL)",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(0, 26)) ;
+        "strict serial"
+    )]
+    #[test_case(
+        "CALL f($$a$$)",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(0, 13)) ;
+        "strict dollar quoted string"
+    )]
+    #[test_case(
+        "CALL f(E'a\\tb')",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(0, 15)) ;
+        "strict escape string"
+    )]
+    #[test_case(
+        "CALL f(X'FF', B'1010')",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(0, 22)) ;
+        "strict bit string"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT, PRIMARY KEY (a DESC))",
+        ParserOptions::new().with_mode(ParserMode::Strict),
+        ScriptError::DialectExtension(Span::new(0, 45)) ;
+        "strict key column ordering"
+    )]
+    #[test_case(
+        "CREATE TABLE tb ()",
+        ParserOptions::new(),
+        ScriptError::InvalidStatement(Span::new(0, 18)) ;
+        "empty table element list"
+    )]
+    #[test_case(
+        "CREATE TABLE tb ()",
+        ParserOptions::new()
+            .with_mode(ParserMode::Strict)
+            .with_allow_empty_table_element_list(true),
+        ScriptError::InvalidStatement(Span::new(0, 18)) ;
+        "strict empty table element list"
+    )]
+    fn script_options_errors(sql: &str, options: ParserOptions, expected: ScriptError) {
+        assert_eq!(Err(expected), parse_script_with(sql, &options));
+    }
+
+    #[test_case(
+        "DROP TABLE a CASCADE; -- note\nDROP TABLE b CASCADE; /* end */",
+        &["DROP TABLE a CASCADE", "DROP TABLE b CASCADE"] ;
+        "comments after the terminator"
+    )]
+    #[test_case(
+        "-- header\n/* hint */ DROP TABLE a CASCADE;",
+        &["DROP TABLE a CASCADE"] ;
+        "comments before the first statement"
+    )]
+    #[test_case(
+        "DROP TABLE a CASCADE /* a; */ ;\n-- b;\nDROP TABLE b CASCADE;",
+        &["DROP TABLE a CASCADE", "DROP TABLE b CASCADE"] ;
+        "comments with semicolons"
+    )]
+    fn script_spans_with_comments(sql: &str, expected: &[&str]) {
+        let options = ParserOptions::new().with_require_semicolon(true);
+        let spans = parse_script_with(sql, &options)
+            .unwrap()
+            .iter()
+            .map(|(_, span)| span.source_text(sql).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected, spans);
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (a DECIMAL(10, 2), \"(\" INT)",
+        ParserOptions::new().with_max_nesting_depth(2) ;
+        "nesting within limit"
+    )]
+    #[test_case(
+        "CALL f($$(((a)))$$);\nCALL g($fn$;$fn$)",
+        ParserOptions::new().with_max_nesting_depth(1) ;
+        "dollar quoted strings"
+    )]
+    #[test_case(
+        "CALL f('(((', E'\\'((');\nCALL g(1) -- ((\n/* (( */",
+        ParserOptions::new().with_max_nesting_depth(1) ;
+        "string literals and comments"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (\"select\" INT)",
+        ParserOptions::new().with_mode(ParserMode::Strict) ;
+        "strict quoted reserved word"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT, CONSTRAINT pk PRIMARY KEY (a), UNIQUE (a))",
+        ParserOptions::new().with_mode(ParserMode::Strict) ;
+        "strict table constraints"
+    )]
+    #[test_case(
+        "ALTER TABLE tb ALTER COLUMN a SET DATA TYPE DOUBLE",
+        ParserOptions::new() ;
+        "numeric type alias"
+    )]
+    #[test_case(
+        "CREATE TABLE tb ( );\nDROP TABLE tb CASCADE",
+        ParserOptions::new().with_allow_empty_table_element_list(true) ;
+        "empty table element list"
+    )]
+    #[test_case(
+        "DROP TABLE abc CASCADE;",
+        ParserOptions::new()
+            .with_max_statement_length(22)
+            .with_require_semicolon(true) ;
+        "within limits"
+    )]
+    fn script_options_accepted(sql: &str, options: ParserOptions) {
+        assert!(parse_script_with(sql, &options).is_ok());
+    }
+
+    #[test_case("CREATE TABLE t (a INT, PRIMARY KEY (a DESC))" ; "primary key")]
+    #[test_case(
+        "CREATE TABLE t (a INT, b INT, CONSTRAINT s.u UNIQUE (b ASC, a))" ;
+        "named unique constraint"
+    )]
+    #[test_case("CREATE TABLE t (UNIQUE (a), a INT)" ; "constraint before the columns")]
+    #[test_case("CREATE TABLE t (a INT REFERENCES u (b))" ; "column foreign key")]
+    #[test_case(
+        "CREATE TABLE t (a INT NOT NULL CONSTRAINT fk REFERENCES u (b, PERIOD p))" ;
+        "column foreign key with period"
+    )]
+    #[test_case(
+        "CREATE TABLE t (a INT, CONSTRAINT fk FOREIGN KEY (a, PERIOD p) REFERENCES u (b, PERIOD q) \
+         MATCH SIMPLE ON DELETE SET NULL ON UPDATE CASCADE)" ;
+        "table foreign key"
+    )]
+    fn script_table_constraints(sql: &str) {
+        let statements = parse_script(sql)
+            .unwrap()
+            .into_iter()
+            .map(|(statement, _)| statement.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![sql.to_string()], statements);
+    }
+
+    #[test_case(
+        "DROP TABLE a CASCADE\nGO\nDROP TABLE b CASCADE; DROP TABLE c CASCADE\ngo 2",
+        BatchSeparator::Go,
+        &["DROP TABLE a CASCADE", "DROP TABLE b CASCADE", "DROP TABLE c CASCADE"] ;
+        "go"
+    )]
+    #[test_case(
+        "DELIMITER //\nDROP TABLE a CASCADE//\nDELIMITER ;\nDROP TABLE b CASCADE;",
+        BatchSeparator::Delimiter,
+        &["DROP TABLE a CASCADE", "DROP TABLE b CASCADE"] ;
+        "delimiter"
+    )]
+    fn script_batch_separator(sql: &str, separator: BatchSeparator, expected: &[&str]) {
+        let options = ParserOptions::new()
+            .with_batch_separator(separator)
+            .with_require_semicolon(true);
+        let spans = parse_script_with(sql, &options)
+            .unwrap()
+            .iter()
+            .map(|(_, span)| span.source_text(sql).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected, spans);
+    }
+
+    #[test]
+    fn script_batch_separator_invalid_statement() {
+        let options = ParserOptions::new().with_batch_separator(BatchSeparator::Go);
+
+        assert_eq!(
+            parse_script_with("DROP TABLE a RESTRICT\nGO\nSELECT 1\nGO\n", &options),
+            Err(ScriptError::InvalidStatement(Span::new(25, 33)))
+        );
+    }
+
+    #[test]
+    fn empty_table_element_list() {
+        let options = ParserOptions::new().with_allow_empty_table_element_list(true);
+        let statements = parse_script_with("CREATE TABLE tb ( )", &options).unwrap();
+
+        let Statement::CreateTable(create_table) = &statements[0].0 else {
+            unreachable!();
+        };
+        let TableContentsSource::TableElementList(table_element_list) =
+            create_table.table_contents_source();
+        assert!(table_element_list.element_list().is_empty());
+        assert_eq!("CREATE TABLE tb ()", create_table.to_string());
+    }
+
+    #[test]
+    fn script_identifier_case() {
+        let options = ParserOptions::new().with_identifier_case(IdentifierCase::Upper);
+        let statements = parse_script_with("CREATE TABLE Sch.tb (Id INT, \"Name\" CLOB)", &options)
+            .unwrap()
+            .iter()
+            .map(|(statement, _)| statement.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec!["CREATE TABLE SCH.TB (ID INT, \"Name\" CLOB)"],
+            statements
+        );
+    }
+
+    #[test]
+    fn script_invalid_statement() {
+        assert_eq!(
+            parse_script("DROP TABLE a RESTRICT;\nSELECT 1"),
+            Err(ScriptError::InvalidStatement(Span::new(23, 31)))
+        );
+    }
+}
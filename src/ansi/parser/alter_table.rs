@@ -0,0 +1,122 @@
+use nom::branch::alt;
+use nom::combinator::{map, opt};
+use nom::sequence::{delimited, pair, preceded, separated_pair, terminated, tuple};
+use nom::IResult;
+
+use crate::ansi::ast::alter_table::{AlterColumnAction, AlterTable, AlterTableAction};
+use crate::ansi::parser::common::{column_definition, drop_behavior, table_name};
+use crate::ansi::parser::data_types::data_type;
+use crate::common::parsers::{delimited_ws1, ident, keyword, statement_terminator, terminated_ws1};
+
+/// Parses an `ALTER TABLE` statement.
+///
+/// # Errors
+/// If the alter table statement is malformed or has unsupported features, this
+/// function call will fail. Check the alter table statement documentation
+/// [(1)][`AlterTable`] for supported syntax.
+pub fn alter_table(i: &[u8]) -> IResult<&[u8], AlterTable> {
+    let (i, (table_name, alter_table_action)) = delimited(
+        pair(
+            terminated_ws1(keyword("ALTER")),
+            terminated_ws1(keyword("TABLE")),
+        ),
+        pair(terminated_ws1(table_name), alter_table_action),
+        statement_terminator,
+    )(i)?;
+
+    let alter_table = AlterTable::new(table_name, alter_table_action);
+
+    Ok((i, alter_table))
+}
+
+/// Parses a `RENAME TABLE` statement, a dialect extension, as the equivalent
+/// `ALTER TABLE <table name> RENAME TO <table name>` [(1)].
+///
+/// # Errors
+/// If the statement is not a case-insensitive `RENAME TABLE` followed by the
+/// table name, `TO` and the new table name, this function call will fail.
+///
+/// [(1)]: AlterTableAction::RenameTable
+pub fn rename_table(i: &[u8]) -> IResult<&[u8], AlterTable> {
+    let (i, (table_name, new_table_name)) = delimited(
+        terminated_ws1(keyword("RENAME TABLE")),
+        separated_pair(table_name, delimited_ws1(keyword("TO")), table_name),
+        statement_terminator,
+    )(i)?;
+
+    let alter_table = AlterTable::new(table_name, AlterTableAction::RenameTable(new_table_name));
+
+    Ok((i, alter_table))
+}
+
+fn alter_table_action(i: &[u8]) -> IResult<&[u8], AlterTableAction> {
+    alt((
+        map(
+            preceded(column_keyword("ADD"), column_definition),
+            AlterTableAction::AddColumn,
+        ),
+        map(
+            preceded(
+                column_keyword("ALTER"),
+                pair(terminated_ws1(ident), alter_column_action),
+            ),
+            |(column_name, alter_column_action)| {
+                AlterTableAction::AlterColumn(column_name, alter_column_action)
+            },
+        ),
+        map(
+            preceded(
+                column_keyword("DROP"),
+                pair(terminated_ws1(ident), drop_behavior),
+            ),
+            |(column_name, drop_behavior)| AlterTableAction::DropColumn(column_name, drop_behavior),
+        ),
+        map(
+            preceded(
+                pair(
+                    terminated_ws1(keyword("RENAME")),
+                    terminated_ws1(keyword("TO")),
+                ),
+                table_name,
+            ),
+            AlterTableAction::RenameTable,
+        ),
+        map(
+            preceded(
+                column_keyword("RENAME"),
+                separated_pair(ident, delimited_ws1(keyword("TO")), ident),
+            ),
+            |(column_name, new_column_name)| {
+                AlterTableAction::RenameColumn(column_name, new_column_name)
+            },
+        ),
+    ))(i)
+}
+
+fn alter_column_action(i: &[u8]) -> IResult<&[u8], AlterColumnAction> {
+    alt((
+        map(
+            preceded(
+                tuple((
+                    terminated_ws1(keyword("SET")),
+                    terminated_ws1(keyword("DATA")),
+                    terminated_ws1(keyword("TYPE")),
+                )),
+                data_type,
+            ),
+            AlterColumnAction::SetDataType,
+        ),
+        map(
+            pair(terminated_ws1(keyword("DROP")), keyword("IDENTITY")),
+            |_| AlterColumnAction::DropIdentity,
+        ),
+    ))(i)
+}
+
+/// Parses the action keyword, followed by the optional `COLUMN` keyword.
+fn column_keyword<'a>(action: &'static str) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], &'a [u8]> {
+    terminated(
+        terminated_ws1(keyword(action)),
+        opt(terminated_ws1(keyword("COLUMN"))),
+    )
+}
@@ -0,0 +1,46 @@
+use nom::multi::separated_list0;
+use nom::sequence::{delimited, pair};
+use nom::IResult;
+
+use crate::ansi::ast::call::Call;
+use crate::ansi::parser::common::{routine_name, value_specification};
+use crate::common::parsers::{
+    delimited_ws0, keyword, paren_delimited, preceded_ws0, statement_terminator, terminated_ws1,
+};
+use crate::common::tokens::comma;
+
+/// Parses a `CALL` statement.
+///
+/// # Errors
+/// If the call statement is malformed or has unsupported features, this
+/// function call will fail. Check the call statement documentation
+/// [(1)][`Call`] for supported syntax.
+pub fn call(i: &[u8]) -> IResult<&[u8], Call> {
+    let (i, (routine_name, arguments)) = delimited(
+        terminated_ws1(keyword("CALL")),
+        pair(
+            routine_name,
+            preceded_ws0(paren_delimited(separated_list0(
+                delimited_ws0(comma),
+                value_specification,
+            ))),
+        ),
+        statement_terminator,
+    )(i)?;
+
+    Ok((i, Call::new(routine_name, arguments)))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("CALL proc()", "CALL proc()")]
+    #[test_case("call sch.proc ( 1,'a' , :b )", "CALL sch.proc(1, 'a', :b)")]
+    fn parse_call(input: &str, expected: &str) {
+        assert_str_eq!(expected, call(input.as_bytes()).unwrap().1.to_string());
+    }
+}
@@ -0,0 +1,21 @@
+use nom::sequence::delimited;
+use nom::IResult;
+
+use crate::ansi::ast::close::Close;
+use crate::common::parsers::{ident, keyword, statement_terminator, terminated_ws1};
+
+/// Parses a `CLOSE` statement.
+///
+/// # Errors
+/// If the close statement is malformed or has unsupported features, this
+/// function call will fail. Check the close statement documentation
+/// [(1)][`Close`] for supported syntax.
+pub fn close(i: &[u8]) -> IResult<&[u8], Close> {
+    let (i, cursor_name) = delimited(
+        terminated_ws1(keyword("CLOSE")),
+        ident,
+        statement_terminator,
+    )(i)?;
+
+    Ok((i, Close::new(cursor_name)))
+}
@@ -1,18 +1,27 @@
 use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
-use nom::combinator::{map, opt, peek};
-use nom::multi::separated_list1;
-use nom::sequence::{pair, preceded, terminated, tuple};
+use nom::character::complete::{digit0, digit1, i64};
+use nom::combinator::{map, not, opt, peek, recognize, value};
+use nom::multi::{many0, separated_list1};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::IResult;
 
 use crate::ansi::ast::common::{
-    ColumnDefinition, ColumnNameList, DeleteRule, DropBehavior, LocalOrSchemaQualifier,
-    LocalQualifier, MatchType, ReferentialAction, ReferentialTriggeredAction, SchemaName,
-    SystemVersioningClause, TableName, UpdateRule,
+    ColumnConstraint, ColumnConstraintDefinition, ColumnDefinition, ColumnNameList, DeleteRule,
+    DropBehavior, IdentityColumnSpecification, IdentityGeneration, IdentitySyntax, KeyColumn,
+    Literal, LocalOrSchemaQualifier, LocalQualifier, MatchType, Ordering, ReferencesSpecification,
+    ReferentialAction, ReferentialTriggeredAction, RoutineName, SchemaName, SchemaQualifiedName,
+    SystemVersioningClause, TableName, UniqueSpecification, UpdateRule, ValueSpecification,
 };
+use crate::ansi::ast::data_types::DataType;
 use crate::ansi::parser::data_types::data_type;
-use crate::common::parsers::{delimited_ws0, ident, preceded_ws1, terminated_ws1};
-use crate::common::tokens::{comma, period};
+use crate::common::dialect::StringEscapes;
+use crate::common::parsers::{
+    binary_string_literal, character_string_literal, delimited_ws0, dollar_quoted_string, ident,
+    keyword, paren_delimited, preceded_ws0, preceded_ws1, terminated_ws1, whitespace0,
+};
+use crate::common::tokens::{colon, comma, minus_sign, period, plus_sign, question_mark};
+use crate::common::Ident;
 
 /// Parses a schema name [(1)](SchemaName).
 ///
@@ -22,10 +31,10 @@ use crate::common::tokens::{comma, period};
 pub fn schema_name(i: &[u8]) -> IResult<&[u8], SchemaName> {
     alt((
         map(
-            pair(terminated(ident, period), ident),
-            |(catalog, schema)| SchemaName::new(Some(&catalog), &schema),
+            pair(terminated(ident, delimited_ws0(period)), ident),
+            |(catalog, schema)| SchemaName::new(schema).with_catalog_name(catalog),
         ),
-        map(ident, |schema| SchemaName::new(None, &schema)),
+        map(ident, SchemaName::new),
     ))(i)
 }
 
@@ -35,12 +44,14 @@ pub fn schema_name(i: &[u8]) -> IResult<&[u8], SchemaName> {
 /// If the table name has too many qualifications or invalid structure, this
 /// function call will fail.
 pub fn table_name(i: &[u8]) -> IResult<&[u8], TableName> {
-    let (i, (opt_local_or_schema, name)) =
-        pair(opt(terminated(local_or_schema_qualifier, period)), ident)(i)?;
+    let (i, (opt_local_or_schema, name)) = pair(
+        opt(terminated(local_or_schema_qualifier, delimited_ws0(period))),
+        ident,
+    )(i)?;
 
-    let mut table_name = TableName::new(&name);
+    let mut table_name = TableName::new(name);
     if let Some(local_or_schema) = opt_local_or_schema {
-        table_name.with_local_or_schema(local_or_schema);
+        table_name = table_name.with_local_or_schema(local_or_schema);
     }
 
     Ok((i, table_name))
@@ -73,14 +84,15 @@ pub fn schema_for_qualified_table_name(i: &[u8]) -> IResult<&[u8], SchemaName> {
     alt((
         map(
             terminated(
-                tuple((terminated(ident, period), ident)),
-                peek(tuple((period, ident))),
+                tuple((terminated(ident, delimited_ws0(period)), ident)),
+                peek(tuple((delimited_ws0(period), ident))),
             ),
-            |(catalog, schema)| SchemaName::new(Some(&catalog), &schema),
+            |(catalog, schema)| SchemaName::new(schema).with_catalog_name(catalog),
+        ),
+        map(
+            terminated(ident, peek(tuple((delimited_ws0(period), ident)))),
+            SchemaName::new,
         ),
-        map(terminated(ident, peek(tuple((period, ident)))), |schema| {
-            SchemaName::new(None, &schema)
-        }),
     ))(i)
 }
 
@@ -90,7 +102,7 @@ pub fn schema_for_qualified_table_name(i: &[u8]) -> IResult<&[u8], SchemaName> {
 /// If the input does not match a case-insensitive `MODULE` word, this function
 /// call will fail.
 pub fn local_qualifier(i: &[u8]) -> IResult<&[u8], LocalQualifier> {
-    map(tag_no_case("MODULE"), |_| LocalQualifier::Module)(i)
+    map(keyword("MODULE"), |_| LocalQualifier::Module)(i)
 }
 
 /// Parses a column definition [(1)](ColumnDefinition).
@@ -100,15 +112,170 @@ pub fn local_qualifier(i: &[u8]) -> IResult<&[u8], LocalQualifier> {
 /// call will fail. Check the described syntax on column definition structure to
 /// understand the supported syntax.
 pub fn column_definition(i: &[u8]) -> IResult<&[u8], ColumnDefinition> {
-    let (i, (column_name, opt_data_type)) = pair(ident, opt(preceded_ws1(data_type)))(i)?;
-
-    let mut column_def = ColumnDefinition::new(&column_name);
+    let (i, ((column_name, opt_data_type, opt_identity_column_specification), constraints)) =
+        pair(
+            alt((
+                map(
+                    pair(ident, preceded_ws1(serial_type)),
+                    |(column_name, data_type)| {
+                        let identity_column_specification =
+                            IdentityColumnSpecification::new(IdentityGeneration::ByDefault)
+                                .with_syntax(IdentitySyntax::Serial);
+                        (
+                            column_name,
+                            Some(data_type),
+                            Some(identity_column_specification),
+                        )
+                    },
+                ),
+                tuple((
+                    ident,
+                    opt(preceded_ws1(data_type)),
+                    opt(preceded_ws1(alt((
+                        identity_column_specification,
+                        auto_increment,
+                    )))),
+                )),
+            )),
+            many0(preceded_ws1(column_constraint_definition)),
+        )(i)?;
+
+    let mut column_def = ColumnDefinition::new(column_name);
 
     if let Some(data_type) = opt_data_type {
-        column_def.with_data_type(data_type);
+        column_def = column_def.with_data_type(data_type);
+    }
+
+    if let Some(identity_column_specification) = opt_identity_column_specification {
+        column_def = column_def.with_identity_column_specification(identity_column_specification);
+    }
+
+    Ok((
+        i,
+        column_def.with_column_constraint_definitions(constraints),
+    ))
+}
+
+/// Parses a column constraint definition [(1)](ColumnConstraintDefinition).
+///
+/// # Errors
+/// If the input is not a column constraint, optionally named, this function
+/// call will fail.
+pub fn column_constraint_definition(i: &[u8]) -> IResult<&[u8], ColumnConstraintDefinition> {
+    map(
+        pair(
+            opt(terminated_ws1(constraint_name_definition)),
+            column_constraint,
+        ),
+        |(opt_constraint_name, column_constraint)| {
+            let column_constraint_definition = ColumnConstraintDefinition::new(column_constraint);
+            match opt_constraint_name {
+                Some(constraint_name) => {
+                    column_constraint_definition.with_constraint_name(constraint_name)
+                }
+                None => column_constraint_definition,
+            }
+        },
+    )(i)
+}
+
+/// Parses a column constraint [(1)](ColumnConstraint).
+///
+/// # Errors
+/// If the input is not `NOT NULL`, a unique specification or a references
+/// specification, this function call will fail.
+pub fn column_constraint(i: &[u8]) -> IResult<&[u8], ColumnConstraint> {
+    alt((
+        value(ColumnConstraint::NotNull, keyword("NOT NULL")),
+        map(unique_specification, ColumnConstraint::Unique),
+        map(references_specification, ColumnConstraint::References),
+    ))(i)
+}
+
+/// Parses an identity column specification
+/// [(1)](IdentityColumnSpecification).
+///
+/// # Errors
+/// If the input is not a valid identity column specification, this function
+/// call will fail.
+pub fn identity_column_specification(i: &[u8]) -> IResult<&[u8], IdentityColumnSpecification> {
+    let (i, (identity_generation, opt_options)) = pair(
+        delimited(
+            terminated_ws1(keyword("GENERATED")),
+            identity_generation,
+            preceded_ws1(pair(terminated_ws1(keyword("AS")), keyword("IDENTITY"))),
+        ),
+        opt(preceded_ws0(paren_delimited(pair(
+            opt(preceded(
+                pair(
+                    terminated_ws1(keyword("START")),
+                    terminated_ws1(keyword("WITH")),
+                ),
+                i64,
+            )),
+            opt(preceded(
+                tuple((
+                    whitespace0,
+                    terminated_ws1(keyword("INCREMENT")),
+                    terminated_ws1(keyword("BY")),
+                )),
+                i64,
+            )),
+        )))),
+    )(i)?;
+
+    let mut identity_column_specification = IdentityColumnSpecification::new(identity_generation);
+    if let Some((opt_start_with, opt_increment_by)) = opt_options {
+        if let Some(start_with) = opt_start_with {
+            identity_column_specification =
+                identity_column_specification.with_start_with(start_with);
+        }
+        if let Some(increment_by) = opt_increment_by {
+            identity_column_specification =
+                identity_column_specification.with_increment_by(increment_by);
+        }
     }
 
-    Ok((i, column_def))
+    Ok((i, identity_column_specification))
+}
+
+/// Parses a `PostgreSQL` `SERIAL` type, returning the integer type it
+/// replaces.
+fn serial_type(i: &[u8]) -> IResult<&[u8], DataType> {
+    alt((
+        value(DataType::Smallint, keyword("SMALLSERIAL")),
+        value(DataType::Integer, keyword("SERIAL")),
+        value(DataType::Bigint, keyword("BIGSERIAL")),
+    ))(i)
+}
+
+/// Parses a `MySQL` `AUTO_INCREMENT` column attribute, as a `BY DEFAULT`
+/// identity column specification [(1)](IdentityColumnSpecification).
+///
+/// # Errors
+/// If the input is not a case-insensitive `AUTO_INCREMENT`, this function call
+/// will fail.
+pub fn auto_increment(i: &[u8]) -> IResult<&[u8], IdentityColumnSpecification> {
+    value(
+        IdentityColumnSpecification::new(IdentityGeneration::ByDefault)
+            .with_syntax(IdentitySyntax::AutoIncrement),
+        keyword("AUTO_INCREMENT"),
+    )(i)
+}
+
+/// Parses the identity generation [(1)](IdentityGeneration).
+///
+/// # Errors
+/// If the input is not a case-insensitive `ALWAYS` or `BY DEFAULT`, this
+/// function call will fail.
+pub fn identity_generation(i: &[u8]) -> IResult<&[u8], IdentityGeneration> {
+    alt((
+        map(keyword("ALWAYS"), |_| IdentityGeneration::Always),
+        map(
+            pair(terminated_ws1(keyword("BY")), keyword("DEFAULT")),
+            |_| IdentityGeneration::ByDefault,
+        ),
+    ))(i)
 }
 
 /// Parses the drop behavior [(1)](DropBehavior).
@@ -118,8 +285,8 @@ pub fn column_definition(i: &[u8]) -> IResult<&[u8], ColumnDefinition> {
 /// `CASCADE` keywords, this function call will fail.
 pub fn drop_behavior(i: &[u8]) -> IResult<&[u8], DropBehavior> {
     alt((
-        map(tag_no_case("CASCADE"), |_| DropBehavior::Cascade),
-        map(tag_no_case("RESTRICT"), |_| DropBehavior::Restrict),
+        map(keyword("CASCADE"), |_| DropBehavior::Cascade),
+        map(keyword("RESTRICT"), |_| DropBehavior::Restrict),
     ))(i)
 }
 
@@ -130,13 +297,11 @@ pub fn drop_behavior(i: &[u8]) -> IResult<&[u8], DropBehavior> {
 /// referential action enum, this function will return an error.
 pub fn referential_action(i: &[u8]) -> IResult<&[u8], ReferentialAction> {
     alt((
-        map(tag_no_case("CASCADE"), |_| ReferentialAction::Cascade),
-        map(tag_no_case("SET NULL"), |_| ReferentialAction::SetNull),
-        map(tag_no_case("SET DEFAULT"), |_| {
-            ReferentialAction::SetDefault
-        }),
-        map(tag_no_case("RESTRICT"), |_| ReferentialAction::Restrict),
-        map(tag_no_case("NO ACTION"), |_| ReferentialAction::NoAction),
+        map(keyword("CASCADE"), |_| ReferentialAction::Cascade),
+        map(keyword("SET NULL"), |_| ReferentialAction::SetNull),
+        map(keyword("SET DEFAULT"), |_| ReferentialAction::SetDefault),
+        map(keyword("RESTRICT"), |_| ReferentialAction::Restrict),
+        map(keyword("NO ACTION"), |_| ReferentialAction::NoAction),
     ))(i)
 }
 
@@ -148,7 +313,7 @@ pub fn referential_action(i: &[u8]) -> IResult<&[u8], ReferentialAction> {
 /// error.
 pub fn delete_rule(i: &[u8]) -> IResult<&[u8], DeleteRule> {
     map(
-        preceded(terminated_ws1(tag_no_case("ON DELETE")), referential_action),
+        preceded(terminated_ws1(keyword("ON DELETE")), referential_action),
         DeleteRule::new,
     )(i)
 }
@@ -161,7 +326,7 @@ pub fn delete_rule(i: &[u8]) -> IResult<&[u8], DeleteRule> {
 /// error.
 pub fn update_rule(i: &[u8]) -> IResult<&[u8], UpdateRule> {
     map(
-        preceded(terminated_ws1(tag_no_case("ON UPDATE")), referential_action),
+        preceded(terminated_ws1(keyword("ON UPDATE")), referential_action),
         UpdateRule::new,
     )(i)
 }
@@ -191,21 +356,154 @@ pub fn referential_triggered_action(i: &[u8]) -> IResult<&[u8], ReferentialTrigg
 /// this function call will return an error.
 pub fn match_type(i: &[u8]) -> IResult<&[u8], MatchType> {
     alt((
-        map(tag_no_case("FULL"), |_| MatchType::Full),
-        map(tag_no_case("PARTIAL"), |_| MatchType::Partial),
-        map(tag_no_case("SIMPLE"), |_| MatchType::Simple),
+        map(keyword("FULL"), |_| MatchType::Full),
+        map(keyword("PARTIAL"), |_| MatchType::Partial),
+        map(keyword("SIMPLE"), |_| MatchType::Simple),
     ))(i)
 }
 
+/// Parses a references specification [(1)](ReferencesSpecification).
+///
+/// # Errors
+/// If the referenced table name is malformed, the referenced column list is
+/// empty, or the period specification does not follow a column list, this
+/// function call will return an error.
+pub fn references_specification(i: &[u8]) -> IResult<&[u8], ReferencesSpecification> {
+    let (i, (table_name, opt_referenced_columns, opt_match_type, opt_triggered_action)) = preceded(
+        terminated_ws1(keyword("REFERENCES")),
+        tuple((
+            table_name,
+            opt(preceded_ws0(paren_delimited(column_name_list_with_period))),
+            opt(preceded_ws1(preceded(
+                terminated_ws1(keyword("MATCH")),
+                match_type,
+            ))),
+            opt(preceded_ws1(referential_triggered_action)),
+        )),
+    )(i)?;
+
+    let mut references = ReferencesSpecification::new(table_name);
+    if let Some((referenced_columns, opt_period_name)) = opt_referenced_columns {
+        references = references.with_referenced_columns(referenced_columns);
+        if let Some(period_name) = opt_period_name {
+            references = references.with_referenced_period(period_name);
+        }
+    }
+    if let Some(match_type) = opt_match_type {
+        references = references.with_match_type(match_type);
+    }
+    if let Some(triggered_action) = opt_triggered_action {
+        references = references.with_referential_triggered_action(triggered_action);
+    }
+
+    Ok((i, references))
+}
+
+/// Parses a referenced period specification, returning the application time
+/// period name.
+///
+/// # Errors
+/// If the input is not a case-insensitive `PERIOD` followed by an identifier,
+/// this function call will return an error.
+pub fn referenced_period_specification(i: &[u8]) -> IResult<&[u8], Ident> {
+    preceded(terminated_ws1(keyword("PERIOD")), ident)(i)
+}
+
+/// Parses a column name list [(1)](ColumnNameList) followed by an optional
+/// period specification [(2)](referenced_period_specification), as used by
+/// the referencing and the referenced columns of foreign keys.
+///
+/// # Errors
+/// If the column list has invalid identifiers, or if there's no columns to be
+/// parsed, this function call will return an error.
+pub fn column_name_list_with_period(i: &[u8]) -> IResult<&[u8], (ColumnNameList, Option<Ident>)> {
+    pair(
+        map(
+            separated_list1(
+                delimited_ws0(comma),
+                preceded(not(referenced_period_specification), ident),
+            ),
+            ColumnNameList::new,
+        ),
+        opt(preceded(
+            delimited_ws0(comma),
+            referenced_period_specification,
+        )),
+    )(i)
+}
+
 /// Parses a column name list [(1)](ColumnNameList).
 ///
 /// # Errors
 /// If the column list has invalid identifiers, or if there's no columns to be
 /// parsed, this function call will return an error.
 pub fn column_name_list(i: &[u8]) -> IResult<&[u8], ColumnNameList> {
-    map(separated_list1(delimited_ws0(comma), ident), |list| {
-        ColumnNameList::new(&list)
-    })(i)
+    map(
+        separated_list1(delimited_ws0(comma), ident),
+        ColumnNameList::new,
+    )(i)
+}
+
+/// Parses a key column list [(1)](KeyColumn), as used by unique and primary
+/// key constraints.
+///
+/// # Errors
+/// If the list has invalid identifiers or sort directions, or if there's no
+/// columns to be parsed, this function call will return an error.
+pub fn key_column_list(i: &[u8]) -> IResult<&[u8], Vec<KeyColumn>> {
+    separated_list1(delimited_ws0(comma), key_column)(i)
+}
+
+/// Parses a key column with an optional sort direction [(1)](KeyColumn).
+///
+/// # Errors
+/// If the column name is not a valid identifier, this function call will
+/// return an error.
+pub fn key_column(i: &[u8]) -> IResult<&[u8], KeyColumn> {
+    map(
+        pair(ident, opt(preceded_ws1(ordering))),
+        |(column_name, opt_ordering)| {
+            let key_column = KeyColumn::new(column_name);
+            match opt_ordering {
+                Some(ordering) => key_column.with_ordering(ordering),
+                None => key_column,
+            }
+        },
+    )(i)
+}
+
+/// Parses a sort direction [(1)](Ordering).
+///
+/// # Errors
+/// If the input is not a case-insensitive `ASC` or `DESC`, this function call
+/// will return an error.
+pub fn ordering(i: &[u8]) -> IResult<&[u8], Ordering> {
+    alt((
+        value(Ordering::Asc, keyword("ASC")),
+        value(Ordering::Desc, keyword("DESC")),
+    ))(i)
+}
+
+/// Parses a unique specification [(1)](UniqueSpecification).
+///
+/// # Errors
+/// If the input is not a case-insensitive `UNIQUE` or `PRIMARY KEY`, this
+/// function call will return an error.
+pub fn unique_specification(i: &[u8]) -> IResult<&[u8], UniqueSpecification> {
+    alt((
+        value(UniqueSpecification::Unique, keyword("UNIQUE")),
+        value(UniqueSpecification::PrimaryKey, keyword("PRIMARY KEY")),
+    ))(i)
+}
+
+/// Parses a constraint name definition (i.e., `CONSTRAINT <constraint name>`),
+/// returning the constraint name.
+///
+/// # Errors
+/// If the input is not a case-insensitive `CONSTRAINT` followed by a schema
+/// qualified name [(1)](schema_qualified_name), this function call will fail.
+pub fn constraint_name_definition(i: &[u8]) -> IResult<&[u8], SchemaQualifiedName> {
+    preceded(terminated_ws1(keyword("CONSTRAINT")), schema_qualified_name)(i)
 }
 
 /// Parses a system versioning clause [(1)](SystemVersioningClause).
@@ -248,21 +546,123 @@ pub fn column_name_list(i: &[u8]) -> IResult<&[u8], ColumnNameList> {
 /// ```
 pub fn system_versioning_clause(i: &[u8]) -> IResult<&[u8], SystemVersioningClause> {
     map(
-        pair(
-            tag_no_case("SYSTEM"),
-            preceded_ws1(tag_no_case("VERSIONING")),
-        ),
+        pair(keyword("SYSTEM"), preceded_ws1(keyword("VERSIONING"))),
         |_| SystemVersioningClause {},
     )(i)
 }
 
+/// Parses a routine name [(1)](RoutineName), as any other schema qualified
+/// name [(2)](schema_qualified_name).
+///
+/// # Errors
+/// If the routine name has too many qualifications or invalid structure, this
+/// function call will fail.
+pub fn routine_name(i: &[u8]) -> IResult<&[u8], RoutineName> {
+    schema_qualified_name(i)
+}
+
+/// Parses a schema qualified name [(1)](SchemaQualifiedName).
+///
+/// # Errors
+/// If the name has too many qualifications or invalid structure, this
+/// function call will fail.
+pub fn schema_qualified_name(i: &[u8]) -> IResult<&[u8], SchemaQualifiedName> {
+    let (i, (opt_schema_name, name)) = pair(
+        opt(terminated(
+            schema_for_qualified_table_name,
+            delimited_ws0(period),
+        )),
+        ident,
+    )(i)?;
+
+    let mut schema_qualified_name = SchemaQualifiedName::new(name);
+    if let Some(schema_name) = opt_schema_name {
+        schema_qualified_name = schema_qualified_name.with_schema_name(schema_name);
+    }
+
+    Ok((i, schema_qualified_name))
+}
+
+/// Parses the `WHERE CURRENT OF <cursor name>` clause of positioned
+/// statements, returning the cursor name.
+///
+/// # Errors
+/// If the input is not a case-insensitive `WHERE CURRENT OF` followed by an
+/// identifier, this function call will fail.
+pub fn where_current_of(i: &[u8]) -> IResult<&[u8], Ident> {
+    preceded(
+        tuple((
+            terminated_ws1(keyword("WHERE")),
+            terminated_ws1(keyword("CURRENT")),
+            terminated_ws1(keyword("OF")),
+        )),
+        ident,
+    )(i)
+}
+
+/// Parses a value specification [(1)](ValueSpecification).
+///
+/// # Errors
+/// If the input is not a literal, a parameter or `NULL`, this function call
+/// will fail.
+pub fn value_specification(i: &[u8]) -> IResult<&[u8], ValueSpecification> {
+    alt((
+        map(literal, ValueSpecification::Literal),
+        value(ValueSpecification::Null, keyword("NULL")),
+        map(preceded(colon, ident), ValueSpecification::HostParameter),
+        value(ValueSpecification::DynamicParameter, question_mark),
+        map(ident, ValueSpecification::SqlParameter),
+    ))(i)
+}
+
+/// Parses a literal [(1)](Literal).
+///
+/// Besides the `ANSI` character strings, the `PostgreSQL` escape strings
+/// (`E'...'`) and dollar-quoted strings, and the bit strings (`B'...'`) are
+/// accepted.
+///
+/// # Errors
+/// If the input is not a numeric, character string, escape string,
+/// dollar-quoted string, binary string or boolean literal, this function call
+/// will fail.
+pub fn literal(i: &[u8]) -> IResult<&[u8], Literal> {
+    alt((
+        map(numeric_literal, Literal::Numeric),
+        map(binary_string_literal, Literal::Binary),
+        map(dollar_quoted_string, Literal::DollarQuotedString),
+        character_string_literal(StringEscapes::EscapeStringPrefix),
+        value(Literal::True, keyword("TRUE")),
+        value(Literal::False, keyword("FALSE")),
+        value(Literal::Unknown, keyword("UNKNOWN")),
+    ))(i)
+}
+
+/// Parses a signed numeric literal, returning it as written.
+fn numeric_literal(i: &[u8]) -> IResult<&[u8], String> {
+    map(
+        recognize(tuple((
+            opt(alt((plus_sign, minus_sign))),
+            alt((
+                recognize(pair(digit1, opt(pair(period, digit0)))),
+                recognize(pair(period, digit1)),
+            )),
+            opt(tuple((
+                tag_no_case("E"),
+                opt(alt((plus_sign, minus_sign))),
+                digit1,
+            ))),
+        ))),
+        |literal| String::from_utf8_lossy(literal).into_owned(),
+    )(i)
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_str_eq;
     use test_case::test_case;
 
     use crate::ansi::ast::data_types::DataType;
-    use crate::common::Ident;
+    use crate::common::display_comma_separated;
 
     use super::*;
 
@@ -272,16 +672,28 @@ mod tests {
         let (_, column_def_1) = column_definition(input_1.as_ref()).unwrap();
         assert_eq!(
             column_def_1,
-            *ColumnDefinition::new(&Ident::new(b"name")).with_data_type(DataType::Varchar(None))
+            ColumnDefinition::new("name").with_data_type(DataType::Varchar(None))
         );
 
         let input_2 = "name";
         let (_, column_def_2) = column_definition(input_2.as_ref()).unwrap();
-        assert_eq!(column_def_2, ColumnDefinition::new(&Ident::new(b"name")));
+        assert_eq!(column_def_2, ColumnDefinition::new("name"));
     }
 
     #[test_case("name")]
     #[test_case("name VARCHAR")]
+    #[test_case("id INT GENERATED ALWAYS AS IDENTITY")]
+    #[test_case("code CHAR VARYING GENERATED ALWAYS AS IDENTITY")]
+    #[test_case("id INT GENERATED BY DEFAULT AS IDENTITY (START WITH 1 INCREMENT BY 1)")]
+    #[test_case("id BIGINT GENERATED BY DEFAULT AS IDENTITY (START WITH 10)")]
+    #[test_case("id BIGINT GENERATED ALWAYS AS IDENTITY (INCREMENT BY -1)")]
+    #[test_case("id INT NOT NULL PRIMARY KEY")]
+    #[test_case("id SERIAL CONSTRAINT pk PRIMARY KEY")]
+    #[test_case("name UNIQUE")]
+    #[test_case(
+        "id INT GENERATED ALWAYS AS IDENTITY CONSTRAINT sch.fk REFERENCES tb (a, PERIOD p)"
+    )]
+    #[test_case("id INT REFERENCES tb MATCH FULL ON DELETE CASCADE NOT NULL")]
     fn parse_column_definition_serialisation(input: &str) {
         assert_str_eq!(
             input,
@@ -340,6 +752,73 @@ mod tests {
         assert_str_eq!(input, match_type(input.as_ref()).unwrap().1.to_string());
     }
 
+    #[test_case("REFERENCES tb", "REFERENCES tb")]
+    #[test_case("references sch.tb ( a,b )", "REFERENCES sch.tb (a, b)")]
+    #[test_case(
+        "REFERENCES tb (a, period  valid_time)",
+        "REFERENCES tb (a, PERIOD valid_time)"
+    )]
+    #[test_case("REFERENCES tb (period)", "REFERENCES tb (period)")]
+    #[test_case(
+        "REFERENCES tb (a) MATCH FULL ON DELETE CASCADE",
+        "REFERENCES tb (a) MATCH FULL ON DELETE CASCADE"
+    )]
+    #[test_case(
+        "REFERENCES tb (a, PERIOD p) match simple on update no action",
+        "REFERENCES tb (a, PERIOD p) MATCH SIMPLE ON UPDATE NO ACTION"
+    )]
+    fn parse_references_specification(input: &str, expected: &str) {
+        let (_, references) = references_specification(input.as_ref()).unwrap();
+
+        assert_str_eq!(expected, references.to_string());
+    }
+
+    #[test]
+    fn parse_references_specification_ast() {
+        let (_, references) = references_specification(b"REFERENCES tb (a, PERIOD p)").unwrap();
+
+        assert_eq!(
+            ReferencesSpecification::new(TableName::new("tb"))
+                .with_referenced_columns(ColumnNameList::new(vec!["a".into()]))
+                .with_referenced_period("p"),
+            references
+        );
+    }
+
+    #[test_case("REFERENCES tb (PERIOD p)"; "period without columns")]
+    #[test_case("REFERENCES tb (a, PERIOD p, b)"; "period before columns")]
+    #[test_case("REFERENCES tb ()"; "empty column list")]
+    #[test_case("REFERENCES tb (a) MATCH"; "missing match type")]
+    fn parse_references_specification_failure(input: &str) {
+        let result = references_specification(input.as_ref());
+        assert!(result.is_err() || !result.unwrap().0.is_empty());
+    }
+
+    #[test_case("proc")]
+    #[test_case("sch.proc")]
+    #[test_case("cat.sch.proc")]
+    fn parse_routine_name(input: &str) {
+        assert_str_eq!(input, routine_name(input.as_ref()).unwrap().1.to_string());
+    }
+
+    #[test_case("1", "1")]
+    #[test_case("-1.50", "-1.50")]
+    #[test_case("+.5e-3", "+.5e-3")]
+    #[test_case("'it''s'", "'it''s'")]
+    #[test_case("''", "''" ; "empty string")]
+    #[test_case("true", "TRUE")]
+    #[test_case("Unknown", "UNKNOWN")]
+    #[test_case("null", "NULL")]
+    #[test_case("?", "?" ; "dynamic parameter")]
+    #[test_case(":name", ":name")]
+    #[test_case("\"param\"", "\"param\"")]
+    fn parse_value_specification(input: &str, expected: &str) {
+        assert_str_eq!(
+            expected,
+            value_specification(input.as_ref()).unwrap().1.to_string()
+        );
+    }
+
     #[test_case("name")]
     #[test_case("name_1, name_2")]
     #[test_case("name_1, name_2, name_3, name_4")]
@@ -351,8 +830,36 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
     fn parse_empty_column_name_list() {
         column_name_list(b"").unwrap();
     }
+
+    #[test_case("id", "id")]
+    #[test_case("a asc, b DESC, c", "a ASC, b DESC, c")]
+    #[test_case("a  desc ,b", "a DESC, b")]
+    fn parse_key_column_list(input: &str, expected: &str) {
+        let (_, key_columns) = key_column_list(input.as_ref()).unwrap();
+
+        assert_str_eq!(expected, display_comma_separated(&key_columns));
+    }
+
+    #[test]
+    fn parse_key_column_ast() {
+        let (_, key_column) = key_column(b"id DESC").unwrap();
+
+        assert_eq!(
+            KeyColumn::new("id").with_ordering(Ordering::Desc),
+            key_column
+        );
+        assert_eq!(Some(Ordering::Desc), key_column.opt_ordering());
+    }
+
+    #[test]
+    fn parse_key_column_rejects_keyword_prefix() {
+        let (rest, key_column) = key_column(b"id ascending").unwrap();
+
+        assert_eq!(KeyColumn::new("id"), key_column);
+        assert_eq!(b" ascending", rest);
+    }
 }
@@ -1,12 +1,11 @@
 use nom::branch::alt;
-use nom::bytes::complete::tag_no_case;
 use nom::combinator::map;
 use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::IResult;
 
 use crate::ansi::ast::create_schema::{CreateSchema, SchemaNameClause};
 use crate::ansi::parser::common::schema_name;
-use crate::common::parsers::{delimited_ws1, ident, statement_terminator, terminated_ws1};
+use crate::common::parsers::{delimited_ws1, ident, keyword, statement_terminator, terminated_ws1};
 
 /// Parses a `CREATE SCHEMA` statement [(1)](SchemaNameClause).
 ///
@@ -17,14 +16,14 @@ use crate::common::parsers::{delimited_ws1, ident, statement_terminator, termina
 pub fn create_schema(i: &[u8]) -> IResult<&[u8], CreateSchema> {
     let (i, schema_name_clause) = delimited(
         tuple((
-            terminated_ws1(tag_no_case("CREATE")),
-            terminated_ws1(tag_no_case("SCHEMA")),
+            terminated_ws1(keyword("CREATE")),
+            terminated_ws1(keyword("SCHEMA")),
         )),
         schema_name_clause,
         statement_terminator,
     )(i)?;
 
-    let create_schema = CreateSchema::new(&schema_name_clause);
+    let create_schema = CreateSchema::new(schema_name_clause);
 
     Ok((i, create_schema))
 }
@@ -39,7 +38,7 @@ pub fn schema_name_clause(i: &[u8]) -> IResult<&[u8], SchemaNameClause> {
     let (remaining, (schema_name_clause,)) = tuple((alt((
         map(
             pair(
-                terminated(schema_name, delimited_ws1(tag_no_case("AUTHORIZATION"))),
+                terminated(schema_name, delimited_ws1(keyword("AUTHORIZATION"))),
                 ident,
             ),
             |(schema_name, authorization_name)| {
@@ -47,7 +46,7 @@ pub fn schema_name_clause(i: &[u8]) -> IResult<&[u8], SchemaNameClause> {
             },
         ),
         map(
-            preceded(terminated_ws1(tag_no_case("AUTHORIZATION")), ident),
+            preceded(terminated_ws1(keyword("AUTHORIZATION")), ident),
             SchemaNameClause::Authorization,
         ),
         map(schema_name, SchemaNameClause::Simple),
@@ -1,16 +1,26 @@
 use nom::branch::alt;
-use nom::bytes::complete::tag_no_case;
-use nom::combinator::{map, opt};
-use nom::multi::separated_list1;
-use nom::sequence::{preceded, terminated, tuple};
+use nom::character::complete::u32;
+use nom::combinator::{map, opt, peek, value, verify};
+use nom::multi::{many0, separated_list0, separated_list1};
+use nom::sequence::{pair, preceded, terminated, tuple};
 use nom::IResult;
 
 use crate::ansi::ast::create_table::{
-    CreateTable, TableContentsSource, TableElement, TableElementList, TableScope,
+    CreateTable, PartitionBound, PartitionBy, PartitionDefinition, PartitionMethod,
+    PartitionValues, ReferentialConstraintDefinition, TableConstraint, TableConstraintDefinition,
+    TableContentsSource, TableElement, TableElementList, TableScope, UniqueConstraintDefinition,
 };
-use crate::ansi::parser::common::{column_definition, table_name};
-use crate::common::parsers::{delimited_ws0, paren_delimited, preceded_ws1, statement_terminator};
-use crate::common::tokens::comma;
+use crate::ansi::parser::common::{
+    column_definition, column_name_list_with_period, constraint_name_definition, key_column_list,
+    literal, references_specification, table_name, unique_specification,
+};
+use crate::common::ast::SqlOption;
+use crate::common::expr::Expr;
+use crate::common::parsers::{
+    delimited_ws0, ident, keyword, paren_delimited, preceded_ws0, preceded_ws1, raw_expr,
+    sql_option, statement_terminator, terminated_ws1, whitespace0,
+};
+use crate::common::tokens::{comma, right_paren};
 
 /// Parses a `CREATE TABLE` statement.
 ///
@@ -19,44 +29,311 @@ use crate::common::tokens::comma;
 /// function call will fail. Check the create table statement documentation
 /// [(1)][`CreateTable`] for supported syntax.
 pub fn create_table(i: &[u8]) -> IResult<&[u8], CreateTable> {
-    let (i, (opt_table_scope, table_name, table_contents_source)) = terminated(
+    create_table_with(i, false)
+}
+
+/// Parses a `CREATE TABLE` statement, as [`create_table`], but also accepting
+/// an empty table element list (e.g., `CREATE TABLE tb ()`), which is not
+/// valid `ANSI` but is emitted by some tools.
+///
+/// # Errors
+/// If the create table statement is malformed or has unsupported features, this
+/// function call will fail.
+pub fn create_table_allowing_empty(i: &[u8]) -> IResult<&[u8], CreateTable> {
+    create_table_with(i, true)
+}
+
+fn create_table_with(i: &[u8], allow_empty: bool) -> IResult<&[u8], CreateTable> {
+    let source = i;
+    let (i, (opt_table_scope, table_name, table_contents_source, trailing_clauses)) = terminated(
         tuple((
-            preceded(tag_no_case("CREATE"), opt(preceded_ws1(table_scope))),
-            preceded(preceded_ws1(tag_no_case("TABLE")), preceded_ws1(table_name)),
-            preceded_ws1(table_contents_source),
+            preceded(keyword("CREATE"), opt(preceded_ws1(table_scope))),
+            preceded(preceded_ws1(keyword("TABLE")), preceded_ws1(table_name)),
+            preceded_ws0(|i| table_contents_source(i, allow_empty)),
+            many0(preceded(
+                pair(whitespace0, opt(pair(comma, whitespace0))),
+                alt((
+                    map(partition_by(source), TrailingClause::PartitionBy),
+                    map(sql_option, TrailingClause::TableOption),
+                )),
+            )),
         )),
         statement_terminator,
     )(i)?;
 
-    let mut create_table = CreateTable::new(&table_name, &table_contents_source);
+    let mut create_table = CreateTable::new(table_name, table_contents_source);
     if let Some(table_scope) = opt_table_scope {
-        create_table.with_table_scope(table_scope);
+        create_table = create_table.with_table_scope(table_scope);
+    }
+    for trailing_clause in trailing_clauses {
+        match trailing_clause {
+            TrailingClause::TableOption(option) => create_table.table_options_mut().push(option),
+            TrailingClause::PartitionBy(_) if create_table.opt_partition_by().is_some() => {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    i,
+                    nom::error::ErrorKind::Verify,
+                )));
+            }
+            TrailingClause::PartitionBy(partition_by) => {
+                let position = create_table.table_options().len();
+                create_table = create_table
+                    .with_partition_by(partition_by)
+                    .with_partition_by_position(position);
+            }
+        }
     }
 
     Ok((i, create_table))
 }
 
+/// Clause written after the table contents source.
+enum TrailingClause {
+    TableOption(SqlOption),
+    PartitionBy(PartitionBy),
+}
+
+/// Parses a partition clause [(1)], with the expressions spans relative to
+/// `source`.
+///
+/// [(1)]: PartitionBy
+fn partition_by<'a>(source: &'a [u8]) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], PartitionBy> {
+    move |i: &'a [u8]| {
+        let (i, (partition_method, columns, partition_keys, opt_partitions, opt_definitions)) =
+            preceded(
+                keyword("PARTITION BY"),
+                tuple((
+                    preceded_ws1(partition_method),
+                    map(opt(preceded_ws1(keyword("COLUMNS"))), |opt_columns| {
+                        opt_columns.is_some()
+                    }),
+                    preceded_ws0(paren_delimited(separated_list1(
+                        delimited_ws0(comma),
+                        partition_key(source),
+                    ))),
+                    opt(preceded(
+                        preceded_ws1(keyword("PARTITIONS")),
+                        preceded_ws1(u32),
+                    )),
+                    opt(preceded_ws0(paren_delimited(separated_list1(
+                        delimited_ws0(comma),
+                        partition_definition(source),
+                    )))),
+                )),
+            )(i)?;
+
+        let mut partition_by =
+            PartitionBy::new(partition_method, partition_keys).with_columns(columns);
+        if let Some(partitions) = opt_partitions {
+            partition_by = partition_by.with_partitions(partitions);
+        }
+        if let Some(partition_definitions) = opt_definitions {
+            partition_by = partition_by.with_partition_definitions(partition_definitions);
+        }
+
+        Ok((i, partition_by))
+    }
+}
+
+fn partition_method(i: &[u8]) -> IResult<&[u8], PartitionMethod> {
+    alt((
+        value(PartitionMethod::Range, keyword("RANGE")),
+        value(PartitionMethod::List, keyword("LIST")),
+        value(PartitionMethod::Hash, keyword("HASH")),
+    ))(i)
+}
+
+/// Parses a partition key, which is a column name when it's a single
+/// identifier, or an expression otherwise.
+fn partition_key<'a>(source: &'a [u8]) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Expr> {
+    move |i: &'a [u8]| {
+        alt((
+            map(terminated(ident, end_of_list_item), Expr::Identifier),
+            map(raw_expr(source), Expr::Raw),
+        ))(i)
+    }
+}
+
+fn partition_definition<'a>(
+    source: &'a [u8],
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], PartitionDefinition> {
+    move |i: &'a [u8]| {
+        let (i, (name, opt_partition_values, options)) = tuple((
+            preceded(keyword("PARTITION"), preceded_ws1(ident)),
+            opt(preceded_ws1(partition_values(source))),
+            many0(preceded_ws1(sql_option)),
+        ))(i)?;
+
+        let mut partition_definition = PartitionDefinition::new(name).with_options(options);
+        if let Some(partition_values) = opt_partition_values {
+            partition_definition = partition_definition.with_partition_values(partition_values);
+        }
+
+        Ok((i, partition_definition))
+    }
+}
+
+fn partition_values<'a>(
+    source: &'a [u8],
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], PartitionValues> {
+    move |i: &'a [u8]| {
+        preceded(
+            keyword("VALUES"),
+            preceded_ws1(alt((
+                value(
+                    PartitionValues::LessThanMaxValue,
+                    keyword("LESS THAN MAXVALUE"),
+                ),
+                map(
+                    preceded(
+                        keyword("LESS THAN"),
+                        preceded_ws0(paren_delimited(separated_list1(
+                            delimited_ws0(comma),
+                            alt((
+                                value(
+                                    PartitionBound::MaxValue,
+                                    terminated(keyword("MAXVALUE"), end_of_list_item),
+                                ),
+                                map(partition_value(source), PartitionBound::Value),
+                            )),
+                        ))),
+                    ),
+                    PartitionValues::LessThan,
+                ),
+                map(
+                    preceded(
+                        keyword("IN"),
+                        preceded_ws0(paren_delimited(separated_list1(
+                            delimited_ws0(comma),
+                            partition_value(source),
+                        ))),
+                    ),
+                    PartitionValues::In,
+                ),
+            ))),
+        )(i)
+    }
+}
+
+/// Parses a partition bound value, which is kept as an expression when it's
+/// not a literal, `NULL` or an identifier.
+fn partition_value<'a>(source: &'a [u8]) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Expr> {
+    move |i: &'a [u8]| {
+        alt((
+            terminated(
+                alt((
+                    map(literal, Expr::Literal),
+                    value(Expr::Null, keyword("NULL")),
+                    map(ident, Expr::Identifier),
+                )),
+                end_of_list_item,
+            ),
+            map(raw_expr(source), Expr::Raw),
+        ))(i)
+    }
+}
+
+/// Checks, without consuming it, that the next token ends a list item.
+fn end_of_list_item(i: &[u8]) -> IResult<&[u8], ()> {
+    map(
+        peek(preceded(whitespace0, alt((comma, right_paren)))),
+        |_| (),
+    )(i)
+}
+
 fn table_scope(i: &[u8]) -> IResult<&[u8], TableScope> {
     alt((
-        map(tag_no_case("GLOBAL TEMPORARY"), |_| TableScope::Global),
-        map(tag_no_case("LOCAL TEMPORARY"), |_| TableScope::Local),
+        map(keyword("GLOBAL TEMPORARY"), |_| TableScope::Global),
+        map(keyword("LOCAL TEMPORARY"), |_| TableScope::Local),
     ))(i)
 }
 
-fn table_contents_source(i: &[u8]) -> IResult<&[u8], TableContentsSource> {
+fn table_contents_source(i: &[u8], allow_empty: bool) -> IResult<&[u8], TableContentsSource> {
     alt((map(
-        table_element_list,
+        |i| table_element_list(i, allow_empty),
         TableContentsSource::TableElementList,
     ),))(i)
 }
 
-fn table_element_list(i: &[u8]) -> IResult<&[u8], TableElementList> {
+fn table_element_list(i: &[u8], allow_empty: bool) -> IResult<&[u8], TableElementList> {
     map(
-        paren_delimited(separated_list1(delimited_ws0(comma), table_element)),
-        |list| TableElementList::new(&list),
+        verify(
+            paren_delimited(separated_list0(delimited_ws0(comma), table_element)),
+            |element_list: &Vec<TableElement>| allow_empty || !element_list.is_empty(),
+        ),
+        TableElementList::new,
     )(i)
 }
 
 fn table_element(i: &[u8]) -> IResult<&[u8], TableElement> {
-    alt((map(column_definition, TableElement::ColumnDefinition),))(i)
+    // OBS: constraints come first, as their keywords would be taken as column
+    // names otherwise.
+    alt((
+        map(
+            table_constraint_definition,
+            TableElement::TableConstraintDefinition,
+        ),
+        map(column_definition, TableElement::ColumnDefinition),
+    ))(i)
+}
+
+fn table_constraint_definition(i: &[u8]) -> IResult<&[u8], TableConstraintDefinition> {
+    map(
+        pair(
+            opt(terminated_ws1(constraint_name_definition)),
+            table_constraint,
+        ),
+        |(opt_constraint_name, table_constraint)| {
+            let table_constraint_definition = TableConstraintDefinition::new(table_constraint);
+            match opt_constraint_name {
+                Some(constraint_name) => {
+                    table_constraint_definition.with_constraint_name(constraint_name)
+                }
+                None => table_constraint_definition,
+            }
+        },
+    )(i)
+}
+
+fn table_constraint(i: &[u8]) -> IResult<&[u8], TableConstraint> {
+    alt((
+        map(unique_constraint_definition, TableConstraint::Unique),
+        map(
+            referential_constraint_definition,
+            TableConstraint::Referential,
+        ),
+    ))(i)
+}
+
+fn unique_constraint_definition(i: &[u8]) -> IResult<&[u8], UniqueConstraintDefinition> {
+    map(
+        pair(
+            unique_specification,
+            preceded_ws0(paren_delimited(key_column_list)),
+        ),
+        |(unique_specification, key_columns)| {
+            UniqueConstraintDefinition::new(unique_specification, key_columns)
+        },
+    )(i)
+}
+
+fn referential_constraint_definition(i: &[u8]) -> IResult<&[u8], ReferentialConstraintDefinition> {
+    map(
+        pair(
+            preceded(
+                keyword("FOREIGN KEY"),
+                preceded_ws0(paren_delimited(column_name_list_with_period)),
+            ),
+            preceded_ws0(references_specification),
+        ),
+        |((referencing_columns, opt_period_name), references_specification)| {
+            let referential_constraint_definition =
+                ReferentialConstraintDefinition::new(referencing_columns, references_specification);
+            match opt_period_name {
+                Some(period_name) => {
+                    referential_constraint_definition.with_referencing_period(period_name)
+                }
+                None => referential_constraint_definition,
+            }
+        },
+    )(i)
 }
@@ -1,69 +1,105 @@
 use nom::branch::alt;
-use nom::bytes::complete::{tag, tag_no_case};
+use nom::bytes::complete::tag;
 use nom::character::complete::u32;
 use nom::combinator::{map, opt};
+use nom::error::{Error, ErrorKind};
 use nom::sequence::{pair, preceded, separated_pair, tuple};
 use nom::IResult;
 
 use crate::ansi::ast::data_types::{
     CharLengthUnits, CharacterLargeObjectLength, CharacterLength, DataType, ExactNumberInfo,
-    LargeObjectLength, Multiplier, WithOrWithoutTimeZone,
+    LargeObjectLength, Multiplier, NumericTypeAlias, WithOrWithoutTimeZone,
 };
 use crate::common::parsers::{
-    delimited_ws0, paren_delimited, preceded_ws0, preceded_ws1, terminated_ws0,
+    delimited_ws0, keyword, leading_word, paren_delimited, preceded_ws0, preceded_ws1,
 };
 use crate::common::tokens::comma;
 
 /// Parses `ANSI` data type [(1)].
 ///
+/// The dialect aliases of the numeric types (e.g., `INT8`) are also accepted,
+/// kept as written [(2)].
+///
 /// # Errors
 /// This function returns an error if the data type is not supported or not
 /// exists in the current dialect.
 ///
 /// [(1)]: crate::ansi::DataType
+/// [(2)]: NumericTypeAlias
 pub fn data_type(input: &[u8]) -> IResult<&[u8], DataType> {
-    // OBS: the order matters to parse data types. Do not change it.
-    alt((
-        character_large_object_types,
-        character_string,
-        binary_string_types,
-        decimal_floating_point_type,
-        exact_numeric_type,
-        approximate_numeric_type,
-        boolean_type,
-        datetime_type,
-    ))(input)
+    // OBS: the leading word selects the data type family, so only the parsers
+    // of that family are tried.
+    let word = leading_word(input);
+    let Some((_, family)) = DATA_TYPE_FAMILIES
+        .iter()
+        .find(|(keyword, _)| word.eq_ignore_ascii_case(keyword.as_bytes()))
+    else {
+        return Err(nom::Err::Error(Error::new(input, ErrorKind::Alt)));
+    };
+
+    family(input)
+}
+
+/// Parser of the data types that start with the same keyword.
+type DataTypeFamily = fn(&[u8]) -> IResult<&[u8], DataType>;
+
+/// Leading keyword of each data type, with the parser of its family.
+const DATA_TYPE_FAMILIES: &[(&str, DataTypeFamily)] = &[
+    ("CHARACTER", character_types),
+    ("CHAR", character_types),
+    ("VARCHAR", character_string),
+    ("CLOB", character_large_object_types),
+    ("BINARY", binary_string_types),
+    ("BLOB", binary_string_types),
+    ("VARBINARY", binary_string_types),
+    ("DECFLOAT", decimal_floating_point_type),
+    ("DECIMAL", exact_numeric_type),
+    ("NUMERIC", exact_numeric_type),
+    ("DEC", exact_numeric_type),
+    ("SMALLINT", exact_numeric_type),
+    ("INTEGER", exact_numeric_type),
+    ("BIGINT", exact_numeric_type),
+    ("INT", exact_numeric_type),
+    ("FLOAT", approximate_numeric_type),
+    ("REAL", approximate_numeric_type),
+    ("DOUBLE", approximate_numeric_type),
+    ("FLOAT4", numeric_type_alias),
+    ("FLOAT8", numeric_type_alias),
+    ("INT2", numeric_type_alias),
+    ("INT4", numeric_type_alias),
+    ("INT8", numeric_type_alias),
+    ("BOOLEAN", boolean_type),
+    ("DATE", datetime_type),
+    ("TIMESTAMP", datetime_type),
+    ("TIME", datetime_type),
+];
+
+fn character_types(input: &[u8]) -> IResult<&[u8], DataType> {
+    // OBS: large objects share their leading words with character strings, so
+    // they must be tried first.
+    alt((character_large_object_types, character_string))(input)
 }
 
 fn character_string(input: &[u8]) -> IResult<&[u8], DataType> {
     alt((
         map(
-            preceded(
-                terminated_ws0(tag_no_case("CHARACTER VARYING")),
-                opt_character_length,
-            ),
+            preceded(keyword("CHARACTER VARYING"), opt_character_length),
             DataType::CharacterVarying,
         ),
         map(
-            preceded(
-                terminated_ws0(tag_no_case("CHAR VARYING")),
-                opt_character_length,
-            ),
+            preceded(keyword("CHAR VARYING"), opt_character_length),
             DataType::CharVarying,
         ),
         map(
-            preceded(
-                terminated_ws0(tag_no_case("CHARACTER")),
-                opt_character_length,
-            ),
+            preceded(keyword("CHARACTER"), opt_character_length),
             DataType::Character,
         ),
         map(
-            preceded(terminated_ws0(tag_no_case("VARCHAR")), opt_character_length),
+            preceded(keyword("VARCHAR"), opt_character_length),
             DataType::Varchar,
         ),
         map(
-            preceded(terminated_ws0(tag_no_case("CHAR")), opt_character_length),
+            preceded(keyword("CHAR"), opt_character_length),
             DataType::Char,
         ),
     ))(input)
@@ -73,22 +109,22 @@ fn character_large_object_types(input: &[u8]) -> IResult<&[u8], DataType> {
     alt((
         map(
             preceded(
-                tag_no_case("CHARACTER LARGE OBJECT"),
-                opt(paren_delimited(character_large_object_length)),
+                keyword("CHARACTER LARGE OBJECT"),
+                opt(preceded_ws0(paren_delimited(character_large_object_length))),
             ),
             DataType::CharacterLargeObject,
         ),
         map(
             preceded(
-                tag_no_case("CHAR LARGE OBJECT"),
-                opt(paren_delimited(character_large_object_length)),
+                keyword("CHAR LARGE OBJECT"),
+                opt(preceded_ws0(paren_delimited(character_large_object_length))),
             ),
             DataType::CharLargeObject,
         ),
         map(
             preceded(
-                tag_no_case("CLOB"),
-                opt(paren_delimited(character_large_object_length)),
+                keyword("CLOB"),
+                opt(preceded_ws0(paren_delimited(character_large_object_length))),
             ),
             DataType::Clob,
         ),
@@ -99,37 +135,34 @@ fn binary_string_types(input: &[u8]) -> IResult<&[u8], DataType> {
     alt((
         map(
             preceded(
-                tag_no_case("BINARY LARGE OBJECT"),
+                keyword("BINARY LARGE OBJECT"),
                 opt(preceded_ws0(paren_delimited(large_object_length))),
             ),
             DataType::BinaryLargeObject,
         ),
         map(
             preceded(
-                tag_no_case("BLOB"),
+                keyword("BLOB"),
                 opt(preceded_ws0(paren_delimited(large_object_length))),
             ),
             DataType::Blob,
         ),
         map(
             preceded(
-                tag_no_case("VARBINARY"),
+                keyword("VARBINARY"),
                 opt(preceded_ws0(paren_delimited(u32))),
             ),
             DataType::Varbinary,
         ),
         map(
             preceded(
-                tag_no_case("BINARY VARYING"),
+                keyword("BINARY VARYING"),
                 opt(preceded_ws0(paren_delimited(u32))),
             ),
             DataType::BinaryVarying,
         ),
         map(
-            preceded(
-                tag_no_case("BINARY"),
-                opt(preceded_ws0(paren_delimited(u32))),
-            ),
+            preceded(keyword("BINARY"), opt(preceded_ws0(paren_delimited(u32)))),
             DataType::Binary,
         ),
     ))(input)
@@ -138,62 +171,75 @@ fn binary_string_types(input: &[u8]) -> IResult<&[u8], DataType> {
 fn exact_numeric_type(i: &[u8]) -> IResult<&[u8], DataType> {
     alt((
         map(
-            preceded(tag_no_case("DECIMAL"), exact_number_info),
+            preceded(keyword("DECIMAL"), exact_number_info),
             DataType::Decimal,
         ),
         map(
-            preceded(tag_no_case("NUMERIC"), exact_number_info),
+            preceded(keyword("NUMERIC"), exact_number_info),
             DataType::Numeric,
         ),
-        map(
-            preceded(tag_no_case("DEC"), exact_number_info),
-            DataType::Dec,
-        ),
-        map(tag_no_case("SMALLINT"), |_| DataType::Smallint),
-        map(tag_no_case("INTEGER"), |_| DataType::Integer),
-        map(tag_no_case("BIGINT"), |_| DataType::Bigint),
-        map(tag_no_case("INT"), |_| DataType::Int),
+        map(preceded(keyword("DEC"), exact_number_info), DataType::Dec),
+        map(keyword("SMALLINT"), |_| DataType::Smallint),
+        map(keyword("INTEGER"), |_| DataType::Integer),
+        map(keyword("BIGINT"), |_| DataType::Bigint),
+        map(keyword("INT"), |_| DataType::Int),
     ))(i)
 }
 
 fn approximate_numeric_type(i: &[u8]) -> IResult<&[u8], DataType> {
     alt((
-        map(tag_no_case("FLOAT"), |_| DataType::Float),
-        map(tag_no_case("REAL"), |_| DataType::Real),
-        map(tag_no_case("DOUBLE PRECISION"), |_| {
-            DataType::DoublePrecision
-        }),
+        map(keyword("FLOAT"), |_| DataType::Float),
+        map(keyword("REAL"), |_| DataType::Real),
+        map(keyword("DOUBLE PRECISION"), |_| DataType::DoublePrecision),
+        numeric_type_alias,
     ))(i)
 }
 
+fn numeric_type_alias(i: &[u8]) -> IResult<&[u8], DataType> {
+    map(
+        alt((
+            map(keyword("DOUBLE"), |_| NumericTypeAlias::Double),
+            map(keyword("FLOAT4"), |_| NumericTypeAlias::Float4),
+            map(keyword("FLOAT8"), |_| NumericTypeAlias::Float8),
+            map(keyword("INT2"), |_| NumericTypeAlias::Int2),
+            map(keyword("INT4"), |_| NumericTypeAlias::Int4),
+            map(keyword("INT8"), |_| NumericTypeAlias::Int8),
+        )),
+        DataType::NumericAlias,
+    )(i)
+}
+
 fn decimal_floating_point_type(i: &[u8]) -> IResult<&[u8], DataType> {
     map(
-        preceded(
-            tag_no_case("DECFLOAT"),
-            opt(preceded_ws0(paren_delimited(u32))),
-        ),
+        preceded(keyword("DECFLOAT"), opt(preceded_ws0(paren_delimited(u32)))),
         DataType::DecFloat,
     )(i)
 }
 
 fn boolean_type(i: &[u8]) -> IResult<&[u8], DataType> {
-    map(tag_no_case("BOOLEAN"), |_| DataType::Boolean)(i)
+    map(keyword("BOOLEAN"), |_| DataType::Boolean)(i)
 }
 
 fn datetime_type(i: &[u8]) -> IResult<&[u8], DataType> {
     alt((
-        map(tag_no_case("DATE"), |_| DataType::Date),
+        map(keyword("DATE"), |_| DataType::Date),
         map(
             preceded(
-                tag_no_case("TIMESTAMP"),
-                tuple((opt(paren_delimited(u32)), with_or_without_timezone)),
+                keyword("TIMESTAMP"),
+                tuple((
+                    opt(preceded_ws0(paren_delimited(u32))),
+                    with_or_without_timezone,
+                )),
             ),
             |(precision, tz_info)| DataType::Timestamp(precision, tz_info),
         ),
         map(
             preceded(
-                tag_no_case("TIME"),
-                tuple((opt(paren_delimited(u32)), with_or_without_timezone)),
+                keyword("TIME"),
+                tuple((
+                    opt(preceded_ws0(paren_delimited(u32))),
+                    with_or_without_timezone,
+                )),
             ),
             |(precision, tz_info)| DataType::Time(precision, tz_info),
         ),
@@ -202,13 +248,13 @@ fn datetime_type(i: &[u8]) -> IResult<&[u8], DataType> {
 
 fn opt_character_length(i: &[u8]) -> IResult<&[u8], Option<CharacterLength>> {
     map(
-        opt(paren_delimited(pair(
+        opt(preceded_ws0(paren_delimited(pair(
             u32,
             opt(preceded_ws1(char_length_units)),
-        ))),
+        )))),
         |opt_character_length| {
             if let Some((length, opt_units)) = opt_character_length {
-                Some(*CharacterLength::new(length).with_opt_units(opt_units))
+                Some(CharacterLength::new(length).with_opt_units(opt_units))
             } else {
                 None
             }
@@ -222,18 +268,18 @@ fn character_large_object_length(i: &[u8]) -> IResult<&[u8], CharacterLargeObjec
 
     let mut character_length = CharacterLargeObjectLength::new(length);
     if let Some(units) = opt_units {
-        character_length.with_units(units);
+        character_length = character_length.with_units(units);
     }
 
     Ok((i, character_length))
 }
 
 fn large_object_length(i: &[u8]) -> IResult<&[u8], LargeObjectLength> {
-    let (i, (length, opt_multiplier)) = pair(u32, opt(multiplier))(i)?;
+    let (i, (length, opt_multiplier)) = pair(u32, opt(preceded_ws0(multiplier)))(i)?;
 
     let mut large_object_length = LargeObjectLength::new(length);
     if let Some(multiplier) = opt_multiplier {
-        large_object_length.with_multiplier(multiplier);
+        large_object_length = large_object_length.with_multiplier(multiplier);
     }
 
     Ok((i, large_object_length))
@@ -241,38 +287,45 @@ fn large_object_length(i: &[u8]) -> IResult<&[u8], LargeObjectLength> {
 
 fn multiplier(i: &[u8]) -> IResult<&[u8], Multiplier> {
     alt((
-        map(tag_no_case("K"), |_| Multiplier::K),
-        map(tag_no_case("M"), |_| Multiplier::M),
-        map(tag_no_case("G"), |_| Multiplier::G),
-        map(tag_no_case("T"), |_| Multiplier::T),
-        map(tag_no_case("P"), |_| Multiplier::P),
+        map(keyword("K"), |_| Multiplier::K),
+        map(keyword("M"), |_| Multiplier::M),
+        map(keyword("G"), |_| Multiplier::G),
+        map(keyword("T"), |_| Multiplier::T),
+        map(keyword("P"), |_| Multiplier::P),
     ))(i)
 }
 
 fn char_length_units(i: &[u8]) -> IResult<&[u8], CharLengthUnits> {
     alt((
-        map(tag_no_case("OCTETS"), |_| CharLengthUnits::Octets),
-        map(tag_no_case("CHARACTERS"), |_| CharLengthUnits::Characters),
+        map(keyword("OCTETS"), |_| CharLengthUnits::Octets),
+        map(keyword("CHARACTERS"), |_| CharLengthUnits::Characters),
     ))(i)
 }
 
 fn exact_number_info(i: &[u8]) -> IResult<&[u8], ExactNumberInfo> {
     alt((
         map(
-            paren_delimited(separated_pair(u32, delimited_ws0(comma), u32)),
+            preceded_ws0(paren_delimited(separated_pair(
+                u32,
+                delimited_ws0(comma),
+                u32,
+            ))),
             |(precision, scale)| ExactNumberInfo::PrecisionAndScale(precision, scale),
         ),
-        map(paren_delimited(u32), ExactNumberInfo::Precision),
+        map(
+            preceded_ws0(paren_delimited(u32)),
+            ExactNumberInfo::Precision,
+        ),
         map(tag(""), |_| ExactNumberInfo::None),
     ))(i)
 }
 
 fn with_or_without_timezone(i: &[u8]) -> IResult<&[u8], WithOrWithoutTimeZone> {
     alt((
-        map(preceded_ws1(tag_no_case("WITHOUT TIME ZONE")), |_| {
+        map(preceded_ws1(keyword("WITHOUT TIME ZONE")), |_| {
             WithOrWithoutTimeZone::WithoutTimeZone
         }),
-        map(preceded_ws1(tag_no_case("WITH TIME ZONE")), |_| {
+        map(preceded_ws1(keyword("WITH TIME ZONE")), |_| {
             WithOrWithoutTimeZone::WithTimeZone
         }),
         map(tag(""), |_| WithOrWithoutTimeZone::None),
@@ -307,14 +360,14 @@ mod tests {
         assert_expected_data_type!(
             "CHARACTER VARYING(20 OCTETS)",
             DataType::CharacterVarying(Some(
-                *CharacterLength::new(20).with_units(CharLengthUnits::Octets)
+                CharacterLength::new(20).with_units(CharLengthUnits::Octets)
             ))
         );
 
         assert_expected_data_type!(
             "CHARACTER VARYING(20 CHARACTERS)",
             DataType::CharacterVarying(Some(
-                *CharacterLength::new(20).with_units(CharLengthUnits::Characters)
+                CharacterLength::new(20).with_units(CharLengthUnits::Characters)
             ))
         );
     }
@@ -331,14 +384,14 @@ mod tests {
         assert_expected_data_type!(
             "CHAR VARYING(20 OCTETS)",
             DataType::CharVarying(Some(
-                *CharacterLength::new(20).with_units(CharLengthUnits::Octets)
+                CharacterLength::new(20).with_units(CharLengthUnits::Octets)
             ))
         );
 
         assert_expected_data_type!(
             "CHAR VARYING(20 CHARACTERS)",
             DataType::CharVarying(Some(
-                *CharacterLength::new(20).with_units(CharLengthUnits::Characters)
+                CharacterLength::new(20).with_units(CharLengthUnits::Characters)
             ))
         );
     }
@@ -355,14 +408,14 @@ mod tests {
         assert_expected_data_type!(
             "CHARACTER(20 OCTETS)",
             DataType::Character(Some(
-                *CharacterLength::new(20).with_units(CharLengthUnits::Octets)
+                CharacterLength::new(20).with_units(CharLengthUnits::Octets)
             ))
         );
 
         assert_expected_data_type!(
             "CHARACTER(20 CHARACTERS)",
             DataType::Character(Some(
-                *CharacterLength::new(20).with_units(CharLengthUnits::Characters)
+                CharacterLength::new(20).with_units(CharLengthUnits::Characters)
             ))
         );
     }
@@ -379,14 +432,14 @@ mod tests {
         assert_expected_data_type!(
             "VARCHAR(20 OCTETS)",
             DataType::Varchar(Some(
-                *CharacterLength::new(20).with_units(CharLengthUnits::Octets)
+                CharacterLength::new(20).with_units(CharLengthUnits::Octets)
             ))
         );
 
         assert_expected_data_type!(
             "VARCHAR(20 CHARACTERS)",
             DataType::Varchar(Some(
-                *CharacterLength::new(20).with_units(CharLengthUnits::Characters)
+                CharacterLength::new(20).with_units(CharLengthUnits::Characters)
             ))
         );
     }
@@ -400,14 +453,14 @@ mod tests {
         assert_expected_data_type!(
             "CHAR(20 OCTETS)",
             DataType::Char(Some(
-                *CharacterLength::new(20).with_units(CharLengthUnits::Octets)
+                CharacterLength::new(20).with_units(CharLengthUnits::Octets)
             ))
         );
 
         assert_expected_data_type!(
             "CHAR(20 CHARACTERS)",
             DataType::Char(Some(
-                *CharacterLength::new(20).with_units(CharLengthUnits::Characters)
+                CharacterLength::new(20).with_units(CharLengthUnits::Characters)
             ))
         );
     }
@@ -429,7 +482,7 @@ mod tests {
         assert_expected_data_type!(
             "CHARACTER LARGE OBJECT(20 CHARACTERS)",
             DataType::CharacterLargeObject(Some(
-                *CharacterLargeObjectLength::new(LargeObjectLength::new(20))
+                CharacterLargeObjectLength::new(LargeObjectLength::new(20))
                     .with_units(CharLengthUnits::Characters)
             ))
         );
@@ -437,15 +490,15 @@ mod tests {
         assert_expected_data_type!(
             "CHARACTER LARGE OBJECT(20K)",
             DataType::CharacterLargeObject(Some(CharacterLargeObjectLength::new(
-                *LargeObjectLength::new(20).with_multiplier(Multiplier::K)
+                LargeObjectLength::new(20).with_multiplier(Multiplier::K)
             )))
         );
 
         assert_expected_data_type!(
             "CHARACTER LARGE OBJECT(20K CHARACTERS)",
             DataType::CharacterLargeObject(Some(
-                *CharacterLargeObjectLength::new(
-                    *LargeObjectLength::new(20).with_multiplier(Multiplier::K)
+                CharacterLargeObjectLength::new(
+                    LargeObjectLength::new(20).with_multiplier(Multiplier::K)
                 )
                 .with_units(CharLengthUnits::Characters)
             ))
@@ -466,7 +519,7 @@ mod tests {
         assert_expected_data_type!(
             "CHAR LARGE OBJECT(20 CHARACTERS)",
             DataType::CharLargeObject(Some(
-                *CharacterLargeObjectLength::new(LargeObjectLength::new(20))
+                CharacterLargeObjectLength::new(LargeObjectLength::new(20))
                     .with_units(CharLengthUnits::Characters)
             ))
         );
@@ -474,15 +527,15 @@ mod tests {
         assert_expected_data_type!(
             "CHAR LARGE OBJECT(20K)",
             DataType::CharLargeObject(Some(CharacterLargeObjectLength::new(
-                *LargeObjectLength::new(20).with_multiplier(Multiplier::K)
+                LargeObjectLength::new(20).with_multiplier(Multiplier::K)
             )))
         );
 
         assert_expected_data_type!(
             "CHAR LARGE OBJECT(20K CHARACTERS)",
             DataType::CharLargeObject(Some(
-                *CharacterLargeObjectLength::new(
-                    *LargeObjectLength::new(20).with_multiplier(Multiplier::K)
+                CharacterLargeObjectLength::new(
+                    LargeObjectLength::new(20).with_multiplier(Multiplier::K)
                 )
                 .with_units(CharLengthUnits::Characters)
             ))
@@ -503,7 +556,7 @@ mod tests {
         assert_expected_data_type!(
             "CLOB(20 CHARACTERS)",
             DataType::Clob(Some(
-                *CharacterLargeObjectLength::new(LargeObjectLength::new(20))
+                CharacterLargeObjectLength::new(LargeObjectLength::new(20))
                     .with_units(CharLengthUnits::Characters)
             ))
         );
@@ -511,15 +564,15 @@ mod tests {
         assert_expected_data_type!(
             "CLOB(20K)",
             DataType::Clob(Some(CharacterLargeObjectLength::new(
-                *LargeObjectLength::new(20).with_multiplier(Multiplier::K)
+                LargeObjectLength::new(20).with_multiplier(Multiplier::K)
             )))
         );
 
         assert_expected_data_type!(
             "CLOB(20K CHARACTERS)",
             DataType::Clob(Some(
-                *CharacterLargeObjectLength::new(
-                    *LargeObjectLength::new(20).with_multiplier(Multiplier::K)
+                CharacterLargeObjectLength::new(
+                    LargeObjectLength::new(20).with_multiplier(Multiplier::K)
                 )
                 .with_units(CharLengthUnits::Characters)
             ))
@@ -554,7 +607,7 @@ mod tests {
         assert_expected_data_type!(
             "BINARY LARGE OBJECT(20K)",
             DataType::BinaryLargeObject(Some(
-                *LargeObjectLength::new(20).with_multiplier(Multiplier::K)
+                LargeObjectLength::new(20).with_multiplier(Multiplier::K)
             ))
         );
     }
@@ -566,7 +619,7 @@ mod tests {
         assert_expected_data_type!(
             "BLOB(20K)",
             DataType::Blob(Some(
-                *LargeObjectLength::new(20).with_multiplier(Multiplier::K)
+                LargeObjectLength::new(20).with_multiplier(Multiplier::K)
             ))
         );
     }
@@ -649,6 +702,25 @@ mod tests {
         assert_expected_data_type!("DOUBLE PRECISION", DataType::DoublePrecision);
     }
 
+    #[test]
+    fn parse_numeric_type_alias() {
+        assert_expected_data_type!("DOUBLE", DataType::NumericAlias(NumericTypeAlias::Double));
+        assert_expected_data_type!("FLOAT4", DataType::NumericAlias(NumericTypeAlias::Float4));
+        assert_expected_data_type!("FLOAT8", DataType::NumericAlias(NumericTypeAlias::Float8));
+        assert_expected_data_type!("INT2", DataType::NumericAlias(NumericTypeAlias::Int2));
+        assert_expected_data_type!("INT4", DataType::NumericAlias(NumericTypeAlias::Int4));
+        assert_expected_data_type!("INT8", DataType::NumericAlias(NumericTypeAlias::Int8));
+    }
+
+    #[test]
+    fn parse_numeric_type_alias_keeps_spelling() {
+        let (remaining, parsed) = data_type(b"int8").unwrap();
+        assert!(remaining.is_empty());
+        assert_str_eq!("INT8", parsed.to_string());
+        assert_eq!(DataType::Bigint, parsed.canonical());
+        assert!(data_type(b"INT16").is_err());
+    }
+
     #[test]
     fn parse_decimal_floating_point_type() {
         assert_expected_data_type!("DECFLOAT", DataType::DecFloat(None));
@@ -0,0 +1,25 @@
+use nom::sequence::{delimited, pair};
+use nom::IResult;
+
+use crate::ansi::ast::delete::DeletePositioned;
+use crate::ansi::parser::common::{table_name, where_current_of};
+use crate::common::parsers::{keyword, statement_terminator, terminated_ws1};
+
+/// Parses a positioned `DELETE` statement.
+///
+/// # Errors
+/// If the delete statement is malformed or has unsupported features (e.g.,
+/// it's a searched delete), this function call will fail. Check the delete
+/// statement documentation [(1)][`DeletePositioned`] for supported syntax.
+pub fn delete_positioned(i: &[u8]) -> IResult<&[u8], DeletePositioned> {
+    let (i, (table_name, cursor_name)) = delimited(
+        pair(
+            terminated_ws1(keyword("DELETE")),
+            terminated_ws1(keyword("FROM")),
+        ),
+        pair(terminated_ws1(table_name), where_current_of),
+        statement_terminator,
+    )(i)?;
+
+    Ok((i, DeletePositioned::new(table_name, cursor_name)))
+}
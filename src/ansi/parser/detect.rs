@@ -0,0 +1,243 @@
+use std::fmt;
+
+use nom::branch::alt;
+use nom::combinator::{opt, value};
+use nom::sequence::{pair, preceded, tuple};
+use nom::IResult;
+
+use crate::ansi::Statement;
+use crate::common::parsers::{keyword, terminated_ws1, whitespace0};
+
+/// Kind of a statement, as detected from its leading keywords [(1)].
+///
+/// Besides the statements supported by the parser, common statements that are
+/// not parsed yet (e.g., `SELECT`) are also detected, so the input can be
+/// classified without being parsed. `UPDATE` and `DELETE` are only parsed in
+/// their positioned form (`WHERE CURRENT OF <cursor name>`).
+///
+/// [(1)]: detect_statement_kind
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatementKind {
+    /// `CREATE SCHEMA` statement.
+    CreateSchema,
+    /// `DROP SCHEMA` statement.
+    DropSchema,
+    /// `CREATE TABLE` statement.
+    CreateTable,
+    /// `DROP TABLE` statement.
+    DropTable,
+    /// `ALTER TABLE` statement.
+    AlterTable,
+    /// `OPEN` statement.
+    Open,
+    /// `FETCH` statement.
+    Fetch,
+    /// `CLOSE` statement.
+    Close,
+    /// `CALL` statement.
+    Call,
+    /// `RETURN` statement.
+    Return,
+    /// `EXPLAIN` statement (a dialect extension).
+    Explain,
+    /// `GRANT` statement (only parsed when granting privileges).
+    Grant,
+    /// `REVOKE` statement (only parsed when revoking privileges).
+    Revoke,
+    /// `SELECT` statement (not parsed yet).
+    Select,
+    /// `INSERT` statement (not parsed yet).
+    Insert,
+    /// `UPDATE` statement (only parsed when positioned).
+    Update,
+    /// `DELETE` statement (only parsed when positioned).
+    Delete,
+}
+
+impl StatementKind {
+    /// Returns whether the statement is parsed to a [`Statement`].
+    #[must_use]
+    pub const fn is_parsed(&self) -> bool {
+        matches!(
+            self,
+            Self::CreateSchema
+                | Self::DropSchema
+                | Self::CreateTable
+                | Self::DropTable
+                | Self::AlterTable
+                | Self::Open
+                | Self::Fetch
+                | Self::Close
+                | Self::Call
+                | Self::Return
+                | Self::Explain
+                | Self::Grant
+                | Self::Revoke
+                | Self::Update
+                | Self::Delete
+        )
+    }
+}
+
+impl Statement {
+    /// Returns the kind of the statement.
+    #[must_use]
+    pub const fn kind(&self) -> StatementKind {
+        match self {
+            Self::CreateSchema(_) => StatementKind::CreateSchema,
+            Self::DropSchema(_) => StatementKind::DropSchema,
+            Self::DropTable(_) => StatementKind::DropTable,
+            Self::CreateTable(_) => StatementKind::CreateTable,
+            Self::AlterTable(_) => StatementKind::AlterTable,
+            Self::Open(_) => StatementKind::Open,
+            Self::Fetch(_) => StatementKind::Fetch,
+            Self::Close(_) => StatementKind::Close,
+            Self::Call(_) => StatementKind::Call,
+            Self::Return(_) => StatementKind::Return,
+            Self::UpdatePositioned(_) => StatementKind::Update,
+            Self::DeletePositioned(_) => StatementKind::Delete,
+            Self::Explain(_) => StatementKind::Explain,
+            Self::GrantPrivilege(_) => StatementKind::Grant,
+            Self::RevokePrivilege(_) => StatementKind::Revoke,
+        }
+    }
+}
+
+impl fmt::Display for StatementKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CreateSchema => write!(f, "CREATE SCHEMA")?,
+            Self::DropSchema => write!(f, "DROP SCHEMA")?,
+            Self::CreateTable => write!(f, "CREATE TABLE")?,
+            Self::DropTable => write!(f, "DROP TABLE")?,
+            Self::AlterTable => write!(f, "ALTER TABLE")?,
+            Self::Open => write!(f, "OPEN")?,
+            Self::Fetch => write!(f, "FETCH")?,
+            Self::Close => write!(f, "CLOSE")?,
+            Self::Call => write!(f, "CALL")?,
+            Self::Return => write!(f, "RETURN")?,
+            Self::Explain => write!(f, "EXPLAIN")?,
+            Self::Grant => write!(f, "GRANT")?,
+            Self::Revoke => write!(f, "REVOKE")?,
+            Self::Select => write!(f, "SELECT")?,
+            Self::Insert => write!(f, "INSERT")?,
+            Self::Update => write!(f, "UPDATE")?,
+            Self::Delete => write!(f, "DELETE")?,
+        }
+        Ok(())
+    }
+}
+
+/// Detects the kind of the statement from its leading keywords, without
+/// parsing the rest of it.
+///
+/// As only the leading keywords are checked, a detected statement may still be
+/// invalid.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::parser::detect::{detect_statement_kind, StatementKind};
+/// assert_eq!(
+///     detect_statement_kind("  create local temporary table tb (id INT)"),
+///     Some(StatementKind::CreateTable)
+/// );
+/// assert_eq!(detect_statement_kind("SELECT * FROM tb"), Some(StatementKind::Select));
+/// assert_eq!(detect_statement_kind("CREATE VIEW v AS SELECT 1"), None);
+/// ```
+#[must_use]
+pub fn detect_statement_kind(sql: &str) -> Option<StatementKind> {
+    preceded(whitespace0, statement_kind)(sql.as_bytes())
+        .ok()
+        .map(|(_, kind)| kind)
+}
+
+fn statement_kind(i: &[u8]) -> IResult<&[u8], StatementKind> {
+    alt((
+        value(
+            StatementKind::CreateSchema,
+            pair(terminated_ws1(keyword("CREATE")), keyword("SCHEMA")),
+        ),
+        value(
+            StatementKind::CreateTable,
+            tuple((
+                terminated_ws1(keyword("CREATE")),
+                opt(terminated_ws1(alt((
+                    keyword("GLOBAL TEMPORARY"),
+                    keyword("LOCAL TEMPORARY"),
+                )))),
+                keyword("TABLE"),
+            )),
+        ),
+        value(
+            StatementKind::DropSchema,
+            pair(terminated_ws1(keyword("DROP")), keyword("SCHEMA")),
+        ),
+        value(
+            StatementKind::DropTable,
+            pair(terminated_ws1(keyword("DROP")), keyword("TABLE")),
+        ),
+        value(
+            StatementKind::AlterTable,
+            alt((
+                pair(terminated_ws1(keyword("ALTER")), keyword("TABLE")),
+                pair(terminated_ws1(keyword("RENAME")), keyword("TABLE")),
+            )),
+        ),
+        value(StatementKind::Open, keyword("OPEN")),
+        value(StatementKind::Fetch, keyword("FETCH")),
+        value(StatementKind::Close, keyword("CLOSE")),
+        value(StatementKind::Call, keyword("CALL")),
+        value(StatementKind::Return, keyword("RETURN")),
+        value(StatementKind::Explain, keyword("EXPLAIN")),
+        value(StatementKind::Grant, keyword("GRANT")),
+        value(StatementKind::Revoke, keyword("REVOKE")),
+        value(StatementKind::Select, keyword("SELECT")),
+        value(StatementKind::Insert, keyword("INSERT")),
+        value(StatementKind::Update, keyword("UPDATE")),
+        value(StatementKind::Delete, keyword("DELETE")),
+    ))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_statement;
+
+    use super::*;
+
+    #[test_case("CREATE SCHEMA sch;")]
+    #[test_case("DROP SCHEMA sch CASCADE")]
+    #[test_case("CREATE TABLE tb (id INT)")]
+    #[test_case("CREATE GLOBAL TEMPORARY TABLE tb (id INT)")]
+    #[test_case("DROP TABLE tb RESTRICT")]
+    #[test_case("ALTER TABLE tb DROP COLUMN id CASCADE")]
+    #[test_case("RENAME TABLE a TO b")]
+    #[test_case("OPEN c")]
+    #[test_case("FETCH NEXT FROM c INTO :a")]
+    #[test_case("CLOSE c")]
+    #[test_case("CALL proc(1)")]
+    #[test_case("RETURN NULL")]
+    #[test_case("UPDATE tb SET id = 1 WHERE CURRENT OF c")]
+    #[test_case("DELETE FROM tb WHERE CURRENT OF c")]
+    #[test_case("EXPLAIN ANALYZE CLOSE c")]
+    #[test_case("GRANT SELECT ON tb TO usr")]
+    #[test_case("REVOKE SELECT ON tb FROM usr CASCADE")]
+    fn detects_parsed_statements(input: &str) {
+        let (_, statement) = parse_statement(input.as_bytes()).unwrap();
+
+        assert_eq!(Some(statement.kind()), detect_statement_kind(input));
+    }
+
+    #[test_case("\n\tinsert into tb VALUES (1)", Some(StatementKind::Insert))]
+    #[test_case("UPDATE tb SET id = 1", Some(StatementKind::Update))]
+    #[test_case("DELETE FROM tb", Some(StatementKind::Delete))]
+    #[test_case("CREATE TABLES", None; "partial keyword")]
+    #[test_case("CREATE INDEX idx ON tb (id)", None; "unknown statement")]
+    #[test_case("", None; "empty")]
+    fn detects_leading_keywords(input: &str, expected: Option<StatementKind>) {
+        assert_eq!(expected, detect_statement_kind(input));
+    }
+}
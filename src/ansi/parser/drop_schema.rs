@@ -1,10 +1,9 @@
-use nom::bytes::complete::tag_no_case;
 use nom::sequence::{delimited, pair};
 use nom::IResult;
 
 use crate::ansi::ast::drop_schema::DropSchema;
 use crate::ansi::parser::common::{drop_behavior, schema_name};
-use crate::common::parsers::{statement_terminator, terminated_ws1};
+use crate::common::parsers::{keyword, statement_terminator, terminated_ws1};
 
 /// Parses a `DROP SCHEMA` statement.
 ///
@@ -15,14 +14,14 @@ use crate::common::parsers::{statement_terminator, terminated_ws1};
 pub fn drop_schema(i: &[u8]) -> IResult<&[u8], DropSchema> {
     let (i, (schema_name, drop_behavior)) = delimited(
         pair(
-            terminated_ws1(tag_no_case("DROP")),
-            terminated_ws1(tag_no_case("SCHEMA")),
+            terminated_ws1(keyword("DROP")),
+            terminated_ws1(keyword("SCHEMA")),
         ),
         pair(terminated_ws1(schema_name), drop_behavior),
         statement_terminator,
     )(i)?;
 
-    let drop_schema = DropSchema::new(&schema_name, drop_behavior);
+    let drop_schema = DropSchema::new(schema_name, drop_behavior);
 
     Ok((i, drop_schema))
 }
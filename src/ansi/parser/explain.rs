@@ -0,0 +1,52 @@
+use nom::combinator::{opt, verify};
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::ansi::ast::explain::Explain;
+use crate::ansi::parser::parse_statement;
+use crate::ansi::Statement;
+use crate::common::parsers::{keyword, terminated_ws1};
+
+/// Parses an `EXPLAIN` statement.
+///
+/// The explained statement can be any parsable statement but another
+/// `EXPLAIN`, and carries the statement terminator.
+///
+/// # Errors
+/// If the explain statement is malformed or has unsupported features, this
+/// function call will fail. Check the explain statement documentation
+/// [(1)][`Explain`] for supported syntax.
+pub fn explain(i: &[u8]) -> IResult<&[u8], Explain> {
+    let (i, analyze) = preceded(
+        terminated_ws1(keyword("EXPLAIN")),
+        opt(terminated_ws1(keyword("ANALYZE"))),
+    )(i)?;
+    let (i, statement) = verify(parse_statement, |statement| {
+        !matches!(statement, Statement::Explain(_))
+    })(i)?;
+
+    Ok((i, Explain::new(statement).with_analyze(analyze.is_some())))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("EXPLAIN DROP TABLE tb CASCADE", "EXPLAIN DROP TABLE tb CASCADE")]
+    #[test_case("explain  analyze close c ;", "EXPLAIN ANALYZE CLOSE c")]
+    fn parse_explain(input: &str, expected: &str) {
+        let (_, explain) = explain(input.as_bytes()).unwrap();
+
+        assert_eq!(expected, explain.to_string());
+    }
+
+    #[test_case("EXPLAIN"; "missing statement")]
+    #[test_case("EXPLAIN ANALYZE"; "missing analyzed statement")]
+    #[test_case("EXPLAIN EXPLAIN CLOSE c"; "nested explain")]
+    fn parse_explain_failure(input: &str) {
+        assert!(explain(input.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+use nom::branch::alt;
+use nom::character::complete::i64;
+use nom::combinator::{map, opt};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, preceded, terminated, tuple};
+use nom::IResult;
+
+use crate::ansi::ast::fetch::{Fetch, FetchOrientation, TargetSpecification};
+use crate::common::parsers::{delimited_ws0, ident, keyword, statement_terminator, terminated_ws1};
+use crate::common::tokens::{colon, comma};
+
+/// Parses a `FETCH` statement.
+///
+/// # Errors
+/// If the fetch statement is malformed or has unsupported features, this
+/// function call will fail. Check the fetch statement documentation
+/// [(1)][`Fetch`] for supported syntax.
+pub fn fetch(i: &[u8]) -> IResult<&[u8], Fetch> {
+    let (i, (opt_fetch_orientation, cursor_name, target_list)) = delimited(
+        terminated_ws1(keyword("FETCH")),
+        tuple((
+            opt_fetch_orientation,
+            terminated_ws1(ident),
+            preceded(
+                terminated_ws1(keyword("INTO")),
+                separated_list1(delimited_ws0(comma), target_specification),
+            ),
+        )),
+        statement_terminator,
+    )(i)?;
+
+    let mut fetch = Fetch::new(cursor_name, target_list);
+    if let Some(fetch_orientation) = opt_fetch_orientation {
+        fetch = fetch.with_fetch_orientation(fetch_orientation);
+    }
+
+    Ok((i, fetch))
+}
+
+/// Parses the optional orientation, followed by `FROM`, which is optional
+/// only without orientation.
+fn opt_fetch_orientation(i: &[u8]) -> IResult<&[u8], Option<FetchOrientation>> {
+    alt((
+        map(
+            terminated(
+                terminated_ws1(fetch_orientation),
+                terminated_ws1(keyword("FROM")),
+            ),
+            Some,
+        ),
+        map(opt(terminated_ws1(keyword("FROM"))), |_| None),
+    ))(i)
+}
+
+fn fetch_orientation(i: &[u8]) -> IResult<&[u8], FetchOrientation> {
+    alt((
+        map(keyword("NEXT"), |_| FetchOrientation::Next),
+        map(keyword("PRIOR"), |_| FetchOrientation::Prior),
+        map(keyword("FIRST"), |_| FetchOrientation::First),
+        map(keyword("LAST"), |_| FetchOrientation::Last),
+        map(
+            preceded(terminated_ws1(keyword("ABSOLUTE")), i64),
+            FetchOrientation::Absolute,
+        ),
+        map(
+            preceded(terminated_ws1(keyword("RELATIVE")), i64),
+            FetchOrientation::Relative,
+        ),
+    ))(i)
+}
+
+fn target_specification(i: &[u8]) -> IResult<&[u8], TargetSpecification> {
+    alt((
+        map(preceded(colon, ident), TargetSpecification::HostParameter),
+        map(ident, TargetSpecification::SqlParameter),
+    ))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("FETCH c INTO :a", "FETCH c INTO :a")]
+    #[test_case("fetch from c into :a,b", "FETCH c INTO :a, b")]
+    #[test_case("FETCH NEXT FROM c INTO :a", "FETCH NEXT FROM c INTO :a")]
+    #[test_case("FETCH absolute -3 FROM c INTO :a", "FETCH ABSOLUTE -3 FROM c INTO :a")]
+    #[test_case("FETCH RELATIVE +2 FROM c INTO :a", "FETCH RELATIVE 2 FROM c INTO :a")]
+    fn parse_fetch(input: &str, expected: &str) {
+        let (_, fetch) = fetch(input.as_bytes()).unwrap();
+
+        assert_eq!(expected, fetch.to_string());
+    }
+
+    #[test_case("FETCH NEXT c INTO :a"; "orientation without from")]
+    #[test_case("FETCH ABSOLUTE FROM c INTO :a"; "missing position")]
+    #[test_case("FETCH c"; "missing targets")]
+    fn parse_fetch_failure(input: &str) {
+        assert!(fetch(input.as_bytes()).is_err());
+    }
+}
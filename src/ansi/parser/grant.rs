@@ -0,0 +1,248 @@
+use nom::branch::alt;
+use nom::combinator::{map, opt, value};
+use nom::multi::{separated_list0, separated_list1};
+use nom::sequence::{delimited, pair, preceded, separated_pair, tuple};
+use nom::IResult;
+
+use crate::ansi::ast::grant::{
+    Action, GrantObject, GrantPrivilege, Grantee, Grantor, ObjectPrivileges, Privileges,
+    RoutineType, SpecificRoutineDesignator,
+};
+use crate::ansi::parser::common::{
+    column_name_list, routine_name, schema_qualified_name, table_name,
+};
+use crate::ansi::parser::data_types::data_type;
+use crate::common::parsers::{
+    delimited_ws0, delimited_ws1, ident, keyword, paren_delimited, preceded_ws0, preceded_ws1,
+    statement_terminator, terminated_ws1,
+};
+use crate::common::tokens::comma;
+
+/// Parses a `GRANT` privilege statement.
+///
+/// # Errors
+/// If the grant statement is malformed or has unsupported features, this
+/// function call will fail. Check the grant statement documentation
+/// [(1)][`GrantPrivilege`] for supported syntax.
+pub fn grant_privilege(i: &[u8]) -> IResult<&[u8], GrantPrivilege> {
+    let (i, (privileges, grantees, opt_hierarchy_option, opt_grant_option, opt_grantor)) =
+        delimited(
+            terminated_ws1(keyword("GRANT")),
+            tuple((
+                privileges,
+                preceded(delimited_ws1(keyword("TO")), grantee_list),
+                opt(preceded_ws1(keyword("WITH HIERARCHY OPTION"))),
+                opt(preceded_ws1(keyword("WITH GRANT OPTION"))),
+                opt(preceded_ws1(granted_by)),
+            )),
+            statement_terminator,
+        )(i)?;
+
+    let mut grant = GrantPrivilege::new(privileges, grantees)
+        .with_hierarchy_option(opt_hierarchy_option.is_some())
+        .with_grant_option(opt_grant_option.is_some());
+    if let Some(grantor) = opt_grantor {
+        grant = grant.with_grantor(grantor);
+    }
+
+    Ok((i, grant))
+}
+
+/// Parses the privileges on an object [(1)](Privileges), shared by the
+/// `GRANT` and `REVOKE` statements.
+///
+/// # Errors
+/// If there are no valid actions, or the object name is malformed, this
+/// function call will fail.
+pub fn privileges(i: &[u8]) -> IResult<&[u8], Privileges> {
+    map(
+        separated_pair(
+            object_privileges,
+            delimited_ws1(keyword("ON")),
+            grant_object,
+        ),
+        |(object_privileges, object)| Privileges::new(object_privileges, object),
+    )(i)
+}
+
+/// Parses a comma separated list of grantees [(1)](Grantee).
+///
+/// # Errors
+/// If there are no valid grantees, this function call will fail.
+pub fn grantee_list(i: &[u8]) -> IResult<&[u8], Vec<Grantee>> {
+    separated_list1(
+        delimited_ws0(comma),
+        alt((
+            value(Grantee::Public, keyword("PUBLIC")),
+            map(ident, Grantee::AuthorizationIdentifier),
+        )),
+    )(i)
+}
+
+/// Parses a `GRANTED BY <grantor>` clause, returning the grantor
+/// [(1)](Grantor).
+///
+/// # Errors
+/// If the input is not a case-insensitive `GRANTED BY` followed by
+/// `CURRENT_USER` or `CURRENT_ROLE`, this function call will fail.
+pub fn granted_by(i: &[u8]) -> IResult<&[u8], Grantor> {
+    preceded(
+        terminated_ws1(keyword("GRANTED BY")),
+        alt((
+            value(Grantor::CurrentUser, keyword("CURRENT_USER")),
+            value(Grantor::CurrentRole, keyword("CURRENT_ROLE")),
+        )),
+    )(i)
+}
+
+fn object_privileges(i: &[u8]) -> IResult<&[u8], ObjectPrivileges> {
+    alt((
+        value(ObjectPrivileges::AllPrivileges, keyword("ALL PRIVILEGES")),
+        map(
+            separated_list1(delimited_ws0(comma), action),
+            ObjectPrivileges::Actions,
+        ),
+    ))(i)
+}
+
+fn action(i: &[u8]) -> IResult<&[u8], Action> {
+    let opt_columns = || opt(preceded_ws0(paren_delimited(column_name_list)));
+
+    alt((
+        map(preceded(keyword("SELECT"), opt_columns()), Action::Select),
+        value(Action::Delete, keyword("DELETE")),
+        map(preceded(keyword("INSERT"), opt_columns()), Action::Insert),
+        map(preceded(keyword("UPDATE"), opt_columns()), Action::Update),
+        map(
+            preceded(keyword("REFERENCES"), opt_columns()),
+            Action::References,
+        ),
+        value(Action::Usage, keyword("USAGE")),
+        value(Action::Trigger, keyword("TRIGGER")),
+        value(Action::Under, keyword("UNDER")),
+        value(Action::Execute, keyword("EXECUTE")),
+    ))(i)
+}
+
+fn grant_object(i: &[u8]) -> IResult<&[u8], GrantObject> {
+    alt((
+        map(
+            preceded(terminated_ws1(keyword("DOMAIN")), schema_qualified_name),
+            GrantObject::Domain,
+        ),
+        map(
+            preceded(terminated_ws1(keyword("SEQUENCE")), schema_qualified_name),
+            GrantObject::Sequence,
+        ),
+        map(
+            preceded(terminated_ws1(keyword("TYPE")), schema_qualified_name),
+            GrantObject::Type,
+        ),
+        map(
+            preceded(
+                terminated_ws1(keyword("CHARACTER SET")),
+                schema_qualified_name,
+            ),
+            GrantObject::CharacterSet,
+        ),
+        map(specific_routine_designator, GrantObject::Routine),
+        map(
+            preceded(opt(terminated_ws1(keyword("TABLE"))), table_name),
+            GrantObject::Table,
+        ),
+    ))(i)
+}
+
+fn specific_routine_designator(i: &[u8]) -> IResult<&[u8], SpecificRoutineDesignator> {
+    let (i, ((routine_type, routine_name), opt_data_type_list)) = pair(
+        pair(
+            terminated_ws1(alt((
+                value(RoutineType::Function, keyword("FUNCTION")),
+                value(RoutineType::Procedure, keyword("PROCEDURE")),
+            ))),
+            routine_name,
+        ),
+        opt(preceded_ws0(paren_delimited(separated_list0(
+            delimited_ws0(comma),
+            data_type,
+        )))),
+    )(i)?;
+
+    let mut designator = SpecificRoutineDesignator::new(routine_type, routine_name);
+    if let Some(data_type_list) = opt_data_type_list {
+        designator = designator.with_data_type_list(data_type_list);
+    }
+
+    Ok((i, designator))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("GRANT SELECT ON tb TO usr", "GRANT SELECT ON tb TO usr")]
+    #[test_case(
+        "grant select (a,b), insert, delete on table sch.tb to a , b with grant option",
+        "GRANT SELECT (a, b), INSERT, DELETE ON sch.tb TO a, b WITH GRANT OPTION"
+    )]
+    #[test_case(
+        "GRANT ALL PRIVILEGES ON tb TO usr",
+        "GRANT ALL PRIVILEGES ON tb TO usr"
+    )]
+    #[test_case(
+        "GRANT USAGE ON DOMAIN sch.dom TO usr",
+        "GRANT USAGE ON DOMAIN sch.dom TO usr"
+    )]
+    #[test_case(
+        "GRANT USAGE ON SEQUENCE seq TO usr",
+        "GRANT USAGE ON SEQUENCE seq TO usr"
+    )]
+    #[test_case(
+        "GRANT UNDER ON TYPE sch.typ TO usr",
+        "GRANT UNDER ON TYPE sch.typ TO usr"
+    )]
+    #[test_case(
+        "GRANT USAGE ON character set cs TO usr",
+        "GRANT USAGE ON CHARACTER SET cs TO usr"
+    )]
+    #[test_case(
+        "GRANT EXECUTE ON FUNCTION sch.fn ( INT,VARCHAR(10) ) TO usr",
+        "GRANT EXECUTE ON FUNCTION sch.fn(INT, VARCHAR(10)) TO usr"
+    )]
+    #[test_case(
+        "GRANT EXECUTE ON PROCEDURE proc TO usr",
+        "GRANT EXECUTE ON PROCEDURE proc TO usr"
+    )]
+    fn parse_grant_privilege(input: &str, expected: &str) {
+        let (_, grant) = grant_privilege(input.as_bytes()).unwrap();
+
+        assert_str_eq!(expected, grant.to_string());
+    }
+
+    #[test_case(
+        "GRANT UNDER ON TYPE typ TO PUBLIC, \"PUBLIC\" WITH HIERARCHY OPTION WITH GRANT OPTION",
+        "GRANT UNDER ON TYPE typ TO PUBLIC, \"PUBLIC\" WITH HIERARCHY OPTION WITH GRANT OPTION"
+    )]
+    #[test_case(
+        "grant select on tb to public granted by current_role",
+        "GRANT SELECT ON tb TO PUBLIC GRANTED BY CURRENT_ROLE"
+    )]
+    fn parse_grant_privilege_clauses(input: &str, expected: &str) {
+        let (_, grant) = grant_privilege(input.as_bytes()).unwrap();
+
+        assert_str_eq!(expected, grant.to_string());
+    }
+
+    #[test_case("GRANT ON tb TO usr"; "missing privileges")]
+    #[test_case("GRANT SELECT ON tb TO usr GRANTED BY usr"; "grantor not current")]
+    #[test_case("GRANT SELECT ON tb TO usr WITH GRANT OPTION WITH HIERARCHY OPTION"; "options out of order")]
+    #[test_case("GRANT SELECT ON tb"; "missing grantees")]
+    #[test_case("GRANT SELECT () ON tb TO usr"; "empty column list")]
+    #[test_case("GRANT EXECUTE ON FUNCTION TO usr"; "missing routine name")]
+    fn parse_grant_privilege_failure(input: &str) {
+        assert!(grant_privilege(input.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,18 @@
+use nom::sequence::delimited;
+use nom::IResult;
+
+use crate::ansi::ast::open::Open;
+use crate::common::parsers::{ident, keyword, statement_terminator, terminated_ws1};
+
+/// Parses an `OPEN` statement.
+///
+/// # Errors
+/// If the open statement is malformed or has unsupported features, this
+/// function call will fail. Check the open statement documentation
+/// [(1)][`Open`] for supported syntax.
+pub fn open(i: &[u8]) -> IResult<&[u8], Open> {
+    let (i, cursor_name) =
+        delimited(terminated_ws1(keyword("OPEN")), ident, statement_terminator)(i)?;
+
+    Ok((i, Open::new(cursor_name)))
+}
@@ -0,0 +1,152 @@
+use crate::ansi::normalize::IdentifierCase;
+use crate::common::split::BatchSeparator;
+
+/// Options of the script parsing [(1)].
+///
+/// The default options keep the behavior of [`parse_script`][(2)]: reserved
+/// words are accepted as identifiers, there are no limits, identifiers are
+/// kept as written, the statement terminator is optional, only semicolons
+/// terminate statements, and table element lists can't be empty.
+///
+/// [(1)]: crate::ansi::parser::parse_script_with
+/// [(2)]: crate::ansi::parser::parse_script
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ParserOptions {
+    mode: ParserMode,
+    /// Maximum depth of nested parentheses in a statement.
+    opt_max_nesting_depth: Option<usize>,
+    /// Maximum length of a statement, in bytes.
+    opt_max_statement_length: Option<usize>,
+    /// Case that unquoted identifiers are folded to after parsing.
+    identifier_case: IdentifierCase,
+    /// Whether every statement must be terminated by a semicolon.
+    require_semicolon: bool,
+    /// Separator of statement batches, besides the semicolons.
+    batch_separator: BatchSeparator,
+    /// Whether `CREATE TABLE` accepts an empty table element list, in
+    /// permissive mode.
+    allow_empty_table_element_list: bool,
+}
+
+/// Conformance level of the parsed statements.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum ParserMode {
+    /// Accepts `ANSI` reserved words as unquoted identifiers, as most
+    /// databases do, and dialect extensions such as `EXPLAIN` or `RENAME TABLE`.
+    #[default]
+    Permissive,
+    /// Rejects `ANSI` reserved words as unquoted identifiers and dialect
+    /// extensions.
+    Strict,
+}
+
+impl ParserOptions {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            mode: ParserMode::Permissive,
+            opt_max_nesting_depth: None,
+            opt_max_statement_length: None,
+            identifier_case: IdentifierCase::Preserve,
+            require_semicolon: false,
+            batch_separator: BatchSeparator::Semicolon,
+            allow_empty_table_element_list: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_mode(mut self, mode: ParserMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_nesting_depth(mut self, max_nesting_depth: usize) -> Self {
+        self.opt_max_nesting_depth = Some(max_nesting_depth);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_statement_length(mut self, max_statement_length: usize) -> Self {
+        self.opt_max_statement_length = Some(max_statement_length);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_identifier_case(mut self, identifier_case: IdentifierCase) -> Self {
+        self.identifier_case = identifier_case;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_require_semicolon(mut self, require_semicolon: bool) -> Self {
+        self.require_semicolon = require_semicolon;
+        self
+    }
+
+    /// Sets the separator of statement batches (e.g., `MSSQL` `GO` lines).
+    ///
+    /// With a separator other than the semicolon, the script is split into
+    /// statements [(1)] before they are parsed, and the terminator is never
+    /// required, as in the client tools that recognize the separators.
+    ///
+    /// [(1)]: crate::common::split::split_statements_with
+    #[must_use]
+    pub const fn with_batch_separator(mut self, batch_separator: BatchSeparator) -> Self {
+        self.batch_separator = batch_separator;
+        self
+    }
+
+    /// Sets whether `CREATE TABLE` accepts an empty table element list (e.g.,
+    /// `CREATE TABLE tb ()`), as emitted by some tools. It's ignored in strict
+    /// mode, where the list must have at least one element.
+    #[must_use]
+    pub const fn with_allow_empty_table_element_list(
+        mut self,
+        allow_empty_table_element_list: bool,
+    ) -> Self {
+        self.allow_empty_table_element_list = allow_empty_table_element_list;
+        self
+    }
+
+    #[must_use]
+    pub const fn mode(&self) -> ParserMode {
+        self.mode
+    }
+
+    #[must_use]
+    pub const fn opt_max_nesting_depth(&self) -> Option<usize> {
+        self.opt_max_nesting_depth
+    }
+
+    #[must_use]
+    pub const fn opt_max_statement_length(&self) -> Option<usize> {
+        self.opt_max_statement_length
+    }
+
+    #[must_use]
+    pub const fn identifier_case(&self) -> IdentifierCase {
+        self.identifier_case
+    }
+
+    #[must_use]
+    pub const fn require_semicolon(&self) -> bool {
+        self.require_semicolon
+    }
+
+    #[must_use]
+    pub const fn batch_separator(&self) -> BatchSeparator {
+        self.batch_separator
+    }
+
+    #[must_use]
+    pub const fn allow_empty_table_element_list(&self) -> bool {
+        self.allow_empty_table_element_list
+    }
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
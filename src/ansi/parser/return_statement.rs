@@ -0,0 +1,22 @@
+use nom::sequence::delimited;
+use nom::IResult;
+
+use crate::ansi::ast::return_statement::Return;
+use crate::ansi::parser::common::value_specification;
+use crate::common::parsers::{keyword, statement_terminator, terminated_ws1};
+
+/// Parses a `RETURN` statement.
+///
+/// # Errors
+/// If the return statement is malformed or has unsupported features, this
+/// function call will fail. Check the return statement documentation
+/// [(1)][`Return`] for supported syntax.
+pub fn return_statement(i: &[u8]) -> IResult<&[u8], Return> {
+    let (i, return_value) = delimited(
+        terminated_ws1(keyword("RETURN")),
+        value_specification,
+        statement_terminator,
+    )(i)?;
+
+    Ok((i, Return::new(return_value)))
+}
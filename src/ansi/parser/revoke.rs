@@ -0,0 +1,91 @@
+use nom::branch::alt;
+use nom::combinator::{opt, value};
+use nom::sequence::{delimited, preceded, tuple};
+use nom::IResult;
+
+use crate::ansi::ast::revoke::{RevokeOptionExtension, RevokePrivilege};
+use crate::ansi::parser::common::drop_behavior;
+use crate::ansi::parser::grant::{granted_by, grantee_list, privileges};
+use crate::common::parsers::{
+    delimited_ws1, keyword, preceded_ws1, statement_terminator, terminated_ws1,
+};
+
+/// Parses a `REVOKE` privilege statement.
+///
+/// # Errors
+/// If the revoke statement is malformed or has unsupported features, this
+/// function call will fail. Check the revoke statement documentation
+/// [(1)][`RevokePrivilege`] for supported syntax.
+pub fn revoke_privilege(i: &[u8]) -> IResult<&[u8], RevokePrivilege> {
+    let (i, (opt_revoke_option_extension, privileges, grantees, opt_grantor, drop_behavior)) =
+        delimited(
+            terminated_ws1(keyword("REVOKE")),
+            tuple((
+                opt(terminated_ws1(revoke_option_extension)),
+                privileges,
+                preceded(delimited_ws1(keyword("FROM")), grantee_list),
+                opt(preceded_ws1(granted_by)),
+                preceded_ws1(drop_behavior),
+            )),
+            statement_terminator,
+        )(i)?;
+
+    let mut revoke = RevokePrivilege::new(privileges, grantees, drop_behavior);
+    if let Some(revoke_option_extension) = opt_revoke_option_extension {
+        revoke = revoke.with_revoke_option_extension(revoke_option_extension);
+    }
+    if let Some(grantor) = opt_grantor {
+        revoke = revoke.with_grantor(grantor);
+    }
+
+    Ok((i, revoke))
+}
+
+fn revoke_option_extension(i: &[u8]) -> IResult<&[u8], RevokeOptionExtension> {
+    alt((
+        value(
+            RevokeOptionExtension::GrantOption,
+            keyword("GRANT OPTION FOR"),
+        ),
+        value(
+            RevokeOptionExtension::HierarchyOption,
+            keyword("HIERARCHY OPTION FOR"),
+        ),
+    ))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(
+        "REVOKE SELECT ON tb FROM usr CASCADE",
+        "REVOKE SELECT ON tb FROM usr CASCADE"
+    )]
+    #[test_case(
+        "revoke grant option for update (a) on table tb from a,b restrict",
+        "REVOKE GRANT OPTION FOR UPDATE (a) ON tb FROM a, b RESTRICT"
+    )]
+    #[test_case(
+        "revoke hierarchy option for under on type typ from public granted by current_user cascade",
+        "REVOKE HIERARCHY OPTION FOR UNDER ON TYPE typ FROM PUBLIC GRANTED BY CURRENT_USER CASCADE"
+    )]
+    #[test_case(
+        "REVOKE EXECUTE ON FUNCTION fn() FROM usr RESTRICT",
+        "REVOKE EXECUTE ON FUNCTION fn() FROM usr RESTRICT"
+    )]
+    fn parse_revoke_privilege(input: &str, expected: &str) {
+        let (_, revoke) = revoke_privilege(input.as_bytes()).unwrap();
+
+        assert_str_eq!(expected, revoke.to_string());
+    }
+
+    #[test_case("REVOKE SELECT ON tb FROM usr"; "missing drop behavior")]
+    #[test_case("REVOKE SELECT ON tb CASCADE"; "missing grantees")]
+    fn parse_revoke_privilege_failure(input: &str) {
+        assert!(revoke_privilege(input.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use nom::branch::alt;
+use nom::combinator::{map, value};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, preceded, separated_pair, tuple};
+use nom::IResult;
+
+use crate::ansi::ast::update::{SetClause, UpdatePositioned, UpdateSource};
+use crate::ansi::parser::common::{table_name, value_specification, where_current_of};
+use crate::common::parsers::{delimited_ws0, ident, keyword, statement_terminator, terminated_ws1};
+use crate::common::tokens::{comma, equals_operator};
+
+/// Parses a positioned `UPDATE` statement.
+///
+/// # Errors
+/// If the update statement is malformed or has unsupported features (e.g.,
+/// it's a searched update), this function call will fail. Check the update
+/// statement documentation [(1)][`UpdatePositioned`] for supported syntax.
+pub fn update_positioned(i: &[u8]) -> IResult<&[u8], UpdatePositioned> {
+    let (i, (table_name, set_clause_list, cursor_name)) = delimited(
+        terminated_ws1(keyword("UPDATE")),
+        tuple((
+            terminated_ws1(table_name),
+            preceded(
+                terminated_ws1(keyword("SET")),
+                terminated_ws1(separated_list1(delimited_ws0(comma), set_clause)),
+            ),
+            where_current_of,
+        )),
+        statement_terminator,
+    )(i)?;
+
+    Ok((
+        i,
+        UpdatePositioned::new(table_name, set_clause_list, cursor_name),
+    ))
+}
+
+fn set_clause(i: &[u8]) -> IResult<&[u8], SetClause> {
+    map(
+        separated_pair(ident, delimited_ws0(equals_operator), update_source),
+        |(column_name, update_source)| SetClause::new(column_name, update_source),
+    )(i)
+}
+
+fn update_source(i: &[u8]) -> IResult<&[u8], UpdateSource> {
+    alt((
+        value(UpdateSource::Default, keyword("DEFAULT")),
+        map(value_specification, UpdateSource::Value),
+    ))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(
+        "UPDATE tb SET a = 1 WHERE CURRENT OF c",
+        "UPDATE tb SET a = 1 WHERE CURRENT OF c"
+    )]
+    #[test_case(
+        "update sch.tb set a=:a ,b = default,c=null where current of c",
+        "UPDATE sch.tb SET a = :a, b = DEFAULT, c = NULL WHERE CURRENT OF c"
+    )]
+    fn parse_update_positioned(input: &str, expected: &str) {
+        assert_str_eq!(
+            expected,
+            update_positioned(input.as_bytes()).unwrap().1.to_string()
+        );
+    }
+
+    #[test_case("UPDATE tb SET a = 1"; "searched without condition")]
+    #[test_case("UPDATE tb SET WHERE CURRENT OF c"; "empty set clause list")]
+    fn parse_update_positioned_failure(input: &str) {
+        assert!(update_positioned(input.as_bytes()).is_err());
+    }
+}
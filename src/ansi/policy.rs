@@ -0,0 +1,452 @@
+//! Policies over destructive operations, to gate migration scripts.
+//!
+//! A [`PolicyChecker`] reports every statement of a script that violates its
+//! policies as an error [`Diagnostic`], so a non-empty result can fail a CI
+//! job. By default, every policy is enforced, as expected for scripts applied
+//! to production databases.
+use crate::ansi::ast::alter_table::{AlterColumnAction, AlterTableAction};
+use crate::ansi::ast::common::{
+    ColumnConstraint, ColumnDefinition, TableName, UniqueSpecification,
+};
+use crate::ansi::ast::create_table::{CreateTable, TableContentsSource};
+use crate::ansi::ast::data_types::DataType;
+use crate::ansi::lint::{Diagnostic, Severity};
+use crate::ansi::parser::{parse_script, ScriptError};
+use crate::ansi::Statement;
+use crate::common::span::Span;
+use crate::common::Ident;
+
+/// Name of the policy that forbids dropping schemas, tables and columns.
+pub const DESTRUCTIVE_DROP: &str = "destructive-drop";
+
+/// Name of the policy that forbids changing data types in a way that may
+/// truncate or reject the stored values [(1)].
+///
+/// [(1)]: DataType::is_narrowing_to
+pub const TYPE_NARROWING: &str = "type-narrowing";
+
+/// Name of the policy that forbids adding columns that can't be null without
+/// a value for the existing rows.
+///
+/// As column defaults aren't supported, only identity columns can be added
+/// with a `NOT NULL` or `PRIMARY KEY` constraint.
+pub const NOT_NULL_WITHOUT_DEFAULT: &str = "not-null-without-default";
+
+/// Configurable checker of the destructive operation policies.
+///
+/// The data type of the altered columns is tracked from the table
+/// definitions given to the checker and the statements of the checked script.
+/// Changing the data type of an unknown column violates the type narrowing
+/// policy, as the change can't be proven safe.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::policy::PolicyChecker;
+/// let sql = "CREATE TABLE tb (name VARCHAR(20));\n\
+///            ALTER TABLE tb ALTER COLUMN name SET DATA TYPE VARCHAR(10);\n\
+///            DROP TABLE tb RESTRICT;";
+///
+/// let diagnostics = PolicyChecker::new().check(sql).unwrap();
+/// assert_eq!(
+///     diagnostics
+///         .iter()
+///         .map(ToString::to_string)
+///         .collect::<Vec<_>>(),
+///     vec![
+///         "36..94: error[type-narrowing]: column `tb.name` is narrowed from `VARCHAR(20)` to `VARCHAR(10)`",
+///         "96..118: error[destructive-drop]: table `tb` is dropped",
+///     ]
+/// );
+///
+/// let diagnostics = PolicyChecker::new().with_allow_drops(true).check(sql).unwrap();
+/// assert_eq!(diagnostics.len(), 1);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PolicyChecker {
+    /// Whether schemas, tables and columns can be dropped.
+    allow_drops: bool,
+    /// Whether data types can be narrowed.
+    allow_type_narrowing: bool,
+    /// Whether columns that can't be null can be added without a default.
+    allow_not_null_without_default: bool,
+    /// Definitions of the tables before the checked scripts are applied.
+    table_definitions: Vec<CreateTable>,
+}
+
+/// Data type of a column, tracked while checking a script.
+struct ColumnType {
+    table_name: TableName,
+    column_name: Ident,
+    opt_data_type: Option<DataType>,
+}
+
+impl PolicyChecker {
+    /// Creates a checker that enforces every policy.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            allow_drops: false,
+            allow_type_narrowing: false,
+            allow_not_null_without_default: false,
+            table_definitions: vec![],
+        }
+    }
+
+    #[must_use]
+    pub const fn with_allow_drops(mut self, allow_drops: bool) -> Self {
+        self.allow_drops = allow_drops;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_allow_type_narrowing(mut self, allow_type_narrowing: bool) -> Self {
+        self.allow_type_narrowing = allow_type_narrowing;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_allow_not_null_without_default(
+        mut self,
+        allow_not_null_without_default: bool,
+    ) -> Self {
+        self.allow_not_null_without_default = allow_not_null_without_default;
+        self
+    }
+
+    /// Sets the definitions of the existing tables, so the data type changes
+    /// of their columns can be checked.
+    #[must_use]
+    pub fn with_table_definitions(mut self, table_definitions: Vec<CreateTable>) -> Self {
+        self.table_definitions = table_definitions;
+        self
+    }
+
+    #[must_use]
+    pub const fn allow_drops(&self) -> bool {
+        self.allow_drops
+    }
+
+    #[must_use]
+    pub const fn allow_type_narrowing(&self) -> bool {
+        self.allow_type_narrowing
+    }
+
+    #[must_use]
+    pub const fn allow_not_null_without_default(&self) -> bool {
+        self.allow_not_null_without_default
+    }
+
+    #[must_use]
+    pub fn table_definitions(&self) -> &[CreateTable] {
+        &self.table_definitions
+    }
+
+    /// Parses the script and checks its statements.
+    ///
+    /// # Errors
+    /// If any statement of the script can't be parsed [(1)].
+    ///
+    /// [(1)]: parse_script
+    pub fn check(&self, sql: &str) -> Result<Vec<Diagnostic>, ScriptError> {
+        Ok(self.check_statements(&parse_script(sql)?))
+    }
+
+    /// Checks the statements, in order, each one with the span used for its
+    /// diagnostics.
+    #[must_use]
+    pub fn check_statements(&self, statements: &[(Statement, Span)]) -> Vec<Diagnostic> {
+        let mut column_types = vec![];
+        for create_table in &self.table_definitions {
+            push_table_columns(&mut column_types, create_table);
+        }
+
+        let mut diagnostics = vec![];
+        for (statement, span) in statements {
+            for (policy, message) in self.check_statement(&mut column_types, statement) {
+                diagnostics.push(Diagnostic::new(policy, Severity::Error, message, *span));
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Returns the violated policies of the statement, with their messages,
+    /// and tracks the column data types it changes.
+    fn check_statement(
+        &self,
+        column_types: &mut Vec<ColumnType>,
+        statement: &Statement,
+    ) -> Vec<(&'static str, String)> {
+        let mut violations = vec![];
+        let mut drop = |dropped: String| {
+            if !self.allow_drops {
+                violations.push((DESTRUCTIVE_DROP, format!("{dropped} is dropped")));
+            }
+        };
+
+        match statement {
+            Statement::DropSchema(drop_schema) => {
+                drop(format!("schema `{}`", drop_schema.schema_name()));
+            }
+            Statement::DropTable(drop_table) => {
+                let table_name = drop_table.table_name();
+                column_types.retain(|column_type| !column_type.table_name.refers_to(table_name));
+                drop(format!("table `{table_name}`"));
+            }
+            Statement::CreateTable(create_table) => {
+                push_table_columns(column_types, create_table);
+            }
+            Statement::AlterTable(alter_table) => {
+                let table_name = alter_table.table_name();
+                let position = |column_types: &[ColumnType], column_name: &Ident| {
+                    column_types.iter().position(|column_type| {
+                        column_type.table_name.refers_to(table_name)
+                            && column_type.column_name.refers_to(column_name)
+                    })
+                };
+
+                match alter_table.alter_table_action() {
+                    AlterTableAction::AddColumn(column_definition) => {
+                        push_column(column_types, table_name, column_definition);
+                        let opt_message =
+                            added_not_null_without_default(table_name, column_definition);
+                        if let (Some(message), false) =
+                            (opt_message, self.allow_not_null_without_default)
+                        {
+                            violations.push((NOT_NULL_WITHOUT_DEFAULT, message));
+                        }
+                    }
+                    AlterTableAction::DropColumn(column_name, _) => {
+                        if let Some(index) = position(column_types, column_name) {
+                            column_types.remove(index);
+                        }
+                        drop(format!("column `{table_name}.{column_name}`"));
+                    }
+                    AlterTableAction::AlterColumn(
+                        column_name,
+                        AlterColumnAction::SetDataType(data_type),
+                    ) => {
+                        let opt_index = position(column_types, column_name);
+                        let opt_previous =
+                            opt_index.and_then(|index| column_types[index].opt_data_type);
+                        if let Some(index) = opt_index {
+                            column_types[index].opt_data_type = Some(*data_type);
+                        }
+
+                        let message = match opt_previous {
+                            Some(previous) if !previous.is_narrowing_to(data_type) => None,
+                            Some(previous) => Some(format!(
+                                "column `{table_name}.{column_name}` is narrowed from \
+                                 `{previous}` to `{data_type}`"
+                            )),
+                            None => Some(format!(
+                                "column `{table_name}.{column_name}` is changed to \
+                                 `{data_type}` from an unknown data type"
+                            )),
+                        };
+                        if let (Some(message), false) = (message, self.allow_type_narrowing) {
+                            violations.push((TYPE_NARROWING, message));
+                        }
+                    }
+                    AlterTableAction::AlterColumn(_, AlterColumnAction::DropIdentity) => {}
+                    AlterTableAction::RenameColumn(column_name, new_column_name) => {
+                        if let Some(index) = position(column_types, column_name) {
+                            column_types[index].column_name = new_column_name.clone();
+                        }
+                    }
+                    AlterTableAction::RenameTable(new_table_name) => {
+                        for column_type in column_types
+                            .iter_mut()
+                            .filter(|column_type| column_type.table_name.refers_to(table_name))
+                        {
+                            column_type.table_name = new_table_name.clone();
+                        }
+                    }
+                }
+            }
+            // OBS: only `EXPLAIN ANALYZE` executes the explained statement.
+            Statement::Explain(explain) if explain.analyze() => {
+                return self.check_statement(column_types, explain.statement());
+            }
+            Statement::CreateSchema(_)
+            | Statement::Open(_)
+            | Statement::Fetch(_)
+            | Statement::Close(_)
+            | Statement::Call(_)
+            | Statement::Return(_)
+            | Statement::UpdatePositioned(_)
+            | Statement::DeletePositioned(_)
+            | Statement::Explain(_)
+            | Statement::GrantPrivilege(_)
+            | Statement::RevokePrivilege(_) => {}
+        }
+
+        violations
+    }
+}
+
+impl Default for PolicyChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Tracks the columns of the created table, replacing any previous definition
+/// of the table.
+fn push_table_columns(column_types: &mut Vec<ColumnType>, create_table: &CreateTable) {
+    let table_name = create_table.table_name();
+    column_types.retain(|column_type| !column_type.table_name.refers_to(table_name));
+
+    let TableContentsSource::TableElementList(table_element_list) =
+        create_table.table_contents_source();
+    for column_definition in table_element_list.columns() {
+        push_column(column_types, table_name, column_definition);
+    }
+}
+
+/// Returns the violation message if the added column can't be null and has no
+/// value for the existing rows.
+fn added_not_null_without_default(
+    table_name: &TableName,
+    column_definition: &ColumnDefinition,
+) -> Option<String> {
+    let not_null = column_definition
+        .column_constraint_definitions()
+        .iter()
+        .any(|column_constraint_definition| {
+            matches!(
+                column_constraint_definition.column_constraint(),
+                ColumnConstraint::NotNull
+                    | ColumnConstraint::Unique(UniqueSpecification::PrimaryKey)
+            )
+        });
+
+    (not_null
+        && column_definition
+            .opt_identity_column_specification()
+            .is_none())
+    .then(|| {
+        format!(
+            "column `{table_name}.{}` is added as not null without a default",
+            column_definition.column_name()
+        )
+    })
+}
+
+fn push_column(
+    column_types: &mut Vec<ColumnType>,
+    table_name: &TableName,
+    column_definition: &ColumnDefinition,
+) {
+    column_types.push(ColumnType {
+        table_name: table_name.clone(),
+        column_name: column_definition.column_name().clone(),
+        opt_data_type: column_definition.opt_data_type(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::parser::create_table::create_table;
+
+    use super::*;
+
+    fn violations(checker: &PolicyChecker, sql: &str) -> Vec<String> {
+        checker
+            .check(sql)
+            .unwrap()
+            .iter()
+            .map(|diagnostic| format!("{}: {}", diagnostic.rule(), diagnostic.message()))
+            .collect()
+    }
+
+    #[test_case("DROP SCHEMA sch RESTRICT", &["destructive-drop: schema `sch` is dropped"])]
+    #[test_case("DROP TABLE sch.tb CASCADE", &["destructive-drop: table `sch.tb` is dropped"])]
+    #[test_case(
+        "ALTER TABLE tb DROP COLUMN id RESTRICT",
+        &["destructive-drop: column `tb.id` is dropped"]
+    )]
+    #[test_case(
+        "EXPLAIN ANALYZE DROP TABLE tb RESTRICT; EXPLAIN DROP TABLE tb RESTRICT",
+        &["destructive-drop: table `tb` is dropped"] ;
+        "only executed explains"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT, b VARCHAR(10)); \
+         ALTER TABLE tb ALTER COLUMN a SET DATA TYPE BIGINT; \
+         ALTER TABLE tb ALTER COLUMN b SET DATA TYPE CHARACTER VARYING(10)",
+        &[] ;
+        "widening"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a DECIMAL(10, 2)); \
+         ALTER TABLE tb ALTER COLUMN a SET DATA TYPE DECIMAL(10, 1)",
+        &["type-narrowing: column `tb.a` is narrowed from `DECIMAL(10, 2)` to `DECIMAL(10, 1)`"] ;
+        "reduced scale"
+    )]
+    #[test_case(
+        "ALTER TABLE tb ADD COLUMN a TIMESTAMP; \
+         ALTER TABLE tb RENAME COLUMN a TO b; \
+         ALTER TABLE tb ALTER COLUMN b SET DATA TYPE DATE",
+        &["type-narrowing: column `tb.b` is narrowed from `TIMESTAMP` to `DATE`"] ;
+        "renamed column"
+    )]
+    #[test_case(
+        "ALTER TABLE tb ALTER COLUMN a SET DATA TYPE BIGINT",
+        &["type-narrowing: column `tb.a` is changed to `BIGINT` from an unknown data type"] ;
+        "unknown column"
+    )]
+    #[test_case(
+        "ALTER TABLE tb ADD COLUMN a INT NOT NULL; \
+         ALTER TABLE tb ADD COLUMN b INT PRIMARY KEY",
+        &[
+            "not-null-without-default: column `tb.a` is added as not null without a default",
+            "not-null-without-default: column `tb.b` is added as not null without a default",
+        ] ;
+        "not null added column"
+    )]
+    #[test_case(
+        "ALTER TABLE tb ADD COLUMN a INT UNIQUE; \
+         ALTER TABLE tb ADD COLUMN b INT GENERATED ALWAYS AS IDENTITY NOT NULL; \
+         CREATE TABLE tc (c INT NOT NULL)",
+        &[] ;
+        "nullable or generated added column"
+    )]
+    fn check(sql: &str, expected: &[&str]) {
+        assert_eq!(expected, violations(&PolicyChecker::new(), sql));
+    }
+
+    #[test]
+    fn allowed_operations() {
+        let checker = PolicyChecker::new()
+            .with_allow_drops(true)
+            .with_allow_type_narrowing(true)
+            .with_allow_not_null_without_default(true);
+
+        assert_eq!(
+            Vec::<String>::new(),
+            violations(
+                &checker,
+                "DROP TABLE tb RESTRICT; ALTER TABLE tb ALTER COLUMN a SET DATA TYPE SMALLINT; \
+                 ALTER TABLE tb ADD COLUMN b INT NOT NULL"
+            )
+        );
+    }
+
+    #[test]
+    fn existing_table_definitions() {
+        let (_, users) = create_table(b"CREATE TABLE users (id BIGINT)").unwrap();
+        let checker = PolicyChecker::new().with_table_definitions(vec![users]);
+
+        assert_eq!(
+            vec!["type-narrowing: column `USERS.id` is narrowed from `BIGINT` to `INT`"],
+            violations(
+                &checker,
+                "ALTER TABLE USERS ALTER COLUMN id SET DATA TYPE INT"
+            )
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::ansi::visitor::VisitorMut;
+use crate::common::expr::Literal;
+
+/// Value that replaces the redacted literals [(1)].
+///
+/// [(1)]: crate::ansi::Statement::redact
+pub const REDACTED_LITERAL: &str = "?";
+
+/// Visitor that replaces the string, binary and numeric literals of a
+/// statement by a `'?'` character string, so the statement can be logged
+/// without its values.
+///
+/// Boolean literals carry no user data, so they are kept.
+pub(crate) struct Redactor;
+
+impl VisitorMut for Redactor {
+    fn visit_literal(&mut self, literal: &mut Literal) {
+        match literal {
+            Literal::Numeric(_)
+            | Literal::CharacterString(_)
+            | Literal::EscapeString(_)
+            | Literal::DollarQuotedString(_)
+            | Literal::Binary(_) => {
+                *literal = Literal::CharacterString(REDACTED_LITERAL.to_string());
+            }
+            Literal::True | Literal::False | Literal::Unknown => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_statement;
+
+    #[test_case(
+        "CALL login('admin', 'hunter2', 42, :session)",
+        "CALL login('?', '?', '?', :session)" ;
+        "strings and numbers"
+    )]
+    #[test_case(
+        "CALL f(E'it\\'s', $tag$secret$tag$, X'FF', -1.5E3)",
+        "CALL f('?', '?', '?', '?')" ;
+        "dialect literals"
+    )]
+    #[test_case(
+        "UPDATE tb SET a = TRUE, b = NULL, c = 'x' WHERE CURRENT OF cur",
+        "UPDATE tb SET a = TRUE, b = NULL, c = '?' WHERE CURRENT OF cur" ;
+        "booleans and null are kept"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT GENERATED ALWAYS AS IDENTITY (START WITH 10))",
+        "CREATE TABLE tb (id INT GENERATED ALWAYS AS IDENTITY (START WITH 10))" ;
+        "identity options are not literals"
+    )]
+    fn redact(input: &str, expected: &str) {
+        let (_, statement) = parse_statement(input.as_ref()).unwrap();
+
+        assert_str_eq!(expected, statement.redact().to_string());
+    }
+}
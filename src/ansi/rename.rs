@@ -0,0 +1,349 @@
+//! Rename refactorings over whole scripts.
+//!
+//! References are matched following the identifiers case folding [(1)], so
+//! `users`, `USERS` and `"USERS"` are all renamed, while `"users"` is not. The
+//! qualification of each reference is kept as written, and only its name is
+//! replaced by the new identifier, with the quote style of the new identifier.
+//!
+//! Renames performed by the script itself (e.g., `ALTER TABLE .. RENAME TO`)
+//! are not followed, so the references to the new name are not changed.
+//!
+//! [(1)]: crate::common::Ident::refers_to
+use crate::ansi::ast::alter_table::{AlterTable, AlterTableAction};
+use crate::ansi::ast::common::{ReferencesSpecification, TableName};
+use crate::ansi::ast::create_table::{
+    CreateTable, TableConstraint, TableContentsSource, TableElement,
+};
+use crate::ansi::ast::grant::{GrantObject, ObjectPrivileges, Privileges};
+use crate::ansi::ast::update::UpdatePositioned;
+use crate::ansi::visitor::{
+    walk_alter_table, walk_create_table, walk_privileges, walk_references_specification,
+    walk_table_name, walk_update_positioned, VisitorMut,
+};
+use crate::ansi::Statement;
+use crate::common::expr::Expr;
+use crate::common::Ident;
+
+/// Renames every reference to the `old` table, in both data definition and
+/// data manipulation statements.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::common::TableName;
+/// # use sql_helper::ansi::parser::parse_script;
+/// # use sql_helper::ansi::rename::rename_table;
+/// # use sql_helper::ansi::script::{to_script, TerminatorPolicy};
+/// let mut statements = parse_script("CREATE TABLE users (id INT); DELETE FROM USERS WHERE CURRENT OF cur")
+///     .unwrap()
+///     .into_iter()
+///     .map(|(statement, _)| statement)
+///     .collect::<Vec<_>>();
+///
+/// rename_table(&mut statements, &TableName::new("users"), &"accounts".into());
+///
+/// assert_eq!(
+///     to_script(&statements, TerminatorPolicy::Separator),
+///     "CREATE TABLE accounts (id INT);\nDELETE FROM accounts WHERE CURRENT OF cur"
+/// );
+/// ```
+pub fn rename_table(statements: &mut [Statement], old: &TableName, new: &Ident) {
+    let mut renamer = TableRenamer { old, new };
+    for statement in statements {
+        renamer.visit_statement(statement);
+    }
+}
+
+/// Renames every reference to the `old` column of the received table, in both
+/// data definition and data manipulation statements.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::common::TableName;
+/// # use sql_helper::ansi::parser::parse_script;
+/// # use sql_helper::ansi::rename::rename_column;
+/// # use sql_helper::ansi::script::{to_script, TerminatorPolicy};
+/// let mut statements = parse_script("CREATE TABLE users (name INT); UPDATE users SET name = 1 WHERE CURRENT OF cur")
+///     .unwrap()
+///     .into_iter()
+///     .map(|(statement, _)| statement)
+///     .collect::<Vec<_>>();
+///
+/// rename_column(
+///     &mut statements,
+///     &TableName::new("users"),
+///     &"name".into(),
+///     &"full_name".into(),
+/// );
+///
+/// assert_eq!(
+///     to_script(&statements, TerminatorPolicy::Separator),
+///     "CREATE TABLE users (full_name INT);\nUPDATE users SET full_name = 1 WHERE CURRENT OF cur"
+/// );
+/// ```
+pub fn rename_column(statements: &mut [Statement], table: &TableName, old: &Ident, new: &Ident) {
+    let mut renamer = ColumnRenamer { table, old, new };
+    for statement in statements {
+        renamer.visit_statement(statement);
+    }
+}
+
+/// Renames the column in the definition of its own table, as [`rename_column`]
+/// does for the statements that define the table.
+pub(crate) fn rename_table_column(create_table: &mut CreateTable, old: &Ident, new: &Ident) {
+    let table = create_table.table_name().clone();
+    ColumnRenamer {
+        table: &table,
+        old,
+        new,
+    }
+    .visit_create_table(create_table);
+}
+
+/// Visitor that renames the references to a table.
+struct TableRenamer<'a> {
+    old: &'a TableName,
+    new: &'a Ident,
+}
+
+impl VisitorMut for TableRenamer<'_> {
+    fn visit_table_name(&mut self, table_name: &mut TableName) {
+        if table_name.refers_to(self.old) {
+            *table_name.name_mut() = self.new.clone();
+        }
+        walk_table_name(self, table_name);
+    }
+}
+
+/// Visitor that renames the references to a column of a table.
+struct ColumnRenamer<'a> {
+    table: &'a TableName,
+    old: &'a Ident,
+    new: &'a Ident,
+}
+
+impl ColumnRenamer<'_> {
+    fn rename(&self, column_name: &mut Ident) {
+        if column_name.refers_to(self.old) {
+            *column_name = self.new.clone();
+        }
+    }
+}
+
+impl VisitorMut for ColumnRenamer<'_> {
+    fn visit_create_table(&mut self, create_table: &mut CreateTable) {
+        if create_table.table_name().refers_to(self.table) {
+            match create_table.table_contents_source_mut() {
+                TableContentsSource::TableElementList(table_element_list) => {
+                    for table_element in table_element_list.element_list_mut() {
+                        match table_element {
+                            TableElement::ColumnDefinition(column_definition) => {
+                                self.rename(column_definition.column_name_mut());
+                            }
+                            TableElement::TableConstraintDefinition(
+                                table_constraint_definition,
+                            ) => match table_constraint_definition.table_constraint_mut() {
+                                TableConstraint::Unique(unique_constraint_definition) => {
+                                    for key_column in unique_constraint_definition.key_columns_mut()
+                                    {
+                                        self.rename(key_column.column_name_mut());
+                                    }
+                                }
+                                TableConstraint::Referential(referential_constraint_definition) => {
+                                    for column_name in referential_constraint_definition
+                                        .referencing_columns_mut()
+                                        .column_names_mut()
+                                    {
+                                        self.rename(column_name);
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+
+            if let Some(partition_by) = create_table.opt_partition_by_mut() {
+                for partition_key in partition_by.partition_keys_mut() {
+                    if let Expr::Identifier(column_name) = partition_key {
+                        self.rename(column_name);
+                    }
+                }
+            }
+        }
+        walk_create_table(self, create_table);
+    }
+
+    fn visit_alter_table(&mut self, alter_table: &mut AlterTable) {
+        if alter_table.table_name().refers_to(self.table) {
+            match alter_table.alter_table_action_mut() {
+                AlterTableAction::AddColumn(column_definition) => {
+                    self.rename(column_definition.column_name_mut());
+                }
+                AlterTableAction::AlterColumn(column_name, _)
+                | AlterTableAction::DropColumn(column_name, _)
+                | AlterTableAction::RenameColumn(column_name, _) => self.rename(column_name),
+                AlterTableAction::RenameTable(_) => {}
+            }
+        }
+        walk_alter_table(self, alter_table);
+    }
+
+    fn visit_update_positioned(&mut self, update: &mut UpdatePositioned) {
+        if update.table_name().refers_to(self.table) {
+            for set_clause in update.set_clause_list_mut() {
+                self.rename(set_clause.column_name_mut());
+            }
+        }
+        walk_update_positioned(self, update);
+    }
+
+    fn visit_privileges(&mut self, privileges: &mut Privileges) {
+        let is_table = matches!(
+            privileges.object(),
+            GrantObject::Table(table_name) if table_name.refers_to(self.table)
+        );
+        if let (true, ObjectPrivileges::Actions(actions)) =
+            (is_table, privileges.object_privileges_mut())
+        {
+            for column_name_list in actions
+                .iter_mut()
+                .filter_map(|action| action.opt_column_name_list_mut())
+            {
+                for column_name in column_name_list.column_names_mut() {
+                    self.rename(column_name);
+                }
+            }
+        }
+        walk_privileges(self, privileges);
+    }
+
+    fn visit_references_specification(
+        &mut self,
+        references_specification: &mut ReferencesSpecification,
+    ) {
+        if references_specification.table_name().refers_to(self.table) {
+            if let Some(referenced_columns) = references_specification.opt_referenced_columns_mut()
+            {
+                for column_name in referenced_columns.column_names_mut() {
+                    self.rename(column_name);
+                }
+            }
+        }
+        walk_references_specification(self, references_specification);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use crate::ansi::ast::common::{LocalOrSchemaQualifier, SchemaName};
+    use crate::ansi::parser::parse_script;
+    use crate::ansi::script::{to_script, TerminatorPolicy};
+
+    use super::*;
+
+    fn parse(input: &str) -> Vec<Statement> {
+        parse_script(input)
+            .unwrap()
+            .into_iter()
+            .map(|(statement, _)| statement)
+            .collect()
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (id INT); DROP TABLE tb CASCADE",
+        &TableName::new("tb"),
+        "CREATE TABLE renamed (id INT);\nDROP TABLE renamed CASCADE" ;
+        "ddl"
+    )]
+    #[test_case(
+        "CREATE TABLE other (id INT REFERENCES tb, FOREIGN KEY (id) REFERENCES tb (id))",
+        &TableName::new("tb"),
+        "CREATE TABLE other (id INT REFERENCES renamed, FOREIGN KEY (id) REFERENCES renamed (id))" ;
+        "foreign keys"
+    )]
+    #[test_case(
+        "UPDATE TB SET a = 1 WHERE CURRENT OF cur; DELETE FROM \"TB\" WHERE CURRENT OF cur",
+        &TableName::new("tb"),
+        "UPDATE renamed SET a = 1 WHERE CURRENT OF cur;\nDELETE FROM renamed WHERE CURRENT OF cur" ;
+        "dml with case folding"
+    )]
+    #[test_case(
+        "DROP TABLE \"tb\" CASCADE",
+        &TableName::new("tb"),
+        "DROP TABLE \"tb\" CASCADE" ;
+        "case-sensitive quoted name is kept"
+    )]
+    #[test_case(
+        "DROP TABLE sch.tb CASCADE; DROP TABLE other.tb CASCADE; DROP TABLE tb CASCADE",
+        &TableName::new("tb").with_local_or_schema(LocalOrSchemaQualifier::Schema(SchemaName::new("sch"))),
+        "DROP TABLE sch.renamed CASCADE;\nDROP TABLE other.tb CASCADE;\nDROP TABLE tb CASCADE" ;
+        "qualification"
+    )]
+    #[test_case(
+        "GRANT SELECT ON TABLE tb TO PUBLIC; EXPLAIN DELETE FROM tb WHERE CURRENT OF cur",
+        &TableName::new("tb"),
+        "GRANT SELECT ON renamed TO PUBLIC;\nEXPLAIN DELETE FROM renamed WHERE CURRENT OF cur" ;
+        "nested references"
+    )]
+    fn rename_tables(input: &str, old: &TableName, expected: &str) {
+        let mut statements = parse(input);
+
+        rename_table(&mut statements, old, &Ident::from("renamed"));
+
+        assert_str_eq!(
+            expected,
+            to_script(&statements, TerminatorPolicy::Separator)
+        );
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (col INT, other INT); CREATE TABLE tb2 (col INT)",
+        "CREATE TABLE tb (renamed INT, other INT);\nCREATE TABLE tb2 (col INT)" ;
+        "column definitions"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (col INT, other INT, PRIMARY KEY (col DESC), UNIQUE (other))",
+        "CREATE TABLE tb (renamed INT, other INT, PRIMARY KEY (renamed DESC), UNIQUE (other))" ;
+        "key columns"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (col INT, FOREIGN KEY (col) REFERENCES tb2 (col)); \
+         CREATE TABLE tb2 (col INT REFERENCES tb (col))",
+        "CREATE TABLE tb (renamed INT, FOREIGN KEY (renamed) REFERENCES tb2 (col));\n\
+         CREATE TABLE tb2 (col INT REFERENCES tb (renamed))" ;
+        "foreign keys"
+    )]
+    #[test_case(
+        "ALTER TABLE tb DROP COLUMN COL CASCADE; ALTER TABLE tb RENAME COLUMN col TO c",
+        "ALTER TABLE tb DROP COLUMN renamed CASCADE;\nALTER TABLE tb RENAME COLUMN renamed TO c" ;
+        "alter table actions"
+    )]
+    #[test_case(
+        "UPDATE tb SET col = 1, other = col WHERE CURRENT OF cur",
+        "UPDATE tb SET renamed = 1, other = col WHERE CURRENT OF cur" ;
+        "update targets"
+    )]
+    #[test_case(
+        "GRANT UPDATE (col, other) ON TABLE tb TO PUBLIC; GRANT UPDATE (col) ON TABLE tb2 TO PUBLIC",
+        "GRANT UPDATE (renamed, other) ON tb TO PUBLIC;\nGRANT UPDATE (col) ON tb2 TO PUBLIC" ;
+        "privileged columns"
+    )]
+    fn rename_columns(input: &str, expected: &str) {
+        let mut statements = parse(input);
+
+        rename_column(
+            &mut statements,
+            &TableName::new("tb"),
+            &Ident::from("col"),
+            &Ident::from("renamed"),
+        );
+
+        assert_str_eq!(
+            expected,
+            to_script(&statements, TerminatorPolicy::Separator)
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Serialization of statements into scripts.
+//!
+//! Statements never render their own terminator, so the terminators of a
+//! script are added here, following a [`TerminatorPolicy`].
+use crate::ansi::Statement;
+
+/// Policy used to terminate the statements of a script.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum TerminatorPolicy {
+    /// Every statement is terminated by a semicolon, in its own line
+    /// (`a;\nb;\n`).
+    #[default]
+    Always,
+    /// Semicolons only separate statements, so the last one is not terminated
+    /// (`a;\nb`).
+    Separator,
+}
+
+/// Serializes the statements into a script, terminating them following the
+/// received policy.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::parser::parse_script;
+/// # use sql_helper::ansi::script::{to_script, TerminatorPolicy};
+/// let statements = parse_script("CREATE SCHEMA sch; DROP TABLE sch.tb CASCADE")
+///     .unwrap()
+///     .into_iter()
+///     .map(|(statement, _)| statement)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     to_script(&statements, TerminatorPolicy::Always),
+///     "CREATE SCHEMA sch;\nDROP TABLE sch.tb CASCADE;\n"
+/// );
+/// assert_eq!(
+///     to_script(&statements, TerminatorPolicy::Separator),
+///     "CREATE SCHEMA sch;\nDROP TABLE sch.tb CASCADE"
+/// );
+/// ```
+#[must_use]
+pub fn to_script(statements: &[Statement], policy: TerminatorPolicy) -> String {
+    join_statements(statements.iter().map(ToString::to_string), policy)
+}
+
+/// Joins the already rendered statements into a script, terminating them
+/// following the received policy.
+pub(crate) fn join_statements(
+    statements: impl IntoIterator<Item = String>,
+    policy: TerminatorPolicy,
+) -> String {
+    let script = statements
+        .into_iter()
+        .fold(String::new(), |script, statement| {
+            script + &statement + ";\n"
+        });
+
+    match policy {
+        TerminatorPolicy::Always => script,
+        TerminatorPolicy::Separator => script.strip_suffix(";\n").unwrap_or_default().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_script;
+
+    use super::*;
+
+    #[test_case("DROP SCHEMA a CASCADE; DROP TABLE b RESTRICT")]
+    #[test_case("CREATE SCHEMA a;\nCREATE TABLE b (id INT);")]
+    #[test_case("DROP TABLE a CASCADE")]
+    fn script_round_trip(input: &str) {
+        let statements = parse_script(input)
+            .unwrap()
+            .into_iter()
+            .map(|(statement, _)| statement)
+            .collect::<Vec<_>>();
+
+        for policy in [TerminatorPolicy::Always, TerminatorPolicy::Separator] {
+            let script = to_script(&statements, policy);
+            let reparsed = parse_script(&script)
+                .unwrap()
+                .into_iter()
+                .map(|(statement, _)| statement)
+                .collect::<Vec<_>>();
+
+            assert_str_eq!(to_script(&statements, policy), to_script(&reparsed, policy));
+        }
+    }
+
+    #[test]
+    fn empty_script() {
+        assert_str_eq!("", to_script(&[], TerminatorPolicy::Always));
+        assert_str_eq!("", to_script(&[], TerminatorPolicy::Separator));
+    }
+}
@@ -0,0 +1,333 @@
+//! [proptest] strategies generating random `ANSI` statements.
+//!
+//! Every generated statement is displayed as SQL that the parser accepts, so
+//! they can be used to check that displaying and parsing a statement round
+//! trips (e.g., `parse(display(statement)) == statement`).
+//!
+//! # Examples
+//! ```rust
+//! # use proptest::strategy::{Strategy, ValueTree};
+//! # use proptest::test_runner::TestRunner;
+//! # use sql_helper::ansi::parser::parse_statement;
+//! # use sql_helper::ansi::strategies::statement;
+//! let mut runner = TestRunner::default();
+//! let statement = statement().new_tree(&mut runner).unwrap().current();
+//! let sql = statement.to_string();
+//!
+//! assert_eq!(parse_statement(sql.as_bytes()).map(|(_, parsed)| parsed), Ok(statement));
+//! ```
+//!
+//! [proptest]: https://docs.rs/proptest
+use proptest::option;
+use proptest::prelude::*;
+
+use crate::ansi::ast::alter_table::{AlterColumnAction, AlterTable, AlterTableAction};
+use crate::ansi::ast::close::Close;
+use crate::ansi::ast::common::{
+    ColumnDefinition, DropBehavior, IdentityColumnSpecification, IdentityGeneration,
+    LocalOrSchemaQualifier, LocalQualifier, SchemaName, TableName,
+};
+use crate::ansi::ast::create_schema::{CreateSchema, SchemaNameClause};
+use crate::ansi::ast::create_table::{
+    CreateTable, TableContentsSource, TableElement, TableElementList, TableScope,
+};
+use crate::ansi::ast::data_types::{
+    CharLengthUnits, CharacterLargeObjectLength, CharacterLength, DataType, ExactNumberInfo,
+    LargeObjectLength, Multiplier, NumericTypeAlias, WithOrWithoutTimeZone,
+};
+use crate::ansi::ast::drop_schema::DropSchema;
+use crate::ansi::ast::drop_table::DropTable;
+use crate::ansi::ast::open::Open;
+use crate::ansi::keywords::is_reserved_word;
+use crate::ansi::Statement;
+use crate::common::{Ident, QuoteStyle};
+
+/// Generates a statement of any of the supported kinds.
+pub fn statement() -> impl Strategy<Value = Statement> {
+    prop_oneof![
+        create_schema().prop_map(Statement::CreateSchema),
+        drop_schema().prop_map(Statement::DropSchema),
+        create_table().prop_map(Statement::CreateTable),
+        drop_table().prop_map(Statement::DropTable),
+        alter_table().prop_map(Statement::AlterTable),
+        ident().prop_map(|cursor_name| Statement::Open(Open::new(cursor_name))),
+        ident().prop_map(|cursor_name| Statement::Close(Close::new(cursor_name))),
+    ]
+}
+
+/// Generates a `CREATE SCHEMA` statement.
+pub fn create_schema() -> impl Strategy<Value = CreateSchema> {
+    prop_oneof![
+        schema_name().prop_map(SchemaNameClause::Simple),
+        ident().prop_map(SchemaNameClause::Authorization),
+        (schema_name(), ident()).prop_map(|(schema_name, authorization)| {
+            SchemaNameClause::NamedAuthorization(schema_name, authorization)
+        }),
+    ]
+    .prop_map(CreateSchema::new)
+}
+
+/// Generates a `DROP SCHEMA` statement.
+pub fn drop_schema() -> impl Strategy<Value = DropSchema> {
+    (schema_name(), drop_behavior())
+        .prop_map(|(schema_name, drop_behavior)| DropSchema::new(schema_name, drop_behavior))
+}
+
+/// Generates a `CREATE TABLE` statement.
+pub fn create_table() -> impl Strategy<Value = CreateTable> {
+    (
+        option::of(prop_oneof![
+            Just(TableScope::Global),
+            Just(TableScope::Local)
+        ]),
+        table_name(),
+        prop::collection::vec(column_definition(), 1..5),
+    )
+        .prop_map(|(opt_table_scope, table_name, column_definitions)| {
+            let element_list = column_definitions
+                .into_iter()
+                .map(TableElement::ColumnDefinition)
+                .collect();
+            let create_table = CreateTable::new(
+                table_name,
+                TableContentsSource::TableElementList(TableElementList::new(element_list)),
+            );
+
+            match opt_table_scope {
+                Some(table_scope) => create_table.with_table_scope(table_scope),
+                None => create_table,
+            }
+        })
+}
+
+/// Generates a `DROP TABLE` statement.
+pub fn drop_table() -> impl Strategy<Value = DropTable> {
+    (table_name(), drop_behavior())
+        .prop_map(|(table_name, drop_behavior)| DropTable::new(table_name, drop_behavior))
+}
+
+/// Generates an `ALTER TABLE` statement, including the dialect extensions.
+pub fn alter_table() -> impl Strategy<Value = AlterTable> {
+    let alter_table_action = prop_oneof![
+        column_definition().prop_map(AlterTableAction::AddColumn),
+        (ident(), data_type()).prop_map(|(column_name, data_type)| {
+            AlterTableAction::AlterColumn(column_name, AlterColumnAction::SetDataType(data_type))
+        }),
+        ident().prop_map(|column_name| {
+            AlterTableAction::AlterColumn(column_name, AlterColumnAction::DropIdentity)
+        }),
+        (ident(), drop_behavior()).prop_map(|(column_name, drop_behavior)| {
+            AlterTableAction::DropColumn(column_name, drop_behavior)
+        }),
+        table_name().prop_map(AlterTableAction::RenameTable),
+        (ident(), ident()).prop_map(|(column_name, new_column_name)| {
+            AlterTableAction::RenameColumn(column_name, new_column_name)
+        }),
+    ];
+
+    (table_name(), alter_table_action).prop_map(|(table_name, alter_table_action)| {
+        AlterTable::new(table_name, alter_table_action)
+    })
+}
+
+/// Generates a column definition, with or without data type and identity
+/// column specification.
+pub fn column_definition() -> impl Strategy<Value = ColumnDefinition> {
+    (
+        ident(),
+        option::of(data_type()),
+        option::of(identity_column_specification()),
+    )
+        .prop_map(
+            |(column_name, opt_data_type, opt_identity_column_specification)| {
+                let mut column_definition = ColumnDefinition::new(column_name);
+                if let Some(data_type) = opt_data_type {
+                    column_definition = column_definition.with_data_type(data_type);
+                }
+                if let Some(identity_column_specification) = opt_identity_column_specification {
+                    column_definition = column_definition
+                        .with_identity_column_specification(identity_column_specification);
+                }
+                column_definition
+            },
+        )
+}
+
+/// Generates an identity column specification.
+pub fn identity_column_specification() -> impl Strategy<Value = IdentityColumnSpecification> {
+    (
+        prop_oneof![
+            Just(IdentityGeneration::Always),
+            Just(IdentityGeneration::ByDefault)
+        ],
+        option::of(any::<i64>()),
+        option::of(any::<i64>()),
+    )
+        .prop_map(|(identity_generation, opt_start_with, opt_increment_by)| {
+            let mut specification = IdentityColumnSpecification::new(identity_generation);
+            if let Some(start_with) = opt_start_with {
+                specification = specification.with_start_with(start_with);
+            }
+            if let Some(increment_by) = opt_increment_by {
+                specification = specification.with_increment_by(increment_by);
+            }
+            specification
+        })
+}
+
+/// Generates a data type of any kind, with any combination of parameters.
+pub fn data_type() -> impl Strategy<Value = DataType> {
+    let character_length = || option::of(character_length());
+    let lob_length = || option::of(large_object_length());
+    let clob_length = || option::of(character_large_object_length());
+    let length = || option::of(1..=u32::MAX);
+    let precision = || option::of(0..=u32::MAX);
+
+    prop_oneof![
+        character_length().prop_map(DataType::Character),
+        character_length().prop_map(DataType::Char),
+        character_length().prop_map(DataType::CharacterVarying),
+        character_length().prop_map(DataType::CharVarying),
+        character_length().prop_map(DataType::Varchar),
+        clob_length().prop_map(DataType::CharacterLargeObject),
+        clob_length().prop_map(DataType::CharLargeObject),
+        clob_length().prop_map(DataType::Clob),
+        length().prop_map(DataType::Binary),
+        length().prop_map(DataType::BinaryVarying),
+        length().prop_map(DataType::Varbinary),
+        lob_length().prop_map(DataType::BinaryLargeObject),
+        lob_length().prop_map(DataType::Blob),
+        exact_number_info().prop_map(DataType::Numeric),
+        exact_number_info().prop_map(DataType::Decimal),
+        exact_number_info().prop_map(DataType::Dec),
+        Just(DataType::Smallint),
+        Just(DataType::Integer),
+        Just(DataType::Int),
+        Just(DataType::Bigint),
+        Just(DataType::Float),
+        Just(DataType::Real),
+        Just(DataType::DoublePrecision),
+        precision().prop_map(DataType::DecFloat),
+        Just(DataType::Boolean),
+        Just(DataType::Date),
+        (precision(), with_or_without_time_zone())
+            .prop_map(|(opt_precision, time_zone)| DataType::Time(opt_precision, time_zone)),
+        (precision(), with_or_without_time_zone())
+            .prop_map(|(opt_precision, time_zone)| DataType::Timestamp(opt_precision, time_zone)),
+        numeric_type_alias().prop_map(DataType::NumericAlias),
+    ]
+}
+
+/// Generates an unquoted or double quoted identifier. Unquoted identifiers
+/// are never reserved words.
+pub fn ident() -> impl Strategy<Value = Ident> {
+    prop_oneof![
+        "[a-zA-Z][a-zA-Z0-9_]{0,10}"
+            .prop_filter("reserved word", |value| !is_reserved_word(value))
+            .prop_map(|value| Ident::new(value.as_bytes())),
+        "[a-zA-Z0-9 _.\"]{1,10}"
+            .prop_map(|value| Ident::new_quoted(value.as_bytes(), QuoteStyle::DoubleQuote)),
+    ]
+}
+
+/// Generates a schema name, optionally qualified by a catalog name.
+pub fn schema_name() -> impl Strategy<Value = SchemaName> {
+    (ident(), option::of(ident())).prop_map(|(name, opt_catalog_name)| {
+        let schema_name = SchemaName::new(name);
+        match opt_catalog_name {
+            Some(catalog_name) => schema_name.with_catalog_name(catalog_name),
+            None => schema_name,
+        }
+    })
+}
+
+/// Generates a table name, optionally qualified by a schema name or the
+/// `MODULE` local qualifier.
+pub fn table_name() -> impl Strategy<Value = TableName> {
+    let local_or_schema = prop_oneof![
+        schema_name().prop_map(LocalOrSchemaQualifier::Schema),
+        Just(LocalOrSchemaQualifier::LocalQualifier(
+            LocalQualifier::Module
+        )),
+    ];
+
+    (ident(), option::of(local_or_schema)).prop_map(|(name, opt_local_or_schema)| {
+        let table_name = TableName::new(name);
+        match opt_local_or_schema {
+            Some(local_or_schema) => table_name.with_local_or_schema(local_or_schema),
+            None => table_name,
+        }
+    })
+}
+
+fn drop_behavior() -> impl Strategy<Value = DropBehavior> {
+    prop_oneof![Just(DropBehavior::Cascade), Just(DropBehavior::Restrict)]
+}
+
+fn character_length() -> impl Strategy<Value = CharacterLength> {
+    (1..=u32::MAX, option::of(char_length_units()))
+        .prop_map(|(length, opt_units)| CharacterLength::new(length).with_opt_units(opt_units))
+}
+
+fn character_large_object_length() -> impl Strategy<Value = CharacterLargeObjectLength> {
+    (large_object_length(), option::of(char_length_units())).prop_map(|(length, opt_units)| {
+        let clob_length = CharacterLargeObjectLength::new(length);
+        match opt_units {
+            Some(units) => clob_length.with_units(units),
+            None => clob_length,
+        }
+    })
+}
+
+fn large_object_length() -> impl Strategy<Value = LargeObjectLength> {
+    let multiplier = prop_oneof![
+        Just(Multiplier::K),
+        Just(Multiplier::M),
+        Just(Multiplier::G),
+        Just(Multiplier::T),
+        Just(Multiplier::P),
+    ];
+
+    (1..=u32::MAX, option::of(multiplier)).prop_map(|(length, opt_multiplier)| {
+        let large_object_length = LargeObjectLength::new(length);
+        match opt_multiplier {
+            Some(multiplier) => large_object_length.with_multiplier(multiplier),
+            None => large_object_length,
+        }
+    })
+}
+
+fn char_length_units() -> impl Strategy<Value = CharLengthUnits> {
+    prop_oneof![
+        Just(CharLengthUnits::Characters),
+        Just(CharLengthUnits::Octets)
+    ]
+}
+
+fn exact_number_info() -> impl Strategy<Value = ExactNumberInfo> {
+    prop_oneof![
+        Just(ExactNumberInfo::None),
+        (0..=u32::MAX).prop_map(ExactNumberInfo::Precision),
+        (0..=u32::MAX, 0..=u32::MAX)
+            .prop_map(|(precision, scale)| ExactNumberInfo::PrecisionAndScale(precision, scale)),
+    ]
+}
+
+fn with_or_without_time_zone() -> impl Strategy<Value = WithOrWithoutTimeZone> {
+    prop_oneof![
+        Just(WithOrWithoutTimeZone::None),
+        Just(WithOrWithoutTimeZone::WithTimeZone),
+        Just(WithOrWithoutTimeZone::WithoutTimeZone),
+    ]
+}
+
+fn numeric_type_alias() -> impl Strategy<Value = NumericTypeAlias> {
+    prop_oneof![
+        Just(NumericTypeAlias::Double),
+        Just(NumericTypeAlias::Float4),
+        Just(NumericTypeAlias::Float8),
+        Just(NumericTypeAlias::Int2),
+        Just(NumericTypeAlias::Int4),
+        Just(NumericTypeAlias::Int8),
+    ]
+}
@@ -0,0 +1,407 @@
+//! Semantic checks of parsed `ANSI` statements.
+//!
+//! The parser only checks the syntax of the statements, so it accepts
+//! statements that no database would (e.g., a table with two columns of the
+//! same name). Those are reported here.
+use crate::ansi::ast::common::{
+    ColumnConstraint, ColumnDefinition, KeyColumn, ReferencesSpecification, UniqueSpecification,
+};
+use crate::ansi::ast::create_table::{
+    CreateTable, TableConstraint, TableContentsSource, TableElement,
+};
+use crate::ansi::ast::data_types::{DataType, ExactNumberInfo};
+use crate::common::dialect::NumericLimits;
+use crate::common::Ident;
+
+/// Semantic errors of a statement.
+#[derive(thiserror::Error, Clone, Eq, PartialEq, Debug)]
+pub enum ValidationError {
+    /// The column is defined more than once. Names are compared as a catalog
+    /// would [(1)], so `id` and `ID` are the same column.
+    ///
+    /// [(1)]: crate::common::Ident::refers_to
+    #[error("column `{0}` is defined more than once")]
+    DuplicateColumn(String),
+    /// The column type precision is larger than the database accepts.
+    #[error("column `{0}` precision {1} exceeds the maximum of {2}")]
+    PrecisionOutOfRange(String, u32, u32),
+    /// The column type scale is larger than the database accepts, or than
+    /// the type precision.
+    #[error("column `{0}` scale {1} exceeds the maximum of {2}")]
+    ScaleOutOfRange(String, u32, u32),
+    /// The table declares more than one primary key, either as column or as
+    /// table constraints.
+    #[error("table `{0}` has more than one primary key")]
+    MultiplePrimaryKeys(String),
+    /// A key or a foreign key names a column that is not defined in the
+    /// table.
+    #[error("constraint column `{0}` is not defined")]
+    UnknownConstraintColumn(String),
+    /// The foreign key has a different number of referencing and referenced
+    /// columns.
+    #[error("foreign key has {1} columns, but references {2} columns of `{0}`")]
+    ForeignKeyColumnCount(String, usize, usize),
+}
+
+impl CreateTable {
+    /// Returns the semantic errors of the table definition, in the order of
+    /// the elements that cause them.
+    ///
+    /// Besides the duplicate columns, the constraints are checked: there can
+    /// be only one primary key, the keys and the foreign keys must name
+    /// columns of the table, and the foreign keys must reference as many
+    /// columns as they have, when the referenced columns are listed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// # use sql_helper::ansi::validate::ValidationError;
+    /// # use sql_helper::ansi::Statement;
+    /// let (_, statement) = parse_statement(b"CREATE TABLE tb (id INT, name VARCHAR(10), ID INT)").unwrap();
+    /// let Statement::CreateTable(create_table) = statement else {
+    ///     unreachable!()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     create_table.validate(),
+    ///     vec![ValidationError::DuplicateColumn("ID".to_string())]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let TableContentsSource::TableElementList(table_element_list) =
+            self.table_contents_source();
+        let column_names = table_element_list
+            .columns()
+            .map(ColumnDefinition::column_name)
+            .collect::<Vec<_>>();
+        let unknown_columns = |constraint_columns: Vec<&Ident>| {
+            constraint_columns
+                .into_iter()
+                .filter(|constraint_column| {
+                    !column_names
+                        .iter()
+                        .any(|column_name| column_name.refers_to(constraint_column))
+                })
+                .map(|constraint_column| {
+                    ValidationError::UnknownConstraintColumn(constraint_column.to_string())
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut errors = vec![];
+        let mut primary_keys = 0;
+        let mut multiple_primary_keys = false;
+        let mut columns = 0;
+
+        for table_element in table_element_list.element_list() {
+            match table_element {
+                TableElement::ColumnDefinition(column_definition) => {
+                    let column_name = column_definition.column_name();
+                    if column_names[..columns]
+                        .iter()
+                        .any(|previous| previous.refers_to(column_name))
+                    {
+                        errors.push(ValidationError::DuplicateColumn(column_name.to_string()));
+                    }
+                    columns += 1;
+
+                    for column_constraint_definition in
+                        column_definition.column_constraint_definitions()
+                    {
+                        match column_constraint_definition.column_constraint() {
+                            ColumnConstraint::Unique(UniqueSpecification::PrimaryKey) => {
+                                primary_keys += 1;
+                            }
+                            ColumnConstraint::References(references_specification) => {
+                                errors.extend(foreign_key_column_count_error(
+                                    1,
+                                    references_specification,
+                                ));
+                            }
+                            ColumnConstraint::NotNull
+                            | ColumnConstraint::Unique(UniqueSpecification::Unique) => {}
+                        }
+                    }
+                }
+                TableElement::TableConstraintDefinition(table_constraint_definition) => {
+                    match table_constraint_definition.table_constraint() {
+                        TableConstraint::Unique(unique_constraint_definition) => {
+                            if unique_constraint_definition.unique_specification()
+                                == UniqueSpecification::PrimaryKey
+                            {
+                                primary_keys += 1;
+                            }
+                            errors.extend(unknown_columns(
+                                unique_constraint_definition
+                                    .key_columns()
+                                    .iter()
+                                    .map(KeyColumn::column_name)
+                                    .collect(),
+                            ));
+                        }
+                        TableConstraint::Referential(referential_constraint_definition) => {
+                            let referencing_columns = referential_constraint_definition
+                                .referencing_columns()
+                                .column_names();
+                            errors.extend(unknown_columns(referencing_columns.iter().collect()));
+                            errors.extend(foreign_key_column_count_error(
+                                referencing_columns.len(),
+                                referential_constraint_definition.references_specification(),
+                            ));
+                        }
+                    }
+                }
+            }
+
+            // OBS: reported once, at the element with the second primary key.
+            if primary_keys > 1 && !multiple_primary_keys {
+                errors.push(ValidationError::MultiplePrimaryKeys(
+                    self.table_name().to_string(),
+                ));
+                multiple_primary_keys = true;
+            }
+        }
+
+        errors
+    }
+
+    /// Returns the column types whose precision or scale exceed the limits
+    /// of a database (e.g., [`Dialect::numeric_limits`]), in the order of the
+    /// columns.
+    ///
+    /// The scale of the exact numeric types can't exceed their precision,
+    /// whatever the limits are.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "mssql")] {
+    /// # use sql_helper::ansi::parser::parse_statement;
+    /// # use sql_helper::ansi::validate::ValidationError;
+    /// # use sql_helper::ansi::Statement;
+    /// # use sql_helper::common::dialect::Dialect;
+    /// # use sql_helper::mssql::dialect::MsSqlDialect;
+    /// let (_, statement) = parse_statement(b"CREATE TABLE tb (price DECIMAL(40, 2))").unwrap();
+    /// let Statement::CreateTable(create_table) = statement else {
+    ///     unreachable!()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     create_table.validate_numeric_limits(MsSqlDialect.numeric_limits()),
+    ///     vec![ValidationError::PrecisionOutOfRange("price".to_string(), 40, 38)]
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [`Dialect::numeric_limits`]: crate::common::dialect::Dialect::numeric_limits
+    #[must_use]
+    pub fn validate_numeric_limits(&self, limits: NumericLimits) -> Vec<ValidationError> {
+        let TableContentsSource::TableElementList(table_element_list) =
+            self.table_contents_source();
+
+        table_element_list
+            .columns()
+            .flat_map(|column_definition| numeric_limit_errors(column_definition, limits))
+            .collect()
+    }
+}
+
+fn foreign_key_column_count_error(
+    referencing_columns: usize,
+    references_specification: &ReferencesSpecification,
+) -> Option<ValidationError> {
+    references_specification
+        .opt_referenced_columns()
+        .map(|referenced_columns| referenced_columns.column_names().len())
+        .filter(|referenced_columns| *referenced_columns != referencing_columns)
+        .map(|referenced_columns| {
+            ValidationError::ForeignKeyColumnCount(
+                references_specification.table_name().to_string(),
+                referencing_columns,
+                referenced_columns,
+            )
+        })
+}
+
+fn numeric_limit_errors(
+    column_definition: &ColumnDefinition,
+    limits: NumericLimits,
+) -> Vec<ValidationError> {
+    let column_name = || column_definition.column_name().to_string();
+    let precision_error = |precision: u32, opt_max: Option<u32>| {
+        opt_max
+            .filter(|max| precision > *max)
+            .map(|max| ValidationError::PrecisionOutOfRange(column_name(), precision, max))
+    };
+
+    match column_definition.opt_data_type() {
+        Some(
+            DataType::Numeric(number_info)
+            | DataType::Decimal(number_info)
+            | DataType::Dec(number_info),
+        ) => {
+            let (precision, opt_scale) = match number_info {
+                ExactNumberInfo::None => return vec![],
+                ExactNumberInfo::Precision(precision) => (precision, None),
+                ExactNumberInfo::PrecisionAndScale(precision, scale) => (precision, Some(scale)),
+            };
+            let max_scale = limits
+                .max_decimal_scale()
+                .map_or(precision, |max_scale| max_scale.min(precision));
+            let scale_error = opt_scale
+                .filter(|scale| *scale > max_scale)
+                .map(|scale| ValidationError::ScaleOutOfRange(column_name(), scale, max_scale));
+
+            precision_error(precision, limits.max_decimal_precision())
+                .into_iter()
+                .chain(scale_error)
+                .collect()
+        }
+        Some(DataType::DecFloat(Some(precision))) => {
+            precision_error(precision, limits.max_decfloat_precision())
+                .into_iter()
+                .collect()
+        }
+        Some(DataType::Time(Some(precision), _) | DataType::Timestamp(Some(precision), _)) => {
+            precision_error(precision, limits.max_temporal_precision())
+                .into_iter()
+                .collect()
+        }
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::parser::parse_statement;
+    use crate::ansi::Statement;
+    #[cfg(any(feature = "mssql", feature = "mysql"))]
+    use crate::common::dialect::Dialect;
+    #[cfg(feature = "mssql")]
+    use crate::mssql::dialect::MsSqlDialect;
+    #[cfg(feature = "mysql")]
+    use crate::mysql::dialect::MySqlDialect;
+
+    use super::*;
+
+    fn validate(sql: &str) -> Vec<ValidationError> {
+        match parse_statement(sql.as_bytes()).unwrap().1 {
+            Statement::CreateTable(create_table) => create_table.validate(),
+            statement => panic!("not a CREATE TABLE: {statement}"),
+        }
+    }
+
+    fn validate_numeric_limits(sql: &str, limits: NumericLimits) -> Vec<ValidationError> {
+        match parse_statement(sql.as_bytes()).unwrap().1 {
+            Statement::CreateTable(create_table) => create_table.validate_numeric_limits(limits),
+            statement => panic!("not a CREATE TABLE: {statement}"),
+        }
+    }
+
+    #[test_case("CREATE TABLE tb (id INT, name VARCHAR(10))", &[]; "distinct columns")]
+    #[test_case("CREATE TABLE tb (id INT, \"id\" INT)", &[]; "quoted lower case")]
+    #[test_case("CREATE TABLE tb (id INT, id VARCHAR(10))", &["id"]; "same name")]
+    #[test_case("CREATE TABLE tb (id INT, \"ID\" INT)", &["\"ID\""]; "quoted upper case")]
+    #[test_case("CREATE TABLE tb (a INT, A INT, b INT, a INT)", &["A", "a"]; "repeated")]
+    fn duplicate_columns(input: &str, expected: &[&str]) {
+        let expected = expected
+            .iter()
+            .map(|column_name| ValidationError::DuplicateColumn((*column_name).to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected, validate(input));
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (id INT PRIMARY KEY, a INT UNIQUE, UNIQUE (a), FOREIGN KEY (a) REFERENCES o (x))",
+        &[];
+        "valid constraints"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (id INT PRIMARY KEY, a INT, PRIMARY KEY (a), PRIMARY KEY (id))",
+        &[ValidationError::MultiplePrimaryKeys("tb".to_string())];
+        "multiple primary keys"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (PRIMARY KEY (ID), id INT, UNIQUE (\"id\", b), FOREIGN KEY (c) REFERENCES o)",
+        &[
+            ValidationError::UnknownConstraintColumn("\"id\"".to_string()),
+            ValidationError::UnknownConstraintColumn("b".to_string()),
+            ValidationError::UnknownConstraintColumn("c".to_string()),
+        ];
+        "unknown columns"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT REFERENCES o (x, y), b INT, FOREIGN KEY (a, b) REFERENCES p (x))",
+        &[
+            ValidationError::ForeignKeyColumnCount("o".to_string(), 1, 2),
+            ValidationError::ForeignKeyColumnCount("p".to_string(), 2, 1),
+        ];
+        "foreign key column counts"
+    )]
+    fn constraints(input: &str, expected: &[ValidationError]) {
+        assert_eq!(expected, validate(input));
+    }
+
+    #[test_case("CREATE TABLE tb (a DECIMAL(38, 38), b TIMESTAMP(7))", &[]; "at the limits")]
+    #[test_case(
+        "CREATE TABLE tb (a NUMERIC(39), b DEC(10, 11))",
+        &[
+            ValidationError::PrecisionOutOfRange("a".to_string(), 39, 38),
+            ValidationError::ScaleOutOfRange("b".to_string(), 11, 10),
+        ];
+        "decimal"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a TIME(9), b DECFLOAT(64))",
+        &[ValidationError::PrecisionOutOfRange("a".to_string(), 9, 7)];
+        "temporal"
+    )]
+    #[cfg(feature = "mssql")]
+    fn mssql_numeric_limits(input: &str, expected: &[ValidationError]) {
+        assert_eq!(
+            expected,
+            validate_numeric_limits(input, MsSqlDialect.numeric_limits())
+        );
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (a DECIMAL(65, 30), b DECIMAL(66, 31))",
+        &[
+            ValidationError::PrecisionOutOfRange("b".to_string(), 66, 65),
+            ValidationError::ScaleOutOfRange("b".to_string(), 31, 30),
+        ];
+        "decimal"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a TIMESTAMP(6), b TIME(7) WITH TIME ZONE)",
+        &[ValidationError::PrecisionOutOfRange("b".to_string(), 7, 6)];
+        "temporal"
+    )]
+    #[cfg(feature = "mysql")]
+    fn mysql_numeric_limits(input: &str, expected: &[ValidationError]) {
+        assert_eq!(
+            expected,
+            validate_numeric_limits(input, MySqlDialect.numeric_limits())
+        );
+    }
+
+    #[test]
+    fn configured_numeric_limits() {
+        let limits = NumericLimits::default().with_max_decfloat_precision(34);
+
+        assert_eq!(
+            vec![ValidationError::PrecisionOutOfRange(
+                "a".to_string(),
+                40,
+                34
+            )],
+            validate_numeric_limits(
+                "CREATE TABLE tb (a DECFLOAT(40), b DECIMAL(1000, 2))",
+                limits
+            )
+        );
+    }
+}
@@ -0,0 +1,678 @@
+use crate::ansi::ast::alter_table::{AlterColumnAction, AlterTable, AlterTableAction};
+use crate::ansi::ast::call::Call;
+use crate::ansi::ast::close::Close;
+use crate::ansi::ast::common::{
+    ColumnConstraint, ColumnDefinition, LocalOrSchemaQualifier, ObjectName,
+    ReferencesSpecification, RoutineName, SchemaName, SchemaQualifiedName, TableName,
+    ValueSpecification,
+};
+use crate::ansi::ast::create_schema::{CreateSchema, SchemaNameClause};
+use crate::ansi::ast::create_table::{
+    CreateTable, TableConstraint, TableConstraintDefinition, TableContentsSource, TableElement,
+};
+use crate::ansi::ast::data_types::DataType;
+use crate::ansi::ast::delete::DeletePositioned;
+use crate::ansi::ast::drop_schema::DropSchema;
+use crate::ansi::ast::drop_table::DropTable;
+use crate::ansi::ast::explain::Explain;
+use crate::ansi::ast::fetch::{Fetch, TargetSpecification};
+use crate::ansi::ast::grant::{GrantObject, GrantPrivilege, Grantee, ObjectPrivileges, Privileges};
+use crate::ansi::ast::open::Open;
+use crate::ansi::ast::return_statement::Return;
+use crate::ansi::ast::revoke::RevokePrivilege;
+use crate::ansi::ast::update::{SetClause, UpdatePositioned, UpdateSource};
+use crate::ansi::Statement;
+use crate::common::dialect::Dialect;
+use crate::common::expr::{Expr, Literal};
+use crate::common::Ident;
+
+/// Mutable visitor over the `ANSI` AST.
+///
+/// Every method has a default implementation that only walks into the node
+/// children, using the `walk_*` function with the same suffix. Implementors
+/// override the methods of the nodes they want to rewrite, and should call the
+/// matching `walk_*` function if the children must still be visited.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::common::TableName;
+/// # use sql_helper::ansi::parser::parse_statement;
+/// # use sql_helper::ansi::visitor::{walk_table_name, VisitorMut};
+/// # use sql_helper::common::Ident;
+/// struct RenameTable;
+///
+/// impl VisitorMut for RenameTable {
+///     fn visit_table_name(&mut self, table_name: &mut TableName) {
+///         if table_name.name().value() == "old_name" {
+///             *table_name.name_mut() = Ident::new(b"new_name");
+///         }
+///         walk_table_name(self, table_name);
+///     }
+/// }
+///
+/// let (_, mut statement) = parse_statement(b"DROP TABLE old_name CASCADE").unwrap();
+/// RenameTable.visit_statement(&mut statement);
+/// assert_eq!(statement.to_string(), "DROP TABLE new_name CASCADE");
+/// ```
+pub trait VisitorMut {
+    fn visit_statement(&mut self, statement: &mut Statement) {
+        walk_statement(self, statement);
+    }
+
+    fn visit_create_schema(&mut self, create_schema: &mut CreateSchema) {
+        walk_create_schema(self, create_schema);
+    }
+
+    fn visit_drop_schema(&mut self, drop_schema: &mut DropSchema) {
+        walk_drop_schema(self, drop_schema);
+    }
+
+    fn visit_create_table(&mut self, create_table: &mut CreateTable) {
+        walk_create_table(self, create_table);
+    }
+
+    fn visit_drop_table(&mut self, drop_table: &mut DropTable) {
+        walk_drop_table(self, drop_table);
+    }
+
+    fn visit_alter_table(&mut self, alter_table: &mut AlterTable) {
+        walk_alter_table(self, alter_table);
+    }
+
+    fn visit_open(&mut self, open: &mut Open) {
+        walk_open(self, open);
+    }
+
+    fn visit_fetch(&mut self, fetch: &mut Fetch) {
+        walk_fetch(self, fetch);
+    }
+
+    fn visit_close(&mut self, close: &mut Close) {
+        walk_close(self, close);
+    }
+
+    fn visit_call(&mut self, call: &mut Call) {
+        walk_call(self, call);
+    }
+
+    fn visit_return(&mut self, return_statement: &mut Return) {
+        walk_return(self, return_statement);
+    }
+
+    fn visit_update_positioned(&mut self, update: &mut UpdatePositioned) {
+        walk_update_positioned(self, update);
+    }
+
+    fn visit_delete_positioned(&mut self, delete: &mut DeletePositioned) {
+        walk_delete_positioned(self, delete);
+    }
+
+    fn visit_explain(&mut self, explain: &mut Explain) {
+        walk_explain(self, explain);
+    }
+
+    fn visit_grant_privilege(&mut self, grant: &mut GrantPrivilege) {
+        walk_grant_privilege(self, grant);
+    }
+
+    fn visit_revoke_privilege(&mut self, revoke: &mut RevokePrivilege) {
+        walk_revoke_privilege(self, revoke);
+    }
+
+    fn visit_privileges(&mut self, privileges: &mut Privileges) {
+        walk_privileges(self, privileges);
+    }
+
+    fn visit_grantee(&mut self, grantee: &mut Grantee) {
+        walk_grantee(self, grantee);
+    }
+
+    fn visit_set_clause(&mut self, set_clause: &mut SetClause) {
+        walk_set_clause(self, set_clause);
+    }
+
+    fn visit_schema_name_clause(&mut self, schema_name_clause: &mut SchemaNameClause) {
+        walk_schema_name_clause(self, schema_name_clause);
+    }
+
+    fn visit_schema_name(&mut self, schema_name: &mut SchemaName) {
+        walk_schema_name(self, schema_name);
+    }
+
+    fn visit_table_name(&mut self, table_name: &mut TableName) {
+        walk_table_name(self, table_name);
+    }
+
+    fn visit_object_name(&mut self, object_name: &mut ObjectName) {
+        walk_object_name(self, object_name);
+    }
+
+    fn visit_routine_name(&mut self, routine_name: &mut RoutineName) {
+        walk_schema_qualified_name(self, routine_name);
+    }
+
+    fn visit_schema_qualified_name(&mut self, schema_qualified_name: &mut SchemaQualifiedName) {
+        walk_schema_qualified_name(self, schema_qualified_name);
+    }
+
+    fn visit_value_specification(&mut self, value_specification: &mut ValueSpecification) {
+        walk_value_specification(self, value_specification);
+    }
+
+    fn visit_table_element(&mut self, table_element: &mut TableElement) {
+        walk_table_element(self, table_element);
+    }
+
+    fn visit_column_definition(&mut self, column_definition: &mut ColumnDefinition) {
+        walk_column_definition(self, column_definition);
+    }
+
+    fn visit_table_constraint_definition(
+        &mut self,
+        table_constraint_definition: &mut TableConstraintDefinition,
+    ) {
+        walk_table_constraint_definition(self, table_constraint_definition);
+    }
+
+    fn visit_references_specification(
+        &mut self,
+        references_specification: &mut ReferencesSpecification,
+    ) {
+        walk_references_specification(self, references_specification);
+    }
+
+    fn visit_data_type(&mut self, _data_type: &mut DataType) {}
+
+    fn visit_literal(&mut self, _literal: &mut Literal) {}
+
+    fn visit_ident(&mut self, _ident: &mut Ident) {}
+}
+
+/// Visits the inner statement of a [`Statement`].
+pub fn walk_statement<V: VisitorMut + ?Sized>(visitor: &mut V, statement: &mut Statement) {
+    match statement {
+        Statement::CreateSchema(create_schema) => visitor.visit_create_schema(create_schema),
+        Statement::DropSchema(drop_schema) => visitor.visit_drop_schema(drop_schema),
+        Statement::DropTable(drop_table) => visitor.visit_drop_table(drop_table),
+        Statement::CreateTable(create_table) => visitor.visit_create_table(create_table),
+        Statement::AlterTable(alter_table) => visitor.visit_alter_table(alter_table),
+        Statement::Open(open) => visitor.visit_open(open),
+        Statement::Fetch(fetch) => visitor.visit_fetch(fetch),
+        Statement::Close(close) => visitor.visit_close(close),
+        Statement::Call(call) => visitor.visit_call(call),
+        Statement::Return(return_statement) => visitor.visit_return(return_statement),
+        Statement::UpdatePositioned(update) => visitor.visit_update_positioned(update),
+        Statement::DeletePositioned(delete) => visitor.visit_delete_positioned(delete),
+        Statement::Explain(explain) => visitor.visit_explain(explain),
+        Statement::GrantPrivilege(grant) => visitor.visit_grant_privilege(grant),
+        Statement::RevokePrivilege(revoke) => visitor.visit_revoke_privilege(revoke),
+    }
+}
+
+/// Visits the schema name clause of a [`CreateSchema`].
+pub fn walk_create_schema<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    create_schema: &mut CreateSchema,
+) {
+    visitor.visit_schema_name_clause(create_schema.schema_name_clause_mut());
+}
+
+/// Visits the schema name of a [`DropSchema`].
+pub fn walk_drop_schema<V: VisitorMut + ?Sized>(visitor: &mut V, drop_schema: &mut DropSchema) {
+    visitor.visit_schema_name(drop_schema.schema_name_mut());
+}
+
+/// Visits the table name, all the table elements and the partition clause
+/// identifiers of a [`CreateTable`].
+pub fn walk_create_table<V: VisitorMut + ?Sized>(visitor: &mut V, create_table: &mut CreateTable) {
+    visitor.visit_table_name(create_table.table_name_mut());
+
+    match create_table.table_contents_source_mut() {
+        TableContentsSource::TableElementList(table_element_list) => {
+            for table_element in table_element_list.element_list_mut() {
+                visitor.visit_table_element(table_element);
+            }
+        }
+    }
+
+    if let Some(partition_by) = create_table.opt_partition_by_mut() {
+        for partition_key in partition_by.partition_keys_mut() {
+            if let Expr::Identifier(column_name) = partition_key {
+                visitor.visit_ident(column_name);
+            }
+        }
+        for partition_definition in partition_by.partition_definitions_mut() {
+            visitor.visit_ident(partition_definition.name_mut());
+        }
+    }
+}
+
+/// Visits the table name of a [`DropTable`].
+pub fn walk_drop_table<V: VisitorMut + ?Sized>(visitor: &mut V, drop_table: &mut DropTable) {
+    visitor.visit_table_name(drop_table.table_name_mut());
+}
+
+/// Visits the table name and the action of an [`AlterTable`], including the
+/// added column definition, the altered column name and data type, or the
+/// names of a rename.
+pub fn walk_alter_table<V: VisitorMut + ?Sized>(visitor: &mut V, alter_table: &mut AlterTable) {
+    visitor.visit_table_name(alter_table.table_name_mut());
+
+    match alter_table.alter_table_action_mut() {
+        AlterTableAction::AddColumn(column_definition) => {
+            visitor.visit_column_definition(column_definition);
+        }
+        AlterTableAction::AlterColumn(column_name, alter_column_action) => {
+            visitor.visit_ident(column_name);
+            if let AlterColumnAction::SetDataType(data_type) = alter_column_action {
+                visitor.visit_data_type(data_type);
+            }
+        }
+        AlterTableAction::DropColumn(column_name, _) => visitor.visit_ident(column_name),
+        AlterTableAction::RenameTable(table_name) => visitor.visit_table_name(table_name),
+        AlterTableAction::RenameColumn(column_name, new_column_name) => {
+            visitor.visit_ident(column_name);
+            visitor.visit_ident(new_column_name);
+        }
+    }
+}
+
+/// Visits the cursor name of an [`Open`].
+pub fn walk_open<V: VisitorMut + ?Sized>(visitor: &mut V, open: &mut Open) {
+    visitor.visit_ident(open.cursor_name_mut());
+}
+
+/// Visits the cursor name and the SQL parameters of a [`Fetch`]. Host
+/// parameters are names of the host language, so they are not visited.
+pub fn walk_fetch<V: VisitorMut + ?Sized>(visitor: &mut V, fetch: &mut Fetch) {
+    visitor.visit_ident(fetch.cursor_name_mut());
+    for target_specification in fetch.target_list_mut() {
+        if let TargetSpecification::SqlParameter(name) = target_specification {
+            visitor.visit_ident(name);
+        }
+    }
+}
+
+/// Visits the cursor name of a [`Close`].
+pub fn walk_close<V: VisitorMut + ?Sized>(visitor: &mut V, close: &mut Close) {
+    visitor.visit_ident(close.cursor_name_mut());
+}
+
+/// Visits the routine name and the arguments of a [`Call`].
+pub fn walk_call<V: VisitorMut + ?Sized>(visitor: &mut V, call: &mut Call) {
+    visitor.visit_routine_name(call.routine_name_mut());
+    for argument in call.arguments_mut() {
+        visitor.visit_value_specification(argument);
+    }
+}
+
+/// Visits the return value of a [`Return`].
+pub fn walk_return<V: VisitorMut + ?Sized>(visitor: &mut V, return_statement: &mut Return) {
+    visitor.visit_value_specification(return_statement.return_value_mut());
+}
+
+/// Visits the table name, the set clauses and the cursor name of an
+/// [`UpdatePositioned`].
+pub fn walk_update_positioned<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    update: &mut UpdatePositioned,
+) {
+    visitor.visit_table_name(update.table_name_mut());
+    for set_clause in update.set_clause_list_mut() {
+        visitor.visit_set_clause(set_clause);
+    }
+    visitor.visit_ident(update.cursor_name_mut());
+}
+
+/// Visits the table name and the cursor name of a [`DeletePositioned`].
+pub fn walk_delete_positioned<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    delete: &mut DeletePositioned,
+) {
+    visitor.visit_table_name(delete.table_name_mut());
+    visitor.visit_ident(delete.cursor_name_mut());
+}
+
+/// Visits the explained statement of an [`Explain`].
+pub fn walk_explain<V: VisitorMut + ?Sized>(visitor: &mut V, explain: &mut Explain) {
+    visitor.visit_statement(explain.statement_mut());
+}
+
+/// Visits the privileges and the grantees of a [`GrantPrivilege`].
+pub fn walk_grant_privilege<V: VisitorMut + ?Sized>(visitor: &mut V, grant: &mut GrantPrivilege) {
+    visitor.visit_privileges(grant.privileges_mut());
+    for grantee in grant.grantees_mut() {
+        visitor.visit_grantee(grantee);
+    }
+}
+
+/// Visits the privileges and the grantees of a [`RevokePrivilege`].
+pub fn walk_revoke_privilege<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    revoke: &mut RevokePrivilege,
+) {
+    visitor.visit_privileges(revoke.privileges_mut());
+    for grantee in revoke.grantees_mut() {
+        visitor.visit_grantee(grantee);
+    }
+}
+
+/// Visits the privileged columns and the object of [`Privileges`].
+pub fn walk_privileges<V: VisitorMut + ?Sized>(visitor: &mut V, privileges: &mut Privileges) {
+    if let ObjectPrivileges::Actions(actions) = privileges.object_privileges_mut() {
+        for column_name_list in actions
+            .iter_mut()
+            .filter_map(|action| action.opt_column_name_list_mut())
+        {
+            for column_name in column_name_list.column_names_mut() {
+                visitor.visit_ident(column_name);
+            }
+        }
+    }
+
+    match privileges.object_mut() {
+        GrantObject::Table(table_name) => visitor.visit_table_name(table_name),
+        GrantObject::Domain(name)
+        | GrantObject::Sequence(name)
+        | GrantObject::Type(name)
+        | GrantObject::CharacterSet(name) => visitor.visit_schema_qualified_name(name),
+        GrantObject::Routine(routine) => {
+            visitor.visit_routine_name(routine.routine_name_mut());
+            for data_type in routine.opt_data_type_list_mut().into_iter().flatten() {
+                visitor.visit_data_type(data_type);
+            }
+        }
+    }
+}
+
+/// Visits the authorization identifier of a [`Grantee`], if it's one.
+pub fn walk_grantee<V: VisitorMut + ?Sized>(visitor: &mut V, grantee: &mut Grantee) {
+    match grantee {
+        Grantee::Public => {}
+        Grantee::AuthorizationIdentifier(authorization) => visitor.visit_ident(authorization),
+    }
+}
+
+/// Visits the column name and the assigned value (if any) of a [`SetClause`].
+pub fn walk_set_clause<V: VisitorMut + ?Sized>(visitor: &mut V, set_clause: &mut SetClause) {
+    visitor.visit_ident(set_clause.column_name_mut());
+    if let UpdateSource::Value(value_specification) = set_clause.update_source_mut() {
+        visitor.visit_value_specification(value_specification);
+    }
+}
+
+/// Visits the schema name and the authorization identifier of a
+/// [`SchemaNameClause`].
+pub fn walk_schema_name_clause<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    schema_name_clause: &mut SchemaNameClause,
+) {
+    match schema_name_clause {
+        SchemaNameClause::Simple(schema_name) => visitor.visit_schema_name(schema_name),
+        SchemaNameClause::Authorization(authorization) => visitor.visit_ident(authorization),
+        SchemaNameClause::NamedAuthorization(schema_name, authorization) => {
+            visitor.visit_schema_name(schema_name);
+            visitor.visit_ident(authorization);
+        }
+    }
+}
+
+/// Visits the catalog and schema identifiers of a [`SchemaName`].
+pub fn walk_schema_name<V: VisitorMut + ?Sized>(visitor: &mut V, schema_name: &mut SchemaName) {
+    if let Some(catalog_name) = schema_name.opt_catalog_name_mut() {
+        visitor.visit_ident(catalog_name);
+    }
+    visitor.visit_ident(schema_name.name_mut());
+}
+
+/// Visits the schema qualifier (if any) and the identifier of a [`TableName`].
+pub fn walk_table_name<V: VisitorMut + ?Sized>(visitor: &mut V, table_name: &mut TableName) {
+    if let Some(LocalOrSchemaQualifier::Schema(schema_name)) = table_name.opt_local_or_schema_mut()
+    {
+        visitor.visit_schema_name(schema_name);
+    }
+    visitor.visit_ident(table_name.name_mut());
+}
+
+/// Visits the qualifiers and the identifier of an [`ObjectName`].
+pub fn walk_object_name<V: VisitorMut + ?Sized>(visitor: &mut V, object_name: &mut ObjectName) {
+    for qualifier in object_name.qualifiers_mut() {
+        visitor.visit_ident(qualifier);
+    }
+    visitor.visit_ident(object_name.name_mut());
+}
+
+/// Visits the schema name (if any) and the name of a [`SchemaQualifiedName`].
+pub fn walk_schema_qualified_name<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    schema_qualified_name: &mut SchemaQualifiedName,
+) {
+    if let Some(schema_name) = schema_qualified_name.opt_schema_name_mut() {
+        visitor.visit_schema_name(schema_name);
+    }
+    visitor.visit_ident(schema_qualified_name.name_mut());
+}
+
+/// Visits the literal or the SQL parameter of a [`ValueSpecification`], if
+/// it's one. Host parameters are names of the host language, so they are not
+/// visited.
+pub fn walk_value_specification<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    value_specification: &mut ValueSpecification,
+) {
+    match value_specification {
+        ValueSpecification::Literal(literal) => visitor.visit_literal(literal),
+        ValueSpecification::SqlParameter(name) => visitor.visit_ident(name),
+        ValueSpecification::HostParameter(_)
+        | ValueSpecification::DynamicParameter
+        | ValueSpecification::Null => {}
+    }
+}
+
+/// Visits the inner element of a [`TableElement`].
+pub fn walk_table_element<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    table_element: &mut TableElement,
+) {
+    match table_element {
+        TableElement::ColumnDefinition(column_definition) => {
+            visitor.visit_column_definition(column_definition);
+        }
+        TableElement::TableConstraintDefinition(table_constraint_definition) => {
+            visitor.visit_table_constraint_definition(table_constraint_definition);
+        }
+    }
+}
+
+/// Visits the column name, the data type (if any) and the constraints of a
+/// [`ColumnDefinition`].
+pub fn walk_column_definition<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    column_definition: &mut ColumnDefinition,
+) {
+    visitor.visit_ident(column_definition.column_name_mut());
+    if let Some(data_type) = column_definition.opt_data_type_mut() {
+        visitor.visit_data_type(data_type);
+    }
+    for column_constraint_definition in column_definition.column_constraint_definitions_mut() {
+        if let Some(constraint_name) = column_constraint_definition.opt_constraint_name_mut() {
+            visitor.visit_schema_qualified_name(constraint_name);
+        }
+        if let ColumnConstraint::References(references_specification) =
+            column_constraint_definition.column_constraint_mut()
+        {
+            visitor.visit_references_specification(references_specification);
+        }
+    }
+}
+
+/// Visits the constraint name (if any) and the column names of a
+/// [`TableConstraintDefinition`].
+pub fn walk_table_constraint_definition<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    table_constraint_definition: &mut TableConstraintDefinition,
+) {
+    if let Some(constraint_name) = table_constraint_definition.opt_constraint_name_mut() {
+        visitor.visit_schema_qualified_name(constraint_name);
+    }
+    match table_constraint_definition.table_constraint_mut() {
+        TableConstraint::Unique(unique_constraint_definition) => {
+            for key_column in unique_constraint_definition.key_columns_mut() {
+                visitor.visit_ident(key_column.column_name_mut());
+            }
+        }
+        TableConstraint::Referential(referential_constraint_definition) => {
+            for column_name in referential_constraint_definition
+                .referencing_columns_mut()
+                .column_names_mut()
+            {
+                visitor.visit_ident(column_name);
+            }
+            if let Some(period_name) =
+                referential_constraint_definition.opt_referencing_period_mut()
+            {
+                visitor.visit_ident(period_name);
+            }
+            visitor.visit_references_specification(
+                referential_constraint_definition.references_specification_mut(),
+            );
+        }
+    }
+}
+
+/// Visits the table name, the referenced columns and the referenced period
+/// (if any) of a [`ReferencesSpecification`].
+pub fn walk_references_specification<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    references_specification: &mut ReferencesSpecification,
+) {
+    visitor.visit_table_name(references_specification.table_name_mut());
+    if let Some(referenced_columns) = references_specification.opt_referenced_columns_mut() {
+        for column_name in referenced_columns.column_names_mut() {
+            visitor.visit_ident(column_name);
+        }
+    }
+    if let Some(period_name) = references_specification.opt_referenced_period_mut() {
+        visitor.visit_ident(period_name);
+    }
+}
+
+/// Visitor that rewrites every identifier to the form it should be emitted in
+/// a dialect [(1)].
+///
+/// [(1)]: Ident::for_dialect
+pub(crate) struct DialectIdents<'a> {
+    dialect: &'a dyn Dialect,
+}
+
+impl<'a> DialectIdents<'a> {
+    pub(crate) fn new(dialect: &'a dyn Dialect) -> Self {
+        Self { dialect }
+    }
+}
+
+impl VisitorMut for DialectIdents<'_> {
+    fn visit_ident(&mut self, ident: &mut Ident) {
+        *ident = ident.for_dialect(self.dialect);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use crate::ansi::ast::data_types::{CharacterLargeObjectLength, LargeObjectLength};
+    use crate::ansi::parser::parse_statement;
+
+    use super::*;
+
+    struct RenameTable<'a> {
+        from: &'a str,
+        to: &'a str,
+    }
+
+    impl VisitorMut for RenameTable<'_> {
+        fn visit_table_name(&mut self, table_name: &mut TableName) {
+            if table_name.name().value() == self.from {
+                *table_name.name_mut() = Ident::new(self.to.as_bytes());
+            }
+            walk_table_name(self, table_name);
+        }
+    }
+
+    struct VarcharToClob;
+
+    impl VisitorMut for VarcharToClob {
+        fn visit_data_type(&mut self, data_type: &mut DataType) {
+            if let DataType::Varchar(opt_len) = data_type {
+                *data_type = DataType::Clob(opt_len.map(|len| {
+                    CharacterLargeObjectLength::new(LargeObjectLength::new(len.length()))
+                }));
+            }
+        }
+    }
+
+    struct UppercaseIdents;
+
+    impl VisitorMut for UppercaseIdents {
+        fn visit_ident(&mut self, ident: &mut Ident) {
+            *ident = Ident::new_quoted(
+                ident.value().to_uppercase().as_bytes(),
+                *ident.quote_style(),
+            );
+        }
+    }
+
+    #[test_case("DROP TABLE tb CASCADE", "DROP TABLE renamed CASCADE")]
+    #[test_case("DROP TABLE sc.tb RESTRICT", "DROP TABLE sc.renamed RESTRICT")]
+    #[test_case("CREATE TABLE tb (tb INT)", "CREATE TABLE renamed (tb INT)")]
+    #[test_case("CREATE TABLE other (id INT)", "CREATE TABLE other (id INT)")]
+    #[test_case(
+        "ALTER TABLE tb DROP COLUMN tb CASCADE",
+        "ALTER TABLE renamed DROP COLUMN tb CASCADE"
+    )]
+    fn rename_table(input: &str, expected: &str) {
+        let (_, mut statement) = parse_statement(input.as_ref()).unwrap();
+        RenameTable {
+            from: "tb",
+            to: "renamed",
+        }
+        .visit_statement(&mut statement);
+        assert_str_eq!(expected, statement.to_string());
+    }
+
+    #[test_case(
+        "CREATE TABLE tb (id INT, name VARCHAR(20), description VARCHAR)",
+        "CREATE TABLE tb (id INT, name CLOB(20), description CLOB)"
+    )]
+    fn rewrite_data_types(input: &str, expected: &str) {
+        let (_, mut statement) = parse_statement(input.as_ref()).unwrap();
+        VarcharToClob.visit_statement(&mut statement);
+        assert_str_eq!(expected, statement.to_string());
+    }
+
+    #[test_case(
+        "CREATE SCHEMA ct.sc AUTHORIZATION usr",
+        "CREATE SCHEMA CT.SC AUTHORIZATION USR"
+    )]
+    #[test_case("DROP SCHEMA \"sc\" CASCADE", "DROP SCHEMA \"SC\" CASCADE")]
+    #[test_case("CREATE TABLE ct.sc.tb (id INT)", "CREATE TABLE CT.SC.TB (ID INT)")]
+    #[test_case(
+        "CREATE TABLE tb (id INT, CONSTRAINT sc.pk PRIMARY KEY (id DESC))",
+        "CREATE TABLE TB (ID INT, CONSTRAINT SC.PK PRIMARY KEY (ID DESC))"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (a INT CONSTRAINT fk REFERENCES sc.u (b, PERIOD p), FOREIGN KEY (a) REFERENCES u)",
+        "CREATE TABLE TB (A INT CONSTRAINT FK REFERENCES SC.U (B, PERIOD P), FOREIGN KEY (A) REFERENCES U)"
+    )]
+    #[test_case(
+        "ALTER TABLE sc.tb ALTER COLUMN id DROP IDENTITY",
+        "ALTER TABLE SC.TB ALTER COLUMN ID DROP IDENTITY"
+    )]
+    fn visit_all_idents(input: &str, expected: &str) {
+        let (_, mut statement) = parse_statement(input.as_ref()).unwrap();
+        UppercaseIdents.visit_statement(&mut statement);
+        assert_str_eq!(expected, statement.to_string());
+    }
+}
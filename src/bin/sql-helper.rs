@@ -0,0 +1,129 @@
+#![warn(clippy::pedantic)]
+//! `sql-helper` command-line interface.
+//!
+//! ```plaintext
+//! sql-helper <check | fmt | ast> [FILE...]
+//! ```
+//!
+//! Statements are read from each file, or from the standard input if no file
+//! (or `-`) is given:
+//!
+//! - `check`: parses the statements, reporting the position of the first
+//!   invalid statement of each input;
+//! - `fmt`: pretty prints the statements, keeping the comments before and
+//!   after them, and refusing the inputs with comments between or inside
+//!   them;
+//! - `ast`: dumps the statements AST as a JSON array, where each statement is
+//!   in the versioned JSON format (see `sql_helper::ansi::json`).
+use std::io::Read;
+use std::process::ExitCode;
+use std::{env, fs, io};
+
+use sql_helper::ansi::format::{format_script, FormatOptions};
+use sql_helper::ansi::parser::{parse_script, ScriptError};
+
+const USAGE: &str = "\
+usage: sql-helper <check | fmt | ast> [FILE...]
+
+Reads the standard input if no FILE (or `-`) is given.
+
+commands:
+    check    parse the statements and report errors with their position
+    fmt      pretty print the statements
+    ast      dump the statements AST as versioned JSON";
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Command {
+    Check,
+    Fmt,
+    Ast,
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+
+    let command = match args.next().as_deref() {
+        Some("check") => Command::Check,
+        Some("fmt") => Command::Fmt,
+        Some("ast") => Command::Ast,
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut paths = args.collect::<Vec<_>>();
+    if paths.is_empty() {
+        paths.push("-".to_string());
+    }
+
+    let mut success = true;
+    for path in &paths {
+        match read_input(path) {
+            Ok(sql) => success &= run(command, path, &sql),
+            Err(err) => {
+                eprintln!("{path}: {err}");
+                success = false;
+            }
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn read_input(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut sql = String::new();
+        io::stdin().read_to_string(&mut sql)?;
+        Ok(sql)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Runs the command over the input, returning whether it succeeded.
+fn run(command: Command, path: &str, sql: &str) -> bool {
+    let result = match command {
+        Command::Check => parse_script(sql).map(|_| String::new()),
+        Command::Fmt => {
+            let options = FormatOptions::new().with_element_per_line(true);
+            format_script(sql, &options)
+        }
+        Command::Ast => parse_script(sql).map(|statements| {
+            // OBS: the envelopes are parsed back only to be pretty printed, so
+            // the format is the one of `Statement::to_json`.
+            let statements = statements
+                .iter()
+                .map(|(statement, _)| {
+                    serde_json::from_str(&statement.to_json()).expect("envelopes are valid JSON")
+                })
+                .collect::<Vec<serde_json::Value>>();
+            let json = serde_json::to_string_pretty(&statements).expect("JSON is serializable");
+            format!("{json}\n")
+        }),
+    };
+
+    match result {
+        Ok(output) => {
+            print!("{output}");
+            true
+        }
+        Err(err) => {
+            let (line, column) = err.span().start_line_column(sql);
+            let message = match err {
+                ScriptError::MisplacedComment(_) => "comment can't be formatted",
+                _ => "invalid statement",
+            };
+            eprintln!("{path}:{line}:{column}: {message}");
+            false
+        }
+    }
+}
@@ -1,15 +1,25 @@
+use std::borrow::Cow;
 use std::fmt;
 
 use nom::character::is_alphanumeric;
 
+use crate::common::dialect::Dialect;
+
 pub mod ast;
+pub mod dialect;
+pub mod expr;
 pub mod parsers;
+pub mod quote;
+pub mod report;
+pub mod span;
+pub mod split;
 pub mod tokens;
 
 /// SQL identifiers [(1)].
 ///
 /// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#identifier
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ident {
     /// Identifier internal unquoted value.
     value: String,
@@ -17,13 +27,34 @@ pub struct Ident {
     quote_style: QuoteStyle,
 }
 
+/// SQL identifier that borrows its value from the parsed input, when possible.
+///
+/// Parsing into borrowed identifiers avoids an allocation per identifier,
+/// which is useful when scanning large inputs (e.g., collecting the names in a
+/// dump). Use [`BorrowedIdent::into_owned`] to store it in the AST.
+///
+/// The value is only owned if it had to be unescaped (e.g., `"my""name"`), or
+/// if the input was not valid UTF-8.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BorrowedIdent<'a> {
+    /// Identifier internal unquoted value.
+    value: Cow<'a, str>,
+    /// Identifier quote style.
+    quote_style: QuoteStyle,
+}
+
 /// Possible quote styles for identifiers for all dialects.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum QuoteStyle {
     /// Nonexistent quote style.
     None,
     /// Double quote style (").
     DoubleQuote,
+    /// Bracket quote style ([]).
+    Bracket,
+    /// Backtick quote style (`` ` ``).
+    Backtick,
 }
 
 impl Ident {
@@ -40,6 +71,89 @@ impl Ident {
         }
     }
 
+    /// Creates an unquoted identifier from the received value, without
+    /// validating it.
+    ///
+    /// The value is emitted as is, so use [`Ident::needs_quoting`] (or
+    /// [`Ident::new_auto_quoted`]) if it may not be a valid regular
+    /// identifier.
+    #[must_use]
+    pub fn from_str_unchecked(value: &str) -> Self {
+        Self::new(value.as_bytes())
+    }
+
+    /// Creates a double quoted identifier from the received value.
+    #[must_use]
+    pub fn quoted(value: &str) -> Self {
+        Self::new_quoted(value.as_bytes(), QuoteStyle::DoubleQuote)
+    }
+
+    /// Creates an identifier that is quoted, using the dialect quote style,
+    /// only if the received value can't be emitted unquoted.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::dialect::AnsiDialect;
+    /// # use sql_helper::common::Ident;
+    /// assert_eq!(Ident::new_auto_quoted("name", &AnsiDialect).to_string(), "name");
+    /// assert_eq!(Ident::new_auto_quoted("table", &AnsiDialect).to_string(), "\"table\"");
+    /// assert_eq!(Ident::new_auto_quoted("my name", &AnsiDialect).to_string(), "\"my name\"");
+    /// ```
+    #[must_use]
+    pub fn new_auto_quoted(value: &str, dialect: &dyn Dialect) -> Self {
+        let ident = Self::from_str_unchecked(value);
+
+        if ident.needs_quoting(dialect) {
+            Self::new_quoted(value.as_bytes(), dialect.identifier_quote_style())
+        } else {
+            ident
+        }
+    }
+
+    /// Returns whether the identifier value must be quoted to be valid in the
+    /// received dialect, either because it's a reserved keyword or because it
+    /// has characters that are not allowed in unquoted identifiers.
+    ///
+    /// The current quote style of the identifier is not considered.
+    #[must_use]
+    pub fn needs_quoting(&self, dialect: &dyn Dialect) -> bool {
+        let mut chars = self.value.chars();
+
+        let valid_start = chars
+            .next()
+            .is_some_and(|chr| dialect.is_identifier_start(chr));
+        let valid_parts = chars.all(|chr| dialect.is_identifier_part(chr));
+
+        !valid_start || !valid_parts || dialect.is_reserved_keyword(&self.value)
+    }
+
+    /// Returns the identifier as it should be emitted in the received dialect.
+    ///
+    /// Quoted identifiers use the dialect quote style, and unquoted ones are
+    /// only quoted if they are not valid unquoted identifiers in the dialect.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "mysql")] {
+    /// # use sql_helper::common::Ident;
+    /// # use sql_helper::mysql::dialect::MySqlDialect;
+    /// assert_eq!(Ident::quoted("name").for_dialect(&MySqlDialect).to_string(), "`name`");
+    /// assert_eq!(Ident::from("key").for_dialect(&MySqlDialect).to_string(), "`key`");
+    /// assert_eq!(Ident::from("name").for_dialect(&MySqlDialect).to_string(), "name");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn for_dialect(&self, dialect: &dyn Dialect) -> Self {
+        if self.quote_style != QuoteStyle::None || self.needs_quoting(dialect) {
+            Self {
+                value: self.value.clone(),
+                quote_style: dialect.identifier_quote_style(),
+            }
+        } else {
+            self.clone()
+        }
+    }
+
     #[must_use]
     pub fn value(&self) -> &str {
         &self.value
@@ -49,17 +163,138 @@ impl Ident {
     pub const fn quote_style(&self) -> &QuoteStyle {
         &self.quote_style
     }
+
+    /// Returns the name that the identifier refers to, following the `ANSI`
+    /// case folding: unquoted identifiers are folded to upper case, while
+    /// quoted ones are case-sensitive.
+    ///
+    /// Unlike the identifier equality, which also compares the quote style,
+    /// two identifiers refer to the same object if their canonical names are
+    /// equal, so the canonical name can be used as a catalog lookup key.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::common::Ident;
+    /// assert_eq!(Ident::from("name").canonical(), "NAME");
+    /// assert_eq!(Ident::quoted("name").canonical(), "name");
+    /// assert_eq!(Ident::quoted("NAME").canonical(), Ident::from("Name").canonical());
+    /// ```
+    #[must_use]
+    pub fn canonical(&self) -> Cow<'_, str> {
+        if self.quote_style == QuoteStyle::None {
+            Cow::Owned(self.value.to_uppercase())
+        } else {
+            Cow::Borrowed(&self.value)
+        }
+    }
+
+    /// Returns whether both identifiers refer to the same object, comparing
+    /// their canonical names [(1)].
+    ///
+    /// [(1)]: Ident::canonical
+    #[must_use]
+    pub fn refers_to(&self, other: &Self) -> bool {
+        self.canonical() == other.canonical()
+    }
+}
+
+impl<'a> BorrowedIdent<'a> {
+    #[must_use]
+    pub fn new(value: &'a [u8], quote_style: QuoteStyle) -> Self {
+        Self {
+            value: String::from_utf8_lossy(value),
+            quote_style,
+        }
+    }
+
+    /// Creates an identifier from an unescaped value, that can't be borrowed
+    /// from the input. The value buffer is reused if it's valid UTF-8.
+    #[must_use]
+    pub fn new_owned(value: Vec<u8>, quote_style: QuoteStyle) -> Self {
+        let value = String::from_utf8(value)
+            .unwrap_or_else(|err| String::from_utf8_lossy(err.as_bytes()).into_owned());
+
+        Self {
+            value: Cow::Owned(value),
+            quote_style,
+        }
+    }
+
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    #[must_use]
+    pub const fn quote_style(&self) -> &QuoteStyle {
+        &self.quote_style
+    }
+
+    /// Returns whether the value is borrowed from the input.
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self.value, Cow::Borrowed(_))
+    }
+
+    /// Converts into an owned identifier, reusing the value if it is already
+    /// owned.
+    #[must_use]
+    pub fn into_owned(self) -> Ident {
+        Ident {
+            value: self.value.into_owned(),
+            quote_style: self.quote_style,
+        }
+    }
+}
+
+impl From<BorrowedIdent<'_>> for Ident {
+    fn from(ident: BorrowedIdent<'_>) -> Self {
+        ident.into_owned()
+    }
+}
+
+impl fmt::Display for BorrowedIdent<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_ident(f, self.value(), *self.quote_style())
+    }
+}
+
+impl From<&str> for Ident {
+    fn from(value: &str) -> Self {
+        Self::new(value.as_bytes())
+    }
+}
+
+impl From<String> for Ident {
+    fn from(value: String) -> Self {
+        Self {
+            value,
+            quote_style: QuoteStyle::None,
+        }
+    }
 }
 
 impl fmt::Display for Ident {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.quote_style() {
-            QuoteStyle::None => {
-                write!(f, "{}", self.value)
-            }
-            QuoteStyle::DoubleQuote => {
-                write!(f, "\"{}\"", self.value)
-            }
+        write_ident(f, self.value(), *self.quote_style())
+    }
+}
+
+/// Writes the identifier value, quoted and escaped as the quote style
+/// requires.
+fn write_ident(f: &mut fmt::Formatter<'_>, value: &str, quote_style: QuoteStyle) -> fmt::Result {
+    match quote_style {
+        QuoteStyle::None => {
+            write!(f, "{value}")
+        }
+        QuoteStyle::DoubleQuote => {
+            write!(f, "\"{}\"", value.replace('"', "\"\""))
+        }
+        QuoteStyle::Bracket => {
+            write!(f, "[{}]", value.replace(']', "]]"))
+        }
+        QuoteStyle::Backtick => {
+            write!(f, "`{}`", value.replace('`', "``"))
         }
     }
 }
@@ -86,6 +321,7 @@ pub fn if_some_string_preceded_by(opt_item: Option<impl ToString>, preceded_by:
 
 #[cfg(test)]
 mod tests {
+    use crate::ansi::dialect::AnsiDialect;
     use crate::common::parsers::ident;
 
     use super::*;
@@ -109,6 +345,62 @@ mod tests {
         validate!(b"\"1\"", Ident::new_quoted(b"1", QuoteStyle::DoubleQuote));
     }
 
+    #[test]
+    fn test_ident_from_string() {
+        assert_eq!(Ident::from("name_1"), Ident::new(b"name_1"));
+        assert_eq!(Ident::from(String::from("name_1")), Ident::new(b"name_1"));
+    }
+
+    #[test]
+    fn test_parse_escaped_ident() {
+        let (_, parsed) = ident(b"\"my \"\"name\"\"\"").unwrap();
+        assert_eq!(parsed, Ident::quoted("my \"name\""));
+        assert_eq!(parsed.to_string(), "\"my \"\"name\"\"\"");
+    }
+
+    #[test]
+    fn test_ident_needs_quoting() {
+        macro_rules! validate {
+            ($input:expr, $expected:expr) => {
+                assert_eq!(
+                    Ident::from_str_unchecked($input).needs_quoting(&AnsiDialect),
+                    $expected,
+                    "{}",
+                    $input
+                );
+            };
+        }
+
+        validate!("name_1", false);
+        validate!("Name", false);
+        validate!("table_name", false);
+        validate!("", true);
+        validate!("1name", true);
+        validate!("_name", true);
+        validate!("my name", true);
+        validate!("name-1", true);
+        validate!("TABLE", true);
+        validate!("table", true);
+        validate!("Create", true);
+    }
+
+    #[test]
+    fn test_ident_constructors() {
+        assert_eq!(
+            Ident::from_str_unchecked("1 name").quote_style(),
+            &QuoteStyle::None
+        );
+        assert_eq!(Ident::quoted("name").to_string(), "\"name\"");
+        assert_eq!(
+            Ident::new_auto_quoted("select", &AnsiDialect),
+            Ident::quoted("select")
+        );
+        assert_eq!(
+            Ident::new_auto_quoted("selection", &AnsiDialect),
+            Ident::from("selection")
+        );
+    }
+
     #[test]
     fn test_parse_invalid_ident() {
         let result = ident(b"1");
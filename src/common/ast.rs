@@ -34,6 +34,7 @@ use std::fmt;
 /// | <apostrophe>
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SqlSpecialCharacter {
     /// `<space>`.
     Space,
@@ -126,3 +127,247 @@ impl fmt::Display for SqlSpecialCharacter {
         Ok(())
     }
 }
+
+/// Spatial data type, shared by the dialects that store geometries.
+///
+/// The type modifier is the `PostGIS` syntax to restrict the geometry subtype
+/// and its spatial reference system. `T-SQL` only has `GEOMETRY` and
+/// `GEOGRAPHY`, and `MySQL` has `GEOMETRY` and the subtypes.
+///
+/// # Supported syntax
+/// ```plaintext
+///   GEOMETRY [(<geometry subtype> [, <srid>])]
+/// | GEOGRAPHY [(<geometry subtype> [, <srid>])]
+/// | <geometry subtype>
+/// ```
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::ast::{GeometrySubtype, SpatialType, SpatialTypeModifier};
+/// let spatial_type = SpatialType::Geography(Some(
+///     SpatialTypeModifier::new(GeometrySubtype::Point).with_srid(4326),
+/// ));
+///
+/// assert_eq!(spatial_type.to_string(), "GEOGRAPHY(POINT, 4326)");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpatialType {
+    /// `GEOMETRY [(<geometry subtype> [, <srid>])]`, on a plane.
+    Geometry(Option<SpatialTypeModifier>),
+    /// `GEOGRAPHY [(<geometry subtype> [, <srid>])]`, on an ellipsoid.
+    Geography(Option<SpatialTypeModifier>),
+    /// `<geometry subtype>`, as a type on its own.
+    Subtype(GeometrySubtype),
+}
+
+/// Subtype and spatial reference system of a spatial type (e.g.,
+/// `(POINT, 4326)`).
+///
+/// # Supported syntax
+/// ```plaintext
+/// (<geometry subtype> [, <srid>])
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpatialTypeModifier {
+    /// `<geometry subtype>`
+    subtype: GeometrySubtype,
+    /// `<srid>`
+    opt_srid: Option<u32>,
+}
+
+/// Kind of geometry stored by a spatial type.
+///
+/// # Supported syntax
+/// ```plaintext
+///   POINT
+/// | LINESTRING
+/// | POLYGON
+/// | MULTIPOINT
+/// | MULTILINESTRING
+/// | MULTIPOLYGON
+/// | GEOMETRYCOLLECTION
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GeometrySubtype {
+    /// `POINT`
+    Point,
+    /// `LINESTRING`
+    LineString,
+    /// `POLYGON`
+    Polygon,
+    /// `MULTIPOINT`
+    MultiPoint,
+    /// `MULTILINESTRING`
+    MultiLineString,
+    /// `MULTIPOLYGON`
+    MultiPolygon,
+    /// `GEOMETRYCOLLECTION`
+    GeometryCollection,
+}
+
+impl SpatialType {
+    /// Returns the type modifier, which only `GEOMETRY` and `GEOGRAPHY` have.
+    #[must_use]
+    pub const fn opt_modifier(&self) -> Option<SpatialTypeModifier> {
+        match self {
+            Self::Geometry(opt_modifier) | Self::Geography(opt_modifier) => *opt_modifier,
+            Self::Subtype(_) => None,
+        }
+    }
+}
+
+impl SpatialTypeModifier {
+    #[must_use]
+    pub const fn new(subtype: GeometrySubtype) -> Self {
+        Self {
+            subtype,
+            opt_srid: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_srid(mut self, srid: u32) -> Self {
+        self.opt_srid = Some(srid);
+        self
+    }
+
+    #[must_use]
+    pub const fn subtype(&self) -> GeometrySubtype {
+        self.subtype
+    }
+
+    #[must_use]
+    pub const fn opt_srid(&self) -> Option<u32> {
+        self.opt_srid
+    }
+}
+
+impl fmt::Display for SpatialType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Geometry(opt_modifier) => {
+                write!(f, "GEOMETRY")?;
+
+                if let Some(modifier) = opt_modifier {
+                    write!(f, "{modifier}")?;
+                }
+            }
+            Self::Geography(opt_modifier) => {
+                write!(f, "GEOGRAPHY")?;
+
+                if let Some(modifier) = opt_modifier {
+                    write!(f, "{modifier}")?;
+                }
+            }
+            Self::Subtype(subtype) => write!(f, "{subtype}")?,
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for SpatialTypeModifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}", self.subtype())?;
+        if let Some(srid) = self.opt_srid() {
+            write!(f, ", {srid}")?;
+        }
+        write!(f, ")")?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for GeometrySubtype {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Point => write!(f, "POINT")?,
+            Self::LineString => write!(f, "LINESTRING")?,
+            Self::Polygon => write!(f, "POLYGON")?,
+            Self::MultiPoint => write!(f, "MULTIPOINT")?,
+            Self::MultiLineString => write!(f, "MULTILINESTRING")?,
+            Self::MultiPolygon => write!(f, "MULTIPOLYGON")?,
+            Self::GeometryCollection => write!(f, "GEOMETRYCOLLECTION")?,
+        }
+        Ok(())
+    }
+}
+
+/// Dialect specific option, captured by name and kept as written so it can be
+/// emitted back (e.g., the `ENGINE=InnoDB` option of a `MySQL` table).
+///
+/// The name is a single word or one of the multi-word names known to take a
+/// value (e.g., `DEFAULT CHARSET` or `PARTITION BY`), and the value is a word,
+/// a number, a string or a parenthesized list, followed by any parenthesized
+/// lists.
+///
+/// # Supported syntax
+/// ```plaintext
+/// <option name> [=] <option value>
+/// ```
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::ast::SqlOption;
+/// let option = SqlOption::new("ENGINE", "InnoDB").with_equals(true);
+/// assert_eq!(option.to_string(), "ENGINE=InnoDB");
+///
+/// let option = SqlOption::new("WITH", "(fillfactor=70)");
+/// assert_eq!(option.to_string(), "WITH (fillfactor=70)");
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SqlOption {
+    /// `<option name>`, in uppercase.
+    name: String,
+    /// `<option value>`, as written.
+    value: String,
+    /// Whether the name and the value are separated by `=`.
+    equals: bool,
+}
+
+impl SqlOption {
+    #[must_use]
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            equals: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_equals(mut self, equals: bool) -> Self {
+        self.equals = equals;
+        self
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    #[must_use]
+    pub const fn equals(&self) -> bool {
+        self.equals
+    }
+
+    pub fn value_mut(&mut self) -> &mut String {
+        &mut self.value
+    }
+}
+
+impl fmt::Display for SqlOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = if self.equals() { "=" } else { " " };
+        write!(f, "{}{separator}{}", self.name(), self.value())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use crate::common::QuoteStyle;
+
+/// Lexical rules that change between SQL dialects.
+///
+/// The default implementations follow the `ANSI` rules for regular
+/// identifiers, so dialects only need to override what differs.
+pub trait Dialect {
+    /// Returns whether the received word is reserved in the dialect, and
+    /// therefore can't be used as an unquoted identifier.
+    fn is_reserved_keyword(&self, word: &str) -> bool;
+
+    /// Returns whether the character can start an unquoted identifier.
+    fn is_identifier_start(&self, chr: char) -> bool {
+        chr.is_ascii_alphabetic()
+    }
+
+    /// Returns whether the character can be part of an unquoted identifier.
+    fn is_identifier_part(&self, chr: char) -> bool {
+        chr.is_ascii_alphanumeric() || chr == '_'
+    }
+
+    /// Maximum number of parts of a qualified object name, including the
+    /// unqualified name. `ANSI` names have up to three parts
+    /// (`<catalog>.<schema>.<name>`).
+    fn max_name_parts(&self) -> usize {
+        3
+    }
+
+    /// Quote style used when an identifier must be emitted quoted.
+    fn identifier_quote_style(&self) -> QuoteStyle {
+        QuoteStyle::DoubleQuote
+    }
+
+    /// Limits of the numeric type parameters. `ANSI` leaves them to the
+    /// implementation, so none are set by default.
+    fn numeric_limits(&self) -> NumericLimits {
+        NumericLimits::default()
+    }
+
+    /// Returns whether the dialect spells numeric types with aliases such as
+    /// `DOUBLE`, `FLOAT8` or `INT8`, which aren't defined by `ANSI`.
+    fn supports_numeric_type_aliases(&self) -> bool {
+        false
+    }
+
+    /// Escape semantics of the string literals. `ANSI` only escapes quotes by
+    /// doubling them.
+    fn string_escapes(&self) -> StringEscapes {
+        StringEscapes::Standard
+    }
+}
+
+/// How the quotes and special characters are escaped in string literals.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::dialect::StringEscapes;
+/// assert!(StringEscapes::Backslash.has_backslash_escapes(false));
+/// assert!(!StringEscapes::EscapeStringPrefix.has_backslash_escapes(false));
+/// assert!(StringEscapes::EscapeStringPrefix.has_backslash_escapes(true));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum StringEscapes {
+    /// Quotes are escaped by doubling them (`'it''s'`), and backslashes are
+    /// regular characters.
+    #[default]
+    Standard,
+    /// Backslashes also escape characters in every string (`'it\'s'`), as in
+    /// `MySQL`.
+    Backslash,
+    /// Backslashes only escape characters in the strings prefixed by `E`
+    /// (`E'it\'s'`), as in `PostgreSQL`.
+    EscapeStringPrefix,
+}
+
+impl StringEscapes {
+    /// Returns whether backslashes escape characters in a string, depending on
+    /// whether it's prefixed by `E`.
+    #[must_use]
+    pub const fn has_backslash_escapes(&self, escape_prefix: bool) -> bool {
+        match self {
+            Self::Standard => false,
+            Self::Backslash => true,
+            Self::EscapeStringPrefix => escape_prefix,
+        }
+    }
+
+    /// Returns whether strings can be prefixed by `E`.
+    #[must_use]
+    pub const fn allows_escape_prefix(&self) -> bool {
+        matches!(self, Self::EscapeStringPrefix)
+    }
+
+    /// Quotes the value as a string literal without prefix, escaping the
+    /// quotes and, if they are escape characters, the backslashes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::common::dialect::StringEscapes;
+    /// assert_eq!(StringEscapes::Standard.quote(r"it's C:\"), r"'it''s C:\'");
+    /// assert_eq!(StringEscapes::Backslash.quote(r"it's C:\"), r"'it''s C:\\'");
+    /// ```
+    #[must_use]
+    pub fn quote(&self, value: &str) -> String {
+        let value = if self.has_backslash_escapes(false) {
+            value.replace('\\', r"\\")
+        } else {
+            value.to_string()
+        };
+
+        format!("'{}'", value.replace('\'', "''"))
+    }
+}
+
+/// Maximum precisions and scales accepted by a database, where `None` means
+/// there's no limit.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::dialect::NumericLimits;
+/// let limits = NumericLimits::default().with_max_decimal_precision(38);
+///
+/// assert_eq!(limits.max_decimal_precision(), Some(38));
+/// assert_eq!(limits.max_decimal_scale(), None);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct NumericLimits {
+    /// Precision of `NUMERIC` and `DECIMAL` types.
+    decimal_precision: Option<u32>,
+    /// Scale of `NUMERIC` and `DECIMAL` types, which is also limited by their
+    /// precision.
+    decimal_scale: Option<u32>,
+    /// Precision of `DECFLOAT` types.
+    decfloat_precision: Option<u32>,
+    /// Fractional seconds precision of `TIME` and `TIMESTAMP` types.
+    temporal_precision: Option<u32>,
+}
+
+impl NumericLimits {
+    #[must_use]
+    pub const fn with_max_decimal_precision(mut self, max_decimal_precision: u32) -> Self {
+        self.decimal_precision = Some(max_decimal_precision);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_decimal_scale(mut self, max_decimal_scale: u32) -> Self {
+        self.decimal_scale = Some(max_decimal_scale);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_decfloat_precision(mut self, max_decfloat_precision: u32) -> Self {
+        self.decfloat_precision = Some(max_decfloat_precision);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_temporal_precision(mut self, max_temporal_precision: u32) -> Self {
+        self.temporal_precision = Some(max_temporal_precision);
+        self
+    }
+
+    #[must_use]
+    pub const fn max_decimal_precision(&self) -> Option<u32> {
+        self.decimal_precision
+    }
+
+    #[must_use]
+    pub const fn max_decimal_scale(&self) -> Option<u32> {
+        self.decimal_scale
+    }
+
+    #[must_use]
+    pub const fn max_decfloat_precision(&self) -> Option<u32> {
+        self.decfloat_precision
+    }
+
+    #[must_use]
+    pub const fn max_temporal_precision(&self) -> Option<u32> {
+        self.temporal_precision
+    }
+}
@@ -0,0 +1,762 @@
+//! Value expressions shared by the `ANSI` and the dialect ASTs.
+//!
+//! The expression nodes don't depend on any dialect, so dialect modules reuse
+//! them instead of defining their own, and the conversions to other ASTs are
+//! only written once.
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::common::span::Span;
+use crate::common::Ident;
+
+/// Value expression (`<value expression>`) [(1)].
+///
+/// Parentheses are kept as [`Expr::Nested`] nodes, so the expression is
+/// serialized as written, without needing the operator precedence.
+///
+/// # Supported syntax
+/// ```plaintext
+///   <literal>
+/// | NULL
+/// | <identifier> [{.<identifier>}...]
+/// | <host parameter name>
+/// | <unary operator> <value expression>
+/// | <value expression> <binary operator> <value expression>
+/// | <value expression> IS [NOT] NULL
+/// | (<value expression>)
+/// ```
+///
+/// Expressions outside of the supported syntax can be kept verbatim as
+/// [`Expr::Raw`] nodes.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::expr::{BinaryOperator, Expr, Literal};
+/// # use sql_helper::common::Ident;
+/// let expr = Expr::BinaryOp(
+///     Box::new(Expr::Identifier(Ident::new(b"price"))),
+///     BinaryOperator::Multiply,
+///     Box::new(Expr::Nested(Box::new(Expr::BinaryOp(
+///         Box::new(Expr::Literal(Literal::Numeric("1".to_string()))),
+///         BinaryOperator::Minus,
+///         Box::new(Expr::Identifier(Ident::new(b"discount"))),
+///     )))),
+/// );
+///
+/// assert_eq!(expr.to_string(), "price * (1 - discount)");
+/// ```
+///
+/// [(1)]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#value-expression
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Expr {
+    /// `<literal>`.
+    Literal(Literal),
+    /// `NULL`.
+    Null,
+    /// Unqualified column or parameter reference.
+    Identifier(Ident),
+    /// Qualified column reference (e.g., `tb.id`).
+    CompoundIdentifier(Vec<Ident>),
+    /// `<host parameter name>` (e.g., `:name`).
+    HostParameter(Ident),
+    /// `<unary operator> <value expression>`.
+    UnaryOp(UnaryOperator, Box<Expr>),
+    /// `<value expression> <binary operator> <value expression>`.
+    BinaryOp(Box<Expr>, BinaryOperator, Box<Expr>),
+    /// `<value expression> IS NULL`.
+    IsNull(Box<Expr>),
+    /// `<value expression> IS NOT NULL`.
+    IsNotNull(Box<Expr>),
+    /// `(<value expression>)`.
+    Nested(Box<Expr>),
+    /// Expression captured verbatim, as its grammar isn't supported yet.
+    Raw(RawExpr),
+}
+
+/// Expression source text captured without being parsed.
+///
+/// It lets constructs that contain expressions be represented before the
+/// expression grammar they use is supported, without losing the expression.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::expr::RawExpr;
+/// # use sql_helper::common::span::Span;
+/// let raw = RawExpr::new("price > ALL (SELECT price FROM tb)", Span::new(6, 40));
+///
+/// assert_eq!(raw.to_string(), "price > ALL (SELECT price FROM tb)");
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawExpr {
+    /// Expression text, as written.
+    text: String,
+    /// Position of the text in the parsed source.
+    span: Span,
+}
+
+/// Literal (`<literal>`).
+///
+/// # Supported syntax
+/// ```plaintext
+///   <signed numeric literal>
+/// | <character string literal>
+/// | <escape string>
+/// | <dollar-quoted string>
+/// | <binary string literal>
+/// | TRUE
+/// | FALSE
+/// | UNKNOWN
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Literal {
+    /// `<signed numeric literal>`, as written (e.g., `-1.5E3`), so its
+    /// precision is never lost. Its exact value is available through
+    /// [`Literal::as_decimal_string`].
+    Numeric(String),
+    /// `<character string literal>`, without the quotes and with the doubled
+    /// quotes unescaped.
+    CharacterString(String),
+    /// String with backslash escapes (e.g., `E'it\'s'`).
+    EscapeString(EscapeString),
+    /// `PostgreSQL` dollar-quoted string (e.g., `$$it's$$`).
+    DollarQuotedString(DollarQuotedString),
+    /// Hexadecimal or bit string literal (e.g., `X'FF'` or `0b1010`).
+    Binary(BinaryLiteral),
+    /// `TRUE`.
+    True,
+    /// `FALSE`.
+    False,
+    /// `UNKNOWN`.
+    Unknown,
+}
+
+/// String literal with backslash escapes, either a `PostgreSQL` `E'...'`
+/// literal or a `MySQL` string that uses them.
+///
+/// The text is kept as written, so it's serialized back with the same
+/// escapes, and the decoded value is available through [`EscapeString::value`].
+///
+/// # Supported syntax
+/// ```plaintext
+/// [E]'<text>'
+/// ```
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::expr::EscapeString;
+/// let escape_string = EscapeString::new(r"it\'s\n", "it's\n").with_escape_prefix(true);
+///
+/// assert_eq!(escape_string.value(), "it's\n");
+/// assert_eq!(escape_string.to_string(), r"E'it\'s\n'");
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EscapeString {
+    /// `<text>`, as written.
+    text: String,
+    /// `<text>`, with the escapes decoded.
+    value: String,
+    /// `[E]`.
+    escape_prefix: bool,
+}
+
+/// Binary string literal, written with hexadecimal or binary digits.
+///
+/// The `MySQL` prefixed forms (`0xFF` and `0b1010`) are normalized into the
+/// same node as the quoted forms, keeping the notation they were written with
+/// so they are serialized back as written.
+///
+/// # Supported syntax
+/// ```plaintext
+///   X'<hexadecimal digit>...'
+/// | B'<bit>...'
+/// | 0x<hexadecimal digit>...
+/// | 0b<bit>...
+/// ```
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::expr::{BinaryLiteral, BinaryNotation};
+/// let hexadecimal = BinaryLiteral::hexadecimal("0aFF");
+/// assert_eq!(hexadecimal.to_string(), "X'0aFF'");
+/// assert_eq!(hexadecimal.bytes(), vec![0x0a, 0xff]);
+///
+/// let bit = BinaryLiteral::bit("1000000001").with_notation(BinaryNotation::Prefixed);
+/// assert_eq!(bit.to_string(), "0b1000000001");
+/// assert_eq!(bit.bytes(), vec![0b10, 0b0000_0001]);
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BinaryLiteral {
+    /// Digits, as written.
+    digits: String,
+    /// Whether the digits are hexadecimal or binary.
+    radix: BinaryRadix,
+    /// Notation the literal was written with.
+    notation: BinaryNotation,
+}
+
+/// Digits of a binary string literal.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BinaryRadix {
+    /// Hexadecimal digits (`X'FF'` or `0xFF`).
+    Hexadecimal,
+    /// Binary digits (`B'1010'` or `0b1010`).
+    Bit,
+}
+
+/// Notation of a binary string literal.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BinaryNotation {
+    /// Quoted digits, prefixed by the radix (`X'FF'` or `B'1010'`).
+    #[default]
+    Quoted,
+    /// `MySQL` digits prefixed by `0x` or `0b` (`0xFF` or `0b1010`).
+    Prefixed,
+}
+
+/// `PostgreSQL` dollar-quoted string, commonly used for function bodies.
+///
+/// The value is kept as written, as nothing is escaped inside the delimiters.
+///
+/// # Supported syntax
+/// ```plaintext
+/// $[<tag>]$<value>$[<tag>]$
+/// ```
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::expr::DollarQuotedString;
+/// let body = DollarQuotedString::new("BEGIN RETURN 'a'; END;").with_tag("fn");
+///
+/// assert_eq!(body.to_string(), "$fn$BEGIN RETURN 'a'; END;$fn$");
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DollarQuotedString {
+    /// `<value>`.
+    value: String,
+    /// `[<tag>]`.
+    opt_tag: Option<String>,
+}
+
+/// Prefix operator of an expression.
+///
+/// # Supported syntax
+/// ```plaintext
+///   +
+/// | -
+/// | NOT
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnaryOperator {
+    /// `+`.
+    Plus,
+    /// `-`.
+    Minus,
+    /// `NOT`.
+    Not,
+}
+
+/// Infix operator of an expression.
+///
+/// # Supported syntax
+/// ```plaintext
+///   + | - | * | / | ||
+/// | = | <> | < | <= | > | >=
+/// | AND | OR
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BinaryOperator {
+    /// `+`.
+    Plus,
+    /// `-`.
+    Minus,
+    /// `*`.
+    Multiply,
+    /// `/`.
+    Divide,
+    /// `||`.
+    Concat,
+    /// `=`.
+    Eq,
+    /// `<>`.
+    NotEq,
+    /// `<`.
+    Lt,
+    /// `<=`.
+    LtEq,
+    /// `>`.
+    Gt,
+    /// `>=`.
+    GtEq,
+    /// `AND`.
+    And,
+    /// `OR`.
+    Or,
+}
+
+impl RawExpr {
+    #[must_use]
+    pub fn new(text: impl Into<String>, span: Span) -> Self {
+        Self {
+            text: text.into(),
+            span,
+        }
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn text_mut(&mut self) -> &mut String {
+        &mut self.text
+    }
+}
+
+impl Literal {
+    /// Returns the decoded value of a string literal, or `None` if the literal
+    /// isn't a string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::common::expr::{EscapeString, Literal};
+    /// let literal = Literal::EscapeString(EscapeString::new(r"a\tb", "a\tb"));
+    ///
+    /// assert_eq!(literal.opt_string_value(), Some("a\tb"));
+    /// assert_eq!(Literal::True.opt_string_value(), None);
+    /// ```
+    #[must_use]
+    pub fn opt_string_value(&self) -> Option<&str> {
+        match self {
+            Self::CharacterString(value) => Some(value),
+            Self::EscapeString(escape_string) => Some(escape_string.value()),
+            Self::DollarQuotedString(dollar_quoted_string) => Some(dollar_quoted_string.value()),
+            Self::Numeric(_) | Self::Binary(_) | Self::True | Self::False | Self::Unknown => None,
+        }
+    }
+
+    /// Returns the value of a numeric literal written as an integer, or `None`
+    /// if the literal isn't one or doesn't fit in an `i64`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::common::expr::Literal;
+    /// assert_eq!(Literal::Numeric("-42".to_string()).as_i64(), Some(-42));
+    /// assert_eq!(Literal::Numeric("+7".to_string()).as_i64(), Some(7));
+    /// assert_eq!(Literal::Numeric("1.0".to_string()).as_i64(), None);
+    /// assert_eq!(Literal::Numeric("1E3".to_string()).as_i64(), None);
+    /// ```
+    #[must_use]
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Numeric(text) => text.parse().ok(),
+            Self::CharacterString(_)
+            | Self::EscapeString(_)
+            | Self::DollarQuotedString(_)
+            | Self::Binary(_)
+            | Self::True
+            | Self::False
+            | Self::Unknown => None,
+        }
+    }
+
+    /// Returns the exact value of a numeric literal in plain decimal notation,
+    /// or `None` if the literal isn't numeric or its exponent doesn't fit in
+    /// an `i16`.
+    ///
+    /// The exponent is applied by moving the decimal point, so no precision
+    /// is lost, and the trailing zeros of the fraction are kept, as they're
+    /// part of the literal scale.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::common::expr::Literal;
+    /// let decimal = |text: &str| Literal::Numeric(text.to_string()).as_decimal_string();
+    ///
+    /// assert_eq!(decimal("+1.50"), Some("1.50".to_string()));
+    /// assert_eq!(decimal("-.5"), Some("-0.5".to_string()));
+    /// assert_eq!(decimal("1.5E3"), Some("1500".to_string()));
+    /// assert_eq!(decimal("12e-4"), Some("0.0012".to_string()));
+    /// assert_eq!(decimal("007."), Some("7".to_string()));
+    /// assert_eq!(decimal("-0.0"), Some("0.0".to_string()));
+    /// assert_eq!(Literal::True.as_decimal_string(), None);
+    /// ```
+    #[must_use]
+    pub fn as_decimal_string(&self) -> Option<String> {
+        let Self::Numeric(text) = self else {
+            return None;
+        };
+
+        let (negative, unsigned) = match text.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, exponent.parse::<i16>().ok()?),
+            None => (unsigned, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        // OBS: the decimal point is moved over the digits by the exponent,
+        // padding them with zeros if it moves past either end.
+        let digits = format!("{integer}{fraction}");
+        let point = i64::try_from(integer.len()).ok()? + i64::from(exponent);
+        let (integer, fraction) = match usize::try_from(point) {
+            Err(_) => {
+                let padding = "0".repeat(usize::try_from(-point).ok()?);
+                (String::new(), format!("{padding}{digits}"))
+            }
+            Ok(point) if point >= digits.len() => {
+                let padding = "0".repeat(point - digits.len());
+                (format!("{digits}{padding}"), String::new())
+            }
+            Ok(point) => (digits[..point].to_string(), digits[point..].to_string()),
+        };
+
+        let integer = match integer.trim_start_matches('0') {
+            "" => "0",
+            integer => integer,
+        };
+        let is_zero = integer == "0" && fraction.chars().all(|digit| digit == '0');
+        let sign = if negative && !is_zero { "-" } else { "" };
+
+        if fraction.is_empty() {
+            Some(format!("{sign}{integer}"))
+        } else {
+            Some(format!("{sign}{integer}.{fraction}"))
+        }
+    }
+
+    /// Compares the exact values of two numeric literals, regardless of how
+    /// they're written, or returns `None` if either isn't numeric [(1)].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use sql_helper::common::expr::Literal;
+    /// let numeric = |text: &str| Literal::Numeric(text.to_string());
+    ///
+    /// assert_eq!(numeric("1.50").cmp_numeric_value(&numeric("15E-1")), Some(Ordering::Equal));
+    /// assert_eq!(
+    ///     numeric("0.1000000000000000000001").cmp_numeric_value(&numeric("0.1")),
+    ///     Some(Ordering::Greater)
+    /// );
+    /// assert_eq!(numeric("-2").cmp_numeric_value(&numeric("-10")), Some(Ordering::Greater));
+    /// assert_eq!(numeric("1").cmp_numeric_value(&Literal::True), None);
+    /// ```
+    ///
+    /// [(1)]: Literal::as_decimal_string
+    #[must_use]
+    pub fn cmp_numeric_value(&self, other: &Self) -> Option<Ordering> {
+        let (lhs, rhs) = (self.as_decimal_string()?, other.as_decimal_string()?);
+        let (lhs_negative, lhs_integer, lhs_fraction) = decimal_parts(&lhs);
+        let (rhs_negative, rhs_integer, rhs_fraction) = decimal_parts(&rhs);
+
+        // OBS: the integer parts have no leading zeros and the fractions have
+        // no trailing zeros, so longer integers are larger, and the digits can
+        // be compared as text.
+        let magnitude = lhs_integer
+            .len()
+            .cmp(&rhs_integer.len())
+            .then_with(|| lhs_integer.cmp(rhs_integer))
+            .then_with(|| lhs_fraction.cmp(rhs_fraction));
+
+        Some(match (lhs_negative, rhs_negative) {
+            (false, false) => magnitude,
+            (true, true) => magnitude.reverse(),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+        })
+    }
+
+    /// Returns the value of a boolean literal, or `None` if the literal isn't
+    /// one or is `UNKNOWN`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::common::expr::Literal;
+    /// assert_eq!(Literal::False.as_bool(), Some(false));
+    /// assert_eq!(Literal::Unknown.as_bool(), None);
+    /// ```
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::True => Some(true),
+            Self::False => Some(false),
+            Self::Numeric(_)
+            | Self::CharacterString(_)
+            | Self::EscapeString(_)
+            | Self::DollarQuotedString(_)
+            | Self::Binary(_)
+            | Self::Unknown => None,
+        }
+    }
+}
+
+/// Splits a plain decimal (e.g., `-1.50`) into its sign, its integer part and
+/// its fraction without trailing zeros.
+fn decimal_parts(decimal: &str) -> (bool, &str, &str) {
+    let (negative, unsigned) = match decimal.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, decimal),
+    };
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+    (negative, integer, fraction.trim_end_matches('0'))
+}
+
+impl BinaryLiteral {
+    /// Creates a quoted hexadecimal literal (`X'<digits>'`).
+    #[must_use]
+    pub fn hexadecimal(digits: impl Into<String>) -> Self {
+        Self {
+            digits: digits.into(),
+            radix: BinaryRadix::Hexadecimal,
+            notation: BinaryNotation::Quoted,
+        }
+    }
+
+    /// Creates a quoted bit literal (`B'<digits>'`).
+    #[must_use]
+    pub fn bit(digits: impl Into<String>) -> Self {
+        Self {
+            digits: digits.into(),
+            radix: BinaryRadix::Bit,
+            notation: BinaryNotation::Quoted,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_notation(mut self, notation: BinaryNotation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    #[must_use]
+    pub fn digits(&self) -> &str {
+        &self.digits
+    }
+
+    #[must_use]
+    pub const fn radix(&self) -> BinaryRadix {
+        self.radix
+    }
+
+    #[must_use]
+    pub const fn notation(&self) -> BinaryNotation {
+        self.notation
+    }
+
+    /// Returns the bytes the literal represents, where the leftmost byte is
+    /// padded with zeros if the digits don't fill it (e.g., `0xABC` is
+    /// `[0x0A, 0xBC]`). Invalid digits are read as zeros.
+    #[must_use]
+    pub fn bytes(&self) -> Vec<u8> {
+        let (radix, digits_per_byte) = match self.radix {
+            BinaryRadix::Hexadecimal => (16, 2),
+            BinaryRadix::Bit => (2, 8),
+        };
+        let digits = self
+            .digits
+            .chars()
+            .map(|chr| chr.to_digit(radix).unwrap_or_default())
+            .collect::<Vec<_>>();
+        let padding = (digits_per_byte - digits.len() % digits_per_byte) % digits_per_byte;
+
+        std::iter::repeat_n(0, padding)
+            .chain(digits)
+            .collect::<Vec<_>>()
+            .chunks(digits_per_byte)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0_u32, |byte, digit| byte * radix + digit)
+                    .to_le_bytes()[0]
+            })
+            .collect()
+    }
+}
+
+impl EscapeString {
+    #[must_use]
+    pub fn new(text: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            value: value.into(),
+            escape_prefix: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_escape_prefix(mut self, escape_prefix: bool) -> Self {
+        self.escape_prefix = escape_prefix;
+        self
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    #[must_use]
+    pub const fn escape_prefix(&self) -> bool {
+        self.escape_prefix
+    }
+}
+
+impl DollarQuotedString {
+    #[must_use]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            opt_tag: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.opt_tag = Some(tag.into());
+        self
+    }
+
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    #[must_use]
+    pub fn opt_tag(&self) -> Option<&str> {
+        self.opt_tag.as_deref()
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => write!(f, "{literal}")?,
+            Self::Null => write!(f, "NULL")?,
+            Self::Identifier(ident) => write!(f, "{ident}")?,
+            Self::CompoundIdentifier(idents) => {
+                let parts: Vec<String> = idents.iter().map(ToString::to_string).collect();
+                write!(f, "{}", parts.join("."))?;
+            }
+            Self::HostParameter(name) => write!(f, ":{name}")?,
+            Self::UnaryOp(UnaryOperator::Not, expr) => write!(f, "NOT {expr}")?,
+            Self::UnaryOp(op, expr) => write!(f, "{op}{expr}")?,
+            Self::BinaryOp(left, op, right) => write!(f, "{left} {op} {right}")?,
+            Self::IsNull(expr) => write!(f, "{expr} IS NULL")?,
+            Self::IsNotNull(expr) => write!(f, "{expr} IS NOT NULL")?,
+            Self::Nested(expr) => write!(f, "({expr})")?,
+            Self::Raw(raw) => write!(f, "{raw}")?,
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RawExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Numeric(value) => write!(f, "{value}")?,
+            Self::CharacterString(value) => write!(f, "'{}'", value.replace('\'', "''"))?,
+            Self::EscapeString(value) => write!(f, "{value}")?,
+            Self::DollarQuotedString(value) => write!(f, "{value}")?,
+            Self::Binary(value) => write!(f, "{value}")?,
+            Self::True => write!(f, "TRUE")?,
+            Self::False => write!(f, "FALSE")?,
+            Self::Unknown => write!(f, "UNKNOWN")?,
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for BinaryLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = match (self.notation(), self.radix()) {
+            (BinaryNotation::Quoted, BinaryRadix::Hexadecimal) => "X'",
+            (BinaryNotation::Quoted, BinaryRadix::Bit) => "B'",
+            (BinaryNotation::Prefixed, BinaryRadix::Hexadecimal) => "0x",
+            (BinaryNotation::Prefixed, BinaryRadix::Bit) => "0b",
+        };
+        write!(f, "{prefix}{}", self.digits())?;
+        if self.notation() == BinaryNotation::Quoted {
+            write!(f, "'")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for EscapeString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.escape_prefix() {
+            write!(f, "E")?;
+        }
+        write!(f, "'{}'", self.text())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for DollarQuotedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tag = self.opt_tag().unwrap_or_default();
+        write!(f, "${tag}${}${tag}$", self.value())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plus => write!(f, "+")?,
+            Self::Minus => write!(f, "-")?,
+            Self::Not => write!(f, "NOT")?,
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plus => write!(f, "+")?,
+            Self::Minus => write!(f, "-")?,
+            Self::Multiply => write!(f, "*")?,
+            Self::Divide => write!(f, "/")?,
+            Self::Concat => write!(f, "||")?,
+            Self::Eq => write!(f, "=")?,
+            Self::NotEq => write!(f, "<>")?,
+            Self::Lt => write!(f, "<")?,
+            Self::LtEq => write!(f, "<=")?,
+            Self::Gt => write!(f, ">")?,
+            Self::GtEq => write!(f, ">=")?,
+            Self::And => write!(f, "AND")?,
+            Self::Or => write!(f, "OR")?,
+        }
+        Ok(())
+    }
+}
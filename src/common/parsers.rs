@@ -1,19 +1,33 @@
+use std::borrow::Cow;
+
 use nom::branch::{alt, permutation};
-use nom::bytes::complete::{tag, take_while1};
+use nom::bytes::complete::{
+    is_not, tag, tag_no_case, take_until, take_while, take_while1, take_while_m_n,
+};
+use nom::character::complete::u32;
 use nom::character::complete::{alpha1, line_ending};
-use nom::combinator::{eof, map, peek};
+use nom::combinator::{eof, map, not, opt, peek, recognize};
 use nom::error::{ErrorKind, ParseError};
-use nom::sequence::delimited;
-use nom::{AsChar, Compare, IResult, InputTake, InputTakeAtPosition, Parser};
+use nom::multi::{many0_count, many1_count};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::{AsBytes, AsChar, Compare, IResult, InputTake, Parser};
 
-use crate::common::ast::SqlSpecialCharacter;
+use crate::common::ast::{
+    GeometrySubtype, SpatialType, SpatialTypeModifier, SqlOption, SqlSpecialCharacter,
+};
+use crate::common::dialect::StringEscapes;
+use crate::common::expr::{
+    BinaryLiteral, BinaryNotation, DollarQuotedString, EscapeString, Literal, RawExpr,
+};
+use crate::common::span::Span;
+use crate::common::split::{block_comment_end, dollar_quoted_end, line_comment_end};
 use crate::common::tokens::{
     ampersand, asterisk, circumflex, colon, comma, dollar_sign, double_quote, equals_operator,
     greater_than_operator, is_whitespace, left_brace, left_bracket, left_paren, less_than_operator,
     minus_sign, percent, period, plus_sign, question_mark, quote, right_brace, right_bracket,
     right_paren, semicolon, solidus, space, underscore, vertical_bar,
 };
-use crate::common::{is_sql_identifier, Ident, QuoteStyle};
+use crate::common::{is_sql_identifier, BorrowedIdent, Ident, QuoteStyle};
 
 /// Parse a terminator that ends a SQL statement, returning the remaining
 /// string.
@@ -44,22 +58,213 @@ pub fn statement_terminator(i: &[u8]) -> IResult<&[u8], ()> {
 /// If no possible identifier is found, or the identifier has not a valid quote
 /// style, this method will return an error.
 pub fn ident(i: &[u8]) -> IResult<&[u8], Ident> {
+    map(borrowed_ident, BorrowedIdent::into_owned)(i)
+}
+
+/// Parses a sql identifier as [`ident`], borrowing its value from the input
+/// when it doesn't need to be unescaped.
+///
+/// # Errors
+/// If no possible identifier is found, or the identifier has not a valid quote
+/// style, this method will return an error.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::parsers::borrowed_ident;
+/// let (_, ident) = borrowed_ident(b"\"my name\"").unwrap();
+/// assert_eq!(ident.value(), "my name");
+/// assert!(ident.is_borrowed());
+///
+/// let (_, ident) = borrowed_ident(b"\"my \"\"name\"\"\"").unwrap();
+/// assert_eq!(ident.value(), "my \"name\"");
+/// assert!(!ident.is_borrowed());
+/// ```
+pub fn borrowed_ident(i: &[u8]) -> IResult<&[u8], BorrowedIdent<'_>> {
     let double_quoted_parse = map(
-        delimited(tag("\""), take_while1(is_sql_identifier), tag("\"")),
-        |bytes| Ident::new_quoted(bytes, QuoteStyle::DoubleQuote),
+        delimited(
+            tag("\""),
+            borrowed_delimited_ident_body("\"", "\"\""),
+            tag("\""),
+        ),
+        |body| match body {
+            Cow::Borrowed(bytes) => BorrowedIdent::new(bytes, QuoteStyle::DoubleQuote),
+            Cow::Owned(bytes) => BorrowedIdent::new_owned(bytes, QuoteStyle::DoubleQuote),
+        },
     );
 
     // Here I guarantee that non-quoted identifiers must start with characters
 
     let unquoted = map(
         permutation((peek(alpha1), take_while1(is_sql_identifier))),
-        |(_, bytes)| Ident::new(bytes),
+        |(_, bytes)| BorrowedIdent::new(bytes, QuoteStyle::None),
     );
 
     alt((double_quoted_parse, unquoted))(i)
 }
 
-/// Parses zero or more whitespace characters.
+/// Parses the body of a delimited (quoted) identifier, until the received
+/// closing delimiter, unescaping the received escaped form of the delimiter
+/// (e.g., `""` for double quoted identifiers).
+///
+/// # Errors
+/// If the body is empty, this function call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::parsers::delimited_ident_body;
+/// let mut parser = delimited_ident_body("]", "]]");
+///
+/// assert_eq!(parser(b"my]]name]"), Ok((&b"]"[..], b"my]name".to_vec())));
+/// assert!(parser(b"]").is_err());
+/// ```
+pub fn delimited_ident_body<'a>(
+    close: &'static str,
+    escaped_close: &'static str,
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Vec<u8>> {
+    map(
+        borrowed_delimited_ident_body(close, escaped_close),
+        Cow::into_owned,
+    )
+}
+
+/// Parses the body of a delimited (quoted) identifier, as
+/// [`delimited_ident_body`], borrowing it from the input when it has no
+/// escaped delimiter.
+///
+/// # Errors
+/// If the body is empty, this function call will fail.
+pub fn borrowed_delimited_ident_body<'a>(
+    close: &'static str,
+    escaped_close: &'static str,
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Cow<'a, [u8]>> {
+    map(
+        recognize(many1_count(alt((is_not(close), tag(escaped_close))))),
+        move |body: &'a [u8]| {
+            if body
+                .windows(escaped_close.len())
+                .any(|window| window == escaped_close.as_bytes())
+            {
+                Cow::Owned(unescape(body, close.as_bytes(), escaped_close.as_bytes()))
+            } else {
+                Cow::Borrowed(body)
+            }
+        },
+    )
+}
+
+/// Replaces every escaped delimiter of the body by the delimiter.
+fn unescape(body: &[u8], close: &[u8], escaped_close: &[u8]) -> Vec<u8> {
+    let mut unescaped = Vec::with_capacity(body.len());
+    let mut remaining = body;
+
+    while !remaining.is_empty() {
+        if remaining.starts_with(escaped_close) {
+            unescaped.extend_from_slice(close);
+            remaining = &remaining[escaped_close.len()..];
+        } else {
+            unescaped.push(remaining[0]);
+            remaining = &remaining[1..];
+        }
+    }
+
+    unescaped
+}
+
+/// Returns the leading word (sequence of identifier characters) of the input,
+/// which is empty if the input doesn't start with a word.
+///
+/// It's used to select parsers by keyword, instead of trying each of them.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::parsers::leading_word;
+/// assert_eq!(leading_word(b"VARCHAR(10)"), b"VARCHAR");
+/// assert_eq!(leading_word(b" INT"), b"");
+/// ```
+#[must_use]
+pub fn leading_word(i: &[u8]) -> &[u8] {
+    let len = i
+        .iter()
+        .position(|chr| !is_sql_identifier(*chr))
+        .unwrap_or(i.len());
+
+    &i[..len]
+}
+
+/// Parses a keyword, case-insensitively, returning the matched input.
+///
+/// Keywords only match whole words (sequences of identifier characters), so
+/// `AS` doesn't match the start of `ASC`. The words of multi-word keywords
+/// (e.g., `"DOUBLE PRECISION"`) can be separated by any whitespace.
+///
+/// # Errors
+/// If the input doesn't start with the keyword.
+///
+/// # Examples
+/// ```rust
+/// # use nom::IResult;
+/// # use sql_helper::common::parsers::keyword;
+/// fn parser(i: &[u8]) -> IResult<&[u8], &[u8]> {
+///     keyword("SET NULL")(i)
+/// }
+///
+/// assert_eq!(parser(b"set\n  Null,"), Ok((&b","[..], &b"set\n  Null"[..])));
+/// assert!(parser(b"SETNULL").is_err());
+/// assert!(parser(b"SET NULLS").is_err());
+/// ```
+pub fn keyword<'a, E: ParseError<&'a [u8]>>(
+    keyword: &'static str,
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], &'a [u8], E> {
+    move |i: &'a [u8]| match keyword_len(i, keyword) {
+        Some(len) => Ok(i.take_split(len)),
+        None => Err(nom::Err::Error(E::from_error_kind(i, ErrorKind::Tag))),
+    }
+}
+
+/// Returns the length of the input that matches the keyword as a whole word.
+fn keyword_len(i: &[u8], keyword: &str) -> Option<usize> {
+    let mismatch = keyword
+        .bytes()
+        .zip(i)
+        .position(|(expected, chr)| !chr.eq_ignore_ascii_case(&expected));
+    let len = match mismatch {
+        None if i.len() >= keyword.len() => keyword.len(),
+        // OBS: words are usually separated by a single space, so other
+        // whitespaces and comments are only handled when the input differs
+        // from the keyword after a space.
+        Some(index) => spaced_keyword_len(i, keyword, keyword[..=index].rfind(' ')?)?,
+        _ => return None,
+    };
+
+    (!i.get(len).is_some_and(|chr| is_sql_identifier(*chr))).then_some(len)
+}
+
+/// Returns the length of the input that matches the keyword, starting at the
+/// received index of both, where each keyword space matches any whitespace.
+fn spaced_keyword_len(i: &[u8], keyword: &str, start: usize) -> Option<usize> {
+    let mut len = start;
+
+    for expected in keyword.bytes().skip(start) {
+        if expected == b' ' {
+            let whitespace_len = whitespace_len(&i[len..]);
+            if whitespace_len == 0 {
+                return None;
+            }
+            len += whitespace_len;
+        } else if i
+            .get(len)
+            .is_some_and(|chr| chr.eq_ignore_ascii_case(&expected))
+        {
+            len += 1;
+        } else {
+            return None;
+        }
+    }
+
+    Some(len)
+}
+
+/// Parses zero or more whitespace characters and comments.
 ///
 /// # Errors
 /// This function should not fail, but as the parser can fail, this function let
@@ -76,22 +281,20 @@ pub fn ident(i: &[u8]) -> IResult<&[u8], Ident> {
 /// }
 ///
 /// assert_eq!(parser(" \t\n\r     21c"), Ok(("21c", " \t\n\r     ")));
+/// assert_eq!(parser("-- a\n/* b /* c */ */ 21c"), Ok(("21c", "-- a\n/* b /* c */ */ ")));
 /// assert_eq!(parser("Z21c"), Ok(("Z21c", "")));
 /// assert_eq!(parser(""), Ok(("", "")));
 /// ```
 #[allow(clippy::needless_pass_by_value)]
 pub fn whitespace0<T, E: ParseError<T>>(input: T) -> IResult<T, T, E>
 where
-    T: InputTakeAtPosition,
-    <T as InputTakeAtPosition>::Item: AsChar,
+    T: InputTake + AsBytes,
 {
-    input.split_at_position_complete(|item| {
-        let c = item.as_char();
-        !is_whitespace(c)
-    })
+    let len = whitespace_len(input.as_bytes());
+    Ok(input.take_split(len))
 }
 
-/// Parses one or more whitespace characters.
+/// Parses one or more whitespace characters and comments.
 ///
 /// # Errors
 /// This function will fail if there's no whitespace characters identified.
@@ -122,16 +325,30 @@ where
 #[allow(clippy::needless_pass_by_value)]
 pub fn whitespace1<T, E: ParseError<T>>(input: T) -> IResult<T, T, E>
 where
-    T: InputTakeAtPosition,
-    <T as InputTakeAtPosition>::Item: AsChar,
+    T: InputTake + AsBytes,
 {
-    input.split_at_position1_complete(
-        |item| {
-            let c = item.as_char();
-            !is_whitespace(c)
-        },
-        ErrorKind::MultiSpace,
-    )
+    match whitespace_len(input.as_bytes()) {
+        0 => Err(nom::Err::Error(E::from_error_kind(
+            input,
+            ErrorKind::MultiSpace,
+        ))),
+        len => Ok(input.take_split(len)),
+    }
+}
+
+/// Returns the length of the whitespace characters and comments (`-- ...` and
+/// the possibly nested `/* ... */`) at the start of the input.
+pub(crate) fn whitespace_len(i: &[u8]) -> usize {
+    let mut len = 0;
+
+    loop {
+        match &i[len..] {
+            [chr, ..] if is_whitespace(chr.as_char()) => len += 1,
+            [b'-', b'-', ..] => len = line_comment_end(i, len),
+            [b'/', b'*', ..] => len = block_comment_end(i, len),
+            _ => return len,
+        }
+    }
 }
 
 /// A combinator that takes zero or more leading and trailing whitespaces,
@@ -164,8 +381,7 @@ where
 pub fn delimited_ws0<T, O1, E: ParseError<T>, F>(mut first: F) -> impl FnMut(T) -> IResult<T, O1, E>
 where
     F: Parser<T, O1, E>,
-    T: InputTakeAtPosition,
-    <T as InputTakeAtPosition>::Item: AsChar,
+    T: InputTake + AsBytes,
 {
     move |i: T| {
         let (i, _) = whitespace0(i)?;
@@ -205,8 +421,7 @@ where
 pub fn preceded_ws0<T, O1, E: ParseError<T>, F>(mut parser: F) -> impl FnMut(T) -> IResult<T, O1, E>
 where
     F: Parser<T, O1, E>,
-    T: InputTakeAtPosition,
-    <T as InputTakeAtPosition>::Item: AsChar,
+    T: InputTake + AsBytes,
 {
     move |i: T| {
         let (i, _) = whitespace0(i)?;
@@ -246,8 +461,7 @@ pub fn terminated_ws0<T, O1, E: ParseError<T>, F>(
 ) -> impl FnMut(T) -> IResult<T, O1, E>
 where
     F: Parser<T, O1, E>,
-    T: InputTakeAtPosition,
-    <T as InputTakeAtPosition>::Item: AsChar,
+    T: InputTake + AsBytes,
 {
     move |i: T| {
         let (i, o1) = parser.parse(i)?;
@@ -292,8 +506,7 @@ where
 pub fn delimited_ws1<T, O1, E: ParseError<T>, F>(mut first: F) -> impl FnMut(T) -> IResult<T, O1, E>
 where
     F: Parser<T, O1, E>,
-    T: InputTakeAtPosition,
-    <T as InputTakeAtPosition>::Item: AsChar,
+    T: InputTake + AsBytes,
 {
     move |i: T| {
         let (i, _) = whitespace1(i)?;
@@ -336,8 +549,7 @@ where
 pub fn preceded_ws1<T, O1, E: ParseError<T>, F>(mut parser: F) -> impl FnMut(T) -> IResult<T, O1, E>
 where
     F: Parser<T, O1, E>,
-    T: InputTakeAtPosition,
-    <T as InputTakeAtPosition>::Item: AsChar,
+    T: InputTake + AsBytes,
 {
     move |i: T| {
         let (i, _) = whitespace1(i)?;
@@ -376,8 +588,7 @@ pub fn terminated_ws1<T, O1, E: ParseError<T>, F>(
 ) -> impl FnMut(T) -> IResult<T, O1, E>
 where
     F: Parser<T, O1, E>,
-    T: InputTakeAtPosition,
-    <T as InputTakeAtPosition>::Item: AsChar,
+    T: InputTake + AsBytes,
 {
     move |i: T| {
         let (i, o1) = parser.parse(i)?;
@@ -411,8 +622,7 @@ pub fn paren_delimited<T, O1, E, F>(mut parser: F) -> impl FnMut(T) -> IResult<T
 where
     E: ParseError<T>,
     F: Parser<T, O1, E>,
-    for<'a> T: InputTakeAtPosition + Clone + InputTake + Compare<&'a [u8]>,
-    <T as InputTakeAtPosition>::Item: AsChar,
+    for<'a> T: Clone + InputTake + AsBytes + Compare<&'a [u8]>,
 {
     move |i: T| {
         let (i, _) = terminated_ws0(left_paren)(i)?;
@@ -422,6 +632,536 @@ where
     }
 }
 
+/// Parses an expression without interpreting it, returning its text as a
+/// [`RawExpr`].
+///
+/// The expression ends before the first comma, closing parenthesis or
+/// semicolon that is outside of nested parentheses and quotes, with the
+/// trailing whitespace left in the input. The span is the expression position
+/// in `source`, which must be the input the parsed slice belongs to (e.g., the
+/// whole statement).
+///
+/// # Errors
+/// If the expression is empty, has unbalanced parentheses or quotes, or the
+/// input isn't part of `source`, this function call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::parsers::raw_expr;
+/// # use sql_helper::common::span::Span;
+/// let source = b"CHECK (price > ALL (SELECT 0) OR name <> 'a)')";
+/// let (remaining, raw) = raw_expr(source)(&source[7..]).unwrap();
+///
+/// assert_eq!(raw.text(), "price > ALL (SELECT 0) OR name <> 'a)'");
+/// assert_eq!(raw.span(), Span::new(7, 45));
+/// assert_eq!(remaining, b")");
+/// ```
+pub fn raw_expr<'a>(source: &'a [u8]) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], RawExpr> {
+    move |i: &'a [u8]| {
+        let error = || nom::Err::Error(nom::error::Error::new(i, ErrorKind::TakeUntil));
+
+        let start = (i.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|start| start + i.len() <= source.len())
+            .ok_or_else(error)?;
+        let len = raw_expr_len(i).ok_or_else(error)?;
+        let text = i[..len].trim_ascii_end();
+        if text.is_empty() {
+            return Err(error());
+        }
+
+        let raw = RawExpr::new(
+            String::from_utf8_lossy(text),
+            Span::new(start, start + text.len()),
+        );
+        Ok((&i[text.len()..], raw))
+    }
+}
+
+/// Returns the length of the expression at the start of the input, or `None`
+/// if its parentheses or quotes aren't balanced.
+fn raw_expr_len(i: &[u8]) -> Option<usize> {
+    let mut depth = 0_usize;
+    let mut opt_quote = None;
+    let mut index = 0;
+
+    while let Some(&chr) = i.get(index) {
+        match (opt_quote, chr) {
+            (Some(quote), _) if quote == chr => opt_quote = None,
+            (None, b'\'' | b'"') => opt_quote = Some(chr),
+            (None, b'$') if dollar_quoted_end(i, index) > index + 1 => {
+                let (remaining, _) = dollar_quoted_string(&i[index..]).ok()?;
+                index = i.len() - remaining.len();
+                continue;
+            }
+            (None, b'(') => depth += 1,
+            (None, b')') if depth > 0 => depth -= 1,
+            (None, b',' | b')' | b';') if depth == 0 => return Some(index),
+            _ => {}
+        }
+        index += 1;
+    }
+
+    (depth == 0 && opt_quote.is_none()).then_some(i.len())
+}
+
+/// Parses a string literal with the escape semantics of a dialect [(1)].
+///
+/// Strings with backslash escapes are returned as [`Literal::EscapeString`],
+/// keeping the text as written and its decoded value, and the other ones as
+/// [`Literal::CharacterString`].
+///
+/// # Errors
+/// If the input is not a quoted string, or the closing quote is missing, this
+/// function call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::dialect::StringEscapes;
+/// # use sql_helper::common::expr::Literal;
+/// # use sql_helper::common::parsers::character_string_literal;
+/// let (_, standard) = character_string_literal(StringEscapes::Standard)(br"'C:\'").unwrap();
+/// assert_eq!(standard, Literal::CharacterString(r"C:\".to_string()));
+///
+/// let (_, escaped) = character_string_literal(StringEscapes::EscapeStringPrefix)(br"E'a\'b'").unwrap();
+/// assert_eq!(escaped.opt_string_value(), Some("a'b"));
+/// assert_eq!(escaped.to_string(), r"E'a\'b'");
+/// ```
+///
+/// [(1)]: StringEscapes
+pub fn character_string_literal<'a>(
+    string_escapes: StringEscapes,
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Literal> {
+    move |i: &'a [u8]| {
+        let (i, escape_prefix) = if string_escapes.allows_escape_prefix() {
+            map(opt(tag_no_case("E")), |opt_prefix| opt_prefix.is_some())(i)?
+        } else {
+            (i, false)
+        };
+        let backslash_escapes = string_escapes.has_backslash_escapes(escape_prefix);
+        let (i, text) = delimited(quote, string_text(backslash_escapes), quote)(i)?;
+
+        let text = String::from_utf8_lossy(text);
+        let literal = if escape_prefix || (backslash_escapes && text.contains('\\')) {
+            let value = unescape_backslashes(&text, string_escapes);
+            Literal::EscapeString(EscapeString::new(text, value).with_escape_prefix(escape_prefix))
+        } else {
+            Literal::CharacterString(text.replace("''", "'"))
+        };
+
+        Ok((i, literal))
+    }
+}
+
+/// Parses the text of a string literal, until its closing quote.
+fn string_text<'a>(backslash_escapes: bool) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], &'a [u8]> {
+    move |i: &'a [u8]| {
+        let mut offset = 0;
+
+        while let Some(&chr) = i.get(offset) {
+            match (chr, i.get(offset + 1)) {
+                (b'\\', Some(_)) if backslash_escapes => offset += 2,
+                (b'\'', Some(b'\'')) => offset += 2,
+                (b'\'', _) => return Ok((&i[offset..], &i[..offset])),
+                _ => offset += 1,
+            }
+        }
+
+        Err(nom::Err::Error(nom::error::Error::new(i, ErrorKind::Char)))
+    }
+}
+
+/// Decodes the backslash escapes and the doubled quotes of a string text.
+///
+/// Besides the escapes shared by the dialects (e.g., `\n` or `\'`),
+/// `PostgreSQL` has octal, hexadecimal and unicode escapes, and `MySQL` has
+/// `\Z` and keeps the backslash of `\%` and `\_`, as they are meant for
+/// `LIKE` patterns. Other escaped characters are kept without the backslash.
+pub(crate) fn unescape_backslashes(text: &str, string_escapes: StringEscapes) -> String {
+    let postgres = string_escapes == StringEscapes::EscapeStringPrefix;
+    let bytes = text.as_bytes();
+    let mut value = Vec::with_capacity(bytes.len());
+    let mut offset = 0;
+
+    while let Some(&chr) = bytes.get(offset) {
+        offset += 1;
+        match chr {
+            b'\'' => {
+                if bytes.get(offset) == Some(&b'\'') {
+                    offset += 1;
+                }
+                value.push(b'\'');
+            }
+            b'\\' => {
+                let Some(&escaped) = bytes.get(offset) else {
+                    value.push(b'\\');
+                    break;
+                };
+                offset += 1;
+                match escaped {
+                    b'b' => value.push(b'\x08'),
+                    b'f' => value.push(b'\x0c'),
+                    b'n' => value.push(b'\n'),
+                    b'r' => value.push(b'\r'),
+                    b't' => value.push(b'\t'),
+                    b'0'..=b'7' if postgres => {
+                        let len = digits_len(&bytes[offset - 1..], 3, 8);
+                        value.push(
+                            parse_digits(&bytes[offset - 1..offset - 1 + len], 8).to_le_bytes()[0],
+                        );
+                        offset += len - 1;
+                    }
+                    b'x' if postgres && digits_len(&bytes[offset..], 2, 16) > 0 => {
+                        let len = digits_len(&bytes[offset..], 2, 16);
+                        value.push(parse_digits(&bytes[offset..offset + len], 16).to_le_bytes()[0]);
+                        offset += len;
+                    }
+                    b'u' | b'U' if postgres => {
+                        let len = if escaped == b'u' { 4 } else { 8 };
+                        let opt_chr = (digits_len(&bytes[offset..], len, 16) == len)
+                            .then(|| char::from_u32(parse_digits(&bytes[offset..offset + len], 16)))
+                            .flatten();
+                        let Some(chr) = opt_chr else {
+                            value.push(escaped);
+                            continue;
+                        };
+                        value.extend_from_slice(chr.encode_utf8(&mut [0; 4]).as_bytes());
+                        offset += len;
+                    }
+                    b'0' => value.push(b'\0'),
+                    b'Z' if !postgres => value.push(0x1a),
+                    b'%' | b'_' if !postgres => value.extend_from_slice(&[b'\\', escaped]),
+                    _ => value.push(escaped),
+                }
+            }
+            _ => value.push(chr),
+        }
+    }
+
+    String::from_utf8_lossy(&value).into_owned()
+}
+
+/// Returns how many of the first `max_len` bytes are digits in the radix.
+fn digits_len(bytes: &[u8], max_len: usize, radix: u32) -> usize {
+    bytes
+        .iter()
+        .take(max_len)
+        .take_while(|chr| char::from(**chr).is_digit(radix))
+        .count()
+}
+
+/// Parses digits in the radix, which must have been validated.
+fn parse_digits(digits: &[u8], radix: u32) -> u32 {
+    digits.iter().fold(0, |number, chr| {
+        number * radix + char::from(*chr).to_digit(radix).unwrap_or_default()
+    })
+}
+
+/// Parses a quoted hexadecimal (`X'FF'`) or bit (`B'1010'`) string literal
+/// [(1)].
+///
+/// # Errors
+/// If the input is not a quoted binary string literal, or it has digits that
+/// are invalid for its radix, this function call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::expr::BinaryLiteral;
+/// # use sql_helper::common::parsers::binary_string_literal;
+/// assert_eq!(
+///     binary_string_literal(b"x'1F'"),
+///     Ok((&b""[..], BinaryLiteral::hexadecimal("1F")))
+/// );
+/// assert_eq!(
+///     binary_string_literal(b"B'0101'"),
+///     Ok((&b""[..], BinaryLiteral::bit("0101")))
+/// );
+/// ```
+///
+/// [(1)]: BinaryLiteral
+pub fn binary_string_literal(i: &[u8]) -> IResult<&[u8], BinaryLiteral> {
+    alt((
+        map(
+            preceded(
+                tag_no_case("X"),
+                delimited(quote, take_while(is_hex_digit), quote),
+            ),
+            |digits| BinaryLiteral::hexadecimal(String::from_utf8_lossy(digits)),
+        ),
+        map(
+            preceded(
+                tag_no_case("B"),
+                delimited(quote, take_while(is_bit), quote),
+            ),
+            |digits| BinaryLiteral::bit(String::from_utf8_lossy(digits)),
+        ),
+    ))(i)
+}
+
+/// Parses a hexadecimal (`0xFF`) or bit (`0b1010`) literal prefixed by its
+/// radix, as written in `MySQL` [(1)].
+///
+/// The prefixes are case-sensitive, and the literal can't be followed by
+/// identifier characters (e.g., `0x1G` is not a literal).
+///
+/// # Errors
+/// If the input is not a prefixed binary literal, this function call will
+/// fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::expr::{BinaryLiteral, BinaryNotation};
+/// # use sql_helper::common::parsers::prefixed_binary_literal;
+/// assert_eq!(
+///     prefixed_binary_literal(b"0xFF"),
+///     Ok((
+///         &b""[..],
+///         BinaryLiteral::hexadecimal("FF").with_notation(BinaryNotation::Prefixed)
+///     ))
+/// );
+/// assert!(prefixed_binary_literal(b"0XFF").is_err());
+/// ```
+///
+/// [(1)]: BinaryLiteral
+pub fn prefixed_binary_literal(i: &[u8]) -> IResult<&[u8], BinaryLiteral> {
+    map(
+        terminated(
+            alt((
+                map(preceded(tag("0x"), take_while1(is_hex_digit)), |digits| {
+                    BinaryLiteral::hexadecimal(String::from_utf8_lossy(digits))
+                }),
+                map(preceded(tag("0b"), take_while1(is_bit)), |digits| {
+                    BinaryLiteral::bit(String::from_utf8_lossy(digits))
+                }),
+            )),
+            not(take_while_m_n(1, 1, is_sql_identifier)),
+        ),
+        |binary_literal| binary_literal.with_notation(BinaryNotation::Prefixed),
+    )(i)
+}
+
+const fn is_hex_digit(chr: u8) -> bool {
+    chr.is_ascii_hexdigit()
+}
+
+const fn is_bit(chr: u8) -> bool {
+    matches!(chr, b'0' | b'1')
+}
+
+/// Parses a `PostgreSQL` dollar-quoted string [(1)], keeping its value as
+/// written.
+///
+/// # Errors
+/// If the input doesn't start with a `$[<tag>]$` delimiter, or the delimiter
+/// isn't repeated to close the string, this function call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::expr::DollarQuotedString;
+/// # use sql_helper::common::parsers::dollar_quoted_string;
+/// assert_eq!(
+///     dollar_quoted_string(b"$fn$ SELECT $$; $fn$;"),
+///     Ok((&b";"[..], DollarQuotedString::new(" SELECT $$; ").with_tag("fn")))
+/// );
+/// ```
+///
+/// [(1)]: DollarQuotedString
+pub fn dollar_quoted_string(i: &[u8]) -> IResult<&[u8], DollarQuotedString> {
+    let (body, opt_tag) = delimited(
+        dollar_sign,
+        opt(recognize(pair(
+            take_while_m_n(1, 1, |chr: u8| chr.is_ascii_alphabetic() || chr == b'_'),
+            take_while(|chr: u8| chr.is_ascii_alphanumeric() || chr == b'_'),
+        ))),
+        dollar_sign,
+    )(i)?;
+    let delimiter = &i[..i.len() - body.len()];
+    let (remaining, value) = terminated(take_until(delimiter), tag(delimiter))(body)?;
+
+    let mut dollar_quoted_string = DollarQuotedString::new(String::from_utf8_lossy(value));
+    if let Some(tag) = opt_tag {
+        dollar_quoted_string = dollar_quoted_string.with_tag(String::from_utf8_lossy(tag));
+    }
+
+    Ok((remaining, dollar_quoted_string))
+}
+
+/// Parses a spatial data type [(1)], in any of the dialect syntaxes.
+///
+/// # Errors
+/// If the input is not a spatial type, this function call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::ast::{GeometrySubtype, SpatialType, SpatialTypeModifier};
+/// # use sql_helper::common::parsers::spatial_type;
+/// assert_eq!(
+///     spatial_type(b"geometry(Point, 4326)"),
+///     Ok((
+///         &b""[..],
+///         SpatialType::Geometry(Some(
+///             SpatialTypeModifier::new(GeometrySubtype::Point).with_srid(4326)
+///         ))
+///     ))
+/// );
+/// ```
+///
+/// [(1)]: SpatialType
+pub fn spatial_type(i: &[u8]) -> IResult<&[u8], SpatialType> {
+    alt((
+        map(
+            preceded(
+                keyword("GEOMETRY"),
+                opt(preceded_ws0(spatial_type_modifier)),
+            ),
+            SpatialType::Geometry,
+        ),
+        map(
+            preceded(
+                keyword("GEOGRAPHY"),
+                opt(preceded_ws0(spatial_type_modifier)),
+            ),
+            SpatialType::Geography,
+        ),
+        map(geometry_subtype, SpatialType::Subtype),
+    ))(i)
+}
+
+fn spatial_type_modifier(i: &[u8]) -> IResult<&[u8], SpatialTypeModifier> {
+    map(
+        paren_delimited(pair(
+            geometry_subtype,
+            opt(preceded(delimited_ws0(comma), u32)),
+        )),
+        |(subtype, opt_srid)| {
+            let modifier = SpatialTypeModifier::new(subtype);
+            match opt_srid {
+                Some(srid) => modifier.with_srid(srid),
+                None => modifier,
+            }
+        },
+    )(i)
+}
+
+fn geometry_subtype(i: &[u8]) -> IResult<&[u8], GeometrySubtype> {
+    alt((
+        map(keyword("POINT"), |_| GeometrySubtype::Point),
+        map(keyword("LINESTRING"), |_| GeometrySubtype::LineString),
+        map(keyword("POLYGON"), |_| GeometrySubtype::Polygon),
+        map(keyword("MULTIPOINT"), |_| GeometrySubtype::MultiPoint),
+        map(keyword("MULTILINESTRING"), |_| {
+            GeometrySubtype::MultiLineString
+        }),
+        map(keyword("MULTIPOLYGON"), |_| GeometrySubtype::MultiPolygon),
+        map(keyword("GEOMETRYCOLLECTION"), |_| {
+            GeometrySubtype::GeometryCollection
+        }),
+    ))(i)
+}
+
+/// Names of the options made of more than one word, which can't be told apart
+/// from a single word name followed by its value otherwise.
+const MULTI_WORD_OPTION_NAMES: &[&str] = &[
+    "DEFAULT CHARACTER SET",
+    "DEFAULT CHARSET",
+    "DEFAULT COLLATE",
+    "CHARACTER SET",
+    "PARTITION BY",
+    "SUBPARTITION BY",
+];
+
+/// Parses a dialect specific option [(1)], keeping its value as written.
+///
+/// # Errors
+/// If the input is not an option name followed by a value, this function
+/// call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::ast::SqlOption;
+/// # use sql_helper::common::parsers::sql_option;
+/// let (remaining, option) = sql_option(b"default charset = utf8mb4 ENGINE=InnoDB").unwrap();
+///
+/// assert_eq!(option, SqlOption::new("DEFAULT CHARSET", "utf8mb4").with_equals(true));
+/// assert_eq!(remaining, b" ENGINE=InnoDB");
+/// ```
+///
+/// [(1)]: SqlOption
+pub fn sql_option(i: &[u8]) -> IResult<&[u8], SqlOption> {
+    let (i, (name, opt_equals, value)) = tuple((
+        option_name,
+        opt(preceded(whitespace0, equals_operator)),
+        preceded(whitespace0, option_value),
+    ))(i)?;
+
+    Ok((
+        i,
+        SqlOption::new(name, String::from_utf8_lossy(value)).with_equals(opt_equals.is_some()),
+    ))
+}
+
+fn option_name(i: &[u8]) -> IResult<&[u8], String> {
+    if let Some((remaining, name)) = MULTI_WORD_OPTION_NAMES.iter().find_map(|name| {
+        keyword::<()>(name)(i)
+            .ok()
+            .map(|(remaining, _)| (remaining, name))
+    }) {
+        return Ok((remaining, (*name).to_string()));
+    }
+
+    map(take_while1(is_sql_identifier), |name: &[u8]| {
+        String::from_utf8_lossy(name).to_ascii_uppercase()
+    })(i)
+}
+
+fn option_value(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    recognize(pair(
+        alt((
+            paren_group,
+            recognize(delimited(
+                quote,
+                opt(delimited_ident_body("'", "''")),
+                quote,
+            )),
+            recognize(delimited(
+                double_quote,
+                delimited_ident_body("\"", "\"\""),
+                double_quote,
+            )),
+            take_while1(|chr| is_sql_identifier(chr) || chr == b'.'),
+        )),
+        many0_count(preceded(whitespace0, paren_group)),
+    ))(i)
+}
+
+/// Parses a parenthesized list, as written, ignoring the parentheses that are
+/// quoted.
+fn paren_group(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    let error = || nom::Err::Error(nom::error::Error::new(i, ErrorKind::TakeUntil));
+    if i.first() != Some(&b'(') {
+        return Err(error());
+    }
+
+    let mut depth = 0_usize;
+    let mut opt_quote = None;
+    for (index, chr) in i.iter().enumerate() {
+        match (opt_quote, chr) {
+            (Some(quote), _) if quote == *chr => opt_quote = None,
+            (None, b'\'' | b'"' | b'`') => opt_quote = Some(*chr),
+            (None, b'(') => depth += 1,
+            (None, b')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok((&i[index + 1..], &i[..=index]));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Err(error())
+}
+
 /// Parses a SQL special character.
 ///
 /// # Errors
@@ -467,10 +1207,19 @@ pub fn sql_special_character(i: &[u8]) -> IResult<&[u8], SqlSpecialCharacter> {
 
 #[cfg(test)]
 mod tests {
-    use pretty_assertions::assert_str_eq;
+    use std::borrow::Cow;
+
+    use pretty_assertions::{assert_eq, assert_str_eq};
     use test_case::test_case;
 
-    use crate::common::parsers::sql_special_character;
+    use crate::common::dialect::StringEscapes;
+    use crate::common::parsers::{
+        binary_string_literal, borrowed_delimited_ident_body, borrowed_ident,
+        character_string_literal, dollar_quoted_string, keyword, prefixed_binary_literal, raw_expr,
+        spatial_type, sql_option, sql_special_character,
+    };
+    use crate::common::span::Span;
+    use crate::common::QuoteStyle;
 
     #[test_case(" "; "space")]
     #[test_case(r#"""#; "double quote")]
@@ -505,4 +1254,202 @@ mod tests {
             sql_special_character(input.as_ref()).unwrap().1.to_string()
         );
     }
+
+    #[test_case("name", "name", QuoteStyle::None, true ; "unquoted")]
+    #[test_case("\"my name\"", "my name", QuoteStyle::DoubleQuote, true ; "quoted")]
+    #[test_case("\"a\"\"b\"", "a\"b", QuoteStyle::DoubleQuote, false ; "escaped quote")]
+    fn parse_borrowed_ident(input: &str, value: &str, quote_style: QuoteStyle, borrowed: bool) {
+        let (_, ident) = borrowed_ident(input.as_ref()).unwrap();
+
+        assert_eq!(
+            (value, &quote_style, borrowed),
+            (ident.value(), ident.quote_style(), ident.is_borrowed())
+        );
+        assert_str_eq!(input, ident.to_string());
+    }
+
+    #[test_case(b"ab]x", b"ab", true ; "borrowed")]
+    #[test_case(b"a]]b]x", b"a]b", false ; "unescaped")]
+    fn parse_borrowed_delimited_ident_body(input: &[u8], expected: &[u8], borrowed: bool) {
+        let (remaining, body) = borrowed_delimited_ident_body("]", "]]")(input).unwrap();
+
+        assert_eq!(&b"]x"[..], remaining);
+        assert_eq!(expected, body.as_ref());
+        assert_eq!(borrowed, matches!(body, Cow::Borrowed(_)));
+    }
+
+    #[test_case("DOUBLE PRECISION", "DOUBLE PRECISION)", ")"; "exact")]
+    #[test_case("DOUBLE PRECISION", "double Precision", ""; "mixed case")]
+    #[test_case("DOUBLE PRECISION", "DOUBLE\n\t PRECISION,", ","; "other whitespaces")]
+    #[test_case("AS", "AS(", "("; "followed by special character")]
+    fn parse_keyword(kw: &'static str, input: &str, remaining: &str) {
+        let (i, _) = keyword::<nom::error::Error<_>>(kw)(input.as_bytes()).unwrap();
+
+        assert_eq!(remaining.as_bytes(), i);
+    }
+
+    #[test_case("AS", "ASC"; "prefix of a word")]
+    #[test_case("AS", "A"; "incomplete")]
+    #[test_case("DOUBLE PRECISION", "DOUBLEPRECISION"; "missing whitespace")]
+    #[test_case("DOUBLE PRECISION", "DOUBLE PRECISIONS"; "prefix of last word")]
+    fn parse_keyword_failure(kw: &'static str, input: &str) {
+        assert!(keyword::<nom::error::Error<_>>(kw)(input.as_bytes()).is_err());
+    }
+
+    #[test_case("a + b", "a + b", ""; "whole input")]
+    #[test_case("a + b , c", "a + b", " , c"; "until comma")]
+    #[test_case("f(a, b)) x", "f(a, b)", ") x"; "nested parentheses")]
+    #[test_case("'a;)' || \"b,\";", "'a;)' || \"b,\"", ";"; "quoted terminators")]
+    #[test_case("'it''s')", "'it''s'", ")"; "escaped quote")]
+    #[test_case("f($$a, b)$$)) x", "f($$a, b)$$)", ") x"; "dollar quoted")]
+    #[test_case("$1 + $tag$;$tag$, c", "$1 + $tag$;$tag$", ", c"; "tagged dollar quoted")]
+    fn parse_raw_expr(input: &str, expected: &str, expected_remaining: &str) {
+        let source = format!("CHECK ({input}");
+        let (remaining, raw) = raw_expr(source.as_bytes())(&source.as_bytes()[7..]).unwrap();
+
+        assert_str_eq!(expected, raw.text());
+        assert_eq!(Span::new(7, 7 + expected.len()), raw.span());
+        assert_eq!(expected_remaining.as_bytes(), remaining);
+    }
+
+    #[test_case(""; "empty")]
+    #[test_case(" , a"; "blank")]
+    #[test_case("f(a"; "unclosed parenthesis")]
+    #[test_case("'a"; "unclosed quote")]
+    #[test_case("$$a"; "unclosed dollar quote")]
+    fn parse_raw_expr_failure(input: &str) {
+        assert!(raw_expr(input.as_bytes())(input.as_bytes()).is_err());
+    }
+
+    #[test_case(StringEscapes::Standard, r"'it''s \n'", r"it's \n", r"'it''s \n'"; "standard")]
+    #[test_case(StringEscapes::Backslash, r"'a'", "a", "'a'"; "backslash without escapes")]
+    #[test_case(StringEscapes::Backslash, r"'it\'s ''a'' \\'", r"it's 'a' \", r"'it\'s ''a'' \\'"; "backslash")]
+    #[test_case(StringEscapes::Backslash, r"'\b\f\n\r\t\Z\0\q'", "\x08\x0c\n\r\t\x1a\0q", r"'\b\f\n\r\t\Z\0\q'"; "backslash characters")]
+    #[test_case(StringEscapes::Backslash, r"'50\%\_'", r"50\%\_", r"'50\%\_'"; "backslash pattern characters")]
+    #[test_case(StringEscapes::EscapeStringPrefix, r"'a\'", r"a\", r"'a\'"; "no escape prefix")]
+    #[test_case(StringEscapes::EscapeStringPrefix, "e'a'", "a", "E'a'"; "lowercase escape prefix")]
+    #[test_case(StringEscapes::EscapeStringPrefix, r"E'\101\x42\u00e9\U0001F600\Z'", "ABé😀Z", r"E'\101\x42\u00e9\U0001F600\Z'"; "escape prefix codes")]
+    #[test_case(StringEscapes::EscapeStringPrefix, r"E'\xg\u12'", "xgu12", r"E'\xg\u12'"; "escape prefix invalid codes")]
+    fn parse_character_string_literal(
+        string_escapes: StringEscapes,
+        input: &str,
+        value: &str,
+        expected: &str,
+    ) {
+        let (remaining, parsed) =
+            character_string_literal(string_escapes)(input.as_bytes()).unwrap();
+
+        assert!(remaining.is_empty());
+        assert_str_eq!(value, parsed.opt_string_value().unwrap());
+        assert_str_eq!(expected, parsed.to_string());
+    }
+
+    #[test_case(StringEscapes::Standard, "E'a'"; "standard escape prefix")]
+    #[test_case(StringEscapes::Backslash, r"'a\'"; "escaped closing quote")]
+    #[test_case(StringEscapes::EscapeStringPrefix, r"E'a\'"; "escape prefix escaped closing quote")]
+    #[test_case(StringEscapes::Standard, "'a"; "unclosed")]
+    fn parse_character_string_literal_failure(string_escapes: StringEscapes, input: &str) {
+        assert!(character_string_literal(string_escapes)(input.as_bytes()).is_err());
+    }
+
+    #[test_case("X''", "X''"; "empty hexadecimal")]
+    #[test_case("x'0aF'", "X'0aF'"; "lowercase hexadecimal")]
+    #[test_case("b'01'", "B'01'"; "lowercase bit")]
+    fn parse_binary_string_literal(input: &str, expected: &str) {
+        let (remaining, parsed) = binary_string_literal(input.as_bytes()).unwrap();
+
+        assert!(remaining.is_empty());
+        assert_str_eq!(expected, parsed.to_string());
+    }
+
+    #[test_case("X'0G'"; "invalid hexadecimal digit")]
+    #[test_case("B'012'"; "invalid bit")]
+    #[test_case("X '00'"; "space after prefix")]
+    fn parse_binary_string_literal_failure(input: &str) {
+        assert!(binary_string_literal(input.as_bytes()).is_err());
+    }
+
+    #[test_case("0x1f,", "0x1f", ","; "hexadecimal")]
+    #[test_case("0b101)", "0b101", ")"; "bit")]
+    fn parse_prefixed_binary_literal(input: &str, expected: &str, expected_remaining: &str) {
+        let (remaining, parsed) = prefixed_binary_literal(input.as_bytes()).unwrap();
+
+        assert_str_eq!(expected, parsed.to_string());
+        assert_eq!(expected_remaining.as_bytes(), remaining);
+    }
+
+    #[test_case("0x"; "no digits")]
+    #[test_case("0x1G"; "followed by identifier character")]
+    #[test_case("0b12"; "invalid bit")]
+    #[test_case("0B1"; "uppercase prefix")]
+    fn parse_prefixed_binary_literal_failure(input: &str) {
+        assert!(prefixed_binary_literal(input.as_bytes()).is_err());
+    }
+
+    #[test_case("$$$$", "", None; "empty")]
+    #[test_case("$$it's$$ x", "it's", None; "untagged")]
+    #[test_case("$fn$ $$; $f$ $fn$", " $$; $f$ ", Some("fn"); "tagged")]
+    #[test_case("$_1$a$_1$", "a", Some("_1"); "tag with digit")]
+    fn parse_dollar_quoted_string(input: &str, value: &str, opt_tag: Option<&str>) {
+        let (_, parsed) = dollar_quoted_string(input.as_bytes()).unwrap();
+
+        assert_str_eq!(value, parsed.value());
+        assert_eq!(opt_tag, parsed.opt_tag());
+    }
+
+    #[test_case("$1"; "parameter")]
+    #[test_case("$1$a$1$"; "tag starting with digit")]
+    #[test_case("$fn$a$$"; "unclosed")]
+    #[test_case("'a'"; "quoted string")]
+    fn parse_dollar_quoted_string_failure(input: &str) {
+        assert!(dollar_quoted_string(input.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn parse_raw_expr_outside_source() {
+        assert!(raw_expr(b"a")(b"b").is_err());
+    }
+
+    #[test_case("GEOMETRY", "GEOMETRY")]
+    #[test_case("geography", "GEOGRAPHY"; "lowercase")]
+    #[test_case("GEOMETRY(POINT)", "GEOMETRY(POINT)"; "subtype")]
+    #[test_case("geometry ( MultiPolygon , 4326 )", "GEOMETRY(MULTIPOLYGON, 4326)"; "subtype and srid")]
+    #[test_case("LINESTRING", "LINESTRING"; "subtype only")]
+    #[test_case("GeometryCollection", "GEOMETRYCOLLECTION"; "collection")]
+    fn parse_spatial_type(input: &str, expected: &str) {
+        let (remaining, parsed) = spatial_type(input.as_bytes()).unwrap();
+
+        assert!(remaining.is_empty());
+        assert_str_eq!(expected, parsed.to_string());
+    }
+
+    #[test_case("GEOMETRY(4326)"; "srid without subtype")]
+    #[test_case("POINTS"; "unknown subtype")]
+    fn parse_spatial_type_failure(input: &str) {
+        let parsed = spatial_type(input.as_bytes());
+        assert!(parsed.is_err() || parsed.is_ok_and(|(remaining, _)| !remaining.is_empty()));
+    }
+
+    #[test_case("ENGINE=InnoDB", "ENGINE=InnoDB")]
+    #[test_case("tablespace ts", "TABLESPACE ts"; "without equals")]
+    #[test_case("AUTO_INCREMENT = 10", "AUTO_INCREMENT=10"; "spaced equals")]
+    #[test_case("default  character set utf8mb4", "DEFAULT CHARACTER SET utf8mb4"; "multi word name")]
+    #[test_case("WITH (fillfactor=70, autovacuum_enabled=false)", "WITH (fillfactor=70, autovacuum_enabled=false)"; "parenthesized value")]
+    #[test_case("PARTITION BY RANGE (id) (PARTITION p0 VALUES LESS THAN (10))", "PARTITION BY RANGE (id) (PARTITION p0 VALUES LESS THAN (10))"; "partition by")]
+    #[test_case("COMMENT='a (b'", "COMMENT='a (b'"; "quoted value")]
+    #[test_case("WITH (x = ')')", "WITH (x = ')')"; "quoted parenthesis")]
+    fn parse_sql_option(input: &str, expected: &str) {
+        let (remaining, parsed) = sql_option(input.as_bytes()).unwrap();
+
+        assert!(remaining.is_empty());
+        assert_str_eq!(expected, parsed.to_string());
+    }
+
+    #[test_case("ENGINE="; "missing value")]
+    #[test_case("WITH (fillfactor=70"; "unbalanced parentheses")]
+    #[test_case("=InnoDB"; "missing name")]
+    fn parse_sql_option_failure(input: &str) {
+        let parsed = sql_option(input.as_bytes());
+        assert!(parsed.is_err() || parsed.is_ok_and(|(remaining, _)| !remaining.is_empty()));
+    }
 }
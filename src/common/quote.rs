@@ -0,0 +1,109 @@
+//! Quoting of values as SQL fragments, for SQL generated alongside the AST
+//! builders.
+//!
+//! Values must never be interpolated into SQL as is: a quote in the value
+//! would end the literal or identifier, and the rest of the value would be
+//! parsed as SQL.
+use crate::common::dialect::{Dialect, StringEscapes};
+use crate::common::{Ident, QuoteStyle};
+
+/// Quotes the value as an `ANSI` string literal, doubling its quotes.
+///
+/// Backslashes are kept as is, so the literal is only safe in dialects where
+/// they're regular characters. For the other dialects (e.g., `MySQL`), use
+/// their string escapes [(1)].
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::quote::escape_string_literal;
+/// assert_eq!(escape_string_literal("it's"), "'it''s'");
+/// assert_eq!(escape_string_literal("'; DROP TABLE users; --"), "'''; DROP TABLE users; --'");
+/// ```
+///
+/// [(1)]: StringEscapes::quote
+#[must_use]
+pub fn escape_string_literal(value: &str) -> String {
+    StringEscapes::Standard.quote(value)
+}
+
+/// Quotes the value as an identifier with the dialect quote style, escaping
+/// the closing quotes it contains.
+///
+/// The identifier is always quoted, so it's case-sensitive and can be a
+/// reserved keyword. To quote it only when needed, use
+/// [`Ident::new_auto_quoted`].
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::dialect::AnsiDialect;
+/// # use sql_helper::common::quote::quote_ident;
+/// # use sql_helper::mssql::dialect::MsSqlDialect;
+/// assert_eq!(quote_ident("my \"name\"", &AnsiDialect), "\"my \"\"name\"\"\"");
+/// assert_eq!(quote_ident("a]b", &MsSqlDialect), "[a]]b]");
+/// ```
+#[must_use]
+pub fn quote_ident(value: &str, dialect: &dyn Dialect) -> String {
+    let quote_style = match dialect.identifier_quote_style() {
+        QuoteStyle::None => QuoteStyle::DoubleQuote,
+        quote_style => quote_style,
+    };
+
+    Ident::new_quoted(value.as_bytes(), quote_style).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::ansi::dialect::AnsiDialect;
+    use crate::ansi::parser::common::literal;
+    use crate::ansi::parser::parse_statement;
+    use crate::common::expr::Literal;
+
+    use super::*;
+
+    #[test_case(""; "empty")]
+    #[test_case("it's"; "quote")]
+    #[test_case("''"; "doubled quotes")]
+    #[test_case("'; DROP TABLE users; --"; "injection")]
+    fn string_literal_round_trip(value: &str) {
+        let quoted = escape_string_literal(value);
+
+        assert_eq!(
+            Ok((&b""[..], Literal::CharacterString(value.to_string()))),
+            literal(quoted.as_bytes())
+        );
+    }
+
+    #[test_case("users", "\"users\""; "regular")]
+    #[test_case("table", "\"table\""; "reserved word")]
+    #[test_case("a\"b", "\"a\"\"b\""; "quote")]
+    fn quoted_ident(value: &str, expected: &str) {
+        assert_eq!(expected, quote_ident(value, &AnsiDialect));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn mysql_quoted_ident() {
+        use crate::mysql::dialect::MySqlDialect;
+
+        assert_eq!("`a``b`", quote_ident("a`b", &MySqlDialect));
+    }
+
+    #[test]
+    fn quoted_ident_is_parsed_as_the_value() {
+        let sql = format!(
+            "DROP TABLE {} CASCADE",
+            quote_ident("a\" CASCADE; --", &AnsiDialect)
+        );
+
+        let (remaining, statement) = parse_statement(sql.as_bytes()).unwrap();
+
+        assert!(remaining.is_empty());
+        assert_eq!(
+            "DROP TABLE \"a\"\" CASCADE; --\" CASCADE",
+            statement.to_string()
+        );
+    }
+}
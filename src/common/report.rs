@@ -0,0 +1,201 @@
+//! Rendering of diagnostics with the source text they point to.
+//!
+//! A [`Report`] prints a header (e.g., `error: invalid statement`), the
+//! position of its span, and the source lines covered by the span, underlined
+//! with carets and followed by an optional label:
+//!
+//! ```plaintext
+//! error: invalid statement
+//!  --> script.sql:2:1
+//!   |
+//! 2 | SELECT 1
+//!   | ^^^^^^^^ this statement can't be parsed
+//! ```
+use crate::common::span::Span;
+
+/// Diagnostic that can be rendered against its source text.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Report {
+    /// First line of the report, usually the severity and the message.
+    header: String,
+    /// Span of the source text the report points to.
+    span: Span,
+    /// Label written after the underline.
+    opt_label: Option<String>,
+    /// Name of the source (e.g., a file path), written before the position.
+    opt_origin: Option<String>,
+}
+
+impl Report {
+    #[must_use]
+    pub fn new(header: impl Into<String>, span: Span) -> Self {
+        Self {
+            header: header.into(),
+            span,
+            opt_label: None,
+            opt_origin: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.opt_label = Some(label.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
+        self.opt_origin = Some(origin.into());
+        self
+    }
+
+    #[must_use]
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+
+    #[must_use]
+    pub fn opt_label(&self) -> Option<&str> {
+        self.opt_label.as_deref()
+    }
+
+    #[must_use]
+    pub fn opt_origin(&self) -> Option<&str> {
+        self.opt_origin.as_deref()
+    }
+
+    /// Renders the report against the source text its span points to.
+    ///
+    /// Every line covered by the span is printed and underlined, and the label
+    /// is written after the last underline. Empty spans are underlined with a
+    /// single caret, and spans out of the source bounds are clamped to it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::common::report::Report;
+    /// # use sql_helper::common::span::Span;
+    /// let source = "DROP TABLE a CASCADE;\nDROP TABLE b CASCADE;";
+    /// let report = Report::new("warning: cascading drop", Span::new(35, 42))
+    ///     .with_label("use RESTRICT")
+    ///     .with_origin("drop.sql");
+    ///
+    /// assert_eq!(
+    ///     report.render(source),
+    ///     "warning: cascading drop\n \
+    ///      --> drop.sql:2:14\n  \
+    ///       |\n\
+    ///      2 | DROP TABLE b CASCADE;\n  \
+    ///       |              ^^^^^^^ use RESTRICT\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn render(&self, source: &str) -> String {
+        let start = floor_char_boundary(source, self.span.start());
+        let end = floor_char_boundary(source, self.span.end()).max(start);
+        let (line, column) = Span::new(start, end).start_line_column(source);
+
+        let lines = source.split('\n').collect::<Vec<_>>();
+        let last_line = line + source[start..end].matches('\n').count();
+        let gutter = " ".repeat(last_line.to_string().len());
+
+        let position = match self.opt_origin() {
+            Some(origin) => format!("{origin}:{line}:{column}"),
+            None => format!("{line}:{column}"),
+        };
+        let mut rendered = vec![
+            self.header.clone(),
+            format!("{gutter}--> {position}"),
+            format!("{gutter} |"),
+        ];
+
+        let mut line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+        for (number, text) in (line..=last_line).zip(&lines[line - 1..]) {
+            let text = text.strip_suffix('\r').unwrap_or(text);
+            let from = start.saturating_sub(line_start).min(text.len());
+            let to = end.saturating_sub(line_start).min(text.len());
+
+            let padding = text[..from]
+                .chars()
+                .map(|chr| if chr == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+            let carets = "^".repeat(text[from..to].chars().count().max(1));
+
+            rendered.push(format!("{number:>width$} | {text}", width = gutter.len()));
+            let underline = format!("{gutter} | {padding}{carets}");
+            match (self.opt_label(), number == last_line) {
+                (Some(label), true) => rendered.push(format!("{underline} {label}")),
+                _ => rendered.push(underline),
+            }
+
+            line_start += lines[number - 1].len() + 1;
+        }
+
+        rendered.join("\n") + "\n"
+    }
+}
+
+/// Returns the largest char boundary of the source that is not after the
+/// offset.
+fn floor_char_boundary(source: &str, offset: usize) -> usize {
+    (0..=offset.min(source.len()))
+        .rev()
+        .find(|index| source.is_char_boundary(*index))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(
+        "DROP TABLE a CASCADE",
+        Span::new(13, 20),
+        "error: message\n --> 1:14\n  |\n1 | DROP TABLE a CASCADE\n  |              ^^^^^^^ label\n" ;
+        "single line"
+    )]
+    #[test_case(
+        "CREATE TABLE tb (\n  id INT\n);",
+        Span::new(0, 28),
+        "error: message\n --> 1:1\n  |\n\
+         1 | CREATE TABLE tb (\n  | ^^^^^^^^^^^^^^^^^\n\
+         2 |   id INT\n  | ^^^^^^^^\n\
+         3 | );\n  | ^ label\n" ;
+        "multiple lines"
+    )]
+    #[test_case(
+        "DROP TABLE a CASCADE",
+        Span::new(20, 20),
+        "error: message\n --> 1:21\n  |\n1 | DROP TABLE a CASCADE\n  |                     ^ label\n" ;
+        "empty span at the end"
+    )]
+    #[test_case(
+        "\tDROP TABLE é;\r\nx",
+        Span::new(13, 15),
+        "error: message\n --> 1:13\n  |\n1 | \tDROP TABLE é;\n  | \t           ^^ label\n" ;
+        "tabs and multi-byte characters"
+    )]
+    fn render(source: &str, span: Span, expected: &str) {
+        let report = Report::new("error: message", span).with_label("label");
+
+        assert_str_eq!(expected, report.render(source));
+    }
+
+    #[test]
+    fn render_many_lines_gutter() {
+        let source = "\n".repeat(9) + "DROP TABLE a CASCADE";
+        let report = Report::new("error: message", Span::new(9, 13)).with_origin("a.sql");
+
+        assert_str_eq!(
+            "error: message\n  --> a.sql:10:1\n   |\n10 | DROP TABLE a CASCADE\n   | ^^^^\n",
+            report.render(&source)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+use std::ops::Range;
+
+/// Byte range of a construct in the parsed source text.
+///
+/// The `start` offset is inclusive and the `end` offset is exclusive, so the
+/// span can be used to slice the source it was created from.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    start: usize,
+    end: usize,
+}
+
+impl Span {
+    #[must_use]
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    #[must_use]
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    #[must_use]
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the source text covered by the span, or `None` if the span is
+    /// out of the source bounds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::common::span::Span;
+    /// assert_eq!(Span::new(5, 10).source_text("DROP TABLE tb CASCADE"), Some("TABLE"));
+    /// ```
+    #[must_use]
+    pub fn source_text<'a>(&self, source: &'a str) -> Option<&'a str> {
+        source.get(self.start..self.end)
+    }
+
+    /// Returns the 1-based line and column (in characters) of the span start
+    /// in the source.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::common::span::Span;
+    /// assert_eq!(Span::new(14, 20).start_line_column("DROP TABLE a;\nDROP TABLE b;"), (2, 1));
+    /// ```
+    #[must_use]
+    pub fn start_line_column(&self, source: &str) -> (usize, usize) {
+        let before = source.get(..self.start).unwrap_or(source);
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        (line, column)
+    }
+}
+
+impl From<Range<usize>> for Span {
+    fn from(range: Range<usize>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,463 @@
+use nom::branch::alt;
+use nom::character::complete::{line_ending, space0, space1, u32};
+use nom::combinator::{eof, opt};
+use nom::sequence::{pair, preceded, terminated};
+use nom::IResult;
+
+use crate::common::dialect::StringEscapes;
+use crate::common::is_sql_identifier;
+use crate::common::parsers::keyword;
+use crate::common::span::Span;
+
+/// Separator of statement batches, recognized by the client tools that run
+/// scripts, and not by the databases.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum BatchSeparator {
+    /// Only semicolons terminate statements.
+    #[default]
+    Semicolon,
+    /// `MSSQL` `GO` lines, optionally followed by a repetition count (e.g.,
+    /// `GO 2`), also terminate statements.
+    Go,
+    /// `MySQL` `DELIMITER` lines (e.g., `DELIMITER //`) replace the semicolon
+    /// by another terminator until the next `DELIMITER` line.
+    Delimiter,
+}
+
+/// Splits a script into its statements, at the semicolons that terminate them.
+///
+/// Semicolons inside string literals (`'...'` and `E'...'`, where backslashes
+/// escape quotes), quoted identifiers (`"..."`,
+/// `` `...` `` and `[...]`), comments (`-- ...` and `/* ... */`, which may be
+/// nested) and dollar-quoted bodies (`$$...$$` or `$tag$...$tag$`) don't
+/// terminate statements. Unterminated constructs extend to the end of the
+/// script.
+///
+/// The statements are returned without the terminator and the surrounding
+/// whitespaces, and empty statements are skipped.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::split::split_statements;
+/// let sql = "INSERT INTO tb VALUES ('a;b'); -- c;d\nDROP TABLE tb;;";
+///
+/// assert_eq!(
+///     split_statements(sql),
+///     vec!["INSERT INTO tb VALUES ('a;b')", "-- c;d\nDROP TABLE tb"]
+/// );
+/// ```
+#[must_use]
+pub fn split_statements(sql: &str) -> Vec<&str> {
+    split_statements_with(sql, BatchSeparator::Semicolon)
+}
+
+/// Splits a script into its statements, as [`split_statements`], also
+/// recognizing the batch separator.
+///
+/// Batch separators are only recognized on their own lines, outside of the
+/// constructs that don't terminate statements, and their lines are not part
+/// of any statement.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::split::{split_statements_with, BatchSeparator};
+/// let sql = "DROP TABLE a\nGO\nDROP TABLE b";
+/// assert_eq!(
+///     split_statements_with(sql, BatchSeparator::Go),
+///     vec!["DROP TABLE a", "DROP TABLE b"]
+/// );
+///
+/// let sql = "DELIMITER //\nCALL a(';')//\nDELIMITER ;\nCALL b()";
+/// assert_eq!(
+///     split_statements_with(sql, BatchSeparator::Delimiter),
+///     vec!["CALL a(';')", "CALL b()"]
+/// );
+/// ```
+#[must_use]
+pub fn split_statements_with(sql: &str, separator: BatchSeparator) -> Vec<&str> {
+    split_statements_with_escapes(sql, separator, StringEscapes::Standard)
+}
+
+/// Splits a script into its statements, as [`split_statements_with`], also
+/// recognizing the backslash escapes of the string literals when the escape
+/// semantics have them (e.g., `MySQL` `'it\'s'`).
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::dialect::StringEscapes;
+/// # use sql_helper::common::split::{split_statements_with_escapes, BatchSeparator};
+/// let sql = r"SELECT 'a\';b'; SELECT 1";
+///
+/// assert_eq!(
+///     split_statements_with_escapes(sql, BatchSeparator::Delimiter, StringEscapes::Backslash),
+///     vec![r"SELECT 'a\';b'", "SELECT 1"]
+/// );
+/// assert_eq!(
+///     split_statements_with_escapes(sql, BatchSeparator::Delimiter, StringEscapes::Standard),
+///     vec![r"SELECT 'a\'", "b'; SELECT 1"]
+/// );
+/// ```
+#[must_use]
+pub fn split_statements_with_escapes(
+    sql: &str,
+    separator: BatchSeparator,
+    string_escapes: StringEscapes,
+) -> Vec<&str> {
+    statement_spans(sql, separator, string_escapes)
+        .into_iter()
+        .map(|span| &sql[span.start()..span.end()])
+        .collect()
+}
+
+/// Returns the spans of the statements of a script, as split by
+/// [`split_statements_with_escapes`].
+pub(crate) fn statement_spans(
+    sql: &str,
+    separator: BatchSeparator,
+    string_escapes: StringEscapes,
+) -> Vec<Span> {
+    let bytes = sql.as_bytes();
+    let mut spans = vec![];
+    let mut terminator: &[u8] = b";";
+    let mut start = 0;
+    let mut offset = 0;
+    // OBS: whether there are only whitespaces between the last line break and
+    // the offset, where the separator lines can start.
+    let mut line_start = true;
+
+    while let Some(&chr) = bytes.get(offset) {
+        let opt_separator_line = if line_start {
+            separator_line(sql, offset, separator)
+        } else {
+            None
+        };
+        if let Some((opt_terminator, line_end)) = opt_separator_line {
+            spans.push(Span::new(start, offset));
+            if let Some(new_terminator) = opt_terminator {
+                terminator = new_terminator;
+            }
+            start = line_end;
+            offset = line_end;
+            continue;
+        }
+        if bytes[offset..].starts_with(terminator) {
+            spans.push(Span::new(start, offset));
+            start = offset + terminator.len();
+            offset = start;
+            line_start = false;
+            continue;
+        }
+
+        line_start = chr == b'\n' || (line_start && chr.is_ascii_whitespace());
+        offset = match chr {
+            b'\'' if string_escapes.has_backslash_escapes(false) => {
+                escape_string_end(bytes, offset)
+            }
+            b'\'' => quoted_end(bytes, offset, b'\''),
+            b'E' | b'e' if starts_escape_string(bytes, offset) => {
+                escape_string_end(bytes, offset + 1)
+            }
+            b'"' => quoted_end(bytes, offset, b'"'),
+            b'`' => quoted_end(bytes, offset, b'`'),
+            b'[' => quoted_end(bytes, offset, b']'),
+            b'-' if bytes.get(offset + 1) == Some(&b'-') => line_comment_end(bytes, offset),
+            b'/' if bytes.get(offset + 1) == Some(&b'*') => block_comment_end(bytes, offset),
+            b'$' => dollar_quoted_end(bytes, offset),
+            _ => offset + 1,
+        };
+    }
+    spans.push(Span::new(start, sql.len()));
+
+    spans
+        .into_iter()
+        .filter_map(|span| {
+            let text = &sql[span.start()..span.end()];
+            let trimmed = text.trim();
+            let trimmed_start = span.start() + (text.len() - text.trim_start().len());
+            (!trimmed.is_empty()).then(|| Span::new(trimmed_start, trimmed_start + trimmed.len()))
+        })
+        .collect()
+}
+
+/// Parses a `GO` batch separator command, optionally followed by a repetition
+/// count, up to the end of its line, returning the count.
+///
+/// The command must be at the start of its line, which is checked by the
+/// callers.
+///
+/// # Errors
+/// If the input is not a case-insensitive `GO`, optionally followed by a
+/// count, and then by a line ending or the end of the input, this function
+/// call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::split::go_command;
+/// assert_eq!(go_command(b"GO\nDROP TABLE a"), Ok((&b"DROP TABLE a"[..], None)));
+/// assert_eq!(go_command(b"go 5  "), Ok((&b""[..], Some(5))));
+/// assert!(go_command(b"GOTO a").is_err());
+/// assert!(go_command(b"GO a").is_err());
+/// ```
+pub fn go_command(i: &[u8]) -> IResult<&[u8], Option<u32>> {
+    terminated(
+        preceded(keyword("GO"), opt(preceded(space1, u32))),
+        pair(space0, alt((line_ending, eof))),
+    )(i)
+}
+
+/// Returns the terminator set by the batch separator line starting at the
+/// offset, if any, and the offset where the line ends.
+fn separator_line(
+    sql: &str,
+    offset: usize,
+    separator: BatchSeparator,
+) -> Option<(Option<&[u8]>, usize)> {
+    let line_end = sql[offset..]
+        .find('\n')
+        .map_or(sql.len(), |position| offset + position);
+    let line = sql[offset..line_end].trim_end();
+
+    match separator {
+        BatchSeparator::Semicolon => None,
+        BatchSeparator::Go => go_command(line.as_bytes())
+            .is_ok()
+            .then_some((None, line_end)),
+        BatchSeparator::Delimiter => {
+            let arguments = line
+                .get(..9)
+                .filter(|keyword| keyword.eq_ignore_ascii_case("DELIMITER"))
+                .map(|_| &line[9..])?;
+            if !arguments.starts_with(|chr: char| chr.is_ascii_whitespace()) {
+                return None;
+            }
+            let terminator = arguments.split_ascii_whitespace().next()?;
+            Some((Some(terminator.as_bytes()), line_end))
+        }
+    }
+}
+
+/// Removes the comments of a script, keeping the string literals, quoted
+/// identifiers and dollar-quoted bodies that look like comments (e.g.,
+/// `'-- not a comment'`), which are recognized as in [`split_statements`].
+///
+/// Line comments are removed up to the line break that ends them, which is
+/// kept. Block comments between two tokens are replaced by a space, so the
+/// tokens are not joined (e.g., `a/**/b` becomes `a b`).
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::split::strip_comments;
+/// let sql = "DROP TABLE a; -- old\nCALL f('/* kept */',/* removed */1)";
+///
+/// assert_eq!(strip_comments(sql), "DROP TABLE a; \nCALL f('/* kept */', 1)");
+/// ```
+#[must_use]
+pub fn strip_comments(sql: &str) -> String {
+    let bytes = sql.as_bytes();
+    let mut stripped = String::with_capacity(sql.len());
+    let mut start = 0;
+    let mut offset = 0;
+
+    while let Some(&chr) = bytes.get(offset) {
+        let opt_comment_end = match (chr, bytes.get(offset + 1)) {
+            (b'-', Some(b'-')) => Some(line_comment_end(bytes, offset)),
+            (b'/', Some(b'*')) => Some(block_comment_end(bytes, offset)),
+            _ => None,
+        };
+        if let Some(end) = opt_comment_end {
+            stripped.push_str(&sql[start..offset]);
+
+            let separates_tokens = stripped
+                .chars()
+                .next_back()
+                .is_some_and(|chr| !chr.is_whitespace())
+                && bytes.get(end).is_some_and(|chr| !chr.is_ascii_whitespace());
+            if chr == b'/' && separates_tokens {
+                stripped.push(' ');
+            }
+
+            start = end;
+            offset = end;
+            continue;
+        }
+
+        offset = match chr {
+            b'\'' => quoted_end(bytes, offset, b'\''),
+            b'E' | b'e' if starts_escape_string(bytes, offset) => {
+                escape_string_end(bytes, offset + 1)
+            }
+            b'"' => quoted_end(bytes, offset, b'"'),
+            b'`' => quoted_end(bytes, offset, b'`'),
+            b'[' => quoted_end(bytes, offset, b']'),
+            b'$' => dollar_quoted_end(bytes, offset),
+            _ => offset + 1,
+        };
+    }
+    stripped.push_str(&sql[start..]);
+
+    stripped
+}
+
+/// Returns the offset after the quoted construct starting at the received
+/// offset, where a doubled closing quote is an escaped quote.
+pub(crate) fn quoted_end(bytes: &[u8], start: usize, close: u8) -> usize {
+    let mut offset = start + 1;
+
+    while offset < bytes.len() {
+        if bytes[offset] == close {
+            if bytes.get(offset + 1) != Some(&close) {
+                return offset + 1;
+            }
+            offset += 1;
+        }
+        offset += 1;
+    }
+
+    bytes.len()
+}
+
+/// Returns whether an `E'...'` escape string starts at the offset, instead of
+/// a word that ends with `E` (e.g., `name'...'`).
+pub(crate) fn starts_escape_string(bytes: &[u8], start: usize) -> bool {
+    matches!(bytes[start], b'E' | b'e')
+        && bytes.get(start + 1) == Some(&b'\'')
+        && !start
+            .checked_sub(1)
+            .is_some_and(|previous| is_sql_identifier(bytes[previous]))
+}
+
+/// Returns the offset after the escape string whose opening quote is at the
+/// received offset, where backslashes escape the next character.
+pub(crate) fn escape_string_end(bytes: &[u8], start: usize) -> usize {
+    let mut offset = start + 1;
+
+    while offset < bytes.len() {
+        match bytes[offset] {
+            b'\\' => offset += 1,
+            b'\'' if bytes.get(offset + 1) == Some(&b'\'') => offset += 1,
+            b'\'' => return offset + 1,
+            _ => {}
+        }
+        offset += 1;
+    }
+
+    bytes.len()
+}
+
+/// Returns the offset of the line break that ends the comment.
+pub(crate) fn line_comment_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|chr| *chr == b'\n')
+        .map_or(bytes.len(), |position| start + position)
+}
+
+/// Returns the offset after the (possibly nested) bracketed comment.
+pub(crate) fn block_comment_end(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0_usize;
+    let mut offset = start;
+
+    while offset < bytes.len() {
+        match &bytes[offset..] {
+            [b'/', b'*', ..] => {
+                depth += 1;
+                offset += 2;
+            }
+            [b'*', b'/', ..] => {
+                depth -= 1;
+                offset += 2;
+                if depth == 0 {
+                    return offset;
+                }
+            }
+            _ => offset += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Returns the offset after the dollar-quoted body starting at the received
+/// offset, or the next offset if the dollar sign doesn't open a body (e.g., a
+/// `$1` parameter).
+pub(crate) fn dollar_quoted_end(bytes: &[u8], start: usize) -> usize {
+    let tag_len = bytes[start + 1..]
+        .iter()
+        .position(|chr| !(chr.is_ascii_alphanumeric() || *chr == b'_'))
+        .unwrap_or(bytes.len() - start - 1);
+    let tag_end = start + 1 + tag_len;
+
+    let opens_body =
+        bytes.get(tag_end) == Some(&b'$') && !bytes.get(start + 1).is_some_and(u8::is_ascii_digit);
+    if !opens_body {
+        return start + 1;
+    }
+
+    let delimiter = &bytes[start..=tag_end];
+    let body_start = tag_end + 1;
+    bytes[body_start..]
+        .windows(delimiter.len())
+        .position(|window| window == delimiter)
+        .map_or(bytes.len(), |position| {
+            body_start + position + delimiter.len()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("DROP TABLE a; DROP TABLE b", &["DROP TABLE a", "DROP TABLE b"]; "plain")]
+    #[test_case("SELECT 'a;''b'; SELECT 1", &["SELECT 'a;''b'", "SELECT 1"]; "string literal")]
+    #[test_case(r#"DROP TABLE "a;b"; DROP TABLE c"#, &[r#"DROP TABLE "a;b""#, "DROP TABLE c"]; "double quoted")]
+    #[test_case("DROP TABLE [a;b]; DROP TABLE `c;d`", &["DROP TABLE [a;b]", "DROP TABLE `c;d`"]; "dialect quoted")]
+    #[test_case("SELECT 1 -- a;b\n; SELECT 2", &["SELECT 1 -- a;b", "SELECT 2"]; "line comment")]
+    #[test_case("SELECT /* a; /* b; */ c; */ 1; SELECT 2", &["SELECT /* a; /* b; */ c; */ 1", "SELECT 2"]; "nested block comment")]
+    #[test_case("CREATE FUNCTION f() AS $$ BEGIN; END; $$; SELECT 1", &["CREATE FUNCTION f() AS $$ BEGIN; END; $$", "SELECT 1"]; "dollar quoted")]
+    #[test_case("SELECT $fn$ $$; $fn$; SELECT 1", &["SELECT $fn$ $$; $fn$", "SELECT 1"]; "tagged dollar quoted")]
+    #[test_case(r"SELECT E'a\';b'; SELECT 1", &[r"SELECT E'a\';b'", "SELECT 1"]; "escape string")]
+    #[test_case(r"SELECT name'\'; SELECT 1", &[r"SELECT name'\'", "SELECT 1"]; "word ending with e")]
+    #[test_case("SELECT $1; SELECT $2", &["SELECT $1", "SELECT $2"]; "parameters")]
+    #[test_case("SELECT 'a; SELECT 1", &["SELECT 'a; SELECT 1"]; "unterminated string")]
+    #[test_case(" ;\n; ", &[]; "empty statements")]
+    fn split(input: &str, expected: &[&str]) {
+        assert_eq!(expected.to_vec(), split_statements(input));
+    }
+
+    #[test_case("DROP TABLE a\nGO\nDROP TABLE b", BatchSeparator::Go, &["DROP TABLE a", "DROP TABLE b"]; "go")]
+    #[test_case("DROP TABLE a;\n  go 2  \r\nDROP TABLE b; DROP TABLE c", BatchSeparator::Go, &["DROP TABLE a", "DROP TABLE b", "DROP TABLE c"]; "go with count")]
+    #[test_case("DROP TABLE go\nGOTO\nSELECT 'a\nGO\n'", BatchSeparator::Go, &["DROP TABLE go\nGOTO\nSELECT 'a\nGO\n'"]; "not go lines")]
+    #[test_case("DROP TABLE a\nGO", BatchSeparator::Semicolon, &["DROP TABLE a\nGO"]; "go without separator")]
+    #[test_case("DELIMITER $$\nCALL a(); CALL b()$$\ndelimiter ;\nCALL c();", BatchSeparator::Delimiter, &["CALL a(); CALL b()", "CALL c()"]; "delimiter")]
+    #[test_case("DELIMITER //\nCALL a('//') -- //\n//", BatchSeparator::Delimiter, &["CALL a('//') -- //"]; "quoted delimiter")]
+    #[test_case("CALL a()\nDELIMITER //\nCALL b()", BatchSeparator::Delimiter, &["CALL a()", "CALL b()"]; "unterminated before delimiter")]
+    fn split_with(input: &str, separator: BatchSeparator, expected: &[&str]) {
+        assert_eq!(expected.to_vec(), split_statements_with(input, separator));
+    }
+
+    #[test_case(r"SELECT 'a\';b'; SELECT 1", StringEscapes::Backslash, &[r"SELECT 'a\';b'", "SELECT 1"]; "backslash escaped quote")]
+    #[test_case(r"SELECT 'a\\'; SELECT 1", StringEscapes::Backslash, &[r"SELECT 'a\\'", "SELECT 1"]; "escaped backslash")]
+    #[test_case(r"SELECT 'a\'; SELECT 1", StringEscapes::Standard, &[r"SELECT 'a\'", "SELECT 1"]; "standard backslash")]
+    #[test_case(r"SELECT 'a\'; SELECT 1", StringEscapes::EscapeStringPrefix, &[r"SELECT 'a\'", "SELECT 1"]; "backslash without prefix")]
+    fn split_with_escapes(input: &str, string_escapes: StringEscapes, expected: &[&str]) {
+        assert_eq!(
+            expected.to_vec(),
+            split_statements_with_escapes(input, BatchSeparator::Delimiter, string_escapes)
+        );
+    }
+
+    #[test_case("DROP TABLE a -- b\n", "DROP TABLE a \n"; "line comment")]
+    #[test_case("DROP TABLE a -- b", "DROP TABLE a "; "line comment at the end")]
+    #[test_case("DROP /* a /* b */ */ TABLE", "DROP  TABLE"; "nested block comment")]
+    #[test_case("CALL f(1,/**/2)", "CALL f(1, 2)"; "block comment between tokens")]
+    #[test_case("/* a */DROP TABLE a", "DROP TABLE a"; "block comment at the start")]
+    #[test_case("SELECT '--a', \"/*b*/\", [--c]", "SELECT '--a', \"/*b*/\", [--c]"; "quoted")]
+    #[test_case("SELECT $$ -- a $$, E'\\' -- b'", "SELECT $$ -- a $$, E'\\' -- b'"; "dollar quoted and escape string")]
+    #[test_case("SELECT 1 - -1", "SELECT 1 - -1"; "minus signs")]
+    fn strip(input: &str, expected: &str) {
+        assert_eq!(expected, strip_comments(input));
+    }
+}
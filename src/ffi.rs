@@ -0,0 +1,203 @@
+//! C-compatible interface.
+//!
+//! Strings are received and returned as NUL-terminated UTF-8 strings. Strings
+//! returned by this module are owned by the caller, and must be released with
+//! [`sql_helper_free_string`].
+//!
+//! The C header is generated with [cbindgen] into `include/sql_helper.h`:
+//!
+//! ```plaintext
+//! cbindgen --config cbindgen.toml --output include/sql_helper.h
+//! ```
+//!
+//! [cbindgen]: https://github.com/mozilla/cbindgen
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+use crate::ansi::parser::parse_script;
+
+/// Result of a validation [(1)].
+///
+/// [(1)]: sql_helper_validate
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SqlHelperStatus {
+    /// All the statements are valid.
+    Ok = 0,
+    /// A statement can't be parsed.
+    InvalidStatement = 1,
+    /// The input is a null pointer or is not valid UTF-8.
+    InvalidInput = 2,
+}
+
+/// Parses the script, returning its statements as a JSON array, where each
+/// statement is in the versioned JSON format [(1)].
+///
+/// Returns a null pointer if the input is invalid or any statement can't be
+/// parsed.
+///
+/// # Safety
+/// `sql` must be null or point to a NUL-terminated string that is valid for
+/// reads during the call.
+///
+/// [(1)]: crate::ansi::json
+#[no_mangle]
+pub unsafe extern "C" fn sql_helper_parse_json(sql: *const c_char) -> *mut c_char {
+    let Some(sql) = (unsafe { input(sql) }) else {
+        return ptr::null_mut();
+    };
+    let Ok(statements) = parse_script(sql) else {
+        return ptr::null_mut();
+    };
+
+    let statements = statements
+        .iter()
+        .map(|(statement, _)| statement.to_json())
+        .collect::<Vec<_>>();
+
+    CString::new(format!("[{}]", statements.join(","))).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Validates the script.
+///
+/// If a statement can't be parsed, its 1-based line and column are written to
+/// `line` and `column`, unless they are null.
+///
+/// # Safety
+/// `sql` must be null or point to a NUL-terminated string that is valid for
+/// reads during the call. `line` and `column` must be null or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn sql_helper_validate(
+    sql: *const c_char,
+    line: *mut usize,
+    column: *mut usize,
+) -> SqlHelperStatus {
+    let Some(sql) = (unsafe { input(sql) }) else {
+        return SqlHelperStatus::InvalidInput;
+    };
+
+    match parse_script(sql) {
+        Ok(_) => SqlHelperStatus::Ok,
+        Err(err) => {
+            let (error_line, error_column) = err.span().start_line_column(sql);
+            // SAFETY: the caller guarantees that non-null pointers are valid.
+            unsafe {
+                if !line.is_null() {
+                    *line = error_line;
+                }
+                if !column.is_null() {
+                    *column = error_column;
+                }
+            }
+
+            SqlHelperStatus::InvalidStatement
+        }
+    }
+}
+
+/// Releases a string returned by this library. Null pointers are ignored.
+///
+/// # Safety
+/// `string` must be null or a pointer returned by this library that was not
+/// released yet.
+#[no_mangle]
+pub unsafe extern "C" fn sql_helper_free_string(string: *mut c_char) {
+    if !string.is_null() {
+        // SAFETY: the caller guarantees that the string was returned by
+        // `CString::into_raw` and is released only once.
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// Reads the received input as UTF-8, if it's not null.
+///
+/// # Safety
+/// `sql` must be null or point to a NUL-terminated string that is valid for
+/// reads while the returned string is used.
+unsafe fn input<'a>(sql: *const c_char) -> Option<&'a str> {
+    if sql.is_null() {
+        return None;
+    }
+
+    // SAFETY: the caller guarantees that the pointer is a valid C string.
+    unsafe { CStr::from_ptr(sql) }.to_str().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::ansi::json::WIRE_FORMAT_VERSION;
+    use crate::ansi::Statement;
+
+    fn parse_json(sql: &CStr) -> Option<String> {
+        unsafe {
+            let json = sql_helper_parse_json(sql.as_ptr());
+            if json.is_null() {
+                return None;
+            }
+
+            let value = CStr::from_ptr(json).to_str().unwrap().to_string();
+            sql_helper_free_string(json);
+            Some(value)
+        }
+    }
+
+    #[test]
+    fn parse_to_json() {
+        let json = parse_json(c"DROP TABLE tb CASCADE; DROP SCHEMA sch RESTRICT").unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value[0]["version"], WIRE_FORMAT_VERSION);
+        assert_eq!(
+            value[0]["statement"]["DropTable"]["drop_behavior"],
+            "Cascade"
+        );
+        assert_eq!(
+            Statement::from_json(&value[1].to_string()).unwrap(),
+            parse_script("DROP SCHEMA sch RESTRICT").unwrap()[0].0
+        );
+    }
+
+    #[test]
+    fn parse_invalid_statement() {
+        assert_eq!(None, parse_json(c"SELECT 1"));
+        assert!(unsafe { sql_helper_parse_json(ptr::null()) }.is_null());
+    }
+
+    #[test]
+    fn validate() {
+        let (mut line, mut column) = (0, 0);
+
+        let status = unsafe {
+            sql_helper_validate(
+                c"DROP TABLE a CASCADE;".as_ptr(),
+                &raw mut line,
+                &raw mut column,
+            )
+        };
+        assert_eq!((SqlHelperStatus::Ok, 0, 0), (status, line, column));
+
+        let status = unsafe {
+            sql_helper_validate(
+                c"DROP TABLE a CASCADE;\n  SELECT 1".as_ptr(),
+                &raw mut line,
+                &raw mut column,
+            )
+        };
+        assert_eq!(
+            (SqlHelperStatus::InvalidStatement, 2, 3),
+            (status, line, column)
+        );
+
+        let status = unsafe { sql_helper_validate(ptr::null(), ptr::null_mut(), ptr::null_mut()) };
+        assert_eq!(SqlHelperStatus::InvalidInput, status);
+    }
+
+    #[test]
+    fn free_null_string() {
+        unsafe { sql_helper_free_string(ptr::null_mut()) };
+    }
+}
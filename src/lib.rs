@@ -11,3 +11,15 @@ extern crate core;
 
 pub mod ansi;
 pub mod common;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "mssql")]
+pub mod mssql;
+#[cfg(feature = "mysql")]
+pub mod mysql;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "transpile")]
+pub mod transpile;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,64 @@
+use std::fmt;
+
+use crate::ansi::visitor::{DialectIdents, VisitorMut};
+use crate::common::dialect::Dialect;
+use crate::mssql::ast::create_table::CreateTable;
+use crate::mssql::ast::drop_table::DropTable;
+
+pub mod ast;
+pub mod dialect;
+pub mod keywords;
+pub mod parser;
+
+/// `T-SQL` (SQL Server) statements [(1)].
+///
+/// [(1)]: https://learn.microsoft.com/en-us/sql/t-sql/statements/statements
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Statement {
+    /// `CREATE TABLE` statement.
+    CreateTable(CreateTable),
+    /// `DROP TABLE` statement.
+    DropTable(DropTable),
+}
+
+impl Statement {
+    /// Serializes the statement using the identifier rules of the received
+    /// dialect, instead of the quote styles stored in the AST.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::dialect::AnsiDialect;
+    /// # use sql_helper::mssql::parser::parse_statement;
+    /// let (_, statement) = parse_statement(b"DROP TABLE [dbo].[users]").unwrap();
+    ///
+    /// assert_eq!(statement.to_sql(&AnsiDialect), "DROP TABLE \"dbo\".\"users\"");
+    /// ```
+    #[must_use]
+    pub fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let mut statement = self.clone();
+        let mut visitor = DialectIdents::new(dialect);
+
+        match &mut statement {
+            Self::CreateTable(create_table) => {
+                visitor.visit_object_name(create_table.table_name_mut());
+                for column_definition in create_table.column_definitions_mut() {
+                    visitor.visit_ident(column_definition.column_name_mut());
+                }
+            }
+            Self::DropTable(drop_table) => visitor.visit_object_name(drop_table.table_name_mut()),
+        }
+
+        statement.to_string()
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CreateTable(create_table) => write!(f, "{create_table}")?,
+            Self::DropTable(drop_table) => write!(f, "{drop_table}")?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_table;
+pub mod data_types;
+pub mod drop_table;
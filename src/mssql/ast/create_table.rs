@@ -0,0 +1,172 @@
+use std::fmt;
+
+use crate::ansi::ast::common::ObjectName;
+use crate::common::{display_comma_separated, Ident};
+use crate::mssql::ast::data_types::DataType;
+
+/// `T-SQL` create table statement [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+/// CREATE TABLE <table name> (<column definition> [{, <column definition>}...])
+/// ```
+///
+/// [(1)]: https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateTable {
+    /// `<table name>`.
+    table_name: ObjectName,
+    /// `<column definition>` list.
+    column_definitions: Vec<ColumnDefinition>,
+}
+
+/// `T-SQL` column definition.
+///
+/// # Supported syntax
+/// ```plaintext
+/// <column name> <data type> [<identity>]
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnDefinition {
+    /// `<column name>`.
+    column_name: Ident,
+    /// `<data type>`.
+    data_type: DataType,
+    /// `[<identity>]`.
+    opt_identity: Option<Identity>,
+}
+
+/// Identity column property.
+///
+/// # Supported syntax
+/// ```plaintext
+/// IDENTITY [(<seed>, <increment>)]
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Identity {
+    /// `[(<seed>, <increment>)]`.
+    opt_seed_and_increment: Option<(i64, i64)>,
+}
+
+impl CreateTable {
+    #[must_use]
+    pub fn new(table_name: ObjectName, column_definitions: Vec<ColumnDefinition>) -> Self {
+        Self {
+            table_name,
+            column_definitions,
+        }
+    }
+
+    #[must_use]
+    pub const fn table_name(&self) -> &ObjectName {
+        &self.table_name
+    }
+
+    pub fn table_name_mut(&mut self) -> &mut ObjectName {
+        &mut self.table_name
+    }
+
+    #[must_use]
+    pub fn column_definitions(&self) -> &[ColumnDefinition] {
+        &self.column_definitions
+    }
+
+    pub fn column_definitions_mut(&mut self) -> &mut Vec<ColumnDefinition> {
+        &mut self.column_definitions
+    }
+}
+
+impl fmt::Display for CreateTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CREATE TABLE {} ({})",
+            self.table_name(),
+            display_comma_separated(self.column_definitions())
+        )?;
+        Ok(())
+    }
+}
+
+impl ColumnDefinition {
+    #[must_use]
+    pub fn new(column_name: impl Into<Ident>, data_type: DataType) -> Self {
+        Self {
+            column_name: column_name.into(),
+            data_type,
+            opt_identity: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_identity(mut self, identity: Identity) -> Self {
+        self.opt_identity = Some(identity);
+        self
+    }
+
+    #[must_use]
+    pub const fn column_name(&self) -> &Ident {
+        &self.column_name
+    }
+
+    pub fn column_name_mut(&mut self) -> &mut Ident {
+        &mut self.column_name
+    }
+
+    #[must_use]
+    pub const fn data_type(&self) -> DataType {
+        self.data_type
+    }
+
+    #[must_use]
+    pub const fn opt_identity(&self) -> Option<Identity> {
+        self.opt_identity
+    }
+}
+
+impl fmt::Display for ColumnDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.column_name(), self.data_type())?;
+
+        if let Some(identity) = self.opt_identity() {
+            write!(f, " {identity}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Identity {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            opt_seed_and_increment: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_seed_and_increment(mut self, seed: i64, increment: i64) -> Self {
+        self.opt_seed_and_increment = Some((seed, increment));
+        self
+    }
+
+    #[must_use]
+    pub const fn opt_seed_and_increment(&self) -> Option<(i64, i64)> {
+        self.opt_seed_and_increment
+    }
+}
+
+impl fmt::Display for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "IDENTITY")?;
+
+        if let Some((seed, increment)) = self.opt_seed_and_increment() {
+            write!(f, "({seed}, {increment})")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+use crate::ansi::ast::data_types::DataType as AnsiDataType;
+use crate::common::ast::SpatialType;
+
+/// `T-SQL` data types [(1)].
+///
+/// Types that have the same syntax in `ANSI` and `T-SQL` are kept as their
+/// `ANSI` representation.
+///
+/// [(1)]: https://learn.microsoft.com/en-us/sql/t-sql/data-types/data-types-transact-sql
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataType {
+    /// Data type shared with `ANSI`.
+    Ansi(AnsiDataType),
+    /// `NCHAR[(<length>)]`
+    NChar(Option<u32>),
+    /// `NVARCHAR[(<length> | MAX)]`
+    NVarchar(Option<Length>),
+    /// `VARCHAR[(<length> | MAX)]`
+    Varchar(Option<Length>),
+    /// `VARBINARY[(<length> | MAX)]`
+    Varbinary(Option<Length>),
+    /// `BIT`
+    Bit,
+    /// `TINYINT`
+    Tinyint,
+    /// `DATETIME`
+    Datetime,
+    /// `DATETIME2[(<fractional seconds precision>)]`
+    Datetime2(Option<u32>),
+    /// `GEOMETRY | GEOGRAPHY`
+    Spatial(SpatialType),
+    /// `UNIQUEIDENTIFIER`, a 16-byte GUID.
+    Uniqueidentifier,
+}
+
+/// Length of variable length types.
+///
+/// # Supported syntax
+/// ```plaintext
+/// <length>
+/// | MAX
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Length {
+    /// `<length>`
+    Fixed(u32),
+    /// `MAX`
+    Max,
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ansi(data_type) => write!(f, "{data_type}")?,
+            Self::NChar(opt_len) => {
+                write!(f, "NCHAR")?;
+
+                if let Some(len) = opt_len {
+                    write!(f, "({len})")?;
+                }
+            }
+            Self::NVarchar(opt_len) => {
+                write!(f, "NVARCHAR")?;
+
+                if let Some(len) = opt_len {
+                    write!(f, "({len})")?;
+                }
+            }
+            Self::Varchar(opt_len) => {
+                write!(f, "VARCHAR")?;
+
+                if let Some(len) = opt_len {
+                    write!(f, "({len})")?;
+                }
+            }
+            Self::Varbinary(opt_len) => {
+                write!(f, "VARBINARY")?;
+
+                if let Some(len) = opt_len {
+                    write!(f, "({len})")?;
+                }
+            }
+            Self::Bit => write!(f, "BIT")?,
+            Self::Tinyint => write!(f, "TINYINT")?,
+            Self::Datetime => write!(f, "DATETIME")?,
+            Self::Datetime2(opt_precision) => {
+                write!(f, "DATETIME2")?;
+
+                if let Some(precision) = opt_precision {
+                    write!(f, "({precision})")?;
+                }
+            }
+            Self::Spatial(spatial_type) => write!(f, "{spatial_type}")?,
+            Self::Uniqueidentifier => write!(f, "UNIQUEIDENTIFIER")?,
+        }
+
+        Ok(())
+    }
+}
+
+impl From<AnsiDataType> for DataType {
+    fn from(data_type: AnsiDataType) -> Self {
+        Self::Ansi(data_type)
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed(length) => write!(f, "{length}")?,
+            Self::Max => write!(f, "MAX")?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt;
+
+use crate::ansi::ast::common::ObjectName;
+
+/// `T-SQL` drop table statement [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+/// DROP TABLE [IF EXISTS] <table name>
+/// ```
+///
+/// [(1)]: https://learn.microsoft.com/en-us/sql/t-sql/statements/drop-table-transact-sql
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropTable {
+    /// `[IF EXISTS]`.
+    if_exists: bool,
+    /// `<table name>`.
+    table_name: ObjectName,
+}
+
+impl DropTable {
+    #[must_use]
+    pub const fn new(table_name: ObjectName) -> Self {
+        Self {
+            if_exists: false,
+            table_name,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_if_exists(mut self, if_exists: bool) -> Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    #[must_use]
+    pub const fn if_exists(&self) -> bool {
+        self.if_exists
+    }
+
+    #[must_use]
+    pub const fn table_name(&self) -> &ObjectName {
+        &self.table_name
+    }
+
+    pub fn table_name_mut(&mut self) -> &mut ObjectName {
+        &mut self.table_name
+    }
+}
+
+impl fmt::Display for DropTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DROP TABLE ")?;
+
+        if self.if_exists() {
+            write!(f, "IF EXISTS ")?;
+        }
+
+        write!(f, "{}", self.table_name())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::common::dialect::{Dialect, NumericLimits};
+use crate::common::QuoteStyle;
+use crate::mssql::keywords::is_reserved_word;
+
+/// `T-SQL` (SQL Server) dialect.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct MsSqlDialect;
+
+impl Dialect for MsSqlDialect {
+    fn is_reserved_keyword(&self, word: &str) -> bool {
+        is_reserved_word(word)
+    }
+
+    fn is_identifier_start(&self, chr: char) -> bool {
+        chr.is_ascii_alphabetic() || matches!(chr, '_' | '@' | '#')
+    }
+
+    fn is_identifier_part(&self, chr: char) -> bool {
+        chr.is_ascii_alphanumeric() || matches!(chr, '_' | '@' | '#' | '$')
+    }
+
+    /// Linked server names have four parts (`server.database.schema.object`).
+    fn max_name_parts(&self) -> usize {
+        4
+    }
+
+    fn identifier_quote_style(&self) -> QuoteStyle {
+        QuoteStyle::Bracket
+    }
+
+    /// `DECIMAL(38)` and `DATETIME2(7)` are the largest precisions.
+    fn numeric_limits(&self) -> NumericLimits {
+        NumericLimits::default()
+            .with_max_decimal_precision(38)
+            .with_max_temporal_precision(7)
+    }
+}
@@ -0,0 +1,79 @@
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::multi::{many0, separated_list1};
+use nom::sequence::{delimited, preceded};
+use nom::IResult;
+
+use crate::common::parsers::whitespace0;
+use crate::common::split::go_command;
+use crate::mssql::parser::create_table::create_table;
+use crate::mssql::parser::drop_table::drop_table;
+use crate::mssql::Statement;
+
+pub mod common;
+pub mod create_table;
+pub mod data_types;
+pub mod drop_table;
+
+/// Parses a `T-SQL` `Statement` [(1)] from the given input.
+///
+/// # Errors
+/// This method will raise an error if the input is malformed, or if the
+/// statement is not supported.
+///
+/// [(1)]: crate::mssql::Statement
+pub fn parse_statement(i: &[u8]) -> IResult<&[u8], Statement> {
+    alt((
+        map(create_table, Statement::CreateTable),
+        map(drop_table, Statement::DropTable),
+    ))(i)
+}
+
+/// Parses a `T-SQL` script, made of batches of statements separated by `GO`
+/// lines.
+///
+/// A trailing `GO` does not start a new batch.
+///
+/// # Errors
+/// This method will raise an error if any of the statements is malformed or not
+/// supported.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::mssql::parser::parse_batches;
+/// let script = b"CREATE TABLE a (id INT)\nGO\nDROP TABLE a;\nDROP TABLE b\nGO\n";
+/// let (_, batches) = parse_batches(script).unwrap();
+///
+/// assert_eq!(batches.len(), 2);
+/// assert_eq!(batches[0].len(), 1);
+/// assert_eq!(batches[1].len(), 2);
+/// ```
+pub fn parse_batches(i: &[u8]) -> IResult<&[u8], Vec<Vec<Statement>>> {
+    let (i, mut batches) = preceded(
+        whitespace0,
+        separated_list1(batch_separator, many0(parse_statement)),
+    )(i)?;
+
+    if batches.len() > 1 && batches.last().is_some_and(Vec::is_empty) {
+        batches.pop();
+    }
+
+    Ok((i, batches))
+}
+
+/// Parses a `GO` batch separator [(1)], which must be alone in its line.
+///
+/// The repetition count (e.g., `GO 2`) is accepted but ignored, as each batch
+/// is parsed once.
+///
+/// # Errors
+/// If the input is not a case-insensitive `GO`, optionally followed by a
+/// count, and then by a line ending or the end of the input, this function
+/// call will fail.
+///
+/// [(1)]: go_command
+pub fn batch_separator(i: &[u8]) -> IResult<&[u8], ()> {
+    let (i, _) = delimited(whitespace0, go_command, whitespace0)(i)?;
+
+    Ok((i, ()))
+}
@@ -0,0 +1,107 @@
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::{map, opt, verify};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair};
+use nom::IResult;
+
+use crate::ansi::ast::common::ObjectName;
+use crate::common::dialect::Dialect;
+use crate::common::parsers::{delimited_ident_body, delimited_ws0, whitespace0};
+use crate::common::tokens::{period, semicolon};
+use crate::common::{Ident, QuoteStyle};
+use crate::mssql::dialect::MsSqlDialect;
+
+/// Parses a `T-SQL` identifier, which can be unquoted, double quoted or
+/// delimited by brackets.
+///
+/// # Errors
+/// If no possible identifier is found, this function call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::{Ident, QuoteStyle};
+/// # use sql_helper::mssql::parser::common::ident;
+/// assert_eq!(
+///     ident(b"[my name]"),
+///     Ok((&b""[..], Ident::new_quoted(b"my name", QuoteStyle::Bracket)))
+/// );
+/// assert_eq!(ident(b"name"), Ok((&b""[..], Ident::new(b"name"))));
+/// ```
+pub fn ident(i: &[u8]) -> IResult<&[u8], Ident> {
+    alt((
+        map(
+            delimited(tag("["), delimited_ident_body("]", "]]"), tag("]")),
+            |bytes| Ident::new_quoted(&bytes, QuoteStyle::Bracket),
+        ),
+        crate::common::parsers::ident,
+    ))(i)
+}
+
+/// Parses a `T-SQL` table name, with up to four parts
+/// (`[<server>.][<database>.][<schema>.]<table>`) [(1)].
+///
+/// # Errors
+/// If the table name has too many qualifications or invalid identifiers, this
+/// function call will fail.
+///
+/// [(1)]: MsSqlDialect::max_name_parts
+pub fn table_name(i: &[u8]) -> IResult<&[u8], ObjectName> {
+    map(
+        verify(
+            separated_list1(delimited_ws0(period), ident),
+            |parts: &Vec<Ident>| parts.len() <= MsSqlDialect.max_name_parts(),
+        ),
+        |mut parts| {
+            let name = parts.pop().unwrap_or_else(|| unreachable!());
+            ObjectName::new(name).with_qualifiers(parts)
+        },
+    )(i)
+}
+
+/// Parses the end of a `T-SQL` statement, which is an optional semicolon
+/// surrounded by whitespaces.
+///
+/// # Errors
+/// This function should not fail, but as the parser can fail, this function let
+/// the upstream decide what to do with this possible failure.
+pub fn statement_terminator(i: &[u8]) -> IResult<&[u8], ()> {
+    let (i, _) = pair(whitespace0, opt(pair(semicolon, whitespace0)))(i)?;
+
+    Ok((i, ()))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("tb")]
+    #[test_case("[tb]" ; "bracket quoted")]
+    #[test_case("[my table]" ; "bracket quoted with space")]
+    #[test_case("\"tb\"" ; "double quoted")]
+    #[test_case("dbo.tb")]
+    #[test_case("[dbo].[tb]" ; "bracket quoted schema")]
+    #[test_case("[db].[dbo].[tb]" ; "bracket quoted database")]
+    #[test_case("srv.[db].dbo.tb" ; "linked server")]
+    #[test_case("[a]]b]" ; "bracket quoted escaped")]
+    fn parse_table_name(input: &str) {
+        let (remaining, parsed) = table_name(input.as_ref()).unwrap();
+        assert!(remaining.is_empty());
+        assert_str_eq!(input, parsed.to_string());
+    }
+
+    #[test]
+    fn parse_table_name_spaced_periods() {
+        let (remaining, parsed) = table_name(b"[db] . dbo .tb").unwrap();
+        assert!(remaining.is_empty());
+        assert_str_eq!("[db].dbo.tb", parsed.to_string());
+    }
+
+    #[test]
+    fn parse_table_name_too_many_parts() {
+        assert!(table_name(b"a.b.c.d.e").is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use nom::character::complete::i64;
+use nom::combinator::{map, opt};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, preceded, separated_pair, terminated, tuple};
+use nom::IResult;
+
+use crate::common::parsers::{delimited_ws0, keyword, paren_delimited, preceded_ws0, preceded_ws1};
+use crate::common::tokens::comma;
+use crate::mssql::ast::create_table::{ColumnDefinition, CreateTable, Identity};
+use crate::mssql::parser::common::{ident, statement_terminator, table_name};
+use crate::mssql::parser::data_types::data_type;
+
+/// Parses a `T-SQL` `CREATE TABLE` statement.
+///
+/// # Errors
+/// If the create table statement is malformed or has unsupported features, this
+/// function call will fail. Check the create table statement documentation
+/// [(1)][`CreateTable`] for supported syntax.
+pub fn create_table(i: &[u8]) -> IResult<&[u8], CreateTable> {
+    let (i, (table_name, column_definitions)) = terminated(
+        pair(
+            preceded(
+                pair(keyword("CREATE"), preceded_ws1(keyword("TABLE"))),
+                preceded_ws1(table_name),
+            ),
+            preceded_ws0(paren_delimited(separated_list1(
+                delimited_ws0(comma),
+                column_definition,
+            ))),
+        ),
+        statement_terminator,
+    )(i)?;
+
+    Ok((i, CreateTable::new(table_name, column_definitions)))
+}
+
+/// Parses a `T-SQL` column definition [(1)](ColumnDefinition).
+///
+/// # Errors
+/// If the column definition has unsupported syntax or is invalid, this function
+/// call will fail.
+pub fn column_definition(i: &[u8]) -> IResult<&[u8], ColumnDefinition> {
+    let (i, (column_name, data_type, opt_identity)) =
+        tuple((ident, preceded_ws1(data_type), opt(preceded_ws1(identity))))(i)?;
+
+    let mut column_definition = ColumnDefinition::new(column_name, data_type);
+    if let Some(identity) = opt_identity {
+        column_definition = column_definition.with_identity(identity);
+    }
+
+    Ok((i, column_definition))
+}
+
+/// Parses an identity column property [(1)](Identity).
+///
+/// # Errors
+/// If the input does not start with a case-insensitive `IDENTITY` keyword, this
+/// function call will fail.
+pub fn identity(i: &[u8]) -> IResult<&[u8], Identity> {
+    map(
+        preceded(
+            keyword("IDENTITY"),
+            opt(preceded_ws0(paren_delimited(separated_pair(
+                i64,
+                delimited_ws0(comma),
+                i64,
+            )))),
+        ),
+        |opt_seed_and_increment| match opt_seed_and_increment {
+            Some((seed, increment)) => Identity::new().with_seed_and_increment(seed, increment),
+            None => Identity::new(),
+        },
+    )(i)
+}
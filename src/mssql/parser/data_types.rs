@@ -0,0 +1,122 @@
+use nom::branch::alt;
+use nom::character::complete::u32;
+use nom::combinator::{map, opt, verify};
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::ansi::ast::data_types::DataType as AnsiDataType;
+use crate::ansi::parser::data_types::data_type as ansi_data_type;
+use crate::common::ast::SpatialType;
+use crate::common::dialect::Dialect;
+use crate::common::parsers::{keyword, paren_delimited, preceded_ws0, spatial_type};
+use crate::mssql::ast::data_types::{DataType, Length};
+use crate::mssql::dialect::MsSqlDialect;
+
+/// Parses a `T-SQL` data type [(1)].
+///
+/// # Errors
+/// This function returns an error if the data type is not supported by the
+/// `T-SQL` nor the `ANSI` parsers.
+///
+/// [(1)]: crate::mssql::ast::data_types::DataType
+pub fn data_type(i: &[u8]) -> IResult<&[u8], DataType> {
+    // OBS: T-SQL types must be tried first, as some of them start with `ANSI`
+    // type names (e.g., `DATETIME` and `DATE`).
+    alt((
+        map(
+            preceded(keyword("NVARCHAR"), opt_length),
+            DataType::NVarchar,
+        ),
+        map(
+            preceded(keyword("NCHAR"), opt(preceded_ws0(paren_delimited(u32)))),
+            DataType::NChar,
+        ),
+        map(preceded(keyword("VARCHAR"), opt_length), DataType::Varchar),
+        map(
+            preceded(keyword("VARBINARY"), opt_length),
+            DataType::Varbinary,
+        ),
+        map(keyword("BIT"), |_| DataType::Bit),
+        map(keyword("TINYINT"), |_| DataType::Tinyint),
+        map(
+            preceded(
+                keyword("DATETIME2"),
+                opt(preceded_ws0(paren_delimited(u32))),
+            ),
+            DataType::Datetime2,
+        ),
+        map(keyword("DATETIME"), |_| DataType::Datetime),
+        map(keyword("UNIQUEIDENTIFIER"), |_| DataType::Uniqueidentifier),
+        // OBS: T-SQL spatial types have no subtypes nor type modifiers.
+        map(
+            verify(spatial_type, |spatial_type| {
+                matches!(
+                    spatial_type,
+                    SpatialType::Geometry(None) | SpatialType::Geography(None)
+                )
+            }),
+            DataType::Spatial,
+        ),
+        // OBS: the `ANSI` parser also accepts the numeric type aliases, which
+        // are only kept if the dialect supports them.
+        map(
+            verify(ansi_data_type, |data_type| {
+                MsSqlDialect.supports_numeric_type_aliases()
+                    || !matches!(data_type, AnsiDataType::NumericAlias(_))
+            }),
+            DataType::Ansi,
+        ),
+    ))(i)
+}
+
+fn opt_length(i: &[u8]) -> IResult<&[u8], Option<Length>> {
+    opt(preceded_ws0(paren_delimited(alt((
+        map(keyword("MAX"), |_| Length::Max),
+        map(u32, Length::Fixed),
+    )))))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use crate::ansi::ast::data_types::CharacterLength;
+
+    use super::*;
+
+    #[test_case("NVARCHAR", DataType::NVarchar(None))]
+    #[test_case("NVARCHAR(MAX)", DataType::NVarchar(Some(Length::Max)))]
+    #[test_case("NVARCHAR(20)", DataType::NVarchar(Some(Length::Fixed(20))))]
+    #[test_case("NCHAR(10)", DataType::NChar(Some(10)))]
+    #[test_case("VARCHAR(MAX)", DataType::Varchar(Some(Length::Max)))]
+    #[test_case("VARBINARY(MAX)", DataType::Varbinary(Some(Length::Max)))]
+    #[test_case("BIT", DataType::Bit)]
+    #[test_case("TINYINT", DataType::Tinyint)]
+    #[test_case("DATETIME", DataType::Datetime)]
+    #[test_case("DATETIME2", DataType::Datetime2(None))]
+    #[test_case("DATETIME2(7)", DataType::Datetime2(Some(7)))]
+    #[test_case("UNIQUEIDENTIFIER", DataType::Uniqueidentifier)]
+    #[test_case("GEOMETRY", DataType::Spatial(SpatialType::Geometry(None)))]
+    #[test_case("GEOGRAPHY", DataType::Spatial(SpatialType::Geography(None)))]
+    #[test_case("DATE", DataType::Ansi(AnsiDataType::Date))]
+    #[test_case("INT", DataType::Ansi(AnsiDataType::Int))]
+    #[test_case(
+        "CHARACTER(20)",
+        DataType::Ansi(AnsiDataType::Character(Some(CharacterLength::new(20))))
+    )]
+    fn parse_data_type(input: &str, expected: DataType) {
+        let (remaining, parsed) = data_type(input.as_ref()).unwrap();
+        assert_eq!(expected, parsed);
+        assert_str_eq!(input, parsed.to_string());
+        assert!(remaining.is_empty());
+    }
+
+    #[test_case("INT8")]
+    #[test_case("FLOAT4")]
+    #[test_case("POINT")]
+    fn parse_data_type_unsupported(input: &str) {
+        assert!(data_type(input.as_ref()).is_err());
+    }
+}
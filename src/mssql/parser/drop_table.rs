@@ -0,0 +1,31 @@
+use nom::combinator::opt;
+use nom::sequence::{pair, preceded, terminated};
+use nom::IResult;
+
+use crate::common::parsers::{keyword, terminated_ws1};
+use crate::mssql::ast::drop_table::DropTable;
+use crate::mssql::parser::common::{statement_terminator, table_name};
+
+/// Parses a `T-SQL` `DROP TABLE` statement.
+///
+/// # Errors
+/// If the drop table statement is malformed or has unsupported features, this
+/// function call will fail. Check the drop table statement documentation
+/// [(1)][`DropTable`] for supported syntax.
+pub fn drop_table(i: &[u8]) -> IResult<&[u8], DropTable> {
+    let (i, (opt_if_exists, table_name)) = terminated(
+        preceded(
+            pair(
+                terminated_ws1(keyword("DROP")),
+                terminated_ws1(keyword("TABLE")),
+            ),
+            pair(opt(terminated_ws1(keyword("IF EXISTS"))), table_name),
+        ),
+        statement_terminator,
+    )(i)?;
+
+    Ok((
+        i,
+        DropTable::new(table_name).with_if_exists(opt_if_exists.is_some()),
+    ))
+}
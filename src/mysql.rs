@@ -0,0 +1,74 @@
+use std::fmt;
+
+use crate::ansi::visitor::{DialectIdents, VisitorMut};
+use crate::common::dialect::Dialect;
+use crate::mysql::ast::create_table::{CreateTable, TableElement};
+
+pub mod ast;
+pub mod dialect;
+pub mod keywords;
+pub mod parser;
+
+/// `MySQL` statements [(1)].
+///
+/// [(1)]: https://dev.mysql.com/doc/refman/8.0/en/sql-statements.html
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Statement {
+    /// `CREATE TABLE` statement.
+    CreateTable(CreateTable),
+}
+
+impl Statement {
+    /// Serializes the statement using the identifier rules of the received
+    /// dialect, instead of the quote styles stored in the AST.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use sql_helper::ansi::dialect::AnsiDialect;
+    /// # use sql_helper::mysql::parser::parse_statement;
+    /// let (_, statement) = parse_statement(b"CREATE TABLE `users` (`id` INT, KEY `idx` (`id`))").unwrap();
+    ///
+    /// assert_eq!(
+    ///     statement.to_sql(&AnsiDialect),
+    ///     "CREATE TABLE \"users\" (\"id\" INT, KEY \"idx\" (\"id\"))"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let mut statement = self.clone();
+        let mut visitor = DialectIdents::new(dialect);
+
+        match &mut statement {
+            Self::CreateTable(create_table) => {
+                visitor.visit_object_name(create_table.table_name_mut());
+                for table_element in create_table.elements_mut() {
+                    match table_element {
+                        TableElement::ColumnDefinition(column_definition) => {
+                            visitor.visit_ident(column_definition.column_name_mut());
+                        }
+                        TableElement::IndexDefinition(index_definition) => {
+                            if let Some(name) = index_definition.opt_name_mut() {
+                                visitor.visit_ident(name);
+                            }
+                            for key_part in index_definition.key_parts_mut() {
+                                visitor.visit_ident(key_part.column_name_mut());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        statement.to_string()
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CreateTable(create_table) => write!(f, "{create_table}")?,
+        }
+        Ok(())
+    }
+}
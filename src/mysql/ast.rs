@@ -0,0 +1,2 @@
+pub mod create_table;
+pub mod data_types;
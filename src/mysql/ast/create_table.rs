@@ -0,0 +1,620 @@
+use std::fmt;
+
+use crate::ansi::ast::common::{IdentityColumnSpecification, ObjectName, Ordering};
+use crate::common::ast::SqlOption;
+use crate::common::dialect::Dialect;
+use crate::common::expr::Expr;
+use crate::common::{display_comma_separated, if_some_string_preceded_by, Ident};
+use crate::mysql::ast::data_types::DataType;
+use crate::mysql::dialect::MySqlDialect;
+
+/// `MySQL` create table statement [(1)].
+///
+/// # Supported syntax
+/// ```plaintext
+/// CREATE TABLE <table name> (<table element> [{, <table element>}...])
+///     [<table option> [{[,] <table option>}...]]
+///
+/// <table element> ::=
+///   <column definition>
+/// | <index definition>
+/// ```
+///
+/// [(1)]: https://dev.mysql.com/doc/refman/8.0/en/create-table.html
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateTable {
+    /// `<table name>`.
+    table_name: ObjectName,
+    /// `<table element>` list.
+    elements: Vec<TableElement>,
+    /// `[<table option>...]`.
+    table_options: Vec<SqlOption>,
+}
+
+/// `MySQL` table element.
+// Column definitions are most of the elements of a table, so boxing them would
+// cost an allocation per column to save space on the few index definitions.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TableElement {
+    /// `<column definition>`.
+    ColumnDefinition(ColumnDefinition),
+    /// `<index definition>`.
+    IndexDefinition(IndexDefinition),
+}
+
+/// `MySQL` column definition.
+///
+/// # Supported syntax
+/// ```plaintext
+/// <column name> <data type>
+///     [CHARACTER SET <character set name>] [COLLATE <collation name>]
+///     [NOT NULL | NULL] [DEFAULT <default value>]
+///     [ON UPDATE CURRENT_TIMESTAMP [(<precision>)]] [AUTO_INCREMENT]
+///     [COMMENT '<comment>']
+///
+/// <default value> ::=
+///   <literal>
+/// | NULL
+/// | CURRENT_TIMESTAMP [(<precision>)]
+/// | (<value expression>)
+/// ```
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::data_types::DataType as AnsiDataType;
+/// # use sql_helper::common::Ident;
+/// # use sql_helper::mysql::ast::create_table::ColumnDefinition;
+/// # use sql_helper::mysql::ast::data_types::DataType;
+/// let column_definition = ColumnDefinition::new(Ident::new(b"name"), DataType::Ansi(AnsiDataType::Varchar(None)))
+///     .with_character_set(Ident::new(b"utf8mb4"))
+///     .with_collation(Ident::new(b"utf8mb4_bin"))
+///     .with_comment("user's name");
+///
+/// assert_eq!(
+///     column_definition.to_string(),
+///     "name VARCHAR CHARACTER SET utf8mb4 COLLATE utf8mb4_bin COMMENT 'user''s name'"
+/// );
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnDefinition {
+    /// `<column name>`.
+    column_name: Ident,
+    /// `<data type>`.
+    data_type: DataType,
+    /// `[CHARACTER SET <character set name>]`.
+    opt_character_set: Option<Ident>,
+    /// `[COLLATE <collation name>]`.
+    opt_collation: Option<Ident>,
+    /// `[NOT NULL | NULL]`.
+    opt_nullability: Option<Nullability>,
+    /// `[DEFAULT <default value>]`.
+    opt_default: Option<Expr>,
+    /// `[ON UPDATE CURRENT_TIMESTAMP [(<precision>)]]`.
+    opt_on_update: Option<Expr>,
+    /// `[AUTO_INCREMENT]`, as an identity column specification.
+    opt_identity_column_specification: Option<IdentityColumnSpecification>,
+    /// `[COMMENT '<comment>']`, unescaped.
+    opt_comment: Option<String>,
+}
+
+/// Whether a column accepts `NULL` values.
+///
+/// # Supported syntax
+/// ```plaintext
+///   NOT NULL
+/// | NULL
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nullability {
+    /// `NOT NULL`.
+    NotNull,
+    /// `NULL`.
+    Null,
+}
+
+/// `MySQL` index definition, as emitted by `mysqldump`.
+///
+/// # Supported syntax
+/// ```plaintext
+/// <index kind> [<index name>] (<key part> [{, <key part>}...])
+///     [<index option> [{<index option>}...]]
+///
+/// <index kind> ::=
+///   {INDEX | KEY}
+/// | {UNIQUE | FULLTEXT | SPATIAL} [INDEX | KEY]
+/// | PRIMARY KEY
+/// ```
+///
+/// The index options (e.g., `USING BTREE` or `COMMENT 'text'`) are kept as
+/// written.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::Ident;
+/// # use sql_helper::mysql::ast::create_table::{IndexDefinition, IndexKeyword, IndexKind, KeyPart};
+/// let index_definition = IndexDefinition::new(
+///     IndexKind::Unique,
+///     vec![KeyPart::new(Ident::new(b"email")).with_length(32)],
+/// )
+/// .with_index_keyword(IndexKeyword::Key)
+/// .with_name(Ident::new(b"uq_email"));
+///
+/// assert_eq!(index_definition.to_string(), "UNIQUE KEY uq_email (email(32))");
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndexDefinition {
+    /// `<index kind>`.
+    index_kind: IndexKind,
+    /// `[INDEX | KEY]`, which replaces the kind of plain indexes.
+    opt_index_keyword: Option<IndexKeyword>,
+    /// `[<index name>]`.
+    opt_name: Option<Ident>,
+    /// `(<key part>...)`.
+    key_parts: Vec<KeyPart>,
+    /// `[<index option>...]`.
+    options: Vec<SqlOption>,
+}
+
+/// Kind of a `MySQL` index.
+///
+/// # Supported syntax
+/// ```plaintext
+///   {INDEX | KEY}
+/// | UNIQUE
+/// | FULLTEXT
+/// | SPATIAL
+/// | PRIMARY KEY
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndexKind {
+    /// `{INDEX | KEY}`.
+    Plain,
+    /// `UNIQUE`.
+    Unique,
+    /// `FULLTEXT`.
+    Fulltext,
+    /// `SPATIAL`.
+    Spatial,
+    /// `PRIMARY KEY`.
+    PrimaryKey,
+}
+
+/// Keyword used to declare an index, which are synonyms.
+///
+/// # Supported syntax
+/// ```plaintext
+///   INDEX
+/// | KEY
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndexKeyword {
+    /// `INDEX`.
+    Index,
+    /// `KEY`.
+    Key,
+}
+
+/// Indexed column, with an optional prefix length.
+///
+/// # Supported syntax
+/// ```plaintext
+/// <column name> [(<length>)] [ASC | DESC]
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyPart {
+    /// `<column name>`.
+    column_name: Ident,
+    /// `[(<length>)]`.
+    opt_length: Option<u32>,
+    /// `[ASC | DESC]`.
+    opt_ordering: Option<Ordering>,
+}
+
+impl CreateTable {
+    #[must_use]
+    pub fn new(table_name: ObjectName, elements: Vec<TableElement>) -> Self {
+        Self {
+            table_name,
+            elements,
+            table_options: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn with_table_options(mut self, table_options: Vec<SqlOption>) -> Self {
+        self.table_options = table_options;
+        self
+    }
+
+    #[must_use]
+    pub const fn table_name(&self) -> &ObjectName {
+        &self.table_name
+    }
+
+    pub fn table_name_mut(&mut self) -> &mut ObjectName {
+        &mut self.table_name
+    }
+
+    #[must_use]
+    pub fn elements(&self) -> &[TableElement] {
+        &self.elements
+    }
+
+    pub fn elements_mut(&mut self) -> &mut Vec<TableElement> {
+        &mut self.elements
+    }
+
+    #[must_use]
+    pub fn table_options(&self) -> &[SqlOption] {
+        &self.table_options
+    }
+
+    /// Returns the column definitions, ignoring the other table elements.
+    #[must_use]
+    pub fn column_definitions(&self) -> Vec<&ColumnDefinition> {
+        self.elements()
+            .iter()
+            .filter_map(|table_element| match table_element {
+                TableElement::ColumnDefinition(column_definition) => Some(column_definition),
+                TableElement::IndexDefinition(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the index definitions, ignoring the other table elements.
+    #[must_use]
+    pub fn index_definitions(&self) -> Vec<&IndexDefinition> {
+        self.elements()
+            .iter()
+            .filter_map(|table_element| match table_element {
+                TableElement::IndexDefinition(index_definition) => Some(index_definition),
+                TableElement::ColumnDefinition(_) => None,
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for CreateTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CREATE TABLE {} ({})",
+            self.table_name(),
+            display_comma_separated(self.elements())
+        )?;
+        for option in self.table_options() {
+            write!(f, " {option}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TableElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ColumnDefinition(column_definition) => write!(f, "{column_definition}")?,
+            Self::IndexDefinition(index_definition) => write!(f, "{index_definition}")?,
+        }
+        Ok(())
+    }
+}
+
+impl ColumnDefinition {
+    #[must_use]
+    pub fn new(column_name: impl Into<Ident>, data_type: DataType) -> Self {
+        Self {
+            column_name: column_name.into(),
+            data_type,
+            opt_character_set: None,
+            opt_collation: None,
+            opt_nullability: None,
+            opt_default: None,
+            opt_on_update: None,
+            opt_identity_column_specification: None,
+            opt_comment: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_character_set(mut self, character_set: Ident) -> Self {
+        self.opt_character_set = Some(character_set);
+        self
+    }
+
+    #[must_use]
+    pub fn with_collation(mut self, collation: Ident) -> Self {
+        self.opt_collation = Some(collation);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_nullability(mut self, nullability: Nullability) -> Self {
+        self.opt_nullability = Some(nullability);
+        self
+    }
+
+    #[must_use]
+    pub fn with_default(mut self, default: Expr) -> Self {
+        self.opt_default = Some(default);
+        self
+    }
+
+    #[must_use]
+    pub fn with_on_update(mut self, on_update: Expr) -> Self {
+        self.opt_on_update = Some(on_update);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_identity_column_specification(
+        mut self,
+        identity_column_specification: IdentityColumnSpecification,
+    ) -> Self {
+        self.opt_identity_column_specification = Some(identity_column_specification);
+        self
+    }
+
+    #[must_use]
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.opt_comment = Some(comment.into());
+        self
+    }
+
+    #[must_use]
+    pub const fn column_name(&self) -> &Ident {
+        &self.column_name
+    }
+
+    pub fn column_name_mut(&mut self) -> &mut Ident {
+        &mut self.column_name
+    }
+
+    #[must_use]
+    pub const fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    #[must_use]
+    pub const fn opt_nullability(&self) -> Option<Nullability> {
+        self.opt_nullability
+    }
+
+    #[must_use]
+    pub const fn opt_character_set(&self) -> Option<&Ident> {
+        self.opt_character_set.as_ref()
+    }
+
+    #[must_use]
+    pub const fn opt_collation(&self) -> Option<&Ident> {
+        self.opt_collation.as_ref()
+    }
+
+    #[must_use]
+    pub const fn opt_default(&self) -> Option<&Expr> {
+        self.opt_default.as_ref()
+    }
+
+    #[must_use]
+    pub const fn opt_on_update(&self) -> Option<&Expr> {
+        self.opt_on_update.as_ref()
+    }
+
+    #[must_use]
+    pub const fn opt_identity_column_specification(&self) -> Option<IdentityColumnSpecification> {
+        self.opt_identity_column_specification
+    }
+
+    #[must_use]
+    pub fn opt_comment(&self) -> Option<&str> {
+        self.opt_comment.as_deref()
+    }
+}
+
+impl fmt::Display for ColumnDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{column_name} {data_type}{character_set}{collation}{nullability}{default}{on_update}{identity}",
+            column_name = self.column_name(),
+            data_type = self.data_type(),
+            character_set = if_some_string_preceded_by(self.opt_character_set(), " CHARACTER SET "),
+            collation = if_some_string_preceded_by(self.opt_collation(), " COLLATE "),
+            nullability = if_some_string_preceded_by(self.opt_nullability(), " "),
+            default = if_some_string_preceded_by(self.opt_default(), " DEFAULT "),
+            on_update = if_some_string_preceded_by(self.opt_on_update(), " ON UPDATE "),
+            identity = if_some_string_preceded_by(self.opt_identity_column_specification(), " "),
+        )?;
+        if let Some(comment) = self.opt_comment() {
+            write!(
+                f,
+                " COMMENT {}",
+                MySqlDialect.string_escapes().quote(comment)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Nullability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotNull => write!(f, "NOT NULL")?,
+            Self::Null => write!(f, "NULL")?,
+        }
+        Ok(())
+    }
+}
+
+impl IndexDefinition {
+    #[must_use]
+    pub const fn new(index_kind: IndexKind, key_parts: Vec<KeyPart>) -> Self {
+        Self {
+            index_kind,
+            opt_index_keyword: None,
+            opt_name: None,
+            key_parts,
+            options: vec![],
+        }
+    }
+
+    #[must_use]
+    pub const fn with_index_keyword(mut self, index_keyword: IndexKeyword) -> Self {
+        self.opt_index_keyword = Some(index_keyword);
+        self
+    }
+
+    #[must_use]
+    pub fn with_name(mut self, name: Ident) -> Self {
+        self.opt_name = Some(name);
+        self
+    }
+
+    #[must_use]
+    pub fn with_options(mut self, options: Vec<SqlOption>) -> Self {
+        self.options = options;
+        self
+    }
+
+    #[must_use]
+    pub const fn index_kind(&self) -> IndexKind {
+        self.index_kind
+    }
+
+    #[must_use]
+    pub const fn opt_index_keyword(&self) -> Option<IndexKeyword> {
+        self.opt_index_keyword
+    }
+
+    #[must_use]
+    pub const fn opt_name(&self) -> Option<&Ident> {
+        self.opt_name.as_ref()
+    }
+
+    pub fn opt_name_mut(&mut self) -> Option<&mut Ident> {
+        self.opt_name.as_mut()
+    }
+
+    #[must_use]
+    pub fn key_parts(&self) -> &[KeyPart] {
+        &self.key_parts
+    }
+
+    pub fn key_parts_mut(&mut self) -> &mut Vec<KeyPart> {
+        &mut self.key_parts
+    }
+
+    #[must_use]
+    pub fn options(&self) -> &[SqlOption] {
+        &self.options
+    }
+}
+
+impl fmt::Display for IndexDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.index_kind(), self.opt_index_keyword()) {
+            (IndexKind::Plain, Some(index_keyword)) => write!(f, "{index_keyword}")?,
+            (index_kind, opt_index_keyword) => write!(
+                f,
+                "{index_kind}{index_keyword}",
+                index_keyword = if_some_string_preceded_by(opt_index_keyword, " ")
+            )?,
+        }
+        write!(
+            f,
+            "{name} ({key_parts})",
+            name = if_some_string_preceded_by(self.opt_name(), " "),
+            key_parts = display_comma_separated(self.key_parts())
+        )?;
+        for option in self.options() {
+            write!(f, " {option}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for IndexKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plain => write!(f, "INDEX")?,
+            Self::Unique => write!(f, "UNIQUE")?,
+            Self::Fulltext => write!(f, "FULLTEXT")?,
+            Self::Spatial => write!(f, "SPATIAL")?,
+            Self::PrimaryKey => write!(f, "PRIMARY KEY")?,
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for IndexKeyword {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Index => write!(f, "INDEX")?,
+            Self::Key => write!(f, "KEY")?,
+        }
+        Ok(())
+    }
+}
+
+impl KeyPart {
+    #[must_use]
+    pub const fn new(column_name: Ident) -> Self {
+        Self {
+            column_name,
+            opt_length: None,
+            opt_ordering: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_length(mut self, length: u32) -> Self {
+        self.opt_length = Some(length);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_ordering(mut self, ordering: Ordering) -> Self {
+        self.opt_ordering = Some(ordering);
+        self
+    }
+
+    #[must_use]
+    pub const fn column_name(&self) -> &Ident {
+        &self.column_name
+    }
+
+    pub fn column_name_mut(&mut self) -> &mut Ident {
+        &mut self.column_name
+    }
+
+    #[must_use]
+    pub const fn opt_length(&self) -> Option<u32> {
+        self.opt_length
+    }
+
+    #[must_use]
+    pub const fn opt_ordering(&self) -> Option<Ordering> {
+        self.opt_ordering
+    }
+}
+
+impl fmt::Display for KeyPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.column_name())?;
+        if let Some(length) = self.opt_length() {
+            write!(f, "({length})")?;
+        }
+        if let Some(ordering) = self.opt_ordering() {
+            write!(f, " {ordering}")?;
+        }
+        Ok(())
+    }
+}
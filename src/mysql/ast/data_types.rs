@@ -0,0 +1,203 @@
+use std::fmt;
+
+use crate::ansi::ast::data_types::DataType as AnsiDataType;
+use crate::common::ast::SpatialType;
+use crate::common::dialect::Dialect;
+use crate::mysql::dialect::MySqlDialect;
+
+/// `MySQL` data types [(1)].
+///
+/// Types that have the same syntax in `ANSI` and `MySQL` are kept as their
+/// `ANSI` representation.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::ansi::ast::data_types::DataType as AnsiDataType;
+/// # use sql_helper::mysql::ast::data_types::{DataType, NumericModifiers, Signedness};
+/// let data_type = DataType::Numeric(
+///     AnsiDataType::Int,
+///     NumericModifiers::new()
+///         .with_display_width(11)
+///         .with_signedness(Signedness::Unsigned)
+///         .with_zerofill(true),
+/// );
+///
+/// assert_eq!(data_type.to_string(), "INT(11) UNSIGNED ZEROFILL");
+///
+/// let data_type = DataType::Enum(vec!["small".to_string(), "it's big".to_string()]);
+///
+/// assert_eq!(data_type.to_string(), "ENUM('small', 'it''s big')");
+/// ```
+///
+/// [(1)]: https://dev.mysql.com/doc/refman/8.0/en/data-types.html
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataType {
+    /// Data type shared with `ANSI`.
+    Ansi(AnsiDataType),
+    /// `ANSI` numeric type followed by `MySQL` modifiers (e.g.,
+    /// `INT(11) UNSIGNED`).
+    Numeric(AnsiDataType, NumericModifiers),
+    /// `ENUM(<value> [{, <value>}...])`, which stores one of the values.
+    Enum(Vec<String>),
+    /// `SET(<value> [{, <value>}...])`, which stores any combination of the
+    /// values.
+    Set(Vec<String>),
+    /// `{GEOMETRY | <geometry subtype>} [SRID <srid>]`
+    Spatial(SpatialType, Option<u32>),
+}
+
+/// `MySQL` modifiers of the numeric types [(1)].
+///
+/// The display width is only accepted by the integer types, and is ignored by
+/// `MySQL` unless the type is `ZEROFILL`, which also makes it unsigned.
+///
+/// # Supported syntax
+/// ```plaintext
+/// [(<display width>)] [SIGNED | UNSIGNED] [ZEROFILL]
+/// ```
+///
+/// [(1)]: https://dev.mysql.com/doc/refman/8.0/en/numeric-type-attributes.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumericModifiers {
+    /// `(<display width>)`
+    opt_display_width: Option<u32>,
+    /// `SIGNED | UNSIGNED`
+    opt_signedness: Option<Signedness>,
+    /// `ZEROFILL`
+    zerofill: bool,
+}
+
+/// Whether a numeric type accepts negative values.
+///
+/// # Supported syntax
+/// ```plaintext
+///   SIGNED
+/// | UNSIGNED
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Signedness {
+    /// `SIGNED`
+    Signed,
+    /// `UNSIGNED`
+    Unsigned,
+}
+
+impl NumericModifiers {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            opt_display_width: None,
+            opt_signedness: None,
+            zerofill: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_display_width(mut self, display_width: u32) -> Self {
+        self.opt_display_width = Some(display_width);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_signedness(mut self, signedness: Signedness) -> Self {
+        self.opt_signedness = Some(signedness);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_zerofill(mut self, zerofill: bool) -> Self {
+        self.zerofill = zerofill;
+        self
+    }
+
+    #[must_use]
+    pub const fn opt_display_width(&self) -> Option<u32> {
+        self.opt_display_width
+    }
+
+    #[must_use]
+    pub const fn opt_signedness(&self) -> Option<Signedness> {
+        self.opt_signedness
+    }
+
+    #[must_use]
+    pub const fn zerofill(&self) -> bool {
+        self.zerofill
+    }
+
+    /// Returns whether the type is unsigned, either explicitly or because
+    /// it's `ZEROFILL`.
+    #[must_use]
+    pub const fn is_unsigned(&self) -> bool {
+        self.zerofill || matches!(self.opt_signedness, Some(Signedness::Unsigned))
+    }
+
+    /// Returns whether no modifier is set.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.opt_display_width.is_none() && self.opt_signedness.is_none() && !self.zerofill
+    }
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ansi(data_type) => write!(f, "{data_type}")?,
+            Self::Numeric(data_type, modifiers) => write!(f, "{data_type}{modifiers}")?,
+            Self::Enum(values) => write!(f, "ENUM({})", quoted_values(values))?,
+            Self::Set(values) => write!(f, "SET({})", quoted_values(values))?,
+            Self::Spatial(spatial_type, opt_srid) => {
+                write!(f, "{spatial_type}")?;
+
+                if let Some(srid) = opt_srid {
+                    write!(f, " SRID {srid}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<AnsiDataType> for DataType {
+    fn from(data_type: AnsiDataType) -> Self {
+        Self::Ansi(data_type)
+    }
+}
+
+/// Returns the values as comma separated string literals, with their quotes
+/// and backslashes escaped.
+fn quoted_values(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| MySqlDialect.string_escapes().quote(value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl fmt::Display for NumericModifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(display_width) = self.opt_display_width {
+            write!(f, "({display_width})")?;
+        }
+        if let Some(signedness) = self.opt_signedness {
+            write!(f, " {signedness}")?;
+        }
+        if self.zerofill {
+            write!(f, " ZEROFILL")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Signedness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed => write!(f, "SIGNED")?,
+            Self::Unsigned => write!(f, "UNSIGNED")?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::common::dialect::{Dialect, NumericLimits, StringEscapes};
+use crate::common::QuoteStyle;
+use crate::mysql::keywords::is_reserved_word;
+
+/// `MySQL` dialect.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct MySqlDialect;
+
+impl Dialect for MySqlDialect {
+    fn is_reserved_keyword(&self, word: &str) -> bool {
+        is_reserved_word(word)
+    }
+
+    fn is_identifier_start(&self, chr: char) -> bool {
+        chr.is_ascii_alphabetic() || matches!(chr, '_' | '$')
+    }
+
+    fn is_identifier_part(&self, chr: char) -> bool {
+        chr.is_ascii_alphanumeric() || matches!(chr, '_' | '$')
+    }
+
+    /// `MySQL` has no schemas, so names are qualified by the database only.
+    fn max_name_parts(&self) -> usize {
+        2
+    }
+
+    fn identifier_quote_style(&self) -> QuoteStyle {
+        QuoteStyle::Backtick
+    }
+
+    /// `DECIMAL(65, 30)` and `DATETIME(6)` are the largest precisions.
+    fn numeric_limits(&self) -> NumericLimits {
+        NumericLimits::default()
+            .with_max_decimal_precision(65)
+            .with_max_decimal_scale(30)
+            .with_max_temporal_precision(6)
+    }
+
+    fn supports_numeric_type_aliases(&self) -> bool {
+        true
+    }
+
+    fn string_escapes(&self) -> StringEscapes {
+        StringEscapes::Backslash
+    }
+}
@@ -0,0 +1,20 @@
+use nom::combinator::map;
+use nom::IResult;
+
+use crate::mysql::parser::create_table::create_table;
+use crate::mysql::Statement;
+
+pub mod common;
+pub mod create_table;
+pub mod data_types;
+
+/// Parses a `MySQL` `Statement` [(1)] from the given input.
+///
+/// # Errors
+/// This method will raise an error if the input is malformed, or if the
+/// statement is not supported.
+///
+/// [(1)]: crate::mysql::Statement
+pub fn parse_statement(i: &[u8]) -> IResult<&[u8], Statement> {
+    map(create_table, Statement::CreateTable)(i)
+}
@@ -0,0 +1,97 @@
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::{map, verify};
+use nom::multi::separated_list1;
+use nom::sequence::delimited;
+use nom::IResult;
+
+use crate::ansi::ast::common::ObjectName;
+use crate::common::dialect::Dialect;
+use crate::common::parsers::{character_string_literal, delimited_ident_body, delimited_ws0};
+use crate::common::tokens::period;
+use crate::common::{Ident, QuoteStyle};
+use crate::mysql::dialect::MySqlDialect;
+
+/// Parses a `MySQL` identifier, which can be unquoted, double quoted or
+/// delimited by backticks.
+///
+/// # Errors
+/// If no possible identifier is found, this function call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::common::{Ident, QuoteStyle};
+/// # use sql_helper::mysql::parser::common::ident;
+/// assert_eq!(
+///     ident(b"`my name`"),
+///     Ok((&b""[..], Ident::new_quoted(b"my name", QuoteStyle::Backtick)))
+/// );
+/// assert_eq!(ident(b"name"), Ok((&b""[..], Ident::new(b"name"))));
+/// ```
+pub fn ident(i: &[u8]) -> IResult<&[u8], Ident> {
+    alt((
+        map(
+            delimited(tag("`"), delimited_ident_body("`", "``"), tag("`")),
+            |bytes| Ident::new_quoted(&bytes, QuoteStyle::Backtick),
+        ),
+        crate::common::parsers::ident,
+    ))(i)
+}
+
+/// Parses a `MySQL` table name, with up to two parts (`[<database>.]<table>`)
+/// [(1)].
+///
+/// # Errors
+/// If the table name has too many qualifications or invalid identifiers, this
+/// function call will fail.
+///
+/// [(1)]: MySqlDialect::max_name_parts
+pub fn table_name(i: &[u8]) -> IResult<&[u8], ObjectName> {
+    map(
+        verify(
+            separated_list1(delimited_ws0(period), ident),
+            |parts: &Vec<Ident>| parts.len() <= MySqlDialect.max_name_parts(),
+        ),
+        |mut parts| {
+            let name = parts.pop().unwrap_or_else(|| unreachable!());
+            ObjectName::new(name).with_qualifiers(parts)
+        },
+    )(i)
+}
+
+/// Parses a character string literal, returning its value without the quotes
+/// and with the doubled quotes and backslash escapes decoded.
+///
+/// # Errors
+/// If the input is not a single quoted string, this function call will fail.
+pub fn string_literal(i: &[u8]) -> IResult<&[u8], String> {
+    map(
+        character_string_literal(MySqlDialect.string_escapes()),
+        |literal| literal.opt_string_value().unwrap_or_default().to_string(),
+    )(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("tb")]
+    #[test_case("`tb`" ; "backtick quoted")]
+    #[test_case("`my table`" ; "backtick quoted with space")]
+    #[test_case("`db`.`tb`" ; "backtick quoted database")]
+    #[test_case("db.`tb`" ; "mixed quotes")]
+    #[test_case("`a``b`" ; "backtick quoted escaped")]
+    fn parse_table_name(input: &str) {
+        let (remaining, parsed) = table_name(input.as_ref()).unwrap();
+        assert!(remaining.is_empty());
+        assert_str_eq!(input, parsed.to_string());
+    }
+
+    #[test]
+    fn parse_table_name_too_many_parts() {
+        assert!(table_name(b"a.b.c").is_err());
+    }
+}
@@ -0,0 +1,320 @@
+use nom::branch::alt;
+use nom::character::complete::u32;
+use nom::combinator::{map, opt, recognize, value};
+use nom::multi::{many0, separated_list1};
+use nom::sequence::{pair, preceded, terminated, tuple};
+use nom::IResult;
+
+use crate::ansi::parser::common::{auto_increment, literal, ordering};
+use crate::common::dialect::Dialect;
+use crate::common::expr::{Expr, Literal, RawExpr};
+use crate::common::parsers::{
+    character_string_literal, delimited_ws0, keyword, paren_delimited, preceded_ws0, preceded_ws1,
+    prefixed_binary_literal, raw_expr, sql_option, statement_terminator, terminated_ws1,
+    whitespace0,
+};
+use crate::common::span::Span;
+use crate::common::tokens::comma;
+use crate::mysql::ast::create_table::{
+    ColumnDefinition, CreateTable, IndexDefinition, IndexKeyword, IndexKind, KeyPart, Nullability,
+    TableElement,
+};
+use crate::mysql::dialect::MySqlDialect;
+use crate::mysql::parser::common::{ident, string_literal, table_name};
+use crate::mysql::parser::data_types::data_type;
+
+/// Parses a `MySQL` `CREATE TABLE` statement.
+///
+/// # Errors
+/// If the create table statement is malformed or has unsupported features, this
+/// function call will fail. Check the create table statement documentation
+/// [(1)][`CreateTable`] for supported syntax.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::mysql::parser::create_table::create_table;
+/// let sql = b"CREATE TABLE `users` (
+///   `id` int NOT NULL,
+///   `email` varchar(255) DEFAULT NULL,
+///   PRIMARY KEY (`id`),
+///   UNIQUE KEY `uq_email` (`email`)
+/// ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;";
+/// let (_, create_table) = create_table(sql).unwrap();
+///
+/// assert_eq!(create_table.column_definitions().len(), 2);
+/// assert_eq!(create_table.index_definitions().len(), 2);
+/// ```
+pub fn create_table(i: &[u8]) -> IResult<&[u8], CreateTable> {
+    let source = i;
+    let (i, (table_name, elements, table_options)) = terminated(
+        tuple((
+            preceded(
+                pair(keyword("CREATE"), preceded_ws1(keyword("TABLE"))),
+                preceded_ws1(table_name),
+            ),
+            preceded_ws0(paren_delimited(separated_list1(
+                delimited_ws0(comma),
+                alt((
+                    map(index_definition, TableElement::IndexDefinition),
+                    map(column_definition(source), TableElement::ColumnDefinition),
+                )),
+            ))),
+            many0(preceded(
+                pair(whitespace0, opt(pair(comma, whitespace0))),
+                sql_option,
+            )),
+        )),
+        statement_terminator,
+    )(i)?;
+
+    Ok((
+        i,
+        CreateTable::new(table_name, elements).with_table_options(table_options),
+    ))
+}
+
+/// Parses a `MySQL` column definition [(1)](ColumnDefinition), with the
+/// default expression span relative to `source`.
+///
+/// # Errors
+/// If the column definition has unsupported syntax or is invalid, this function
+/// call will fail.
+pub fn column_definition<'a>(
+    source: &'a [u8],
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], ColumnDefinition> {
+    move |i: &'a [u8]| {
+        let (
+            i,
+            (
+                (column_name, data_type, opt_character_set, opt_collation),
+                (
+                    opt_nullability,
+                    opt_default,
+                    opt_on_update,
+                    opt_identity_column_specification,
+                    opt_comment,
+                ),
+            ),
+        ) = pair(
+            tuple((
+                ident,
+                preceded_ws1(data_type),
+                opt(preceded(
+                    preceded_ws1(alt((keyword("CHARACTER SET"), keyword("CHARSET")))),
+                    preceded_ws1(ident),
+                )),
+                opt(preceded(
+                    preceded_ws1(keyword("COLLATE")),
+                    preceded_ws1(ident),
+                )),
+            )),
+            tuple((
+                opt(preceded_ws1(nullability)),
+                opt(preceded(
+                    preceded_ws1(keyword("DEFAULT")),
+                    preceded_ws1(default_value(source)),
+                )),
+                opt(preceded(
+                    preceded_ws1(keyword("ON UPDATE")),
+                    preceded_ws1(current_timestamp(source)),
+                )),
+                opt(preceded_ws1(auto_increment)),
+                opt(preceded(
+                    preceded_ws1(keyword("COMMENT")),
+                    preceded_ws1(string_literal),
+                )),
+            )),
+        )(i)?;
+
+        let mut column_definition = ColumnDefinition::new(column_name, data_type);
+        if let Some(character_set) = opt_character_set {
+            column_definition = column_definition.with_character_set(character_set);
+        }
+        if let Some(collation) = opt_collation {
+            column_definition = column_definition.with_collation(collation);
+        }
+        if let Some(nullability) = opt_nullability {
+            column_definition = column_definition.with_nullability(nullability);
+        }
+        if let Some(default) = opt_default {
+            column_definition = column_definition.with_default(default);
+        }
+        if let Some(on_update) = opt_on_update {
+            column_definition = column_definition.with_on_update(on_update);
+        }
+        if let Some(identity_column_specification) = opt_identity_column_specification {
+            column_definition =
+                column_definition.with_identity_column_specification(identity_column_specification);
+        }
+        if let Some(comment) = opt_comment {
+            column_definition = column_definition.with_comment(comment);
+        }
+
+        Ok((i, column_definition))
+    }
+}
+
+fn nullability(i: &[u8]) -> IResult<&[u8], Nullability> {
+    alt((
+        value(
+            Nullability::NotNull,
+            pair(terminated_ws1(keyword("NOT")), keyword("NULL")),
+        ),
+        value(Nullability::Null, keyword("NULL")),
+    ))(i)
+}
+
+/// Parses a column default value, keeping the ones that aren't literals nor
+/// `NULL` as raw expressions.
+fn default_value<'a>(source: &'a [u8]) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Expr> {
+    move |i: &'a [u8]| {
+        alt((
+            map(
+                character_string_literal(MySqlDialect.string_escapes()),
+                Expr::Literal,
+            ),
+            map(prefixed_binary_literal, |binary_literal| {
+                Expr::Literal(Literal::Binary(binary_literal))
+            }),
+            map(literal, Expr::Literal),
+            value(Expr::Null, keyword("NULL")),
+            map(paren_delimited(raw_expr(source)), |raw| {
+                Expr::Nested(Box::new(Expr::Raw(raw)))
+            }),
+            current_timestamp(source),
+        ))(i)
+    }
+}
+
+/// Parses a `CURRENT_TIMESTAMP [(<precision>)]` expression, kept as a raw
+/// expression.
+fn current_timestamp<'a>(source: &'a [u8]) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Expr> {
+    move |i: &'a [u8]| {
+        map(
+            recognize(pair(
+                keyword("CURRENT_TIMESTAMP"),
+                opt(preceded_ws0(paren_delimited(opt(u32)))),
+            )),
+            |text: &[u8]| {
+                let start = text.as_ptr() as usize - source.as_ptr() as usize;
+                Expr::Raw(RawExpr::new(
+                    String::from_utf8_lossy(text),
+                    Span::new(start, start + text.len()),
+                ))
+            },
+        )(i)
+    }
+}
+
+/// Parses a `MySQL` index definition [(1)](IndexDefinition).
+///
+/// # Errors
+/// If the input is not an index definition, this function call will fail.
+pub fn index_definition(i: &[u8]) -> IResult<&[u8], IndexDefinition> {
+    let (i, ((index_kind, opt_index_keyword), opt_name, key_parts, options)) = tuple((
+        alt((
+            value(
+                (IndexKind::PrimaryKey, None),
+                pair(terminated_ws1(keyword("PRIMARY")), keyword("KEY")),
+            ),
+            pair(
+                alt((
+                    value(IndexKind::Unique, keyword("UNIQUE")),
+                    value(IndexKind::Fulltext, keyword("FULLTEXT")),
+                    value(IndexKind::Spatial, keyword("SPATIAL")),
+                )),
+                opt(preceded_ws1(index_keyword)),
+            ),
+            map(index_keyword, |index_keyword| {
+                (IndexKind::Plain, Some(index_keyword))
+            }),
+        )),
+        opt(preceded_ws1(ident)),
+        preceded_ws0(paren_delimited(separated_list1(
+            delimited_ws0(comma),
+            key_part,
+        ))),
+        many0(preceded_ws1(sql_option)),
+    ))(i)?;
+
+    let mut index_definition = IndexDefinition::new(index_kind, key_parts).with_options(options);
+    if let Some(index_keyword) = opt_index_keyword {
+        index_definition = index_definition.with_index_keyword(index_keyword);
+    }
+    if let Some(name) = opt_name {
+        index_definition = index_definition.with_name(name);
+    }
+
+    Ok((i, index_definition))
+}
+
+fn index_keyword(i: &[u8]) -> IResult<&[u8], IndexKeyword> {
+    alt((
+        value(IndexKeyword::Index, keyword("INDEX")),
+        value(IndexKeyword::Key, keyword("KEY")),
+    ))(i)
+}
+
+fn key_part(i: &[u8]) -> IResult<&[u8], KeyPart> {
+    let (i, (column_name, opt_length, opt_ordering)) = tuple((
+        ident,
+        opt(preceded_ws0(paren_delimited(u32))),
+        opt(preceded_ws1(ordering)),
+    ))(i)?;
+
+    let mut key_part = KeyPart::new(column_name);
+    if let Some(length) = opt_length {
+        key_part = key_part.with_length(length);
+    }
+    if let Some(ordering) = opt_ordering {
+        key_part = key_part.with_ordering(ordering);
+    }
+
+    Ok((i, key_part))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("PRIMARY KEY (`id`)")]
+    #[test_case("UNIQUE KEY `uq_email` (`email`)" ; "unique key")]
+    #[test_case("UNIQUE (a, b)" ; "unique without keyword nor name")]
+    #[test_case("KEY `idx_name` (`name`(10))" ; "prefix length")]
+    #[test_case("INDEX idx (a DESC, b ASC)" ; "ordering")]
+    #[test_case("FULLTEXT KEY `ft_body` (`body`)" ; "fulltext")]
+    #[test_case("SPATIAL INDEX sp (shape)" ; "spatial")]
+    #[test_case("KEY `idx` (`a`) USING BTREE COMMENT 'lookup'" ; "options")]
+    fn parse_index_definition(input: &str) {
+        let (remaining, parsed) = index_definition(input.as_ref()).unwrap();
+        assert!(remaining.is_empty());
+        assert_str_eq!(input, parsed.to_string());
+    }
+
+    #[test_case("KEY" ; "no key parts")]
+    #[test_case("PRIMARY (id)" ; "primary without key")]
+    #[test_case("UNIQUE INT" ; "column named unique")]
+    fn parse_index_definition_failure(input: &str) {
+        assert!(index_definition(input.as_ref()).is_err());
+    }
+
+    #[test_case("name VARCHAR(10) charset utf8mb4", "name VARCHAR(10) CHARACTER SET utf8mb4" ; "charset synonym")]
+    #[test_case("a INT comment 'x' ", "a INT COMMENT 'x'" ; "lowercase comment")]
+    #[test_case(
+        "ts TIMESTAMP(3) on update current_timestamp(3)",
+        "ts TIMESTAMP(3) ON UPDATE current_timestamp(3)" ;
+        "on update precision"
+    )]
+    #[test_case(
+        "id INT NOT NULL auto_increment COMMENT 'x'",
+        "id INT NOT NULL AUTO_INCREMENT COMMENT 'x'" ;
+        "lowercase auto increment"
+    )]
+    fn parse_column_definition(input: &str, expected: &str) {
+        let (_, parsed) = column_definition(input.as_ref())(input.as_ref()).unwrap();
+        assert_str_eq!(expected, parsed.to_string());
+    }
+}
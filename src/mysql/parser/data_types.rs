@@ -0,0 +1,255 @@
+use nom::branch::alt;
+use nom::character::complete::u32;
+use nom::combinator::{map, opt, verify};
+use nom::error::{Error, ErrorKind};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, preceded, tuple};
+use nom::IResult;
+
+use crate::ansi::ast::data_types::DataType as AnsiDataType;
+use crate::ansi::parser::data_types::data_type as ansi_data_type;
+use crate::common::ast::SpatialType;
+use crate::common::parsers::{
+    delimited_ws0, keyword, paren_delimited, preceded_ws0, preceded_ws1, spatial_type,
+    terminated_ws1,
+};
+use crate::common::tokens::comma;
+use crate::mysql::ast::data_types::{DataType, NumericModifiers, Signedness};
+use crate::mysql::parser::common::string_literal;
+
+/// Parses a `MySQL` data type [(1)].
+///
+/// # Errors
+/// This function returns an error if the data type is not supported by the
+/// `MySQL` nor the `ANSI` parsers.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::mysql::parser::data_types::data_type;
+/// let (_, data_type) = data_type(b"BIGINT(20) UNSIGNED").unwrap();
+///
+/// assert_eq!(data_type.to_string(), "BIGINT(20) UNSIGNED");
+/// ```
+///
+/// [(1)]: crate::mysql::ast::data_types::DataType
+pub fn data_type(i: &[u8]) -> IResult<&[u8], DataType> {
+    alt((
+        map(
+            preceded(keyword("ENUM"), string_literal_list),
+            DataType::Enum,
+        ),
+        map(preceded(keyword("SET"), string_literal_list), DataType::Set),
+        mysql_spatial_type,
+        ansi_based_data_type,
+    ))(i)
+}
+
+/// Parses an `ANSI` data type, with the `MySQL` modifiers of the numeric
+/// types.
+fn ansi_based_data_type(i: &[u8]) -> IResult<&[u8], DataType> {
+    let (i, data_type) = ansi_data_type(i)?;
+    if !is_numeric(data_type) {
+        return Ok((i, DataType::Ansi(data_type)));
+    }
+
+    let (i, modifiers) = numeric_modifiers(is_integer(data_type))(i)?;
+
+    if modifiers.is_empty() {
+        Ok((i, DataType::Ansi(data_type)))
+    } else {
+        Ok((i, DataType::Numeric(data_type, modifiers)))
+    }
+}
+
+/// Parses a spatial type, which has no `GEOGRAPHY` nor type modifiers in
+/// `MySQL`, but can have a spatial reference system.
+fn mysql_spatial_type(i: &[u8]) -> IResult<&[u8], DataType> {
+    map(
+        pair(
+            verify(spatial_type, |spatial_type| {
+                !matches!(spatial_type, SpatialType::Geography(_))
+                    && spatial_type.opt_modifier().is_none()
+            }),
+            opt(preceded_ws1(preceded(terminated_ws1(keyword("SRID")), u32))),
+        ),
+        |(spatial_type, opt_srid)| DataType::Spatial(spatial_type, opt_srid),
+    )(i)
+}
+
+fn numeric_modifiers(integer: bool) -> impl FnMut(&[u8]) -> IResult<&[u8], NumericModifiers> {
+    move |i| {
+        let (i, (opt_display_width, opt_signedness, opt_zerofill)) = tuple((
+            opt(preceded_ws0(paren_delimited(u32))),
+            opt(preceded_ws1(signedness)),
+            opt(preceded_ws1(keyword("ZEROFILL"))),
+        ))(i)?;
+
+        let mut modifiers = NumericModifiers::new().with_zerofill(opt_zerofill.is_some());
+        if let Some(display_width) = opt_display_width {
+            // OBS: only the integer types have a display width.
+            if !integer {
+                return Err(nom::Err::Error(Error::new(i, ErrorKind::Verify)));
+            }
+            modifiers = modifiers.with_display_width(display_width);
+        }
+        if let Some(signedness) = opt_signedness {
+            modifiers = modifiers.with_signedness(signedness);
+        }
+
+        Ok((i, modifiers))
+    }
+}
+
+fn string_literal_list(i: &[u8]) -> IResult<&[u8], Vec<String>> {
+    preceded_ws0(paren_delimited(separated_list1(
+        delimited_ws0(comma),
+        string_literal,
+    )))(i)
+}
+
+fn signedness(i: &[u8]) -> IResult<&[u8], Signedness> {
+    alt((
+        map(keyword("SIGNED"), |_| Signedness::Signed),
+        map(keyword("UNSIGNED"), |_| Signedness::Unsigned),
+    ))(i)
+}
+
+const fn is_numeric(data_type: AnsiDataType) -> bool {
+    is_integer(data_type)
+        || matches!(
+            data_type.canonical(),
+            AnsiDataType::Numeric(_)
+                | AnsiDataType::Decimal(_)
+                | AnsiDataType::Float
+                | AnsiDataType::Real
+                | AnsiDataType::DoublePrecision
+        )
+}
+
+const fn is_integer(data_type: AnsiDataType) -> bool {
+    matches!(
+        data_type.canonical(),
+        AnsiDataType::Smallint | AnsiDataType::Integer | AnsiDataType::Bigint
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use crate::ansi::ast::data_types::{CharacterLength, ExactNumberInfo, NumericTypeAlias};
+    use crate::common::ast::GeometrySubtype;
+
+    use super::*;
+
+    #[test_case("INT", DataType::Ansi(AnsiDataType::Int))]
+    #[test_case(
+        "VARCHAR(20)",
+        DataType::Ansi(AnsiDataType::Varchar(Some(CharacterLength::new(20))))
+    )]
+    #[test_case(
+        "INT(11)",
+        DataType::Numeric(AnsiDataType::Int, NumericModifiers::new().with_display_width(11))
+    )]
+    #[test_case(
+        "INT(11) UNSIGNED ZEROFILL",
+        DataType::Numeric(
+            AnsiDataType::Int,
+            NumericModifiers::new()
+                .with_display_width(11)
+                .with_signedness(Signedness::Unsigned)
+                .with_zerofill(true)
+        )
+    )]
+    #[test_case(
+        "BIGINT SIGNED",
+        DataType::Numeric(
+            AnsiDataType::Bigint,
+            NumericModifiers::new().with_signedness(Signedness::Signed)
+        )
+    )]
+    #[test_case(
+        "INT8(20) UNSIGNED",
+        DataType::Numeric(
+            AnsiDataType::NumericAlias(NumericTypeAlias::Int8),
+            NumericModifiers::new()
+                .with_display_width(20)
+                .with_signedness(Signedness::Unsigned)
+        )
+    )]
+    #[test_case(
+        "DECIMAL(10, 2) UNSIGNED",
+        DataType::Numeric(
+            AnsiDataType::Decimal(ExactNumberInfo::PrecisionAndScale(10, 2)),
+            NumericModifiers::new().with_signedness(Signedness::Unsigned)
+        )
+    )]
+    #[test_case(
+        "DOUBLE ZEROFILL",
+        DataType::Numeric(
+            AnsiDataType::NumericAlias(NumericTypeAlias::Double),
+            NumericModifiers::new().with_zerofill(true)
+        )
+    )]
+    #[test_case("ENUM('a')", DataType::Enum(vec!["a".to_string()]))]
+    #[test_case(
+        "ENUM('small', 'it''s big', '')",
+        DataType::Enum(vec!["small".to_string(), "it's big".to_string(), String::new()])
+    )]
+    #[test_case(
+        "SET('read', 'write')",
+        DataType::Set(vec!["read".to_string(), "write".to_string()])
+    )]
+    #[test_case("GEOMETRY", DataType::Spatial(SpatialType::Geometry(None), None))]
+    #[test_case(
+        "POINT SRID 4326",
+        DataType::Spatial(SpatialType::Subtype(GeometrySubtype::Point), Some(4326))
+    )]
+    #[test_case(
+        "MULTIPOLYGON",
+        DataType::Spatial(SpatialType::Subtype(GeometrySubtype::MultiPolygon), None)
+    )]
+    fn parse_data_type(input: &str, expected: DataType) {
+        assert_str_eq!(input, expected.to_string());
+        assert_eq!(Ok((&b""[..], expected)), data_type(input.as_ref()));
+    }
+
+    #[test]
+    fn parse_data_type_zerofill_is_unsigned() {
+        let (_, parsed) = data_type(b"smallint zerofill").unwrap();
+        let DataType::Numeric(_, modifiers) = parsed else {
+            panic!("expected modifiers, got {parsed:?}");
+        };
+        assert!(modifiers.is_unsigned());
+        assert_eq!(None, modifiers.opt_signedness());
+    }
+
+    #[test]
+    fn parse_data_type_value_list_spacing() {
+        let (remaining, parsed) = data_type(b"set ( 'a','b' )").unwrap();
+        assert!(remaining.is_empty());
+        assert_str_eq!("SET('a', 'b')", parsed.to_string());
+    }
+
+    #[test_case("GEOGRAPHY" ; "geography")]
+    #[test_case("ENUM" ; "missing values")]
+    #[test_case("ENUM()" ; "empty values")]
+    #[test_case("SET(a)" ; "unquoted value")]
+    fn parse_data_type_invalid_value_list(input: &str) {
+        assert!(data_type(input.as_ref()).is_err());
+    }
+
+    #[test]
+    fn parse_data_type_display_width_of_non_integer() {
+        assert!(data_type(b"REAL(10)").is_err());
+    }
+
+    #[test]
+    fn parse_data_type_modifier_of_non_numeric() {
+        let (remaining, parsed) = data_type(b"DATE UNSIGNED").unwrap();
+        assert_eq!(DataType::Ansi(AnsiDataType::Date), parsed);
+        assert_eq!(b" UNSIGNED", remaining);
+    }
+}
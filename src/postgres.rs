@@ -0,0 +1,4 @@
+pub mod ast;
+pub mod dialect;
+pub mod keywords;
+pub mod parser;
@@ -0,0 +1,35 @@
+use std::fmt;
+
+use crate::ansi::ast::data_types::DataType as AnsiDataType;
+
+/// `PostgreSQL` data types [(1)].
+///
+/// Types that have the same syntax in `ANSI` and `PostgreSQL` are kept as
+/// their `ANSI` representation.
+///
+/// [(1)]: https://www.postgresql.org/docs/current/datatype.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataType {
+    /// Data type shared with `ANSI`.
+    Ansi(AnsiDataType),
+    /// `UUID`
+    Uuid,
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ansi(data_type) => write!(f, "{data_type}")?,
+            Self::Uuid => write!(f, "UUID")?,
+        }
+
+        Ok(())
+    }
+}
+
+impl From<AnsiDataType> for DataType {
+    fn from(data_type: AnsiDataType) -> Self {
+        Self::Ansi(data_type)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::common::dialect::{Dialect, NumericLimits, StringEscapes};
+use crate::postgres::keywords::is_reserved_word;
+
+/// `PostgreSQL` dialect.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct PostgresDialect;
+
+impl Dialect for PostgresDialect {
+    fn is_reserved_keyword(&self, word: &str) -> bool {
+        is_reserved_word(word)
+    }
+
+    fn is_identifier_start(&self, chr: char) -> bool {
+        chr.is_ascii_alphabetic() || chr == '_'
+    }
+
+    fn is_identifier_part(&self, chr: char) -> bool {
+        chr.is_ascii_alphanumeric() || matches!(chr, '_' | '$')
+    }
+
+    /// `NUMERIC(1000, 1000)` and `TIMESTAMP(6)` are the largest precisions.
+    fn numeric_limits(&self) -> NumericLimits {
+        NumericLimits::default()
+            .with_max_decimal_precision(1000)
+            .with_max_decimal_scale(1000)
+            .with_max_temporal_precision(6)
+    }
+
+    fn supports_numeric_type_aliases(&self) -> bool {
+        true
+    }
+
+    fn string_escapes(&self) -> StringEscapes {
+        StringEscapes::EscapeStringPrefix
+    }
+}
@@ -0,0 +1 @@
+pub mod data_types;
@@ -0,0 +1,50 @@
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::IResult;
+
+use crate::ansi::parser::data_types::data_type as ansi_data_type;
+use crate::common::parsers::keyword;
+use crate::postgres::ast::data_types::DataType;
+
+/// Parses a `PostgreSQL` data type [(1)].
+///
+/// # Errors
+/// This function returns an error if the data type is not supported by the
+/// `PostgreSQL` nor the `ANSI` parsers.
+///
+/// [(1)]: crate::postgres::ast::data_types::DataType
+pub fn data_type(i: &[u8]) -> IResult<&[u8], DataType> {
+    alt((
+        map(keyword("UUID"), |_| DataType::Uuid),
+        map(ansi_data_type, DataType::Ansi),
+    ))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use pretty_assertions::assert_str_eq;
+    use test_case::test_case;
+
+    use crate::ansi::ast::data_types::{DataType as AnsiDataType, NumericTypeAlias};
+
+    use super::*;
+
+    #[test_case("UUID", DataType::Uuid)]
+    #[test_case(
+        "INT8",
+        DataType::Ansi(AnsiDataType::NumericAlias(NumericTypeAlias::Int8))
+    )]
+    #[test_case("DATE", DataType::Ansi(AnsiDataType::Date))]
+    fn parse_data_type(input: &str, expected: DataType) {
+        let (remaining, parsed) = data_type(input.as_ref()).unwrap();
+        assert_eq!(expected, parsed);
+        assert_str_eq!(input, parsed.to_string());
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn parse_data_type_uuid_whole_word() {
+        assert!(data_type(b"UUIDS").is_err());
+    }
+}
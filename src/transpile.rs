@@ -0,0 +1,779 @@
+use std::fmt;
+
+use crate::ansi::ast::common::{
+    ColumnDefinition as AnsiColumnDefinition, DropBehavior, IdentityColumnSpecification,
+    IdentityGeneration, LocalOrSchemaQualifier, ObjectName, TableName,
+};
+use crate::ansi::ast::create_table::{
+    CreateTable as AnsiCreateTable, TableContentsSource, TableElement, TableElementList,
+};
+use crate::ansi::ast::data_types::{
+    CharacterLength, DataType as AnsiDataType, WithOrWithoutTimeZone,
+};
+use crate::ansi::ast::drop_table::DropTable as AnsiDropTable;
+use crate::ansi::dialect::AnsiDialect;
+use crate::ansi::Statement as AnsiStatement;
+use crate::common::dialect::Dialect;
+use crate::mssql::ast::create_table::{
+    ColumnDefinition as MsSqlColumnDefinition, CreateTable as MsSqlCreateTable, Identity,
+};
+use crate::mssql::ast::data_types::{DataType as MsSqlDataType, Length};
+use crate::mssql::ast::drop_table::DropTable as MsSqlDropTable;
+use crate::mssql::dialect::MsSqlDialect;
+use crate::mssql::Statement as MsSqlStatement;
+use crate::postgres::ast::data_types::DataType as PostgresDataType;
+
+/// Dialects that statements can be transpiled from and to.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DialectKind {
+    /// `ANSI` SQL, parsed by the [`crate::ansi`] module.
+    Ansi,
+    /// `T-SQL` (SQL Server), parsed by the [`crate::mssql`] module.
+    MsSql,
+}
+
+/// Result of a successful transpilation.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transpiled {
+    /// Statement serialized in the target dialect.
+    sql: String,
+    /// Constructs that could not be translated exactly.
+    untranslatable: Vec<Untranslatable>,
+}
+
+/// Construct of the source statement without an exact equivalent in the
+/// target dialect, which was either replaced by the closest construct or
+/// dropped.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Untranslatable {
+    /// Source construct.
+    construct: String,
+    /// Construct used instead, if any.
+    opt_replacement: Option<String>,
+}
+
+/// Errors that prevent a statement from being transpiled.
+#[derive(thiserror::Error, Clone, Eq, PartialEq, Debug)]
+pub enum TranspileError {
+    /// The input is not a valid statement of the source dialect.
+    #[error("invalid {0} statement")]
+    InvalidStatement(DialectKind),
+    /// The statement uses a construct that can't be represented in the target
+    /// dialect, and can't be dropped without changing the statement meaning.
+    #[error("`{construct}` can't be represented in {dialect}")]
+    Unsupported {
+        construct: String,
+        dialect: DialectKind,
+    },
+}
+
+/// Transpiles a statement from one dialect to another, mapping dialect
+/// specific data types and clauses to their closest equivalent.
+///
+/// Every construct that had to be replaced or dropped is reported in the
+/// result [(1)].
+///
+/// # Errors
+/// If the statement can't be parsed in the source dialect, or if it uses a
+/// construct that can't be represented in the target dialect, this function
+/// call will fail.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::transpile::{transpile, DialectKind};
+/// let transpiled = transpile(
+///     "CREATE TABLE [dbo].[users] (id INT IDENTITY(1, 1), name NVARCHAR(MAX))",
+///     DialectKind::MsSql,
+///     DialectKind::Ansi,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     transpiled.sql(),
+///     "CREATE TABLE \"dbo\".\"users\" (id INT GENERATED BY DEFAULT AS IDENTITY \
+///      (START WITH 1 INCREMENT BY 1), name CLOB)"
+/// );
+/// assert_eq!(transpiled.untranslatable().len(), 1);
+/// ```
+///
+/// [(1)]: Transpiled::untranslatable
+pub fn transpile(
+    statement: &str,
+    from: DialectKind,
+    to: DialectKind,
+) -> Result<Transpiled, TranspileError> {
+    let mut untranslatable = Vec::new();
+
+    let sql = match from {
+        DialectKind::Ansi => {
+            let statement = parse_ansi(statement)?;
+            match to {
+                DialectKind::Ansi => statement.to_sql(to.dialect()),
+                DialectKind::MsSql => {
+                    ansi_to_mssql(statement, &mut untranslatable)?.to_sql(to.dialect())
+                }
+            }
+        }
+        DialectKind::MsSql => {
+            let statement = parse_mssql(statement)?;
+            match to {
+                DialectKind::Ansi => {
+                    mssql_to_ansi(statement, &mut untranslatable)?.to_sql(to.dialect())
+                }
+                DialectKind::MsSql => statement.to_sql(to.dialect()),
+            }
+        }
+    };
+
+    Ok(Transpiled {
+        sql,
+        untranslatable,
+    })
+}
+
+/// Transpiles a `PostgreSQL` data type to the type that a statement of the
+/// target dialect would declare, reporting the replacements as [`transpile`].
+///
+/// `UUID` is `UNIQUEIDENTIFIER` in `T-SQL`, and falls back to `CHAR(36)`, its
+/// text representation, in `ANSI`.
+///
+/// # Examples
+/// ```rust
+/// # use sql_helper::postgres::ast::data_types::DataType;
+/// # use sql_helper::transpile::{transpile_postgres_data_type, DialectKind};
+/// let transpiled = transpile_postgres_data_type(DataType::Uuid, DialectKind::Ansi);
+///
+/// assert_eq!(transpiled.sql(), "CHAR(36)");
+/// assert_eq!(transpiled.untranslatable().len(), 1);
+///
+/// let transpiled = transpile_postgres_data_type(DataType::Uuid, DialectKind::MsSql);
+///
+/// assert_eq!(transpiled.sql(), "UNIQUEIDENTIFIER");
+/// assert!(transpiled.untranslatable().is_empty());
+/// ```
+#[must_use]
+pub fn transpile_postgres_data_type(data_type: PostgresDataType, to: DialectKind) -> Transpiled {
+    let mut untranslatable = Vec::new();
+
+    let sql = match (data_type, to) {
+        (PostgresDataType::Ansi(data_type), DialectKind::Ansi) => data_type.to_string(),
+        (PostgresDataType::Ansi(data_type), DialectKind::MsSql) => {
+            ansi_to_mssql_data_type(data_type, &mut untranslatable).to_string()
+        }
+        (PostgresDataType::Uuid, DialectKind::Ansi) => {
+            untranslatable.push(Untranslatable::replaced(&data_type, &UUID_FALLBACK));
+            UUID_FALLBACK.to_string()
+        }
+        (PostgresDataType::Uuid, DialectKind::MsSql) => MsSqlDataType::Uniqueidentifier.to_string(),
+    };
+
+    Transpiled {
+        sql,
+        untranslatable,
+    }
+}
+
+/// `ANSI` type of the UUIDs, stored in their text representation (e.g.,
+/// `123e4567-e89b-12d3-a456-426614174000`).
+const UUID_FALLBACK: AnsiDataType = AnsiDataType::Char(Some(CharacterLength::new(36)));
+
+impl DialectKind {
+    /// Lexical rules of the dialect.
+    #[must_use]
+    pub fn dialect(self) -> &'static dyn Dialect {
+        match self {
+            Self::Ansi => &AnsiDialect,
+            Self::MsSql => &MsSqlDialect,
+        }
+    }
+}
+
+impl fmt::Display for DialectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ansi => write!(f, "ANSI")?,
+            Self::MsSql => write!(f, "T-SQL")?,
+        }
+
+        Ok(())
+    }
+}
+
+impl Transpiled {
+    #[must_use]
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    #[must_use]
+    pub fn untranslatable(&self) -> &[Untranslatable] {
+        &self.untranslatable
+    }
+}
+
+impl Untranslatable {
+    fn replaced(construct: &dyn fmt::Display, replacement: &dyn fmt::Display) -> Self {
+        Self {
+            construct: construct.to_string(),
+            opt_replacement: Some(replacement.to_string()),
+        }
+    }
+
+    fn dropped(construct: &dyn fmt::Display) -> Self {
+        Self {
+            construct: construct.to_string(),
+            opt_replacement: None,
+        }
+    }
+
+    #[must_use]
+    pub fn construct(&self) -> &str {
+        &self.construct
+    }
+
+    #[must_use]
+    pub fn opt_replacement(&self) -> Option<&str> {
+        self.opt_replacement.as_deref()
+    }
+}
+
+impl fmt::Display for Untranslatable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.opt_replacement() {
+            Some(replacement) => write!(f, "`{}` replaced by `{replacement}`", self.construct)?,
+            None => write!(f, "`{}` dropped", self.construct)?,
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_ansi(statement: &str) -> Result<AnsiStatement, TranspileError> {
+    match crate::ansi::parser::parse_statement(statement.as_bytes()) {
+        Ok((remaining, statement)) if remaining.trim_ascii().is_empty() => Ok(statement),
+        _ => Err(TranspileError::InvalidStatement(DialectKind::Ansi)),
+    }
+}
+
+fn parse_mssql(statement: &str) -> Result<MsSqlStatement, TranspileError> {
+    match crate::mssql::parser::parse_statement(statement.as_bytes()) {
+        Ok((remaining, statement)) if remaining.trim_ascii().is_empty() => Ok(statement),
+        _ => Err(TranspileError::InvalidStatement(DialectKind::MsSql)),
+    }
+}
+
+fn unsupported(construct: &dyn fmt::Display, dialect: DialectKind) -> TranspileError {
+    TranspileError::Unsupported {
+        construct: construct.to_string(),
+        dialect,
+    }
+}
+
+fn ansi_to_mssql(
+    statement: AnsiStatement,
+    untranslatable: &mut Vec<Untranslatable>,
+) -> Result<MsSqlStatement, TranspileError> {
+    match statement {
+        AnsiStatement::CreateTable(create_table) => Ok(MsSqlStatement::CreateTable(
+            ansi_to_mssql_create_table(&create_table, untranslatable)?,
+        )),
+        AnsiStatement::DropTable(drop_table) => Ok(MsSqlStatement::DropTable(
+            ansi_to_mssql_drop_table(&drop_table, untranslatable)?,
+        )),
+        AnsiStatement::CreateSchema(_) => Err(unsupported(&"CREATE SCHEMA", DialectKind::MsSql)),
+        AnsiStatement::DropSchema(_) => Err(unsupported(&"DROP SCHEMA", DialectKind::MsSql)),
+        AnsiStatement::AlterTable(_) => Err(unsupported(&"ALTER TABLE", DialectKind::MsSql)),
+        AnsiStatement::Open(_) => Err(unsupported(&"OPEN", DialectKind::MsSql)),
+        AnsiStatement::Fetch(_) => Err(unsupported(&"FETCH", DialectKind::MsSql)),
+        AnsiStatement::Close(_) => Err(unsupported(&"CLOSE", DialectKind::MsSql)),
+        AnsiStatement::Call(_) => Err(unsupported(&"CALL", DialectKind::MsSql)),
+        AnsiStatement::Return(_) => Err(unsupported(&"RETURN", DialectKind::MsSql)),
+        AnsiStatement::UpdatePositioned(_) => Err(unsupported(&"UPDATE", DialectKind::MsSql)),
+        AnsiStatement::DeletePositioned(_) => Err(unsupported(&"DELETE", DialectKind::MsSql)),
+        AnsiStatement::Explain(_) => Err(unsupported(&"EXPLAIN", DialectKind::MsSql)),
+        AnsiStatement::GrantPrivilege(_) => Err(unsupported(&"GRANT", DialectKind::MsSql)),
+        AnsiStatement::RevokePrivilege(_) => Err(unsupported(&"REVOKE", DialectKind::MsSql)),
+    }
+}
+
+fn ansi_to_mssql_create_table(
+    create_table: &AnsiCreateTable,
+    untranslatable: &mut Vec<Untranslatable>,
+) -> Result<MsSqlCreateTable, TranspileError> {
+    if let Some(table_scope) = create_table.opt_table_scope() {
+        return Err(unsupported(&table_scope, DialectKind::MsSql));
+    }
+
+    let TableContentsSource::TableElementList(table_element_list) =
+        create_table.table_contents_source();
+    let column_definitions = table_element_list
+        .element_list()
+        .iter()
+        .map(|table_element| match table_element {
+            TableElement::ColumnDefinition(column_definition) => {
+                ansi_to_mssql_column_definition(column_definition, untranslatable).map(Some)
+            }
+            // OBS: the T-SQL table AST has no constraints, so they are dropped.
+            TableElement::TableConstraintDefinition(table_constraint_definition) => {
+                untranslatable.push(Untranslatable::dropped(table_constraint_definition));
+                Ok(None)
+            }
+        })
+        .filter_map(Result::transpose)
+        .collect::<Result<_, _>>()?;
+    // OBS: the table options are dialect specific, so they have no meaning in
+    // T-SQL even when an option with the same name exists. T-SQL partitioning
+    // also needs a partition function and scheme, which aren't inferred.
+    untranslatable.extend(
+        create_table
+            .table_options()
+            .iter()
+            .map(|option| Untranslatable::dropped(option)),
+    );
+    if let Some(partition_by) = create_table.opt_partition_by() {
+        untranslatable.push(Untranslatable::dropped(partition_by));
+    }
+
+    Ok(MsSqlCreateTable::new(
+        ansi_to_mssql_table_name(create_table.table_name())?,
+        column_definitions,
+    ))
+}
+
+fn ansi_to_mssql_drop_table(
+    drop_table: &AnsiDropTable,
+    untranslatable: &mut Vec<Untranslatable>,
+) -> Result<MsSqlDropTable, TranspileError> {
+    // OBS: T-SQL never drops dependent objects, which is the `RESTRICT`
+    // behavior.
+    if drop_table.drop_behavior() == DropBehavior::Cascade {
+        untranslatable.push(Untranslatable::dropped(&DropBehavior::Cascade));
+    }
+
+    Ok(MsSqlDropTable::new(ansi_to_mssql_table_name(
+        drop_table.table_name(),
+    )?))
+}
+
+fn ansi_to_mssql_table_name(table_name: &TableName) -> Result<ObjectName, TranspileError> {
+    match table_name.opt_local_or_schema() {
+        Some(LocalOrSchemaQualifier::LocalQualifier(local_qualifier)) => Err(unsupported(
+            &format!("{local_qualifier}.{}", table_name.name()),
+            DialectKind::MsSql,
+        )),
+        _ => Ok(table_name.clone().into()),
+    }
+}
+
+fn ansi_to_mssql_column_definition(
+    column_definition: &AnsiColumnDefinition,
+    untranslatable: &mut Vec<Untranslatable>,
+) -> Result<MsSqlColumnDefinition, TranspileError> {
+    let Some(data_type) = column_definition.opt_data_type() else {
+        return Err(unsupported(
+            &format!("{column_definition} (column without data type)"),
+            DialectKind::MsSql,
+        ));
+    };
+
+    let mut mssql_column_definition = MsSqlColumnDefinition::new(
+        column_definition.column_name().clone(),
+        ansi_to_mssql_data_type(data_type, untranslatable),
+    );
+
+    if let Some(identity_column_specification) =
+        column_definition.opt_identity_column_specification()
+    {
+        let identity = ansi_to_mssql_identity(identity_column_specification);
+
+        if identity_column_specification.identity_generation() == IdentityGeneration::Always {
+            untranslatable.push(Untranslatable::replaced(
+                &identity_column_specification,
+                &identity,
+            ));
+        }
+
+        mssql_column_definition = mssql_column_definition.with_identity(identity);
+    }
+
+    untranslatable.extend(
+        column_definition
+            .column_constraint_definitions()
+            .iter()
+            .map(|column_constraint_definition| {
+                Untranslatable::dropped(column_constraint_definition)
+            }),
+    );
+
+    Ok(mssql_column_definition)
+}
+
+fn ansi_to_mssql_identity(identity_column_specification: IdentityColumnSpecification) -> Identity {
+    let opt_start_with = identity_column_specification.opt_start_with();
+    let opt_increment_by = identity_column_specification.opt_increment_by();
+
+    // OBS: T-SQL requires both seed and increment, which default to 1.
+    if opt_start_with.is_none() && opt_increment_by.is_none() {
+        Identity::new()
+    } else {
+        Identity::new()
+            .with_seed_and_increment(opt_start_with.unwrap_or(1), opt_increment_by.unwrap_or(1))
+    }
+}
+
+fn ansi_to_mssql_data_type(
+    data_type: AnsiDataType,
+    untranslatable: &mut Vec<Untranslatable>,
+) -> MsSqlDataType {
+    let mapped = match data_type {
+        AnsiDataType::Character(opt_len) => {
+            AnsiDataType::Character(opt_len.map(without_units)).into()
+        }
+        AnsiDataType::Char(opt_len) => AnsiDataType::Char(opt_len.map(without_units)).into(),
+        AnsiDataType::CharacterVarying(opt_len) => {
+            AnsiDataType::CharacterVarying(opt_len.map(without_units)).into()
+        }
+        AnsiDataType::CharVarying(opt_len) => {
+            AnsiDataType::CharVarying(opt_len.map(without_units)).into()
+        }
+        AnsiDataType::Varchar(opt_len) => {
+            MsSqlDataType::Varchar(opt_len.map(|len| Length::Fixed(len.length())))
+        }
+        AnsiDataType::CharacterLargeObject(_)
+        | AnsiDataType::CharLargeObject(_)
+        | AnsiDataType::Clob(_) => MsSqlDataType::Varchar(Some(Length::Max)),
+        AnsiDataType::BinaryLargeObject(_) | AnsiDataType::Blob(_) => {
+            MsSqlDataType::Varbinary(Some(Length::Max))
+        }
+        AnsiDataType::DecFloat(_) => AnsiDataType::Float.into(),
+        AnsiDataType::Boolean => MsSqlDataType::Bit,
+        AnsiDataType::Time(opt_precision, _) => {
+            AnsiDataType::Time(opt_precision, WithOrWithoutTimeZone::None).into()
+        }
+        // OBS: T-SQL `TIMESTAMP` is a row version, not a temporal type.
+        AnsiDataType::Timestamp(opt_precision, _) => MsSqlDataType::Datetime2(opt_precision),
+        AnsiDataType::NumericAlias(alias) => {
+            ansi_to_mssql_data_type(alias.data_type(), untranslatable)
+        }
+        data_type => data_type.into(),
+    };
+
+    if is_lossy_ansi_data_type(data_type) {
+        untranslatable.push(Untranslatable::replaced(&data_type, &mapped));
+    }
+
+    mapped
+}
+
+fn without_units(character_length: CharacterLength) -> CharacterLength {
+    CharacterLength::new(character_length.length())
+}
+
+fn is_lossy_ansi_data_type(data_type: AnsiDataType) -> bool {
+    match data_type {
+        AnsiDataType::Character(opt_len)
+        | AnsiDataType::Char(opt_len)
+        | AnsiDataType::CharacterVarying(opt_len)
+        | AnsiDataType::CharVarying(opt_len)
+        | AnsiDataType::Varchar(opt_len) => opt_len.is_some_and(|len| len.opt_units().is_some()),
+        AnsiDataType::CharacterLargeObject(_)
+        | AnsiDataType::CharLargeObject(_)
+        | AnsiDataType::Clob(_)
+        | AnsiDataType::BinaryLargeObject(_)
+        | AnsiDataType::Blob(_)
+        | AnsiDataType::DecFloat(_)
+        | AnsiDataType::Boolean => true,
+        AnsiDataType::Time(_, time_zone) | AnsiDataType::Timestamp(_, time_zone) => {
+            time_zone == WithOrWithoutTimeZone::WithTimeZone
+        }
+        _ => false,
+    }
+}
+
+fn mssql_to_ansi(
+    statement: MsSqlStatement,
+    untranslatable: &mut Vec<Untranslatable>,
+) -> Result<AnsiStatement, TranspileError> {
+    match statement {
+        MsSqlStatement::CreateTable(create_table) => Ok(AnsiStatement::CreateTable(
+            mssql_to_ansi_create_table(&create_table, untranslatable)?,
+        )),
+        MsSqlStatement::DropTable(drop_table) => Ok(AnsiStatement::DropTable(
+            mssql_to_ansi_drop_table(&drop_table, untranslatable)?,
+        )),
+    }
+}
+
+fn mssql_to_ansi_create_table(
+    create_table: &MsSqlCreateTable,
+    untranslatable: &mut Vec<Untranslatable>,
+) -> Result<AnsiCreateTable, TranspileError> {
+    let element_list = create_table
+        .column_definitions()
+        .iter()
+        .map(|column_definition| {
+            TableElement::ColumnDefinition(mssql_to_ansi_column_definition(
+                column_definition,
+                untranslatable,
+            ))
+        })
+        .collect();
+
+    Ok(AnsiCreateTable::new(
+        mssql_to_ansi_table_name(create_table.table_name())?,
+        TableContentsSource::TableElementList(TableElementList::new(element_list)),
+    ))
+}
+
+fn mssql_to_ansi_drop_table(
+    drop_table: &MsSqlDropTable,
+    untranslatable: &mut Vec<Untranslatable>,
+) -> Result<AnsiDropTable, TranspileError> {
+    if drop_table.if_exists() {
+        untranslatable.push(Untranslatable::dropped(&"IF EXISTS"));
+    }
+
+    Ok(AnsiDropTable::new(
+        mssql_to_ansi_table_name(drop_table.table_name())?,
+        DropBehavior::Restrict,
+    ))
+}
+
+/// Converts the name to an `ANSI` table name, which has no linked server
+/// qualifier.
+fn mssql_to_ansi_table_name(object_name: &ObjectName) -> Result<TableName, TranspileError> {
+    object_name
+        .to_table_name()
+        .ok_or_else(|| unsupported(object_name, DialectKind::Ansi))
+}
+
+fn mssql_to_ansi_column_definition(
+    column_definition: &MsSqlColumnDefinition,
+    untranslatable: &mut Vec<Untranslatable>,
+) -> AnsiColumnDefinition {
+    let mut ansi_column_definition =
+        AnsiColumnDefinition::new(column_definition.column_name().clone()).with_data_type(
+            mssql_to_ansi_data_type(column_definition.data_type(), untranslatable),
+        );
+
+    if let Some(identity) = column_definition.opt_identity() {
+        let mut identity_column_specification =
+            IdentityColumnSpecification::new(IdentityGeneration::ByDefault);
+
+        if let Some((seed, increment)) = identity.opt_seed_and_increment() {
+            identity_column_specification = identity_column_specification
+                .with_start_with(seed)
+                .with_increment_by(increment);
+        }
+
+        ansi_column_definition = ansi_column_definition
+            .with_identity_column_specification(identity_column_specification);
+    }
+
+    ansi_column_definition
+}
+
+fn mssql_to_ansi_data_type(
+    data_type: MsSqlDataType,
+    untranslatable: &mut Vec<Untranslatable>,
+) -> AnsiDataType {
+    let (mapped, lossy) = match data_type {
+        MsSqlDataType::Ansi(data_type) => (data_type, false),
+        MsSqlDataType::NChar(opt_len) => (
+            AnsiDataType::Character(opt_len.map(CharacterLength::new)),
+            true,
+        ),
+        MsSqlDataType::NVarchar(Some(Length::Max)) | MsSqlDataType::Varchar(Some(Length::Max)) => {
+            (AnsiDataType::Clob(None), true)
+        }
+        MsSqlDataType::NVarchar(opt_len) => (AnsiDataType::Varchar(fixed_length(opt_len)), true),
+        MsSqlDataType::Varchar(opt_len) => (AnsiDataType::Varchar(fixed_length(opt_len)), false),
+        // OBS: `ANSI` has no spatial types, so the geometries are kept as
+        // binary objects.
+        MsSqlDataType::Varbinary(Some(Length::Max)) | MsSqlDataType::Spatial(_) => {
+            (AnsiDataType::Blob(None), true)
+        }
+        MsSqlDataType::Varbinary(opt_len) => (
+            AnsiDataType::Varbinary(fixed_length(opt_len).map(|len| len.length())),
+            false,
+        ),
+        MsSqlDataType::Bit => (AnsiDataType::Boolean, true),
+        MsSqlDataType::Tinyint => (AnsiDataType::Smallint, true),
+        MsSqlDataType::Datetime => (
+            AnsiDataType::Timestamp(Some(3), WithOrWithoutTimeZone::None),
+            true,
+        ),
+        MsSqlDataType::Datetime2(opt_precision) => (
+            AnsiDataType::Timestamp(opt_precision, WithOrWithoutTimeZone::None),
+            false,
+        ),
+        MsSqlDataType::Uniqueidentifier => (UUID_FALLBACK, true),
+    };
+
+    if lossy {
+        untranslatable.push(Untranslatable::replaced(&data_type, &mapped));
+    }
+
+    mapped
+}
+
+fn fixed_length(opt_len: Option<Length>) -> Option<CharacterLength> {
+    match opt_len {
+        Some(Length::Fixed(len)) => Some(CharacterLength::new(len)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(
+        "CREATE TABLE [dbo].[users] (id INT IDENTITY(1, 1), name NVARCHAR(MAX))",
+        "CREATE TABLE \"dbo\".\"users\" (id INT GENERATED BY DEFAULT AS IDENTITY \
+         (START WITH 1 INCREMENT BY 1), name CLOB)",
+        &["`NVARCHAR(MAX)` replaced by `CLOB`"] ;
+        "identity and max length"
+    )]
+    #[test_case(
+        "CREATE TABLE t (a BIT, b TINYINT, c DATETIME2(7), d VARCHAR(20), e VARBINARY(MAX))",
+        "CREATE TABLE t (a BOOLEAN, b SMALLINT, c TIMESTAMP(7), d VARCHAR(20), e BLOB)",
+        &[
+            "`BIT` replaced by `BOOLEAN`",
+            "`TINYINT` replaced by `SMALLINT`",
+            "`VARBINARY(MAX)` replaced by `BLOB`",
+        ] ;
+        "data types"
+    )]
+    #[test_case(
+        "CREATE TABLE t (id UNIQUEIDENTIFIER)",
+        "CREATE TABLE t (id CHAR(36))",
+        &["`UNIQUEIDENTIFIER` replaced by `CHAR(36)`"] ;
+        "uniqueidentifier"
+    )]
+    #[test_case(
+        "CREATE TABLE t (shape GEOMETRY)",
+        "CREATE TABLE t (shape BLOB)",
+        &["`GEOMETRY` replaced by `BLOB`"] ;
+        "spatial type"
+    )]
+    #[test_case(
+        "DROP TABLE IF EXISTS [my table]",
+        "DROP TABLE \"my table\" RESTRICT",
+        &["`IF EXISTS` dropped"] ;
+        "drop table"
+    )]
+    fn mssql_to_ansi(input: &str, expected: &str, expected_untranslatable: &[&str]) {
+        let transpiled = transpile(input, DialectKind::MsSql, DialectKind::Ansi).unwrap();
+
+        assert_str_eq!(expected, transpiled.sql());
+        assert_eq!(
+            expected_untranslatable,
+            transpiled
+                .untranslatable()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test_case(
+        "CREATE TABLE \"users\" (id INT GENERATED BY DEFAULT AS IDENTITY (START WITH 1 INCREMENT BY 1))",
+        "CREATE TABLE [users] (id INT IDENTITY(1, 1))",
+        &[] ;
+        "identity"
+    )]
+    #[test_case(
+        "CREATE TABLE t (id BIGINT GENERATED ALWAYS AS IDENTITY, a BOOLEAN, b CLOB, c TIMESTAMP(3))",
+        "CREATE TABLE t (id BIGINT IDENTITY, a BIT, b VARCHAR(MAX), c DATETIME2(3))",
+        &[
+            "`GENERATED ALWAYS AS IDENTITY` replaced by `IDENTITY`",
+            "`BOOLEAN` replaced by `BIT`",
+            "`CLOB` replaced by `VARCHAR(MAX)`",
+        ] ;
+        "data types"
+    )]
+    #[test_case(
+        "CREATE TABLE t (id INT) ENGINE=InnoDB TABLESPACE ts",
+        "CREATE TABLE t (id INT)",
+        &["`ENGINE=InnoDB` dropped", "`TABLESPACE ts` dropped"] ;
+        "table options"
+    )]
+    #[test_case(
+        "CREATE TABLE t (id INT) PARTITION BY HASH (id) PARTITIONS 4",
+        "CREATE TABLE t (id INT)",
+        &["`PARTITION BY HASH (id) PARTITIONS 4` dropped"] ;
+        "partition clause"
+    )]
+    #[test_case(
+        "CREATE TABLE t (id INT NOT NULL REFERENCES u, a INT, PRIMARY KEY (id))",
+        "CREATE TABLE t (id INT, a INT)",
+        &["`NOT NULL` dropped", "`REFERENCES u` dropped", "`PRIMARY KEY (id)` dropped"] ;
+        "constraints"
+    )]
+    #[test_case(
+        "DROP TABLE sch.t CASCADE",
+        "DROP TABLE sch.t",
+        &["`CASCADE` dropped"] ;
+        "drop table"
+    )]
+    fn ansi_to_mssql(input: &str, expected: &str, expected_untranslatable: &[&str]) {
+        let transpiled = transpile(input, DialectKind::Ansi, DialectKind::MsSql).unwrap();
+
+        assert_str_eq!(expected, transpiled.sql());
+        assert_eq!(
+            expected_untranslatable,
+            transpiled
+                .untranslatable()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test_case("CREATE SCHEMA sch", "CREATE SCHEMA" ; "create schema")]
+    #[test_case(
+        "CREATE GLOBAL TEMPORARY TABLE t (id INT)",
+        "GLOBAL TEMPORARY" ;
+        "temporary table"
+    )]
+    #[test_case("CREATE TABLE t (id)", "id (column without data type)" ; "no data type")]
+    fn ansi_to_mssql_unsupported(input: &str, construct: &str) {
+        assert_eq!(
+            transpile(input, DialectKind::Ansi, DialectKind::MsSql),
+            Err(TranspileError::Unsupported {
+                construct: construct.to_string(),
+                dialect: DialectKind::MsSql,
+            })
+        );
+    }
+
+    #[test]
+    fn mssql_to_ansi_linked_server() {
+        assert_eq!(
+            transpile(
+                "DROP TABLE srv.db.dbo.tb",
+                DialectKind::MsSql,
+                DialectKind::Ansi
+            ),
+            Err(TranspileError::Unsupported {
+                construct: "srv.db.dbo.tb".to_string(),
+                dialect: DialectKind::Ansi,
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_statement() {
+        assert_eq!(
+            transpile("CREATE TABLE", DialectKind::MsSql, DialectKind::Ansi),
+            Err(TranspileError::InvalidStatement(DialectKind::MsSql))
+        );
+    }
+}
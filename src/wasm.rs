@@ -0,0 +1,115 @@
+//! `WebAssembly` bindings, exported through [wasm-bindgen].
+//!
+//! Errors are raised as JavaScript `Error`s, with the line and column of the
+//! invalid statement in the message.
+//!
+//! [wasm-bindgen]: https://rustwasm.github.io/wasm-bindgen/
+use wasm_bindgen::prelude::*;
+
+use crate::ansi::format::{format_script, FormatOptions};
+use crate::ansi::parser::{parse_script, ScriptError};
+use crate::ansi::Statement;
+
+/// Parses the script, returning its statements as JavaScript objects.
+///
+/// # Errors
+/// If any statement of the script can't be parsed.
+#[wasm_bindgen]
+pub fn parse(sql: &str) -> Result<JsValue, JsError> {
+    let statements = statements(sql).map_err(|err| JsError::new(&err))?;
+
+    serde_wasm_bindgen::to_value(&statements).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// Parses the script, returning its statements as a JSON array, where each
+/// statement is in the versioned JSON format [(1)].
+///
+/// # Errors
+/// If any statement of the script can't be parsed.
+///
+/// [(1)]: crate::ansi::json
+#[wasm_bindgen(js_name = parseToJson)]
+pub fn parse_to_json(sql: &str) -> Result<String, JsError> {
+    to_json(sql).map_err(|err| JsError::new(&err))
+}
+
+/// Pretty prints the script, rendering each table element in its own line.
+///
+/// # Errors
+/// If any statement of the script can't be parsed.
+#[wasm_bindgen]
+pub fn format(sql: &str) -> Result<String, JsError> {
+    pretty_print(sql).map_err(|err| JsError::new(&err))
+}
+
+fn statements(sql: &str) -> Result<Vec<Statement>, String> {
+    parse_script(sql)
+        .map(|statements| {
+            statements
+                .into_iter()
+                .map(|(statement, _)| statement)
+                .collect()
+        })
+        .map_err(|err| error_message(sql, err))
+}
+
+fn to_json(sql: &str) -> Result<String, String> {
+    let statements = statements(sql)?
+        .iter()
+        .map(Statement::to_json)
+        .collect::<Vec<_>>();
+
+    Ok(format!("[{}]", statements.join(",")))
+}
+
+fn pretty_print(sql: &str) -> Result<String, String> {
+    let options = FormatOptions::new().with_element_per_line(true);
+
+    format_script(sql, &options).map_err(|err| error_message(sql, err))
+}
+
+fn error_message(sql: &str, err: ScriptError) -> String {
+    let (line, column) = err.span().start_line_column(sql);
+
+    format!("{line}:{column}: {err}")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::ansi::json::WIRE_FORMAT_VERSION;
+
+    #[test]
+    fn json_export() {
+        let json = to_json("DROP TABLE tb CASCADE; DROP SCHEMA sch RESTRICT").unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value[0]["version"], WIRE_FORMAT_VERSION);
+        assert_eq!(
+            value[0]["statement"]["DropTable"]["drop_behavior"],
+            "Cascade"
+        );
+        assert_eq!(
+            value[1]["statement"]["DropSchema"]["drop_behavior"],
+            "Restrict"
+        );
+    }
+
+    #[test]
+    fn pretty_printing() {
+        assert_str_eq!(
+            "CREATE TABLE tb (\n    id INT\n);\n",
+            pretty_print("create table tb (id int)").unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_statement() {
+        assert_eq!(
+            Err("2:1: invalid statement at 22..30".to_string()),
+            to_json("DROP TABLE a CASCADE;\nSELECT 1")
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use spectral::assert_that;
+use sql_helper::ansi;
+use sql_helper::ansi::ast::alter_table::{AlterColumnAction, AlterTableAction};
+use sql_helper::ansi::ast::common::{ColumnDefinition, DropBehavior, SchemaName, TableName};
+use sql_helper::ansi::ast::data_types::{CharacterLength, DataType};
+use sql_helper::ansi::Statement;
+use sql_helper::common::Ident;
+
+use crate::common::verified_stmt;
+
+pub mod common;
+
+#[test]
+fn test_alter_table_add_column() {
+    match verified_stmt("ALTER TABLE table_name ADD COLUMN column_name INTEGER") {
+        Statement::AlterTable(alter_table) => {
+            assert_that!(alter_table.table_name()).is_equal_to(&TableName::new("table_name"));
+            assert_that!(alter_table.alter_table_action()).is_equal_to(
+                &AlterTableAction::AddColumn(
+                    ColumnDefinition::new("column_name").with_data_type(DataType::Integer),
+                ),
+            );
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_alter_table_alter_column() {
+    match verified_stmt(
+        "ALTER TABLE schema_name.table_name ALTER COLUMN column_name SET DATA TYPE VARCHAR(20)",
+    ) {
+        Statement::AlterTable(alter_table) => {
+            let expected_tb = TableName::new("table_name").with_local_or_schema(
+                ansi::ast::common::LocalOrSchemaQualifier::Schema(SchemaName::new("schema_name")),
+            );
+            assert_that!(alter_table.table_name()).is_equal_to(&expected_tb);
+            assert_that!(alter_table.alter_table_action()).is_equal_to(
+                &AlterTableAction::AlterColumn(
+                    Ident::new(b"column_name"),
+                    AlterColumnAction::SetDataType(DataType::Varchar(Some(CharacterLength::new(
+                        20,
+                    )))),
+                ),
+            );
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt("ALTER TABLE table_name ALTER COLUMN column_name DROP IDENTITY") {
+        Statement::AlterTable(alter_table) => {
+            assert_that!(alter_table.alter_table_action()).is_equal_to(
+                &AlterTableAction::AlterColumn(
+                    Ident::new(b"column_name"),
+                    AlterColumnAction::DropIdentity,
+                ),
+            );
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_alter_table_drop_column() {
+    match verified_stmt("ALTER TABLE table_name DROP COLUMN column_name RESTRICT") {
+        Statement::AlterTable(alter_table) => {
+            assert_that!(alter_table.alter_table_action()).is_equal_to(
+                &AlterTableAction::DropColumn(Ident::new(b"column_name"), DropBehavior::Restrict),
+            );
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_alter_table_optional_column_keyword() {
+    let (_, statement) = ansi::parser::parse_statement(b"alter table tb add id INT; ").unwrap();
+    assert_that!(statement.to_string()).is_equal_to("ALTER TABLE tb ADD COLUMN id INT".to_string());
+
+    let (_, statement) = ansi::parser::parse_statement(b"ALTER TABLE tb DROP id CASCADE").unwrap();
+    assert_that!(statement.to_string())
+        .is_equal_to("ALTER TABLE tb DROP COLUMN id CASCADE".to_string());
+}
+
+#[test]
+fn test_alter_table_rename() {
+    match verified_stmt("ALTER TABLE tb RENAME TO sch.tb2") {
+        Statement::AlterTable(alter_table) => {
+            assert_that!(alter_table.alter_table_action()).is_equal_to(
+                &AlterTableAction::RenameTable(TableName::new("tb2").with_local_or_schema(
+                    ansi::ast::common::LocalOrSchemaQualifier::Schema(SchemaName::new("sch")),
+                )),
+            );
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt("ALTER TABLE tb RENAME COLUMN a TO b") {
+        Statement::AlterTable(alter_table) => {
+            assert_that!(alter_table.alter_table_action()).is_equal_to(
+                &AlterTableAction::RenameColumn(Ident::new(b"a"), Ident::new(b"b")),
+            );
+        }
+        _ => unreachable!(),
+    };
+
+    let (_, statement) = ansi::parser::parse_statement(b"alter table tb rename a to b").unwrap();
+    assert_that!(statement.to_string())
+        .is_equal_to("ALTER TABLE tb RENAME COLUMN a TO b".to_string());
+}
+
+#[test]
+fn test_rename_table() {
+    let (_, statement) = ansi::parser::parse_statement(b"RENAME TABLE a TO b;").unwrap();
+    assert_that!(statement.to_string()).is_equal_to("ALTER TABLE a RENAME TO b".to_string());
+
+    assert!(ansi::parser::parse_statement(b"RENAME TABLE a").is_err());
+}
+
+#[test]
+fn test_alter_table_missing_drop_behavior() {
+    assert!(ansi::parser::parse_statement(b"ALTER TABLE tb DROP COLUMN id").is_err());
+}
@@ -1,5 +1,14 @@
 use test_case::test_case;
 
+use sql_helper::ansi::ast::common::{IdentityGeneration, IdentitySyntax};
+use sql_helper::ansi::ast::create_table::{
+    PartitionMethod, PartitionValues, TableContentsSource, TableElement,
+};
+use sql_helper::ansi::ast::data_types::DataType;
+use sql_helper::ansi::parser::parse_statement;
+use sql_helper::ansi::Statement;
+use sql_helper::common::Ident;
+
 use crate::common::verified_stmt;
 
 pub mod common;
@@ -7,15 +16,120 @@ pub mod common;
 #[test_case("CREATE TABLE table_name (id INT)")]
 #[test_case("CREATE GLOBAL TEMPORARY TABLE table_name (id INT)")]
 #[test_case("CREATE LOCAL TEMPORARY TABLE table_name (id INT, name VARCHAR(20))")]
+#[test_case("CREATE TABLE tb (id INT) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4" ; "mysql table options")]
+#[test_case("CREATE TABLE tb (id INT) TABLESPACE ts" ; "tablespace")]
+#[test_case("CREATE TABLE tb (id INT) WITH (fillfactor=70)" ; "postgres storage parameters")]
+#[test_case("CREATE TABLE tb (id INT, PRIMARY KEY (id))" ; "primary key")]
+#[test_case("CREATE TABLE tb (a INT, b INT, CONSTRAINT uq UNIQUE (a DESC, b))" ; "unique constraint")]
+#[test_case("CREATE TABLE tb (id SERIAL PRIMARY KEY, name VARCHAR(20) NOT NULL UNIQUE)" ; "column constraints")]
+#[test_case("CREATE TABLE tb (a INT REFERENCES sch.u (b) ON DELETE CASCADE)" ; "column foreign key")]
+#[test_case("CREATE TABLE tb (a INT, FOREIGN KEY (a) REFERENCES u (b, PERIOD p))" ; "table foreign key")]
+#[test_case("CREATE TABLE tb (id INT) PARTITION BY RANGE (id)" ; "partition by")]
+#[test_case("CREATE TABLE tb (id INT) COMMENT='it''s a table'" ; "quoted option value")]
+#[test_case("CREATE TABLE tb (id INT) PARTITION BY HASH (id) PARTITIONS 4" ; "hash partitions")]
+#[test_case(
+    "CREATE TABLE tb (id INT) ENGINE=InnoDB PARTITION BY RANGE (YEAR(created)) \
+     (PARTITION p0 VALUES LESS THAN (2020) ENGINE=InnoDB, PARTITION p1 VALUES LESS THAN MAXVALUE)" ;
+    "mysql range partitions"
+)]
+#[test_case(
+    "CREATE TABLE tb (a INT, b INT) PARTITION BY RANGE COLUMNS (a, b) \
+     (PARTITION p0 VALUES LESS THAN (10, MAXVALUE))" ;
+    "mysql range columns partitions"
+)]
+#[test_case(
+    "CREATE TABLE tb (region VARCHAR(2)) PARTITION BY LIST COLUMNS (region) \
+     (PARTITION eu VALUES IN ('PT', 'FR'), PARTITION other VALUES IN (NULL))" ;
+    "mysql list partitions"
+)]
+#[test_case(
+    "CREATE TABLE tb (logdate DATE) PARTITION BY RANGE (logdate) TABLESPACE ts" ;
+    "postgres partition before options"
+)]
+#[test_case("CREATE TABLE tb (name VARCHAR(20)) PARTITION BY LIST ((lower(name)))" ; "expression key")]
+#[test_case("CREATE TABLE tb (id INT) PARTITION BY KEY (id)" ; "unstructured partition clause")]
+#[test_case("CREATE TABLE tb (id SERIAL, big_id BIGSERIAL, small_id SMALLSERIAL)" ; "serial types")]
+#[test_case("CREATE TABLE tb (id INT AUTO_INCREMENT)" ; "auto increment")]
 fn test_create_table(input: &str) {
     verified_stmt(input);
 }
 
+#[test_case("CREATE TABLE tb(id INT)", "CREATE TABLE tb (id INT)" ; "no space")]
+#[test_case(
+    "CREATE LOCAL TEMPORARY TABLE sch.tb\n(id INT)",
+    "CREATE LOCAL TEMPORARY TABLE sch.tb (id INT)" ;
+    "newline"
+)]
+fn test_create_table_whitespace(input: &str, expected: &str) {
+    let (_, stmt) = parse_statement(input.as_ref()).unwrap();
+    assert_eq!(expected, stmt.to_string());
+}
+
 #[should_panic]
 #[test_case("CREATE TABLE (id INT)")]
 #[test_case("CREATE TABLE GLOBAL tb (id INT)")]
 #[test_case("CREATE TABLE LOCAL tb (id INT)")]
 #[test_case("CREATE TABLE tb ()")]
+#[test_case("CREATE TABLE tb (id INT) PARTITION BY HASH (id) PARTITION BY HASH (id)" ; "duplicate partition clause")]
 fn test_create_table_should_fail(input: &str) {
     verified_stmt(input);
 }
+
+#[test]
+fn test_create_table_partition_by() {
+    let Statement::CreateTable(create_table) = verified_stmt(
+        "CREATE TABLE tb (a INT, b INT) ENGINE=InnoDB PARTITION BY RANGE COLUMNS (a, b) \
+         (PARTITION p0 VALUES LESS THAN (10, 20), PARTITION p1 VALUES LESS THAN MAXVALUE)",
+    ) else {
+        panic!("not a create table statement");
+    };
+    let partition_by = create_table.opt_partition_by().unwrap();
+
+    assert_eq!(create_table.partition_by_position(), 1);
+    assert_eq!(partition_by.partition_method(), PartitionMethod::Range);
+    assert_eq!(
+        partition_by.column_names(),
+        vec![&Ident::new(b"a"), &Ident::new(b"b")]
+    );
+    assert_eq!(
+        partition_by
+            .partition_definitions()
+            .iter()
+            .map(|definition| definition.name().to_string())
+            .collect::<Vec<_>>(),
+        vec!["p0", "p1"]
+    );
+    assert_eq!(
+        partition_by.partition_definitions()[1].opt_partition_values(),
+        Some(&PartitionValues::LessThanMaxValue)
+    );
+}
+
+#[test_case("CREATE TABLE tb (id BIGSERIAL)", IdentitySyntax::Serial ; "serial")]
+#[test_case("CREATE TABLE tb (id BIGINT AUTO_INCREMENT)", IdentitySyntax::AutoIncrement ; "auto increment")]
+#[test_case(
+    "CREATE TABLE tb (id BIGINT GENERATED BY DEFAULT AS IDENTITY)",
+    IdentitySyntax::Standard ;
+    "standard"
+)]
+fn test_create_table_identity_column(input: &str, syntax: IdentitySyntax) {
+    let Statement::CreateTable(create_table) = verified_stmt(input) else {
+        panic!("not a create table statement");
+    };
+    let TableContentsSource::TableElementList(table_element_list) =
+        create_table.table_contents_source();
+    let TableElement::ColumnDefinition(column_definition) = &table_element_list.element_list()[0]
+    else {
+        panic!("not a column definition");
+    };
+    let identity_column_specification = column_definition
+        .opt_identity_column_specification()
+        .unwrap();
+
+    assert_eq!(column_definition.opt_data_type(), Some(DataType::Bigint));
+    assert_eq!(
+        identity_column_specification.identity_generation(),
+        IdentityGeneration::ByDefault
+    );
+    assert_eq!(identity_column_specification.syntax(), syntax);
+}
@@ -0,0 +1,94 @@
+use spectral::assert_that;
+use sql_helper::ansi::ast::common::{Literal, TableName, ValueSpecification};
+use sql_helper::ansi::ast::fetch::{FetchOrientation, TargetSpecification};
+use sql_helper::ansi::ast::update::{SetClause, UpdateSource};
+use sql_helper::ansi::Statement;
+use sql_helper::common::Ident;
+
+use crate::common::verified_stmt;
+
+pub mod common;
+
+#[test]
+fn test_open() {
+    match verified_stmt("OPEN cursor_name") {
+        Statement::Open(open) => {
+            assert_that!(open.cursor_name()).is_equal_to(&Ident::new(b"cursor_name"));
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_fetch() {
+    match verified_stmt("FETCH cursor_name INTO :id, name") {
+        Statement::Fetch(fetch) => {
+            assert_that!(fetch.opt_fetch_orientation()).is_equal_to(None);
+            assert_that!(fetch.cursor_name()).is_equal_to(&Ident::new(b"cursor_name"));
+            assert_that!(fetch.target_list()).is_equal_to(
+                &[
+                    TargetSpecification::HostParameter(Ident::new(b"id")),
+                    TargetSpecification::SqlParameter(Ident::new(b"name")),
+                ][..],
+            );
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt("FETCH LAST FROM cursor_name INTO :id") {
+        Statement::Fetch(fetch) => {
+            assert_that!(fetch.opt_fetch_orientation()).is_equal_to(Some(FetchOrientation::Last));
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt("FETCH RELATIVE -2 FROM cursor_name INTO :id") {
+        Statement::Fetch(fetch) => {
+            assert_that!(fetch.opt_fetch_orientation())
+                .is_equal_to(Some(FetchOrientation::Relative(-2)));
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_close() {
+    match verified_stmt("CLOSE \"cursor name\"") {
+        Statement::Close(close) => {
+            assert_that!(close.cursor_name().value()).is_equal_to("cursor name");
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_update_positioned() {
+    match verified_stmt("UPDATE table_name SET id = 1, name = DEFAULT WHERE CURRENT OF cursor_name")
+    {
+        Statement::UpdatePositioned(update) => {
+            assert_that!(update.table_name()).is_equal_to(&TableName::new("table_name"));
+            assert_that!(update.set_clause_list()).is_equal_to(
+                &[
+                    SetClause::new(
+                        "id",
+                        UpdateSource::Value(ValueSpecification::Literal(Literal::Numeric(
+                            "1".to_string(),
+                        ))),
+                    ),
+                    SetClause::new("name", UpdateSource::Default),
+                ][..],
+            );
+            assert_that!(update.cursor_name()).is_equal_to(&Ident::new(b"cursor_name"));
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_delete_positioned() {
+    match verified_stmt("DELETE FROM table_name WHERE CURRENT OF cursor_name") {
+        Statement::DeletePositioned(delete) => {
+            assert_that!(delete.table_name()).is_equal_to(&TableName::new("table_name"));
+            assert_that!(delete.cursor_name()).is_equal_to(&Ident::new(b"cursor_name"));
+        }
+        _ => unreachable!(),
+    };
+}
@@ -5,7 +5,6 @@ use sql_helper::ansi::ast::common::{
 };
 use sql_helper::ansi::ast::drop_table::DropTable;
 use sql_helper::ansi::Statement;
-use sql_helper::common::Ident;
 
 use crate::common::verified_stmt;
 
@@ -27,7 +26,7 @@ pub fn parse_drop_table(input: &str) -> DropTable {
 fn test_drop_schema() {
     match verified_stmt("DROP TABLE table_name CASCADE") {
         Statement::DropTable(drop_table) => {
-            let expected_tb = TableName::new(&Ident::new(b"table_name"));
+            let expected_tb = TableName::new("table_name");
             assert_that!(drop_table.table_name()).is_equal_to(&expected_tb);
             assert_that!(drop_table.drop_behavior()).is_equal_to(DropBehavior::Cascade);
         }
@@ -35,7 +34,7 @@ fn test_drop_schema() {
     };
     match verified_stmt("DROP TABLE table_name RESTRICT") {
         Statement::DropTable(drop_table) => {
-            let expected_tb = TableName::new(&Ident::new(b"table_name"));
+            let expected_tb = TableName::new("table_name");
             assert_that!(drop_table.table_name()).is_equal_to(&expected_tb);
             assert_that!(drop_table.drop_behavior()).is_equal_to(DropBehavior::Restrict);
         }
@@ -43,10 +42,9 @@ fn test_drop_schema() {
     };
     match verified_stmt("DROP TABLE MODULE.table_name CASCADE") {
         Statement::DropTable(drop_table) => {
-            let mut expected_tb = TableName::new(&Ident::new(b"table_name"));
-            expected_tb.with_local_or_schema(LocalOrSchemaQualifier::LocalQualifier(
-                LocalQualifier::Module,
-            ));
+            let expected_tb = TableName::new("table_name").with_local_or_schema(
+                LocalOrSchemaQualifier::LocalQualifier(LocalQualifier::Module),
+            );
             assert_that!(drop_table.table_name()).is_equal_to(&expected_tb);
             assert_that!(drop_table.drop_behavior()).is_equal_to(DropBehavior::Cascade);
         }
@@ -54,11 +52,9 @@ fn test_drop_schema() {
     };
     match verified_stmt("DROP TABLE schema_name.table_name CASCADE") {
         Statement::DropTable(drop_table) => {
-            let mut expected_tb = TableName::new(&Ident::new(b"table_name"));
-            expected_tb.with_local_or_schema(LocalOrSchemaQualifier::Schema(SchemaName::new(
-                None,
-                &Ident::new(b"schema_name"),
-            )));
+            let expected_tb = TableName::new("table_name").with_local_or_schema(
+                LocalOrSchemaQualifier::Schema(SchemaName::new("schema_name")),
+            );
             assert_that!(drop_table.table_name()).is_equal_to(&expected_tb);
             assert_that!(drop_table.drop_behavior()).is_equal_to(DropBehavior::Cascade);
         }
@@ -66,11 +62,10 @@ fn test_drop_schema() {
     };
     match verified_stmt("DROP TABLE catalog_name.schema_name.table_name CASCADE") {
         Statement::DropTable(drop_table) => {
-            let mut expected_tb = TableName::new(&Ident::new(b"table_name"));
-            expected_tb.with_local_or_schema(LocalOrSchemaQualifier::Schema(SchemaName::new(
-                Some(&Ident::new(b"catalog_name")),
-                &Ident::new(b"schema_name"),
-            )));
+            let expected_tb =
+                TableName::new("table_name").with_local_or_schema(LocalOrSchemaQualifier::Schema(
+                    SchemaName::new("schema_name").with_catalog_name("catalog_name"),
+                ));
             assert_that!(drop_table.table_name()).is_equal_to(&expected_tb);
             assert_that!(drop_table.drop_behavior()).is_equal_to(DropBehavior::Cascade);
         }
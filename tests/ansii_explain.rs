@@ -0,0 +1,28 @@
+use spectral::assert_that;
+use sql_helper::ansi::ast::close::Close;
+use sql_helper::ansi::Statement;
+use sql_helper::common::Ident;
+
+use crate::common::verified_stmt;
+
+pub mod common;
+
+#[test]
+fn test_explain() {
+    match verified_stmt("EXPLAIN CLOSE cursor_name") {
+        Statement::Explain(explain) => {
+            assert_that!(explain.analyze()).is_equal_to(false);
+            assert_that!(explain.statement())
+                .is_equal_to(&Statement::Close(Close::new(Ident::new(b"cursor_name"))));
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt("EXPLAIN ANALYZE DROP TABLE table_name CASCADE") {
+        Statement::Explain(explain) => {
+            assert_that!(explain.analyze()).is_equal_to(true);
+            assert_that!(explain.statement())
+                .matches(|statement| matches!(statement, Statement::DropTable(_)));
+        }
+        _ => unreachable!(),
+    };
+}
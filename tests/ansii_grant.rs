@@ -0,0 +1,98 @@
+use spectral::assert_that;
+use sql_helper::ansi::ast::common::{
+    ColumnNameList, DropBehavior, RoutineName, SchemaName, SchemaQualifiedName, TableName,
+};
+use sql_helper::ansi::ast::data_types::DataType;
+use sql_helper::ansi::ast::grant::{
+    Action, GrantObject, Grantee, Grantor, ObjectPrivileges, RoutineType, SpecificRoutineDesignator,
+};
+use sql_helper::ansi::ast::revoke::RevokeOptionExtension;
+use sql_helper::ansi::Statement;
+use sql_helper::common::Ident;
+
+use crate::common::verified_stmt;
+
+pub mod common;
+
+#[test]
+fn test_grant_privilege() {
+    match verified_stmt("GRANT SELECT (column_name), DELETE ON table_name TO grantee") {
+        Statement::GrantPrivilege(grant) => {
+            assert_that!(grant.privileges().object_privileges()).is_equal_to(
+                &ObjectPrivileges::Actions(vec![
+                    Action::Select(Some(ColumnNameList::new(vec![Ident::new(b"column_name")]))),
+                    Action::Delete,
+                ]),
+            );
+            assert_that!(grant.privileges().object())
+                .is_equal_to(&GrantObject::Table(TableName::new("table_name")));
+            assert_that!(grant.grantees())
+                .is_equal_to(&[Grantee::AuthorizationIdentifier(Ident::new(b"grantee"))][..]);
+            assert_that!(grant.hierarchy_option()).is_equal_to(false);
+            assert_that!(grant.grant_option()).is_equal_to(false);
+            assert_that!(grant.opt_grantor()).is_equal_to(None);
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt("GRANT USAGE ON DOMAIN schema_name.domain_name TO a, b WITH GRANT OPTION") {
+        Statement::GrantPrivilege(grant) => {
+            assert_that!(grant.privileges().object()).is_equal_to(&GrantObject::Domain(
+                SchemaQualifiedName::new("domain_name")
+                    .with_schema_name(SchemaName::new("schema_name")),
+            ));
+            assert_that!(grant.grant_option()).is_equal_to(true);
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt(
+        "GRANT UNDER ON TYPE type_name TO PUBLIC WITH HIERARCHY OPTION GRANTED BY CURRENT_ROLE",
+    ) {
+        Statement::GrantPrivilege(grant) => {
+            assert_that!(grant.grantees()).is_equal_to(&[Grantee::Public][..]);
+            assert_that!(grant.hierarchy_option()).is_equal_to(true);
+            assert_that!(grant.opt_grantor()).is_equal_to(Some(Grantor::CurrentRole));
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt("GRANT EXECUTE ON PROCEDURE routine_name(INT) TO grantee") {
+        Statement::GrantPrivilege(grant) => {
+            assert_that!(grant.privileges().object()).is_equal_to(&GrantObject::Routine(
+                SpecificRoutineDesignator::new(
+                    RoutineType::Procedure,
+                    RoutineName::new("routine_name"),
+                )
+                .with_data_type_list(vec![DataType::Int]),
+            ));
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_revoke_privilege() {
+    match verified_stmt(
+        "REVOKE GRANT OPTION FOR ALL PRIVILEGES ON SEQUENCE seq FROM grantee CASCADE",
+    ) {
+        Statement::RevokePrivilege(revoke) => {
+            assert_that!(revoke.opt_revoke_option_extension())
+                .is_equal_to(Some(RevokeOptionExtension::GrantOption));
+            assert_that!(revoke.privileges().object_privileges())
+                .is_equal_to(&ObjectPrivileges::AllPrivileges);
+            assert_that!(revoke.privileges().object())
+                .is_equal_to(&GrantObject::Sequence(SchemaQualifiedName::new("seq")));
+            assert_that!(revoke.drop_behavior()).is_equal_to(DropBehavior::Cascade);
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt(
+        "REVOKE HIERARCHY OPTION FOR UNDER ON TYPE type_name FROM PUBLIC GRANTED BY CURRENT_USER RESTRICT",
+    ) {
+        Statement::RevokePrivilege(revoke) => {
+            assert_that!(revoke.opt_revoke_option_extension())
+                .is_equal_to(Some(RevokeOptionExtension::HierarchyOption));
+            assert_that!(revoke.grantees()).is_equal_to(&[Grantee::Public][..]);
+            assert_that!(revoke.opt_grantor()).is_equal_to(Some(Grantor::CurrentUser));
+        }
+        _ => unreachable!(),
+    };
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "proptest")]
+use proptest::prelude::*;
+use sql_helper::ansi::parser::parse_statement;
+use sql_helper::ansi::strategies::{data_type, statement};
+
+proptest! {
+    #[test]
+    fn statement_display_round_trip(statement in statement()) {
+        let sql = statement.to_string();
+
+        prop_assert_eq!(parse_statement(sql.as_bytes()).map(|(_, parsed)| parsed), Ok(statement));
+    }
+
+    #[test]
+    fn statement_display_round_trip_with_semicolon(statement in statement()) {
+        let sql = format!("{statement};");
+
+        prop_assert_eq!(parse_statement(sql.as_bytes()).map(|(_, parsed)| parsed), Ok(statement));
+    }
+
+    #[test]
+    fn data_type_display_round_trip(data_type in data_type()) {
+        let sql = data_type.to_string();
+
+        prop_assert_eq!(
+            sql_helper::ansi::parser::data_types::data_type(sql.as_bytes()),
+            Ok((&b""[..], data_type))
+        );
+    }
+}
+
+/// Replaces the spaces of the SQL outside of quoted identifiers with the
+/// separator, also adding it around punctuation.
+fn spread(sql: &str, separator: &str) -> String {
+    let mut spread = String::new();
+    let mut quoted = false;
+
+    for chr in sql.chars() {
+        match chr {
+            '"' => {
+                quoted = !quoted;
+                spread.push(chr);
+            }
+            ' ' if !quoted => spread.push_str(separator),
+            '(' | ')' | ',' | '.' if !quoted => {
+                spread.push_str(separator);
+                spread.push(chr);
+                spread.push_str(separator);
+            }
+            _ => spread.push(chr),
+        }
+    }
+
+    spread
+}
+
+proptest! {
+    #[test]
+    fn statement_whitespace_tolerance(
+        statement in statement(),
+        separator in prop_oneof![
+            Just("  "),
+            Just("\n"),
+            Just(" \t\r\n "),
+            Just(" /* comment */ "),
+            Just("/* nested /* comment */ */"),
+            Just(" -- comment\n"),
+        ],
+    ) {
+        let sql = spread(&statement.to_string(), separator);
+
+        prop_assert_eq!(parse_statement(sql.as_bytes()).map(|(_, parsed)| parsed), Ok(statement));
+    }
+}
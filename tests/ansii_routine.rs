@@ -0,0 +1,106 @@
+use spectral::assert_that;
+use sql_helper::ansi::ast::common::{Literal, RoutineName, SchemaName, ValueSpecification};
+use sql_helper::ansi::Statement;
+use sql_helper::common::expr::DollarQuotedString;
+use sql_helper::common::Ident;
+
+use crate::common::verified_stmt;
+
+pub mod common;
+
+#[test]
+fn test_call() {
+    match verified_stmt("CALL routine_name()") {
+        Statement::Call(call) => {
+            assert_that!(call.routine_name()).is_equal_to(&RoutineName::new("routine_name"));
+            assert_that!(call.arguments()).is_equal_to(&[][..]);
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt("CALL schema_name.routine_name(-1.5, 'it''s', :param, NULL)") {
+        Statement::Call(call) => {
+            let expected_routine =
+                RoutineName::new("routine_name").with_schema_name(SchemaName::new("schema_name"));
+            assert_that!(call.routine_name()).is_equal_to(&expected_routine);
+            assert_that!(call.arguments()).is_equal_to(
+                &[
+                    ValueSpecification::Literal(Literal::Numeric("-1.5".to_string())),
+                    ValueSpecification::Literal(Literal::CharacterString("it's".to_string())),
+                    ValueSpecification::HostParameter(Ident::new(b"param")),
+                    ValueSpecification::Null,
+                ][..],
+            );
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_call_dollar_quoted_arguments() {
+    match verified_stmt("CALL run($$SELECT 'a';$$, $body$ $$ $body$)") {
+        Statement::Call(call) => {
+            assert_that!(call.arguments()).is_equal_to(
+                &[
+                    ValueSpecification::Literal(Literal::DollarQuotedString(
+                        DollarQuotedString::new("SELECT 'a';"),
+                    )),
+                    ValueSpecification::Literal(Literal::DollarQuotedString(
+                        DollarQuotedString::new(" $$ ").with_tag("body"),
+                    )),
+                ][..],
+            );
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_call_escape_string_argument() {
+    match verified_stmt(r"CALL log(E'it\'s\tdone')") {
+        Statement::Call(call) => {
+            let ValueSpecification::Literal(literal) = &call.arguments()[0] else {
+                unreachable!()
+            };
+            assert_that!(literal.opt_string_value()).is_equal_to(Some("it's\tdone"));
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_call_binary_string_arguments() {
+    match verified_stmt("CALL store(X'CAFE', B'100000001')") {
+        Statement::Call(call) => {
+            let bytes = call
+                .arguments()
+                .iter()
+                .map(|argument| match argument {
+                    ValueSpecification::Literal(Literal::Binary(binary_literal)) => {
+                        binary_literal.bytes()
+                    }
+                    _ => unreachable!(),
+                })
+                .collect::<Vec<_>>();
+            assert_that!(bytes).is_equal_to(vec![vec![0xca, 0xfe], vec![0x01, 0x01]]);
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn test_return() {
+    match verified_stmt("RETURN TRUE") {
+        Statement::Return(return_statement) => {
+            assert_that!(return_statement.return_value())
+                .is_equal_to(&ValueSpecification::Literal(Literal::True));
+        }
+        _ => unreachable!(),
+    };
+    match verified_stmt("RETURN total") {
+        Statement::Return(return_statement) => {
+            assert_that!(return_statement.return_value())
+                .is_equal_to(&ValueSpecification::SqlParameter(Ident::new(b"total")));
+        }
+        _ => unreachable!(),
+    };
+}
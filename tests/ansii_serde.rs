@@ -0,0 +1,54 @@
+#![cfg(feature = "serde")]
+
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use sql_helper::ansi::policy::PolicyChecker;
+use sql_helper::ansi::Statement;
+
+use crate::common::verified_stmt;
+
+pub mod common;
+
+#[test_case("CREATE SCHEMA catalog_name.schema_name AUTHORIZATION authorization_name")]
+#[test_case("DROP SCHEMA \"schema_name\" CASCADE")]
+#[test_case("DROP TABLE MODULE.table_name RESTRICT")]
+#[test_case("CREATE LOCAL TEMPORARY TABLE table_name (id INT, name VARCHAR(20 OCTETS))")]
+#[test_case("CREATE TABLE table_name (created TIMESTAMP(6) WITH TIME ZONE, doc CLOB(2K))")]
+fn test_serde_round_trip(input: &str) {
+    let stmt = verified_stmt(input);
+
+    let json = serde_json::to_string(&stmt).unwrap();
+    let deserialized: Statement = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(stmt, deserialized);
+}
+
+#[test]
+fn test_serde_ident_json() {
+    let stmt = verified_stmt("DROP TABLE \"table_name\" CASCADE");
+
+    let json = serde_json::to_value(&stmt).unwrap();
+
+    assert_eq!(
+        json["DropTable"]["table_name"]["name"],
+        serde_json::json!({"value": "table_name", "quote_style": "DoubleQuote"})
+    );
+}
+
+#[test]
+fn test_serde_policy_diagnostic_json() {
+    let diagnostics = PolicyChecker::new()
+        .check("DROP TABLE table_name RESTRICT")
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&diagnostics[0]).unwrap(),
+        serde_json::json!({
+            "rule": "destructive-drop",
+            "severity": "Error",
+            "message": "table `table_name` is dropped",
+            "span": {"start": 0, "end": 30},
+        })
+    );
+}
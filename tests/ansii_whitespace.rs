@@ -0,0 +1,76 @@
+use pretty_assertions::assert_str_eq;
+use sql_helper::ansi::parser::parse_statement;
+use test_case::test_case;
+
+#[test_case("CREATE TABLE tb (a BINARY ( 20 ))", "CREATE TABLE tb (a BINARY(20))")]
+#[test_case("CREATE TABLE tb (a VARCHAR  (20))", "CREATE TABLE tb (a VARCHAR(20))")]
+#[test_case(
+    "CREATE TABLE tb (a CHAR VARYING\n( 20 CHARACTERS ) )",
+    "CREATE TABLE tb (a CHAR VARYING(20 CHARACTERS))"
+)]
+#[test_case(
+    "CREATE TABLE tb (a DECIMAL (10 , 2))",
+    "CREATE TABLE tb (a DECIMAL(10, 2))"
+)]
+#[test_case("CREATE TABLE tb (a NUMERIC\t(10))", "CREATE TABLE tb (a NUMERIC(10))")]
+#[test_case(
+    "CREATE TABLE tb (a DECFLOAT ( 16 ))",
+    "CREATE TABLE tb (a DECFLOAT(16))"
+)]
+#[test_case(
+    "CREATE TABLE tb (a CLOB ( 2 K CHARACTERS ))",
+    "CREATE TABLE tb (a CLOB(2K CHARACTERS))"
+)]
+#[test_case("CREATE TABLE tb (a BLOB (2\nM))", "CREATE TABLE tb (a BLOB(2M))")]
+#[test_case(
+    "CREATE TABLE tb (a TIMESTAMP (3) WITH\nTIME   ZONE)",
+    "CREATE TABLE tb (a TIMESTAMP(3) WITH TIME ZONE)"
+)]
+#[test_case("CREATE TABLE tb (a TIME (3))", "CREATE TABLE tb (a TIME(3))")]
+#[test_case(
+    "CREATE\nGLOBAL\n\tTEMPORARY\nTABLE\ncat . sch . tb\n(\na\nINT\n,\nb\nDOUBLE\nPRECISION\n)",
+    "CREATE GLOBAL TEMPORARY TABLE cat.sch.tb (a INT, b DOUBLE PRECISION)"
+)]
+#[test_case(
+    "CREATE TABLE tb (a INT GENERATED\nBY  DEFAULT AS IDENTITY ( START WITH 1\nINCREMENT BY 2 ))",
+    "CREATE TABLE tb (a INT GENERATED BY DEFAULT AS IDENTITY (START WITH 1 INCREMENT BY 2))"
+)]
+#[test_case("DROP SCHEMA cat . sch\r\nCASCADE", "DROP SCHEMA cat.sch CASCADE")]
+#[test_case("DROP\tTABLE MODULE . tb\nRESTRICT ;", "DROP TABLE MODULE.tb RESTRICT")]
+#[test_case(
+    "ALTER TABLE tb ALTER\nCOLUMN a SET\nDATA\nTYPE INT",
+    "ALTER TABLE tb ALTER COLUMN a SET DATA TYPE INT"
+)]
+#[test_case(
+    "GRANT SELECT ( a , b ) ON FUNCTION sch . fn TO usr",
+    "GRANT SELECT (a, b) ON FUNCTION sch.fn TO usr"
+)]
+fn whitespace_between_tokens(input: &str, expected: &str) {
+    let (remaining, statement) = parse_statement(input.as_bytes()).unwrap();
+
+    assert!(remaining.is_empty());
+    assert_str_eq!(expected, statement.to_string());
+}
+
+#[test_case("CREATE TABLE tb /* comment */ (a INT)", "CREATE TABLE tb (a INT)")]
+#[test_case("CREATE TABLE tb -- comment\n(a INT)", "CREATE TABLE tb (a INT)")]
+#[test_case("CREATE TABLE tb (a INT) -- comment", "CREATE TABLE tb (a INT)")]
+#[test_case(
+    "CREATE TABLE tb (a INT, -- first\n b INT /* second */)",
+    "CREATE TABLE tb (a INT, b INT)"
+)]
+#[test_case(
+    "CREATE TABLE tb (a INT GENERATED/* a /* nested */ comment */ALWAYS AS IDENTITY)",
+    "CREATE TABLE tb (a INT GENERATED ALWAYS AS IDENTITY)"
+)]
+#[test_case(
+    "ALTER TABLE tb ADD a DOUBLE -- comment\n PRECISION",
+    "ALTER TABLE tb ADD COLUMN a DOUBLE PRECISION"
+)]
+#[test_case("DROP TABLE tb CASCADE /* comment */ ;", "DROP TABLE tb CASCADE")]
+fn comments_between_tokens(input: &str, expected: &str) {
+    let (remaining, statement) = parse_statement(input.as_bytes()).unwrap();
+
+    assert!(remaining.is_empty());
+    assert_str_eq!(expected, statement.to_string());
+}
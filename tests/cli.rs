@@ -0,0 +1,108 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use pretty_assertions::assert_str_eq;
+
+use sql_helper::ansi::json::WIRE_FORMAT_VERSION;
+use sql_helper::ansi::parser::parse_statement;
+use sql_helper::ansi::Statement;
+
+fn sql_helper(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_sql-helper"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn check_valid_script() {
+    let output = sql_helper(&["check"], "DROP TABLE a CASCADE;\nDROP SCHEMA b RESTRICT;");
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn check_reports_position() {
+    let output = sql_helper(&["check", "-"], "DROP TABLE a CASCADE;\n  SELECT 1;");
+
+    assert!(!output.status.success());
+    assert_str_eq!(
+        "-:2:3: invalid statement\n",
+        String::from_utf8(output.stderr).unwrap()
+    );
+}
+
+#[test]
+fn fmt() {
+    let output = sql_helper(
+        &["fmt"],
+        "create table tb (id int, name varchar(20));drop schema s cascade",
+    );
+
+    assert!(output.status.success());
+    assert_str_eq!(
+        "CREATE TABLE tb (\n    id INT,\n    name VARCHAR(20)\n);\nDROP SCHEMA s CASCADE;\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn fmt_keeps_header() {
+    let output = sql_helper(&["fmt"], "-- header\ndrop schema s cascade");
+
+    assert!(output.status.success());
+    assert_str_eq!(
+        "-- header\nDROP SCHEMA s CASCADE;\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn fmt_refuses_inline_comments() {
+    let output = sql_helper(&["fmt"], "create table tb (\n  id int -- the id\n)");
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_str_eq!(
+        "-:2:10: comment can't be formatted\n",
+        String::from_utf8(output.stderr).unwrap()
+    );
+}
+
+#[test]
+fn ast() {
+    let output = sql_helper(&["ast"], "DROP TABLE tb CASCADE; DROP SCHEMA sch RESTRICT");
+
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json[0]["version"], WIRE_FORMAT_VERSION);
+    assert_eq!(
+        json[0]["statement"]["DropTable"]["drop_behavior"],
+        "Cascade"
+    );
+    assert_eq!(
+        Statement::from_json(&json[1].to_string()),
+        Ok(parse_statement(b"DROP SCHEMA sch RESTRICT").unwrap().1)
+    );
+}
+
+#[test]
+fn unknown_command() {
+    let output = sql_helper(&["lint"], "");
+
+    assert_eq!(output.status.code(), Some(2));
+}
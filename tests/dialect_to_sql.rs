@@ -0,0 +1,45 @@
+#![cfg(all(feature = "mssql", feature = "mysql"))]
+
+use pretty_assertions::assert_str_eq;
+use test_case::test_case;
+
+use sql_helper::ansi::dialect::AnsiDialect;
+use sql_helper::ansi::parser::parse_statement;
+use sql_helper::common::dialect::Dialect;
+use sql_helper::mssql::dialect::MsSqlDialect;
+use sql_helper::mysql::dialect::MySqlDialect;
+
+#[test_case(
+    "CREATE TABLE \"my table\" (id INT, \"name\" VARCHAR(20))",
+    &AnsiDialect,
+    "CREATE TABLE \"my table\" (id INT, \"name\" VARCHAR(20))" ;
+    "ansi"
+)]
+#[test_case(
+    "CREATE TABLE \"my table\" (id INT, \"name\" VARCHAR(20))",
+    &MySqlDialect,
+    "CREATE TABLE `my table` (id INT, `name` VARCHAR(20))" ;
+    "mysql"
+)]
+#[test_case(
+    "CREATE TABLE \"my table\" (id INT, \"name\" VARCHAR(20))",
+    &MsSqlDialect,
+    "CREATE TABLE [my table] (id INT, [name] VARCHAR(20))" ;
+    "mssql"
+)]
+#[test_case(
+    "CREATE TABLE sch.tb (\"key\" INT, \"a`b\" INT)",
+    &MySqlDialect,
+    "CREATE TABLE sch.tb (`key` INT, `a``b` INT)" ;
+    "mysql escaped"
+)]
+#[test_case(
+    "DROP SCHEMA cat.identity RESTRICT",
+    &MsSqlDialect,
+    "DROP SCHEMA cat.[identity] RESTRICT" ;
+    "mssql reserved word"
+)]
+fn test_to_sql(input: &str, dialect: &dyn Dialect, expected: &str) {
+    let (_, statement) = parse_statement(input.as_ref()).unwrap();
+    assert_str_eq!(expected, statement.to_sql(dialect));
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "json")]
+
+use pretty_assertions::{assert_eq, assert_str_eq};
+use test_case::test_case;
+
+use sql_helper::ansi::Statement;
+
+use crate::common::verified_stmt;
+
+pub mod common;
+
+#[test_case("CREATE SCHEMA catalog_name.schema_name AUTHORIZATION authorization_name")]
+#[test_case("DROP TABLE MODULE.table_name RESTRICT")]
+#[test_case("CREATE LOCAL TEMPORARY TABLE table_name (id INT, name VARCHAR(20 OCTETS))")]
+#[test_case("ALTER TABLE tb ALTER COLUMN id SET DATA TYPE DECIMAL(10, 2)")]
+#[test_case("CREATE TABLE tb (id INT) ENGINE=InnoDB WITH (fillfactor=70)" ; "table options")]
+#[test_case("CREATE TABLE tb (id INT) ENGINE=InnoDB PARTITION BY HASH (id) PARTITIONS 4" ; "partition by")]
+#[test_case("CREATE TABLE tb (id INT AUTO_INCREMENT, big_id BIGSERIAL)" ; "identity syntax")]
+#[test_case("CREATE TABLE tb (id INT CONSTRAINT pk PRIMARY KEY, a INT NOT NULL REFERENCES o (x))" ; "column constraints")]
+fn test_json_round_trip(input: &str) {
+    let stmt = verified_stmt(input);
+
+    assert_eq!(Ok(stmt.clone()), Statement::from_json(&stmt.to_json()));
+}
+
+/// Pins the version 1 format. If this test breaks, the wire format changed, and
+/// `WIRE_FORMAT_VERSION` must be bumped.
+#[test]
+fn test_json_wire_format_v1() {
+    let stmt = verified_stmt(
+        "CREATE TABLE sch.\"tb\" (id INT GENERATED ALWAYS AS IDENTITY (START WITH 1), name VARCHAR(10))",
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&stmt.to_json()).unwrap();
+
+    assert_str_eq!(
+        serde_json::to_string_pretty(&json).unwrap(),
+        r#"{
+  "statement": {
+    "CreateTable": {
+      "opt_partition_by": null,
+      "opt_table_scope": null,
+      "partition_by_position": 0,
+      "table_contents_source": {
+        "TableElementList": {
+          "element_list": [
+            {
+              "ColumnDefinition": {
+                "column_constraint_definitions": [],
+                "column_name": {
+                  "quote_style": "None",
+                  "value": "id"
+                },
+                "opt_data_type": "Int",
+                "opt_identity_column_specification": {
+                  "identity_generation": "Always",
+                  "opt_increment_by": null,
+                  "opt_start_with": 1,
+                  "syntax": "Standard"
+                }
+              }
+            },
+            {
+              "ColumnDefinition": {
+                "column_constraint_definitions": [],
+                "column_name": {
+                  "quote_style": "None",
+                  "value": "name"
+                },
+                "opt_data_type": {
+                  "Varchar": {
+                    "length": 10,
+                    "opt_units": null
+                  }
+                },
+                "opt_identity_column_specification": null
+              }
+            }
+          ]
+        }
+      },
+      "table_name": {
+        "name": {
+          "quote_style": "DoubleQuote",
+          "value": "tb"
+        },
+        "opt_local_or_schema": {
+          "Schema": {
+            "name": {
+              "quote_style": "None",
+              "value": "sch"
+            },
+            "opt_catalog_name": null
+          }
+        }
+      }
+    }
+  },
+  "version": 1
+}"#
+    );
+}
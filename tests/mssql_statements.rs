@@ -0,0 +1,66 @@
+#![cfg(feature = "mssql")]
+
+use pretty_assertions::{assert_eq, assert_str_eq};
+use test_case::test_case;
+
+use sql_helper::mssql::parser::{parse_batches, parse_statement};
+use sql_helper::mssql::Statement;
+
+/// Tests if the parsed `T-SQL` statement serialization is the same as the
+/// original input.
+#[track_caller]
+fn verified_mssql_stmt(input: &str) -> Statement {
+    let (_, stmt) = parse_statement(input.as_ref()).unwrap();
+    assert_str_eq!(input, stmt.to_string());
+    stmt
+}
+
+#[test_case("CREATE TABLE users (id INT)")]
+#[test_case("CREATE TABLE [dbo].[users] (id INT IDENTITY(1, 1), name NVARCHAR(MAX))")]
+#[test_case("CREATE TABLE [db].[dbo].[my table] ([user id] BIGINT IDENTITY, active BIT)")]
+#[test_case("CREATE TABLE logs (created DATETIME2(7), payload VARBINARY(MAX), level TINYINT)")]
+fn test_create_table(input: &str) {
+    verified_mssql_stmt(input);
+}
+
+#[test_case("DROP TABLE users")]
+#[test_case("DROP TABLE IF EXISTS [dbo].[users]")]
+#[test_case("DROP TABLE [srv].[db].[dbo].[users]")]
+fn test_drop_table(input: &str) {
+    verified_mssql_stmt(input);
+}
+
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+#[test_case("CREATE TABLE [users (id INT)")]
+#[test_case("CREATE TABLE a.b.c.d.e (id INT)")]
+#[test_case("DROP TABLE")]
+fn test_statement_should_fail(input: &str) {
+    verified_mssql_stmt(input);
+}
+
+#[test]
+fn test_parse_batches() {
+    let script = "CREATE TABLE [dbo].[a] (id INT)\n\
+                  GO\n\
+                  DROP TABLE [dbo].[a];\n\
+                  DROP TABLE IF EXISTS b\n\
+                  go 2\n";
+
+    let (remaining, batches) = parse_batches(script.as_ref()).unwrap();
+
+    assert!(remaining.is_empty());
+    let batches: Vec<Vec<String>> = batches
+        .iter()
+        .map(|batch| batch.iter().map(ToString::to_string).collect())
+        .collect();
+    assert_eq!(
+        vec![
+            vec!["CREATE TABLE [dbo].[a] (id INT)".to_string()],
+            vec![
+                "DROP TABLE [dbo].[a]".to_string(),
+                "DROP TABLE IF EXISTS b".to_string()
+            ],
+        ],
+        batches
+    );
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "mysql")]
+
+use pretty_assertions::{assert_eq, assert_str_eq};
+use test_case::test_case;
+
+use sql_helper::ansi::ast::common::IdentitySyntax;
+use sql_helper::mysql::ast::create_table::IndexKind;
+use sql_helper::mysql::parser::parse_statement;
+use sql_helper::mysql::Statement;
+
+/// Tests if the parsed `MySQL` statement serialization is the same as the
+/// original input.
+#[track_caller]
+fn verified_mysql_stmt(input: &str) -> Statement {
+    let (_, stmt) = parse_statement(input.as_ref()).unwrap();
+    assert_str_eq!(input, stmt.to_string());
+    stmt
+}
+
+#[test_case("CREATE TABLE users (id INT)")]
+#[test_case("CREATE TABLE `db`.`users` (`id` INT(11) UNSIGNED NOT NULL, PRIMARY KEY (`id`))")]
+#[test_case("CREATE TABLE t (a INT NULL DEFAULT NULL, b VARCHAR(10) NOT NULL DEFAULT 'x')")]
+#[test_case("CREATE TABLE t (created TIMESTAMP NULL DEFAULT CURRENT_TIMESTAMP(6))")]
+#[test_case("CREATE TABLE t (a INT DEFAULT (1 + 1))" ; "expression default")]
+#[test_case(
+    "CREATE TABLE t (name VARCHAR(20) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin NOT NULL)" ;
+    "character set and collation"
+)]
+#[test_case(
+    "CREATE TABLE t (updated TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP)" ;
+    "on update"
+)]
+#[test_case("CREATE TABLE t (a INT COMMENT 'it''s the a')" ; "comment")]
+#[test_case("CREATE TABLE t (a BINARY(2) DEFAULT 0x00FF, b BINARY(1) DEFAULT X'0a')" ; "hexadecimal defaults")]
+#[test_case("CREATE TABLE t (a INT DEFAULT B'1010', b INT DEFAULT 0b1)" ; "bit defaults")]
+#[test_case(r"CREATE TABLE t (a VARCHAR(10) DEFAULT 'it\'s' COMMENT 'C:\\dir')" ; "backslash escapes")]
+#[test_case("CREATE TABLE t (id BIGINT UNSIGNED NOT NULL AUTO_INCREMENT COMMENT 'key')" ; "auto increment")]
+#[test_case("CREATE TABLE t (a INT, b VARCHAR(100), KEY idx_a (a), FULLTEXT KEY ft_b (b))" ; "indexes")]
+#[test_case("CREATE TABLE t (a INT, UNIQUE INDEX uq (a) USING BTREE) ENGINE=InnoDB" ; "table options")]
+fn test_create_table(input: &str) {
+    verified_mysql_stmt(input);
+}
+
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+#[test_case("CREATE TABLE a.b.c (id INT)")]
+#[test_case("CREATE TABLE t ()")]
+#[test_case("CREATE TABLE t (KEY (a)" ; "unbalanced parentheses")]
+fn test_statement_should_fail(input: &str) {
+    verified_mysql_stmt(input);
+}
+
+#[test]
+fn test_parse_mysqldump_create_table() {
+    let dump = "CREATE TABLE `posts` (\n\
+                \x20 `id` bigint unsigned NOT NULL AUTO_INCREMENT,\n\
+                \x20 `author_id` int NOT NULL,\n\
+                \x20 `title` varchar(255) CHARACTER SET latin1 COLLATE latin1_bin NOT NULL DEFAULT '' COMMENT 'Post title',\n\
+                \x20 `body` varchar(4000),\n\
+                \x20 `created_at` timestamp NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,\n\
+                \x20 PRIMARY KEY (`id`),\n\
+                \x20 UNIQUE KEY `uq_title` (`title`),\n\
+                \x20 KEY `idx_author` (`author_id`),\n\
+                \x20 FULLTEXT KEY `ft_body` (`body`)\n\
+                ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;";
+
+    let (remaining, Statement::CreateTable(create_table)) = parse_statement(dump.as_ref()).unwrap();
+
+    assert!(remaining.is_empty());
+    assert_eq!(create_table.column_definitions().len(), 5);
+    assert!(create_table.column_definitions()[0]
+        .opt_identity_column_specification()
+        .is_some_and(|identity| identity.syntax() == IdentitySyntax::AutoIncrement));
+    assert_eq!(
+        create_table
+            .index_definitions()
+            .iter()
+            .map(|index_definition| index_definition.index_kind())
+            .collect::<Vec<_>>(),
+        vec![
+            IndexKind::PrimaryKey,
+            IndexKind::Unique,
+            IndexKind::Plain,
+            IndexKind::Fulltext
+        ]
+    );
+    assert_str_eq!(
+        "CREATE TABLE `posts` (`id` BIGINT UNSIGNED NOT NULL AUTO_INCREMENT, `author_id` INT NOT NULL, \
+         `title` VARCHAR(255) CHARACTER SET latin1 COLLATE latin1_bin NOT NULL DEFAULT '' \
+         COMMENT 'Post title', `body` VARCHAR(4000), \
+         `created_at` TIMESTAMP NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP, \
+         PRIMARY KEY (`id`), \
+         UNIQUE KEY `uq_title` (`title`), KEY `idx_author` (`author_id`), \
+         FULLTEXT KEY `ft_body` (`body`)) \
+         ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci",
+        create_table.to_string()
+    );
+}
@@ -0,0 +1,33 @@
+use test_case::test_case;
+
+use crate::common::verified_stmt;
+
+pub mod common;
+
+#[test_case("CREATE SCHEMA sch", true, false)]
+#[test_case("DROP SCHEMA sch RESTRICT", true, true)]
+#[test_case("CREATE TABLE tb (id INT)", true, false)]
+#[test_case("DROP TABLE tb CASCADE", true, true)]
+#[test_case("ALTER TABLE tb ADD COLUMN id INT", true, false)]
+#[test_case("ALTER TABLE tb ALTER COLUMN id DROP IDENTITY", true, false)]
+#[test_case("ALTER TABLE tb ALTER COLUMN id SET DATA TYPE SMALLINT", true, true)]
+#[test_case("ALTER TABLE tb DROP COLUMN id CASCADE", true, true)]
+#[test_case("ALTER TABLE tb RENAME COLUMN a TO b", true, false)]
+#[test_case("ALTER TABLE a RENAME TO b", true, false)]
+#[test_case("OPEN c", false, false)]
+#[test_case("FETCH c INTO :a", false, false)]
+#[test_case("CLOSE c", false, false)]
+#[test_case("CALL proc()", false, false)]
+#[test_case("RETURN 1", false, false)]
+#[test_case("UPDATE tb SET id = 1 WHERE CURRENT OF c", false, false)]
+#[test_case("DELETE FROM tb WHERE CURRENT OF c", false, true)]
+#[test_case("EXPLAIN DELETE FROM tb WHERE CURRENT OF c", false, false)]
+#[test_case("EXPLAIN ANALYZE DELETE FROM tb WHERE CURRENT OF c", false, true)]
+#[test_case("GRANT SELECT ON tb TO usr", false, false)]
+#[test_case("REVOKE SELECT ON tb FROM usr CASCADE", false, false)]
+fn test_statement_metadata(input: &str, ddl: bool, destructive: bool) {
+    let stmt = verified_stmt(input);
+
+    assert_eq!(ddl, stmt.is_ddl());
+    assert_eq!(destructive, stmt.is_destructive());
+}